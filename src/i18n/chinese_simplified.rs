@@ -26,7 +26,7 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
             ("Hide Index", "隐藏索引"),
             ("Unhide Index", "显示索引"),
             ("comment expects a value.", "comment 需要一个值。"),
-            ("explain must be followed by find(...).", "explain 必须跟在 find(...) 之后。"),
+            ("explain must be followed by find(...) or aggregate(...).", "explain 必须跟在 find(...) 或 aggregate(...) 之后。"),
             ("finish does not take any arguments.", "finish 不接受任何参数。"),
             ("No methods are supported after finish().", "finish() 之后不支持任何方法。"),
             ("Edit Index...", "编辑索引..."),
@@ -301,10 +301,6 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "数据库 \"{}\" 中集合 \"{}\" 的索引 \"{}\" 将被删除。此操作无法撤销。",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "输入索引名称 \"{}\" 以确认删除索引。",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne 需要一个 filter、一个 update 和可选的 options 对象。",
@@ -346,8 +342,8 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
                 "deleteMany 接受一个 filter 和可选的 options 对象。",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "方法 {} 不受支持。可用方法：find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex。",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "方法 {} 不受支持。可用方法：find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex。",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -724,10 +720,6 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
                 "The second argument to Code must be an object.",
                 "Code 的第二个参数必须是对象。",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "输入准确的索引名以确认。",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "输入准确的集合名以确认。",
@@ -916,8 +908,8 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
                 "watch 的 options 中不支持参数 '{}'。不支持 resume token。",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "aggregate 的 options 中不支持参数 '{}'。允许：allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor。",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "aggregate 的 options 中不支持参数 '{}'。允许：allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor。",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1047,6 +1039,330 @@ pub(crate) fn chinese_simplified_map() -> &'static HashMap<&'static str, &'stati
                 "Pipeline element at index {} must be a JSON object.",
                 "pipeline 在索引 {} 处的元素必须是 JSON 对象。",
             ),
+            ("Select for Compare", "选择以比较"),
+            ("Compare with Selected", "与所选比较"),
+            ("Compare Documents", "比较文档"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "新增：{}，删除：{}，修改：{}，未变：{}"),
+            ("Documents are identical", "文档完全相同"),
+            ("Field", "字段"),
+            ("Show unchanged fields", "显示未更改的字段"),
+            ("Document without _id", "无 _id 的文档"),
+            ("Connections: {} of {}", "连接：{} / {}"),
+            ("Tabs: {}", "标签页：{}"),
+            ("Theme: {}", "主题：{}"),
+            ("Language: {}", "语言：{}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "跳过值 {} 超出了 {} 个匹配文档。最后一页从跳过 {} 开始。"),
+            ("Sample Documents", "抽样文档"),
+            ("Query Log", "查询日志"),
+            ("Filter queries", "筛选查询"),
+            ("Clear", "清除"),
+            ("OK ({})", "成功 ({})"),
+            ("Error: {}", "错误：{}"),
+            ("No queries executed yet", "尚未执行任何查询"),
+            ("Invalid port in host address '{}'", "主机地址 '{}' 中的端口无效"),
+            ("Invalid host address '{}'", "无效的主机地址 '{}'"),
+            ("Direct connection supports only a single host", "直接连接仅支持单个主机"),
+            ("SSH tunnel supports only a single host", "SSH 隧道仅支持单个主机"),
+            ("Optional", "可选"),
+            ("Replica set name", "副本集名称"),
+            ("Replica set name requires the ReplicaSet connection type", "副本集名称需要 ReplicaSet 连接类型"),
+            ("Server default", "服务器默认"),
+            ("Read concern", "读关注"),
+            ("Write concern (w)", "写关注 (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "写关注必须是数字、\"majority\" 或标签集名称"),
+            ("Preview", "预览"),
+            ("Effective query:", "实际查询："),
+            ("Skip and limit do not apply to this operation", "skip 和 limit 不适用于此操作"),
+            ("Query timeout", "查询超时"),
+            ("0 = no timeout", "0 = 不超时"),
+            ("Large document ({}), expand to load", "大型文档（{}），展开以加载"),
+            ("Deeply nested document ({} levels), expand to load", "深度嵌套文档（{} 层），展开以加载"),
+            ("No methods are supported after explain().aggregate(...).", "explain().aggregate(...) 之后不支持任何方法。"),
+            ("Method '{}' is not supported after aggregate(...).", "aggregate(...) 之后不支持方法 '{}'。"),
+            ("Sidebar", "侧边栏"),
+            ("Pin Tab", "固定标签页"),
+            ("Unpin Tab", "取消固定标签页"),
+            ("Reuse the current unpinned tab when opening a collection", "打开集合时复用当前未固定的标签页"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "位置运算符 '$[{}]' 在 arrayFilters 中没有匹配的条目。"),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "标识符 '{}' 的 arrayFilters 条目未在更新中使用。"),
+            ("Show relative time next to dates", "在日期旁显示相对时间"),
+            ("just now", "刚刚"),
+            ("{} s", "{} 秒"),
+            ("{} min", "{} 分钟"),
+            ("{} h", "{} 小时"),
+            ("{} d", "{} 天"),
+            ("{} mo", "{} 个月"),
+            ("{} y", "{} 年"),
+            ("{} ago", "{}前"),
+            ("in {}", "{}后"),
+            ("No documents matched the query.", "没有与查询匹配的文档。"),
+            ("No distinct values found.", "未找到不同的值。"),
+            ("Adjust the filter or pipeline and run the query again.", "请调整过滤条件或管道后重新运行查询。"),
+            ("Copy _id", "复制 _id"),
+            ("Enter the exact index name or key specification to confirm.", "请输入准确的索引名称或键规范以确认。"),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "输入索引名称“{}”或其键规范以确认删除。"),
+            ("Index Builder", "索引构建器"),
+            ("Index Builder...", "索引构建器..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "为集合“{}”（数据库“{}”）创建索引。"),
+            ("Add Field", "添加字段"),
+            ("Index name", "索引名称"),
+            ("Generated from fields", "根据字段生成"),
+            ("TTL (seconds)", "TTL（秒）"),
+            ("No expiration", "不过期"),
+            ("Partial filter", "部分过滤器"),
+            ("Unique", "唯一"),
+            ("Sparse", "稀疏"),
+            ("Open in Editor", "在编辑器中打开"),
+            ("Choose at least one field for the index.", "请至少为索引选择一个字段。"),
+            ("Field '{}' is used more than once.", "字段 '{}' 被使用了多次。"),
+            ("TTL must be a non-negative number of seconds.", "TTL 必须是非负的秒数。"),
+            ("TTL indexes must have exactly one field.", "TTL 索引必须只有一个字段。"),
+            ("Loading existing indexes...", "正在加载现有索引..."),
+            ("Existing indexes are unavailable: {}", "现有索引不可用：{}"),
+            ("Existing indexes", "现有索引"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "索引“{}”已使用此键模式。仍然可以创建该索引。"),
+            ("Export Diagnostic Report", "导出诊断报告"),
+            ("Run", "运行"),
+            ("Confirm Aggregation Output", "确认聚合输出"),
+            ("The pipeline writes its results with {} into {}.", "该管道通过 {} 将结果写入 {}。"),
+            ("The target differs from the source collection {}.", "目标与源集合 {} 不同。"),
+            ("$out replaces all documents in the target collection.", "$out 会替换目标集合中的所有文档。"),
+            ("$merge inserts or updates documents in the target collection.", "$merge 会在目标集合中插入或更新文档。"),
+            ("Open Response in External Editor", "在外部编辑器中打开响应"),
+            ("Tab title", "标签页标题"),
+            ("Collection", "集合"),
+            ("Database / Collection", "数据库 / 集合"),
+            ("Connection / Collection", "连接 / 集合"),
+            ("Connection / Database / Collection", "连接 / 数据库 / 集合"),
+            ("Ask before closing tabs with edited queries that were not run", "关闭包含已编辑但未运行查询的标签页前询问"),
+            ("Close Anyway", "仍然关闭"),
+            ("Close Tab", "关闭标签页"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "标签页“{}”中的查询已编辑但未运行。关闭标签页将丢弃它。"),
+            ("Rename Tab", "重命名标签页"),
+            ("Prefill new tabs with a find query", "新标签页预填 find 查询"),
+            ("Count Documents", "统计文档数"),
+            ("Estimated Count", "估算数量"),
+            ("Counting documents in {}...", "正在统计 {} 中的文档..."),
+            ("{}: about {} documents (estimated)", "{}：约 {} 个文档（估算）"),
+            ("{}: {} documents", "{}：{} 个文档"),
+            ("{}: unexpected count result", "{}：意外的计数结果"),
+            ("Failed to count documents in {}: {}", "统计 {} 中的文档失败：{}"),
+            ("Database name cannot be empty.", "数据库名称不能为空。"),
+            ("Database name cannot contain null characters.", "数据库名称不能包含空字符。"),
+            ("Database name cannot contain the character '{}'.", "数据库名称不能包含字符 '{}'。"),
+            ("Database name must be at most {} bytes long.", "数据库名称长度不能超过 {} 字节。"),
+            ("Collection name cannot contain null characters.", "集合名称不能包含空字符。"),
+            ("Collection name cannot contain the character '{}'.", "集合名称不能包含字符 '{}'。"),
+            ("Collection name cannot start with 'system.'.", "集合名称不能以 'system.' 开头。"),
+            ("Namespace '{}' exceeds {} bytes.", "命名空间 '{}' 超过 {} 字节。"),
+            ("readConcern requires a document with a level.", "readConcern 需要一个包含 level 的文档。"),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern 必须是仅包含字符串字段 'level' 的文档。"),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "不支持的 readConcern 级别 '{}'。允许：local、majority、linearizable、available、snapshot。"),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "find(...) 之后不支持方法 '{}'。支持：sort、hint、limit、skip、maxTimeMS、readConcern、comment、explain、count、countDocuments。"),
+            ("Pipeline stage at index {} is empty.", "索引 {} 处的管道阶段为空。"),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "索引 {} 处的管道阶段必须以 '$' 开头，实际为 '{}'。请将表达式包装在 $match 等阶段中。"),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "索引 {} 处的管道阶段必须只包含一个操作符，实际为：{}。"),
+            ("Date Format Preview", "日期格式预览"),
+            ("Date field", "日期字段"),
+            ("Sample date", "示例日期"),
+            ("Format", "格式"),
+            ("Timezone", "时区"),
+            ("Result", "结果"),
+            ("Copy Expression", "复制表达式"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "格式符：%Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%。"),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "请以 ISO 8601 格式输入示例日期，例如 2024-03-15T13:45:30Z。"),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "不支持的时区 '{}'。请使用 UTC 或偏移量，例如 +03:00。"),
+            ("Unmatched '%' at the end of the format string.", "格式字符串末尾有未匹配的 '%'。"),
+            ("Invalid format character '%{}' in the format string.", "格式字符串中的格式字符 '%{}' 无效。"),
+            ("Value must be a valid base64 string.", "值必须是有效的 base64 字符串。"),
+            ("Generic", "通用"),
+            ("Function", "函数"),
+            ("Binary (old)", "Binary（旧）"),
+            ("UUID (old)", "UUID（旧）"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "已加密"),
+            ("Column", "列"),
+            ("Sensitive", "敏感"),
+            ("User defined", "用户定义"),
+            ("Reserved", "保留"),
+            ("Subtype", "子类型"),
+            ("Input Format", "输入格式"),
+            ("Saving will change the field type from {} to {}.", "保存会将字段类型从 {} 更改为 {}。"),
+            ("Press Save Anyway to confirm.", "点击“仍然保存”以确认。"),
+            ("Save Anyway", "仍然保存"),
+            ("Group connections by host in the sidebar", "在侧边栏中按主机分组连接"),
+            ("Compact sidebar density", "紧凑侧边栏密度"),
+            ("Test All", "全部测试"),
+            ("OK ({} ms)", "成功（{} 毫秒）"),
+            ("Failed after {} ms: {}", "{} 毫秒后失败：{}"),
+            ("Skipped: the password is requested on connect", "已跳过：连接时才会询问密码"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "_id 字段无法原地修改。请启用“允许修改 _id”以替换文档。"),
+            ("Allow changing _id (the document is re-inserted, not updated)", "允许修改 _id（文档将被重新插入，而不是更新）"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "_id 已更改。保存时将插入新文档并删除原文档；这不是原地更新。按“替换文档”确认。"),
+            ("Replace Document", "替换文档"),
+            ("Rollback failed: {}", "回滚失败：{}"),
+            ("Drop and recreate the collection (faster on large collections)", "删除并重新创建集合（对大型集合更快）"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "集合将被删除并以相同选项重新创建，然后重建其索引。其他客户端可能会短暂看不到该集合。"),
+            ("Collection \"{}\" not found.", "未找到集合“{}”。"),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "只有普通集合可以通过重新创建来清空。请改用常规删除。"),
+            ("Text Search...", "文本搜索..."),
+            ("Text Search", "文本搜索"),
+            ("Checking text indexes...", "正在检查文本索引..."),
+            ("This collection has no text index. Create one to use text search.", "该集合没有文本索引。请先创建一个以使用文本搜索。"),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "在集合“{}”中使用文本索引“{}”进行搜索。结果按相关性排序。"),
+            ("Search terms", "搜索词"),
+            ("Read-only (block all write operations)", "只读（阻止所有写操作）"),
+            ("Read-only", "只读"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "连接“{}”为只读。写操作已被阻止。"),
+            ("Color accent", "强调色"),
+            ("None", "无"),
+            ("Reconnect automatically when running a query on a closed connection", "在已关闭的连接上运行查询时自动重新连接"),
+            ("Validation Rules...", "验证规则..."),
+            ("Validation Rules", "验证规则"),
+            ("Loading validation rules...", "正在加载验证规则..."),
+            ("Applying validation rules...", "正在应用验证规则..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "编辑 validator、validationLevel 和 validationAction。保存时通过 collMod 应用；空的 validator 会移除验证。"),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "不支持的字段 \"{}\"。只能修改 validator、validationLevel 和 validationAction。"),
+            ("The validator must be a document.", "validator 必须是文档。"),
+            ("Insert Document...", "插入文档..."),
+            ("By name", "按名称"),
+            ("By size (largest first)", "按大小（最大优先）"),
+            ("Sort databases and collections", "数据库和集合排序"),
+            ("Copy Results as Markdown Table", "将结果复制为 Markdown 表格"),
+            ("The results are not a list of documents and cannot be shown as a table.", "结果不是文档列表，无法显示为表格。"),
+            ("All fields", "全部字段"),
+            ("Preset name cannot be empty.", "预设名称不能为空。"),
+            ("Projection Preset", "投影预设"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "没有自带投影的 find 查询只返回这些字段。使用已有名称保存将替换该预设。"),
+            ("Fields", "字段"),
+            ("summary", "摘要"),
+            ("Invalid field path \"{}\".", "无效的字段路径 \"{}\"。"),
+            ("Enter at least one field to project.", "请至少输入一个要投影的字段。"),
+            ("Refresh All Connections", "刷新所有连接"),
+            ("Copy Selected Rows", "复制所选行"),
+            ("Shortcuts", "快捷键"),
+            ("Send query", "发送查询"),
+            ("Rerun last query", "重新运行上次查询"),
+            ("Close tab", "关闭标签页"),
+            ("Next tab", "下一个标签页"),
+            ("Previous tab", "上一个标签页"),
+            ("Toggle sidebar", "切换侧边栏"),
+            ("Table view", "表格视图"),
+            ("Text view", "文本视图"),
+            ("Shortcut must end with a key.", "快捷键必须以按键结尾。"),
+            ("Unknown modifier \"{}\".", "未知的修饰键 \"{}\"。"),
+            ("Unknown key \"{}\".", "未知的按键 \"{}\"。"),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" 和 \"{}\" 使用了相同的快捷键。"),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "将 Ctrl、Shift 或 Alt 与按键组合，例如 Ctrl+Shift+Tab。在 macOS 上 Ctrl 表示 Cmd。"),
+            ("Conflicts with \"{}\".", "与 \"{}\" 冲突。"),
+            ("Default Shortcuts", "默认快捷键"),
+            ("Show Side by Side with Active Tab", "与当前标签页并排显示"),
+            ("Synchronize scrolling", "同步滚动"),
+            ("Close Side by Side", "关闭并排视图"),
+            ("Render at most", "最多显示"),
+            ("0 = no cap", "0 = 不限制"),
+            ("documents per result", "个文档/每个结果"),
+            ("Showing the first {} of {} returned items.", "显示前 {} 项（共返回 {} 项）。"),
+            ("Find This Document", "查找此文档"),
+            ("Up to stage {} of {}", "执行到第 {} 阶段（共 {} 个）"),
+            ("All {} stages", "全部 {} 个阶段"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "不支持 bulkWrite 操作 '{}'（索引 {}）。允许：insertOne、updateOne、updateMany、replaceOne、deleteOne、deleteMany。"),
+            ("Export Results to JSON...", "将结果导出为 JSON..."),
+            ("Export Results to CSV...", "将结果导出为 CSV..."),
+            ("Results saved to {}", "结果已保存到 {}"),
+            ("Failed to export results: {}", "导出结果失败：{}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} 个标签页未恢复，因为其连接已不存在。"),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "未知的 explain 详细级别 '{}'。允许：queryPlanner、executionStats、allPlansExecution。"),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain 需要详细级别字符串：queryPlanner、executionStats 或 allPlansExecution。"),
+            ("Append Array Element...", "追加数组元素..."),
+            ("Delete Array Element", "删除数组元素"),
+            ("A new element will be appended to the array", "将向数组追加一个新元素"),
+            ("Failed to delete the array element: {}", "删除数组元素失败：{}"),
+            ("Read", "读取"),
+            ("Read preference", "读取偏好"),
+            ("Secondary read preferences keep analysis queries off the primary.", "从节点读取偏好可让分析查询不占用主节点。"),
+            ("Use SRV record (mongodb+srv://)", "使用 SRV 记录 (mongodb+srv://)"),
+            ("From DNS", "来自 DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "SRV 连接从 DNS 获取端口；请将端口留空"),
+            ("An SRV connection takes a single host name without a port", "SRV 连接只接受一个不带端口的主机名"),
+            ("SSH tunnel cannot be used with an SRV connection", "SSH 隧道不能与 SRV 连接一起使用"),
+            ("Insert Template...", "插入模板..."),
+            ("Insert Document", "插入文档"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "输入新文档。如果文档未设置 _id，将自动生成。"),
+            ("Inserting document...", "正在插入文档..."),
+            ("Inserted document with _id {}", "已插入文档，_id 为 {}"),
+            ("Highlight query syntax in the editor", "在编辑器中高亮查询语法"),
+            ("Query cancelled. The server may still finish the operation.", "查询已取消。服务器仍可能完成该操作。"),
+            ("Filter by key or value", "按键或值筛选"),
+            ("Match case", "区分大小写"),
+            ("{} matches", "{} 个匹配"),
+            ("TLS", "TLS"),
+            ("CA file", "CA 文件"),
+            ("Path to PEM file", "PEM 文件路径"),
+            ("Client certificate and key file", "客户端证书和密钥文件"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "允许无效的证书和主机名 (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "设置其中任一选项都会为连接启用 TLS。"),
+            ("TLS CA file not found: {}", "未找到 TLS CA 文件：{}"),
+            ("TLS certificate key file not found: {}", "未找到 TLS 证书密钥文件：{}"),
+            ("Pin to Top", "置顶"),
+            ("Unpin Collection", "取消置顶集合"),
+            ("Expand All opens at most", "“全部展开”最多展开"),
+            ("0 = all levels", "0 = 所有层级"),
+            ("levels", "层"),
+            ("Expand All", "全部展开"),
+            ("Collapse All", "全部折叠"),
+            ("Saved Queries", "已保存的查询"),
+            ("Save Query", "保存查询"),
+            ("Edit Saved Query", "编辑已保存的查询"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "没有已保存的查询。在集合标签页中使用“保存查询”添加。"),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} 和 {{db}} 会替换为加载查询的标签页的名称。"),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "使用 {{collection}} 和 {{db}} 表示加载查询的标签页名称。"),
+            ("Load", "加载"),
+            ("Target", "目标"),
+            ("Query", "查询"),
+            ("Database (optional)", "数据库（可选）"),
+            ("Collection (optional)", "集合（可选）"),
+            ("Slow operations", "慢操作"),
+            ("Query name cannot be empty.", "查询名称不能为空。"),
+            ("Query text cannot be empty.", "查询文本不能为空。"),
+            ("A saved query with this name already exists.", "已存在同名的已保存查询。"),
+            ("Loaded a query saved for {}.", "已加载为 {} 保存的查询。"),
+            ("Saved query \"{}\".", "已保存查询“{}”。"),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce 需要 map 和 reduce 函数以及可选的 options 对象。"),
+            ("mapReduce '{}' must be a function.", "mapReduce 的 '{}' 必须是函数。"),
+            ("mapReduce options must be a JSON object.", "mapReduce 选项必须是 JSON 对象。"),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 的 'out' 必须是集合名称或文档。"),
+            ("mapReduce '{}' must be a document.", "mapReduce 的 '{}' 必须是文档。"),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 的 'limit' 必须是非负整数。"),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "mapReduce 选项不支持参数 '{}'。允许：out, query, sort, limit, finalize。"),
+            ("projection requires a document with the fields to return.", "projection 需要一个包含要返回字段的文档。"),
+            ("Extended JSON", "扩展 JSON"),
+            ("Canonical Extended JSON", "规范扩展 JSON"),
+            ("Show Tree", "显示树"),
+            ("Run a query to see its extended JSON.", "运行查询以查看其扩展 JSON。"),
+            ("Group", "分组"),
+            ("Ungrouped", "未分组"),
+            ("Auto", "自动"),
+            ("Save As", "保存为"),
+            ("Value must be true or false.", "值必须为 true 或 false。"),
+            ("Clear the text or enter null.", "请清空文本或输入 null。"),
+            ("Cannot convert the value to {}: {}", "无法将值转换为 {}：{}"),
+            ("Cannot convert the value to {}: it reads as {}.", "无法将值转换为 {}：它被读取为 {}。"),
+            ("Copy as Node.js", "复制为 Node.js"),
+            ("Query copied as a Node.js driver call.", "查询已复制为 Node.js 驱动调用。"),
+            ("Cannot copy the query as Node.js: {}", "无法将查询复制为 Node.js：{}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "副本集辅助命令在 Node.js 驱动中没有对应项。"),
+            ("Unsupported update in bulkWrite.", "bulkWrite 中存在不支持的更新。"),
+            ("Unsupported operation in bulkWrite.", "bulkWrite 中存在不支持的操作。"),
+            ("(none)", "（无）"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "分片集合无法通过重新创建来清空。请使用常规删除。"),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "集合已删除但无法重新创建：{}。丢失的选项：{}。丢失的索引：{}。"),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "集合已重新创建，但无法恢复其索引：{}。丢失的索引：{}。"),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "find 选项不支持参数 '{}'。允许：sort、hint、skip、limit、maxTimeMS、comment、collation、let。"),
+            ("The element {} is removed from the document. This cannot be undone.", "元素 {} 将从文档中删除。此操作无法撤销。"),
+            ("Diagnostic report saved to {}", "诊断报告已保存到 {}"),
+            ("Failed to save diagnostic report: {}", "无法保存诊断报告：{}"),
         ])
     })
 }
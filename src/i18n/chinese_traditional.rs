@@ -26,7 +26,7 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
             ("Hide Index", "隱藏索引"),
             ("Unhide Index", "顯示索引"),
             ("comment expects a value.", "comment 需要一個值。"),
-            ("explain must be followed by find(...).", "explain 必須在 find(...) 之後。"),
+            ("explain must be followed by find(...) or aggregate(...).", "explain 必須在 find(...) 或 aggregate(...) 之後。"),
             ("finish does not take any arguments.", "finish 不接受任何參數。"),
             ("No methods are supported after finish().", "finish() 之後不支援任何方法。"),
             ("Edit Index...", "編輯索引..."),
@@ -301,10 +301,6 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "資料庫 \"{}\" 中集合 \"{}\" 的索引 \"{}\" 將被刪除。此操作無法復原。",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "輸入索引名稱 \"{}\" 以確認刪除索引。",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne 需要一個 filter、一個 update 與可選的 options 物件。",
@@ -346,8 +342,8 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
                 "deleteMany 接受一個 filter 與可選的 options 物件。",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "方法 {} 不支援。可用方法：find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex。",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "方法 {} 不支援。可用方法：find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex。",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -724,10 +720,6 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
                 "The second argument to Code must be an object.",
                 "Code 的第二個參數必須是物件。",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "輸入精確的索引名稱以確認。",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "輸入精確的集合名稱以確認。",
@@ -916,8 +908,8 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
                 "watch 的 options 中不支援參數 '{}'。不支援 resume token。",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "aggregate 的 options 中不支援參數 '{}'。允許：allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor。",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "aggregate 的 options 中不支援參數 '{}'。允許：allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor。",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1047,6 +1039,330 @@ pub(crate) fn chinese_traditional_map() -> &'static HashMap<&'static str, &'stat
                 "Pipeline element at index {} must be a JSON object.",
                 "pipeline 在索引 {} 的元素必須是 JSON 物件。",
             ),
+            ("Select for Compare", "選擇以比較"),
+            ("Compare with Selected", "與所選比較"),
+            ("Compare Documents", "比較文件"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "新增：{}，刪除：{}，修改：{}，未變：{}"),
+            ("Documents are identical", "文件完全相同"),
+            ("Field", "欄位"),
+            ("Show unchanged fields", "顯示未變更的欄位"),
+            ("Document without _id", "無 _id 的文件"),
+            ("Connections: {} of {}", "連線：{} / {}"),
+            ("Tabs: {}", "分頁：{}"),
+            ("Theme: {}", "主題：{}"),
+            ("Language: {}", "語言：{}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "跳過值 {} 超出了 {} 個符合的文件。最後一頁從跳過 {} 開始。"),
+            ("Sample Documents", "抽樣文件"),
+            ("Query Log", "查詢日誌"),
+            ("Filter queries", "篩選查詢"),
+            ("Clear", "清除"),
+            ("OK ({})", "成功 ({})"),
+            ("Error: {}", "錯誤：{}"),
+            ("No queries executed yet", "尚未執行任何查詢"),
+            ("Invalid port in host address '{}'", "主機位址 '{}' 中的連接埠無效"),
+            ("Invalid host address '{}'", "無效的主機位址 '{}'"),
+            ("Direct connection supports only a single host", "直接連線僅支援單一主機"),
+            ("SSH tunnel supports only a single host", "SSH 通道僅支援單一主機"),
+            ("Optional", "選填"),
+            ("Replica set name", "複本集名稱"),
+            ("Replica set name requires the ReplicaSet connection type", "複本集名稱需要 ReplicaSet 連線類型"),
+            ("Server default", "伺服器預設"),
+            ("Read concern", "讀取關注"),
+            ("Write concern (w)", "寫入關注 (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "寫入關注必須是數字、\"majority\" 或標籤集名稱"),
+            ("Preview", "預覽"),
+            ("Effective query:", "實際查詢："),
+            ("Skip and limit do not apply to this operation", "skip 和 limit 不適用於此操作"),
+            ("Query timeout", "查詢逾時"),
+            ("0 = no timeout", "0 = 不逾時"),
+            ("Large document ({}), expand to load", "大型文件（{}），展開以載入"),
+            ("Deeply nested document ({} levels), expand to load", "深度巢狀文件（{} 層），展開以載入"),
+            ("No methods are supported after explain().aggregate(...).", "explain().aggregate(...) 之後不支援任何方法。"),
+            ("Method '{}' is not supported after aggregate(...).", "aggregate(...) 之後不支援方法 '{}'。"),
+            ("Sidebar", "側邊欄"),
+            ("Pin Tab", "釘選分頁"),
+            ("Unpin Tab", "取消釘選分頁"),
+            ("Reuse the current unpinned tab when opening a collection", "開啟集合時重複使用目前未釘選的分頁"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "位置運算子 '$[{}]' 在 arrayFilters 中沒有對應的項目。"),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "識別碼 '{}' 的 arrayFilters 項目未在更新中使用。"),
+            ("Show relative time next to dates", "在日期旁顯示相對時間"),
+            ("just now", "剛剛"),
+            ("{} s", "{} 秒"),
+            ("{} min", "{} 分鐘"),
+            ("{} h", "{} 小時"),
+            ("{} d", "{} 天"),
+            ("{} mo", "{} 個月"),
+            ("{} y", "{} 年"),
+            ("{} ago", "{}前"),
+            ("in {}", "{}後"),
+            ("No documents matched the query.", "沒有符合查詢的文件。"),
+            ("No distinct values found.", "找不到相異值。"),
+            ("Adjust the filter or pipeline and run the query again.", "請調整篩選條件或管線後重新執行查詢。"),
+            ("Copy _id", "複製 _id"),
+            ("Enter the exact index name or key specification to confirm.", "請輸入準確的索引名稱或鍵規格以確認。"),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "輸入索引名稱「{}」或其鍵規格以確認刪除。"),
+            ("Index Builder", "索引建構器"),
+            ("Index Builder...", "索引建構器..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "為集合「{}」（資料庫「{}」）建立索引。"),
+            ("Add Field", "新增欄位"),
+            ("Index name", "索引名稱"),
+            ("Generated from fields", "依欄位產生"),
+            ("TTL (seconds)", "TTL（秒）"),
+            ("No expiration", "不過期"),
+            ("Partial filter", "部分篩選器"),
+            ("Unique", "唯一"),
+            ("Sparse", "稀疏"),
+            ("Open in Editor", "在編輯器中開啟"),
+            ("Choose at least one field for the index.", "請至少為索引選擇一個欄位。"),
+            ("Field '{}' is used more than once.", "欄位 '{}' 被使用了多次。"),
+            ("TTL must be a non-negative number of seconds.", "TTL 必須是非負的秒數。"),
+            ("TTL indexes must have exactly one field.", "TTL 索引必須只有一個欄位。"),
+            ("Loading existing indexes...", "正在載入現有索引..."),
+            ("Existing indexes are unavailable: {}", "現有索引無法使用：{}"),
+            ("Existing indexes", "現有索引"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "索引「{}」已使用此鍵模式。仍可建立該索引。"),
+            ("Export Diagnostic Report", "匯出診斷報告"),
+            ("Run", "執行"),
+            ("Confirm Aggregation Output", "確認彙總輸出"),
+            ("The pipeline writes its results with {} into {}.", "此管線透過 {} 將結果寫入 {}。"),
+            ("The target differs from the source collection {}.", "目標與來源集合 {} 不同。"),
+            ("$out replaces all documents in the target collection.", "$out 會取代目標集合中的所有文件。"),
+            ("$merge inserts or updates documents in the target collection.", "$merge 會在目標集合中插入或更新文件。"),
+            ("Open Response in External Editor", "在外部編輯器中開啟回應"),
+            ("Tab title", "分頁標題"),
+            ("Collection", "集合"),
+            ("Database / Collection", "資料庫 / 集合"),
+            ("Connection / Collection", "連線 / 集合"),
+            ("Connection / Database / Collection", "連線 / 資料庫 / 集合"),
+            ("Ask before closing tabs with edited queries that were not run", "關閉含有已編輯但未執行查詢的分頁前詢問"),
+            ("Close Anyway", "仍要關閉"),
+            ("Close Tab", "關閉分頁"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "分頁「{}」中的查詢已編輯但未執行。關閉分頁將捨棄它。"),
+            ("Rename Tab", "重新命名分頁"),
+            ("Prefill new tabs with a find query", "新分頁預先填入 find 查詢"),
+            ("Count Documents", "計算文件數"),
+            ("Estimated Count", "估算數量"),
+            ("Counting documents in {}...", "正在計算 {} 中的文件..."),
+            ("{}: about {} documents (estimated)", "{}：約 {} 份文件（估算）"),
+            ("{}: {} documents", "{}：{} 份文件"),
+            ("{}: unexpected count result", "{}：非預期的計數結果"),
+            ("Failed to count documents in {}: {}", "計算 {} 中的文件失敗：{}"),
+            ("Database name cannot be empty.", "資料庫名稱不能為空。"),
+            ("Database name cannot contain null characters.", "資料庫名稱不能包含空字元。"),
+            ("Database name cannot contain the character '{}'.", "資料庫名稱不能包含字元 '{}'。"),
+            ("Database name must be at most {} bytes long.", "資料庫名稱長度不能超過 {} 位元組。"),
+            ("Collection name cannot contain null characters.", "集合名稱不能包含空字元。"),
+            ("Collection name cannot contain the character '{}'.", "集合名稱不能包含字元 '{}'。"),
+            ("Collection name cannot start with 'system.'.", "集合名稱不能以 'system.' 開頭。"),
+            ("Namespace '{}' exceeds {} bytes.", "命名空間 '{}' 超過 {} 位元組。"),
+            ("readConcern requires a document with a level.", "readConcern 需要一個包含 level 的文件。"),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern 必須是僅包含字串欄位 'level' 的文件。"),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "不支援的 readConcern 層級 '{}'。允許：local、majority、linearizable、available、snapshot。"),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "find(...) 之後不支援方法 '{}'。支援：sort、hint、limit、skip、maxTimeMS、readConcern、comment、explain、count、countDocuments。"),
+            ("Pipeline stage at index {} is empty.", "索引 {} 處的管線階段為空。"),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "索引 {} 處的管線階段必須以 '$' 開頭，實際為 '{}'。請將運算式包裝在 $match 等階段中。"),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "索引 {} 處的管線階段必須只包含一個運算子，實際為：{}。"),
+            ("Date Format Preview", "日期格式預覽"),
+            ("Date field", "日期欄位"),
+            ("Sample date", "範例日期"),
+            ("Format", "格式"),
+            ("Timezone", "時區"),
+            ("Result", "結果"),
+            ("Copy Expression", "複製運算式"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "格式符：%Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%。"),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "請以 ISO 8601 格式輸入範例日期，例如 2024-03-15T13:45:30Z。"),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "不支援的時區 '{}'。請使用 UTC 或偏移量，例如 +03:00。"),
+            ("Unmatched '%' at the end of the format string.", "格式字串結尾有未配對的 '%'。"),
+            ("Invalid format character '%{}' in the format string.", "格式字串中的格式字元 '%{}' 無效。"),
+            ("Value must be a valid base64 string.", "值必須是有效的 base64 字串。"),
+            ("Generic", "通用"),
+            ("Function", "函式"),
+            ("Binary (old)", "Binary（舊）"),
+            ("UUID (old)", "UUID（舊）"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "已加密"),
+            ("Column", "欄"),
+            ("Sensitive", "敏感"),
+            ("User defined", "使用者定義"),
+            ("Reserved", "保留"),
+            ("Subtype", "子類型"),
+            ("Input Format", "輸入格式"),
+            ("Saving will change the field type from {} to {}.", "儲存會將欄位類型從 {} 變更為 {}。"),
+            ("Press Save Anyway to confirm.", "點擊「仍然儲存」以確認。"),
+            ("Save Anyway", "仍然儲存"),
+            ("Group connections by host in the sidebar", "在側邊欄中按主機分組連線"),
+            ("Compact sidebar density", "緊湊側邊欄密度"),
+            ("Test All", "全部測試"),
+            ("OK ({} ms)", "成功（{} 毫秒）"),
+            ("Failed after {} ms: {}", "{} 毫秒後失敗：{}"),
+            ("Skipped: the password is requested on connect", "已略過：連線時才會詢問密碼"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "_id 欄位無法原地修改。請啟用「允許修改 _id」以取代文件。"),
+            ("Allow changing _id (the document is re-inserted, not updated)", "允許修改 _id（文件將被重新插入，而非更新）"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "_id 已變更。儲存時將插入新文件並刪除原文件；這不是原地更新。按「取代文件」確認。"),
+            ("Replace Document", "取代文件"),
+            ("Rollback failed: {}", "復原失敗：{}"),
+            ("Drop and recreate the collection (faster on large collections)", "刪除並重新建立集合（對大型集合更快）"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "集合將被刪除並以相同選項重新建立，然後重建其索引。其他用戶端可能會短暫看不到該集合。"),
+            ("Collection \"{}\" not found.", "找不到集合「{}」。"),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "只有一般集合可以透過重新建立來清空。請改用一般刪除。"),
+            ("Text Search...", "文字搜尋..."),
+            ("Text Search", "文字搜尋"),
+            ("Checking text indexes...", "正在檢查文字索引..."),
+            ("This collection has no text index. Create one to use text search.", "此集合沒有文字索引。請先建立一個以使用文字搜尋。"),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "在集合「{}」中使用文字索引「{}」進行搜尋。結果依相關性排序。"),
+            ("Search terms", "搜尋詞"),
+            ("Read-only (block all write operations)", "唯讀（封鎖所有寫入操作）"),
+            ("Read-only", "唯讀"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "連線「{}」為唯讀。寫入操作已被封鎖。"),
+            ("Color accent", "強調色"),
+            ("None", "無"),
+            ("Reconnect automatically when running a query on a closed connection", "在已關閉的連線上執行查詢時自動重新連線"),
+            ("Validation Rules...", "驗證規則..."),
+            ("Validation Rules", "驗證規則"),
+            ("Loading validation rules...", "正在載入驗證規則..."),
+            ("Applying validation rules...", "正在套用驗證規則..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "編輯 validator、validationLevel 與 validationAction。儲存時透過 collMod 套用；空的 validator 會移除驗證。"),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "不支援的欄位 \"{}\"。只能修改 validator、validationLevel 與 validationAction。"),
+            ("The validator must be a document.", "validator 必須是文件。"),
+            ("Insert Document...", "插入文件..."),
+            ("By name", "依名稱"),
+            ("By size (largest first)", "依大小（最大優先）"),
+            ("Sort databases and collections", "資料庫與集合排序"),
+            ("Copy Results as Markdown Table", "將結果複製為 Markdown 表格"),
+            ("The results are not a list of documents and cannot be shown as a table.", "結果不是文件清單，無法顯示為表格。"),
+            ("All fields", "全部欄位"),
+            ("Preset name cannot be empty.", "預設名稱不能為空。"),
+            ("Projection Preset", "投影預設"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "沒有自帶投影的 find 查詢只回傳這些欄位。使用既有名稱儲存將取代該預設。"),
+            ("Fields", "欄位"),
+            ("summary", "摘要"),
+            ("Invalid field path \"{}\".", "無效的欄位路徑 \"{}\"。"),
+            ("Enter at least one field to project.", "請至少輸入一個要投影的欄位。"),
+            ("Refresh All Connections", "重新整理所有連線"),
+            ("Copy Selected Rows", "複製所選列"),
+            ("Shortcuts", "快速鍵"),
+            ("Send query", "送出查詢"),
+            ("Rerun last query", "重新執行上次查詢"),
+            ("Close tab", "關閉分頁"),
+            ("Next tab", "下一個分頁"),
+            ("Previous tab", "上一個分頁"),
+            ("Toggle sidebar", "切換側邊欄"),
+            ("Table view", "表格檢視"),
+            ("Text view", "文字檢視"),
+            ("Shortcut must end with a key.", "快速鍵必須以按鍵結尾。"),
+            ("Unknown modifier \"{}\".", "未知的修飾鍵 \"{}\"。"),
+            ("Unknown key \"{}\".", "未知的按鍵 \"{}\"。"),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" 和 \"{}\" 使用了相同的快速鍵。"),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "將 Ctrl、Shift 或 Alt 與按鍵組合，例如 Ctrl+Shift+Tab。在 macOS 上 Ctrl 代表 Cmd。"),
+            ("Conflicts with \"{}\".", "與 \"{}\" 衝突。"),
+            ("Default Shortcuts", "預設快速鍵"),
+            ("Show Side by Side with Active Tab", "與目前分頁並排顯示"),
+            ("Synchronize scrolling", "同步捲動"),
+            ("Close Side by Side", "關閉並排檢視"),
+            ("Render at most", "最多顯示"),
+            ("0 = no cap", "0 = 不限制"),
+            ("documents per result", "個文件/每個結果"),
+            ("Showing the first {} of {} returned items.", "顯示前 {} 項（共回傳 {} 項）。"),
+            ("Find This Document", "尋找此文件"),
+            ("Up to stage {} of {}", "執行到第 {} 階段（共 {} 個）"),
+            ("All {} stages", "全部 {} 個階段"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "不支援 bulkWrite 操作 '{}'（索引 {}）。允許：insertOne、updateOne、updateMany、replaceOne、deleteOne、deleteMany。"),
+            ("Export Results to JSON...", "將結果匯出為 JSON..."),
+            ("Export Results to CSV...", "將結果匯出為 CSV..."),
+            ("Results saved to {}", "結果已儲存到 {}"),
+            ("Failed to export results: {}", "匯出結果失敗：{}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} 個分頁未還原，因為其連線已不存在。"),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "未知的 explain 詳細等級 '{}'。允許：queryPlanner、executionStats、allPlansExecution。"),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain 需要詳細等級字串：queryPlanner、executionStats 或 allPlansExecution。"),
+            ("Append Array Element...", "附加陣列元素..."),
+            ("Delete Array Element", "刪除陣列元素"),
+            ("A new element will be appended to the array", "將向陣列附加一個新元素"),
+            ("Failed to delete the array element: {}", "刪除陣列元素失敗：{}"),
+            ("Read", "讀取"),
+            ("Read preference", "讀取偏好"),
+            ("Secondary read preferences keep analysis queries off the primary.", "從節點讀取偏好可讓分析查詢不佔用主節點。"),
+            ("Use SRV record (mongodb+srv://)", "使用 SRV 記錄 (mongodb+srv://)"),
+            ("From DNS", "來自 DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "SRV 連線從 DNS 取得連接埠；請將連接埠留空"),
+            ("An SRV connection takes a single host name without a port", "SRV 連線只接受一個不帶連接埠的主機名稱"),
+            ("SSH tunnel cannot be used with an SRV connection", "SSH 通道不能與 SRV 連線一起使用"),
+            ("Insert Template...", "插入範本..."),
+            ("Insert Document", "插入文件"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "輸入新文件。若文件未設定 _id，將自動產生。"),
+            ("Inserting document...", "正在插入文件..."),
+            ("Inserted document with _id {}", "已插入文件，_id 為 {}"),
+            ("Highlight query syntax in the editor", "在編輯器中醒目提示查詢語法"),
+            ("Query cancelled. The server may still finish the operation.", "查詢已取消。伺服器仍可能完成該操作。"),
+            ("Filter by key or value", "依鍵或值篩選"),
+            ("Match case", "區分大小寫"),
+            ("{} matches", "{} 個相符"),
+            ("TLS", "TLS"),
+            ("CA file", "CA 檔案"),
+            ("Path to PEM file", "PEM 檔案路徑"),
+            ("Client certificate and key file", "用戶端憑證與金鑰檔案"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "允許無效的憑證與主機名稱 (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "設定其中任一選項都會為連線啟用 TLS。"),
+            ("TLS CA file not found: {}", "找不到 TLS CA 檔案：{}"),
+            ("TLS certificate key file not found: {}", "找不到 TLS 憑證金鑰檔案：{}"),
+            ("Pin to Top", "置頂"),
+            ("Unpin Collection", "取消置頂集合"),
+            ("Expand All opens at most", "「全部展開」最多展開"),
+            ("0 = all levels", "0 = 所有層級"),
+            ("levels", "層"),
+            ("Expand All", "全部展開"),
+            ("Collapse All", "全部摺疊"),
+            ("Saved Queries", "已儲存的查詢"),
+            ("Save Query", "儲存查詢"),
+            ("Edit Saved Query", "編輯已儲存的查詢"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "沒有已儲存的查詢。在集合分頁中使用「儲存查詢」新增。"),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} 和 {{db}} 會替換為載入查詢的分頁名稱。"),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "使用 {{collection}} 和 {{db}} 表示載入查詢的分頁名稱。"),
+            ("Load", "載入"),
+            ("Target", "目標"),
+            ("Query", "查詢"),
+            ("Database (optional)", "資料庫（選填）"),
+            ("Collection (optional)", "集合（選填）"),
+            ("Slow operations", "慢操作"),
+            ("Query name cannot be empty.", "查詢名稱不能為空。"),
+            ("Query text cannot be empty.", "查詢文字不能為空。"),
+            ("A saved query with this name already exists.", "已存在同名的已儲存查詢。"),
+            ("Loaded a query saved for {}.", "已載入為 {} 儲存的查詢。"),
+            ("Saved query \"{}\".", "已儲存查詢「{}」。"),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce 需要 map 和 reduce 函式以及可選的 options 物件。"),
+            ("mapReduce '{}' must be a function.", "mapReduce 的 '{}' 必須是函式。"),
+            ("mapReduce options must be a JSON object.", "mapReduce 選項必須是 JSON 物件。"),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 的 'out' 必須是集合名稱或文件。"),
+            ("mapReduce '{}' must be a document.", "mapReduce 的 '{}' 必須是文件。"),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 的 'limit' 必須是非負整數。"),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "mapReduce 選項不支援參數 '{}'。允許：out, query, sort, limit, finalize。"),
+            ("projection requires a document with the fields to return.", "projection 需要一個包含要傳回欄位的文件。"),
+            ("Extended JSON", "擴充 JSON"),
+            ("Canonical Extended JSON", "標準擴充 JSON"),
+            ("Show Tree", "顯示樹狀"),
+            ("Run a query to see its extended JSON.", "執行查詢以檢視其擴充 JSON。"),
+            ("Group", "群組"),
+            ("Ungrouped", "未分組"),
+            ("Auto", "自動"),
+            ("Save As", "儲存為"),
+            ("Value must be true or false.", "值必須為 true 或 false。"),
+            ("Clear the text or enter null.", "請清空文字或輸入 null。"),
+            ("Cannot convert the value to {}: {}", "無法將值轉換為 {}：{}"),
+            ("Cannot convert the value to {}: it reads as {}.", "無法將值轉換為 {}：它被讀取為 {}。"),
+            ("Copy as Node.js", "複製為 Node.js"),
+            ("Query copied as a Node.js driver call.", "查詢已複製為 Node.js 驅動程式呼叫。"),
+            ("Cannot copy the query as Node.js: {}", "無法將查詢複製為 Node.js：{}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "複本集輔助命令在 Node.js 驅動程式中沒有對應項。"),
+            ("Unsupported update in bulkWrite.", "bulkWrite 中存在不支援的更新。"),
+            ("Unsupported operation in bulkWrite.", "bulkWrite 中存在不支援的操作。"),
+            ("(none)", "（無）"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "分片集合無法透過重新建立來清空。請使用一般刪除。"),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "集合已刪除但無法重新建立：{}。遺失的選項：{}。遺失的索引：{}。"),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "集合已重新建立，但無法還原其索引：{}。遺失的索引：{}。"),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "find 選項不支援參數 '{}'。允許：sort、hint、skip、limit、maxTimeMS、comment、collation、let。"),
+            ("The element {} is removed from the document. This cannot be undone.", "元素 {} 將從文件中刪除。此操作無法復原。"),
+            ("Diagnostic report saved to {}", "診斷報告已儲存到 {}"),
+            ("Failed to save diagnostic report: {}", "無法儲存診斷報告：{}"),
         ])
     })
 }
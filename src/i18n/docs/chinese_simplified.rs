@@ -95,7 +95,17 @@ Oxide Mongo 是一个开源且免费的工具，面向需要快速、清晰访
 
 find(...) 支持以下方法：
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+要对 aggregate(...) 执行 explain，可使用以下任一形式：
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+要更新匹配的数组元素，请为每个 `$[identifier]` 提供对应的 arrayFilters 条目：
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## 数据库相关
 
@@ -149,7 +159,12 @@ find(...) 支持以下方法：
 - F2 — 切换结果为表格视图
 - F4 — 切换结果为文本视图
 - Ctrl+Enter — 运行当前查询
+- F5 — 重新运行当前标签页中的查询
 - Ctrl+W — 关闭当前标签页
+- Ctrl+Tab / Ctrl+Shift+Tab、Ctrl+PageDown / Ctrl+PageUp — 切换到下一个或上一个标签页
+- Ctrl+B — 隐藏或显示侧边栏
+
+除 Ctrl+PageDown / Ctrl+PageUp 外，其余快捷键都可以在 设置 → 快捷键 中重新分配。
 "#,
                 },
             ),
@@ -95,7 +95,17 @@ Oxide Mongo 是一個開源且免費的工具，面向需要快速、清晰存
 
 find(...) 支援以下方法：
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+要對 aggregate(...) 執行 explain，可使用以下任一形式：
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+若要更新符合條件的陣列元素，請為每個 `$[identifier]` 提供對應的 arrayFilters 項目：
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## 資料庫相關
 
@@ -149,7 +159,12 @@ find(...) 支援以下方法：
 - F2 — 切換結果為表格檢視
 - F4 — 切換結果為文字檢視
 - Ctrl+Enter — 執行目前查詢
+- F5 — 重新執行目前分頁中的查詢
 - Ctrl+W — 關閉目前分頁
+- Ctrl+Tab / Ctrl+Shift+Tab、Ctrl+PageDown / Ctrl+PageUp — 切換到下一個或上一個分頁
+- Ctrl+B — 隱藏或顯示側邊欄
+
+除 Ctrl+PageDown / Ctrl+PageUp 外，其餘快速鍵都可以在 設定 → 快速鍵 中重新指定。
 "#,
                 },
             ),
@@ -95,7 +95,17 @@ Below is a step-by-step scenario for the first launch when you already have a da
 
 For find(...), the following methods are supported:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+To explain aggregate(...), use any of the following forms:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+To update matching array elements, pair every `$[identifier]` with an arrayFilters entry:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## For databases
 
@@ -149,7 +159,12 @@ The stream stops automatically when the number of received elements reaches the
 - F2 — switch results to Table view
 - F4 — switch results to Text view
 - Ctrl+Enter — run the current query
+- F5 — re-run the query in the active tab
 - Ctrl+W — close the active tab
+- Ctrl+Tab / Ctrl+Shift+Tab, Ctrl+PageDown / Ctrl+PageUp — switch to the next or previous tab
+- Ctrl+B — hide or show the sidebar
+
+All of these except Ctrl+PageDown / Ctrl+PageUp can be reassigned in Settings → Shortcuts.
 "#,
                 },
             ),
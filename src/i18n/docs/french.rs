@@ -95,7 +95,17 @@ Ci-dessous, un scénario pas à pas pour un premier lancement lorsque vous avez
 
 Pour find(...), les méthodes suivantes sont prises en charge :
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Pour expliquer aggregate(...), utilisez l'une des formes suivantes :
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Pour mettre à jour les éléments d'un tableau qui correspondent, associez chaque `$[identifier]` à une entrée arrayFilters :
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Pour les bases de données
 
@@ -149,7 +159,12 @@ Le flux s'arrête automatiquement lorsque le nombre d'éléments reçus atteint
 - F2 — basculer les résultats en vue Tableau
 - F4 — basculer les résultats en vue Texte
 - Ctrl+Enter — exécuter la requête actuelle
+- F5 — relancer la requête de l'onglet actif
 - Ctrl+W — fermer l'onglet actif
+- Ctrl+Tab / Ctrl+Maj+Tab, Ctrl+PageDown / Ctrl+PageUp — passer à l'onglet suivant ou précédent
+- Ctrl+B — masquer ou afficher la barre latérale
+
+Tous sauf Ctrl+PageDown / Ctrl+PageUp peuvent être réattribués dans Paramètres → Raccourcis.
 "#,
                 },
             ),
@@ -95,7 +95,17 @@ Unten findest du ein Schritt-für-Schritt-Szenario für den ersten Start, wenn d
 
 Für find(...), werden die folgenden Methoden unterstützt:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Um aggregate(...) zu erklären, verwenden Sie eine der folgenden Formen:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Um passende Array-Elemente zu aktualisieren, ordnen Sie jedem `$[identifier]` einen arrayFilters-Eintrag zu:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Für Datenbanken
 
@@ -149,7 +159,12 @@ Der Stream stoppt automatisch, wenn die Anzahl der empfangenen Elemente den Wert
 - F2 — Ergebnisse in die Tabellenansicht wechseln
 - F4 — Ergebnisse in die Textansicht wechseln
 - Strg+Enter — aktuelle Abfrage ausführen
+- F5 — Abfrage im aktiven Tab erneut ausführen
 - Strg+W — aktiven Tab schließen
+- Strg+Tab / Strg+Umschalt+Tab, Strg+Bild↓ / Strg+Bild↑ — zum nächsten oder vorherigen Tab wechseln
+- Strg+B — Seitenleiste aus- oder einblenden
+
+Alle außer Strg+Bild↓ / Strg+Bild↑ lassen sich unter Einstellungen → Tastenkürzel neu belegen.
 "#,
                 },
             ),
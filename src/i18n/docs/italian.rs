@@ -95,7 +95,17 @@ Di seguito uno scenario passo-passo per il primo avvio quando hai già un databa
 
 Per find(...), sono supportati i seguenti metodi:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Per spiegare aggregate(...), usa una delle seguenti forme:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Per aggiornare gli elementi di un array che corrispondono, associa ogni `$[identifier]` a una voce di arrayFilters:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Per database
 
@@ -149,7 +159,12 @@ Lo stream si interrompe automaticamente quando il numero di elementi ricevuti ra
 - F2 — passa i risultati alla vista Tabella
 - F4 — passa i risultati alla vista Testo
 - Ctrl+Enter — esegui la query corrente
+- F5 — riesegui la query della scheda attiva
 - Ctrl+W — chiudi la scheda attiva
+- Ctrl+Tab / Ctrl+Maiusc+Tab, Ctrl+PagGiù / Ctrl+PagSu — passa alla scheda successiva o precedente
+- Ctrl+B — nascondi o mostra la barra laterale
+
+Tutte tranne Ctrl+PageDown / Ctrl+PageUp possono essere riassegnate in Impostazioni → Scorciatoie.
 "#,
                 },
             ),
@@ -95,7 +95,17 @@ Abaixo está um cenário passo a passo para o primeiro uso, quando você já tem
 
 Para find(...), os seguintes métodos são suportados:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Para explicar aggregate(...), use qualquer uma das seguintes formas:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Para atualizar elementos de array correspondentes, associe cada `$[identifier]` a uma entrada de arrayFilters:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Para bancos de dados
 
@@ -149,7 +159,12 @@ O fluxo é encerrado automaticamente quando o número de elementos recebidos ati
 - F2 — alternar resultados para a visão de Tabela
 - F4 — alternar resultados para a visão de Texto
 - Ctrl+Enter — executar a consulta atual
+- F5 — executar novamente a consulta da aba ativa
 - Ctrl+W — fechar a aba ativa
+- Ctrl+Tab / Ctrl+Shift+Tab, Ctrl+PageDown / Ctrl+PageUp — alternar para a aba seguinte ou anterior
+- Ctrl+B — ocultar ou mostrar a barra lateral
+
+Todos, exceto Ctrl+PageDown / Ctrl+PageUp, podem ser reatribuídos em Configurações → Atalhos.
 "#,
                 },
             ),
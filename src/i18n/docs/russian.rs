@@ -96,7 +96,17 @@ Oxide Mongo — это открытый и бесплатный инструме
 
 Для find(...) поддержаны команды:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Для explain агрегаций поддержаны формы:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Чтобы обновить подходящие элементы массива, сопоставьте каждому `$[identifier]` запись в arrayFilters:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Для базы данных
 
@@ -150,7 +160,12 @@ Oxide Mongo — это открытый и бесплатный инструме
 - F2 — переключить результаты в режим таблицы
 - F4 — переключить результаты в режим текста
 - Ctrl+Enter — выполнить текущий запрос
+- F5 — повторно выполнить запрос в активной вкладке
 - Ctrl+W — закрыть активную вкладку
+- Ctrl+Tab / Ctrl+Shift+Tab, Ctrl+PageDown / Ctrl+PageUp — перейти на следующую или предыдущую вкладку
+- Ctrl+B — скрыть или показать боковую панель
+
+Все сочетания, кроме Ctrl+PageDown / Ctrl+PageUp, можно переназначить в разделе Настройки → Сочетания клавиш.
 "#,
                 },
             ),
@@ -95,7 +95,17 @@ A continuación se muestra un escenario paso a paso para el primer inicio cuando
 
 Para find(...), se admiten los siguientes métodos:
 
-    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), explain(), count(...), countDocuments(...), comment(...)
+    sort(...), hint(...), limit(...), skip(...), maxTimeMS(...), readConcern(...), explain(), count(...), countDocuments(...), comment(...)
+
+Para explicar aggregate(...), use cualquiera de las siguientes formas:
+
+    db.collection.aggregate([...]).explain()
+    db.collection.explain().aggregate([...])
+    db.collection.aggregate([...], { explain: true })
+
+Para actualizar elementos de un array que cumplan una condición, asocie cada `$[identifier]` con una entrada de arrayFilters:
+
+    db.collection.updateMany({}, { $set: { "grades.$[g].passed": true } }, { arrayFilters: [{ "g.score": { $gte: 60 } }] })
 
 ## Para bases de datos
 
@@ -149,7 +159,12 @@ El flujo se detiene automáticamente cuando la cantidad de elementos recibidos a
 - F2 — cambiar resultados a vista Tabla
 - F4 — cambiar resultados a vista Texto
 - Ctrl+Enter — ejecutar la consulta actual
+- F5 — volver a ejecutar la consulta de la pestaña activa
 - Ctrl+W — cerrar la pestaña activa
+- Ctrl+Tab / Ctrl+Mayús+Tab, Ctrl+AvPág / Ctrl+RePág — cambiar a la pestaña siguiente o anterior
+- Ctrl+B — ocultar o mostrar la barra lateral
+
+Todos excepto Ctrl+PageDown / Ctrl+PageUp se pueden reasignar en Configuración → Atajos.
 "#,
                 },
             ),
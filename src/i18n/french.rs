@@ -29,7 +29,7 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
             ("Hide Index", "Masquer l'index"),
             ("Unhide Index", "Afficher l'index"),
             ("comment expects a value.", "comment attend une valeur."),
-            ("explain must be followed by find(...).", "explain doit être suivi de find(...)."),
+            ("explain must be followed by find(...) or aggregate(...).", "explain doit être suivi de find(...) ou aggregate(...)."),
             ("finish does not take any arguments.", "finish n'accepte aucun argument."),
             (
                 "No methods are supported after finish().",
@@ -328,10 +328,6 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "L'index \"{}\" de la collection \"{}\" dans la base \"{}\" sera supprimé. Cette action est irréversible.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Confirmez la suppression de l'index en saisissant son nom \"{}\".",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne attend un filtre, une mise à jour et un objet options optionnel.",
@@ -373,8 +369,8 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany accepte un filtre et un objet options optionnel.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "La méthode {} n'est pas prise en charge. Méthodes disponibles : find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "La méthode {} n'est pas prise en charge. Méthodes disponibles : find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -775,10 +771,6 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
                 "The second argument to Code must be an object.",
                 "Le second argument de Code doit être un objet.",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "Saisissez le nom exact de l'index pour confirmer.",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "Saisissez le nom exact de la collection pour confirmer.",
@@ -964,8 +956,8 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
                 "Le paramètre '{}' n'est pas pris en charge dans les options watch. Les resume tokens ne sont pas pris en charge.",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "Le paramètre '{}' n'est pas pris en charge dans les options aggregate. Autorisés : allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "Le paramètre '{}' n'est pas pris en charge dans les options aggregate. Autorisés : allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1095,6 +1087,330 @@ pub(crate) fn french_map() -> &'static HashMap<&'static str, &'static str> {
                 "Pipeline element at index {} must be a JSON object.",
                 "L'élément pipeline à l'index {} doit être un objet JSON.",
             ),
+            ("Select for Compare", "Sélectionner pour comparer"),
+            ("Compare with Selected", "Comparer avec la sélection"),
+            ("Compare Documents", "Comparer les documents"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Ajoutés : {}, supprimés : {}, modifiés : {}, inchangés : {}"),
+            ("Documents are identical", "Les documents sont identiques"),
+            ("Field", "Champ"),
+            ("Show unchanged fields", "Afficher les champs inchangés"),
+            ("Document without _id", "Document sans _id"),
+            ("Connections: {} of {}", "Connexions : {} sur {}"),
+            ("Tabs: {}", "Onglets : {}"),
+            ("Theme: {}", "Thème : {}"),
+            ("Language: {}", "Langue : {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "Le saut {} dépasse les {} documents correspondants. La dernière page commence au saut {}."),
+            ("Sample Documents", "Échantillon de documents"),
+            ("Query Log", "Journal des requêtes"),
+            ("Filter queries", "Filtrer les requêtes"),
+            ("Clear", "Effacer"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Erreur : {}"),
+            ("No queries executed yet", "Aucune requête exécutée pour le moment"),
+            ("Invalid port in host address '{}'", "Port invalide dans l'adresse d'hôte '{}'"),
+            ("Invalid host address '{}'", "Adresse d'hôte invalide '{}'"),
+            ("Direct connection supports only a single host", "La connexion directe ne prend en charge qu'un seul hôte"),
+            ("SSH tunnel supports only a single host", "Le tunnel SSH ne prend en charge qu'un seul hôte"),
+            ("Optional", "Facultatif"),
+            ("Replica set name", "Nom du replica set"),
+            ("Replica set name requires the ReplicaSet connection type", "Le nom du replica set nécessite le type de connexion ReplicaSet"),
+            ("Server default", "Valeur par défaut du serveur"),
+            ("Read concern", "Read concern"),
+            ("Write concern (w)", "Write concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "Le write concern doit être un nombre, \"majority\" ou un nom de jeu de tags"),
+            ("Preview", "Aperçu"),
+            ("Effective query:", "Requête effective :"),
+            ("Skip and limit do not apply to this operation", "Skip et limit ne s'appliquent pas à cette opération"),
+            ("Query timeout", "Délai d'expiration de la requête"),
+            ("0 = no timeout", "0 = aucun délai"),
+            ("Large document ({}), expand to load", "Document volumineux ({}), développez pour charger"),
+            ("Deeply nested document ({} levels), expand to load", "Document profondément imbriqué ({} niveaux), développez pour charger"),
+            ("No methods are supported after explain().aggregate(...).", "Aucune méthode n'est prise en charge après explain().aggregate(...)."),
+            ("Method '{}' is not supported after aggregate(...).", "La méthode '{}' n'est pas prise en charge après aggregate(...)."),
+            ("Sidebar", "Barre latérale"),
+            ("Pin Tab", "Épingler l'onglet"),
+            ("Unpin Tab", "Détacher l'onglet"),
+            ("Reuse the current unpinned tab when opening a collection", "Réutiliser l'onglet actuel non épinglé à l'ouverture d'une collection"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "L'opérateur positionnel '$[{}]' n'a pas d'entrée correspondante dans arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "L'entrée arrayFilters pour l'identifiant '{}' n'est pas utilisée dans la mise à jour."),
+            ("Show relative time next to dates", "Afficher le temps relatif à côté des dates"),
+            ("just now", "à l'instant"),
+            ("{} s", "{} s"),
+            ("{} min", "{} min"),
+            ("{} h", "{} h"),
+            ("{} d", "{} j"),
+            ("{} mo", "{} mois"),
+            ("{} y", "{} an"),
+            ("{} ago", "il y a {}"),
+            ("in {}", "dans {}"),
+            ("No documents matched the query.", "Aucun document ne correspond à la requête."),
+            ("No distinct values found.", "Aucune valeur distincte trouvée."),
+            ("Adjust the filter or pipeline and run the query again.", "Ajustez le filtre ou le pipeline et relancez la requête."),
+            ("Copy _id", "Copier _id"),
+            ("Enter the exact index name or key specification to confirm.", "Saisissez le nom exact de l'index ou sa spécification de clés pour confirmer."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Confirmez la suppression de l'index en saisissant son nom « {} » ou sa spécification de clés."),
+            ("Index Builder", "Générateur d'index"),
+            ("Index Builder...", "Générateur d'index..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Créer un index sur la collection « {} » de la base de données « {} »."),
+            ("Add Field", "Ajouter un champ"),
+            ("Index name", "Nom de l'index"),
+            ("Generated from fields", "Généré à partir des champs"),
+            ("TTL (seconds)", "TTL (secondes)"),
+            ("No expiration", "Pas d'expiration"),
+            ("Partial filter", "Filtre partiel"),
+            ("Unique", "Unique"),
+            ("Sparse", "Clairsemé"),
+            ("Open in Editor", "Ouvrir dans l'éditeur"),
+            ("Choose at least one field for the index.", "Choisissez au moins un champ pour l'index."),
+            ("Field '{}' is used more than once.", "Le champ '{}' est utilisé plusieurs fois."),
+            ("TTL must be a non-negative number of seconds.", "Le TTL doit être un nombre de secondes positif ou nul."),
+            ("TTL indexes must have exactly one field.", "Les index TTL doivent avoir exactement un champ."),
+            ("Loading existing indexes...", "Chargement des index existants..."),
+            ("Existing indexes are unavailable: {}", "Les index existants sont indisponibles : {}"),
+            ("Existing indexes", "Index existants"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "L'index « {} » utilise déjà ce modèle de clés. Vous pouvez tout de même créer l'index."),
+            ("Export Diagnostic Report", "Exporter le rapport de diagnostic"),
+            ("Run", "Exécuter"),
+            ("Confirm Aggregation Output", "Confirmer la sortie de l'agrégation"),
+            ("The pipeline writes its results with {} into {}.", "Le pipeline écrit ses résultats avec {} dans {}."),
+            ("The target differs from the source collection {}.", "La cible diffère de la collection source {}."),
+            ("$out replaces all documents in the target collection.", "$out remplace tous les documents de la collection cible."),
+            ("$merge inserts or updates documents in the target collection.", "$merge insère ou met à jour des documents dans la collection cible."),
+            ("Open Response in External Editor", "Ouvrir la réponse dans un éditeur externe"),
+            ("Tab title", "Titre de l'onglet"),
+            ("Collection", "Collection"),
+            ("Database / Collection", "Base de données / Collection"),
+            ("Connection / Collection", "Connexion / Collection"),
+            ("Connection / Database / Collection", "Connexion / Base de données / Collection"),
+            ("Ask before closing tabs with edited queries that were not run", "Demander avant de fermer les onglets dont la requête modifiée n'a pas été exécutée"),
+            ("Close Anyway", "Fermer quand même"),
+            ("Close Tab", "Fermer l'onglet"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "La requête de l'onglet « {} » a été modifiée sans être exécutée. La fermeture de l'onglet la supprime."),
+            ("Rename Tab", "Renommer l'onglet"),
+            ("Prefill new tabs with a find query", "Préremplir les nouveaux onglets avec une requête find"),
+            ("Count Documents", "Compter les documents"),
+            ("Estimated Count", "Nombre estimé"),
+            ("Counting documents in {}...", "Comptage des documents dans {}..."),
+            ("{}: about {} documents (estimated)", "{} : environ {} documents (estimation)"),
+            ("{}: {} documents", "{} : {} documents"),
+            ("{}: unexpected count result", "{} : résultat de comptage inattendu"),
+            ("Failed to count documents in {}: {}", "Impossible de compter les documents dans {} : {}"),
+            ("Database name cannot be empty.", "Le nom de la base de données ne peut pas être vide."),
+            ("Database name cannot contain null characters.", "Le nom de la base de données ne peut pas contenir de caractères nuls."),
+            ("Database name cannot contain the character '{}'.", "Le nom de la base de données ne peut pas contenir le caractère '{}'."),
+            ("Database name must be at most {} bytes long.", "Le nom de la base de données doit comporter au plus {} octets."),
+            ("Collection name cannot contain null characters.", "Le nom de la collection ne peut pas contenir de caractères nuls."),
+            ("Collection name cannot contain the character '{}'.", "Le nom de la collection ne peut pas contenir le caractère '{}'."),
+            ("Collection name cannot start with 'system.'.", "Le nom de la collection ne peut pas commencer par 'system.'."),
+            ("Namespace '{}' exceeds {} bytes.", "L'espace de noms '{}' dépasse {} octets."),
+            ("readConcern requires a document with a level.", "readConcern nécessite un document avec un niveau."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern doit être un document avec un seul champ texte 'level'."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Niveau readConcern non pris en charge '{}'. Autorisés : local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "La méthode '{}' n'est pas prise en charge après find(...). Prises en charge : sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "L'étape du pipeline à l'index {} est vide."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "L'étape du pipeline à l'index {} doit commencer par '$', trouvé '{}'. Placez l'expression dans une étape telle que $match."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "L'étape du pipeline à l'index {} doit contenir exactement un opérateur, trouvé : {}."),
+            ("Date Format Preview", "Aperçu du format de date"),
+            ("Date field", "Champ de date"),
+            ("Sample date", "Date d'exemple"),
+            ("Format", "Format"),
+            ("Timezone", "Fuseau horaire"),
+            ("Result", "Résultat"),
+            ("Copy Expression", "Copier l'expression"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Spécificateurs : %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Saisissez la date d'exemple au format ISO 8601, par exemple 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Fuseau horaire non pris en charge '{}'. Utilisez UTC ou un décalage tel que +03:00."),
+            ("Unmatched '%' at the end of the format string.", "'%' isolé à la fin de la chaîne de format."),
+            ("Invalid format character '%{}' in the format string.", "Caractère de format invalide '%{}' dans la chaîne de format."),
+            ("Value must be a valid base64 string.", "La valeur doit être une chaîne base64 valide."),
+            ("Generic", "Générique"),
+            ("Function", "Fonction"),
+            ("Binary (old)", "Binary (ancien)"),
+            ("UUID (old)", "UUID (ancien)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Chiffré"),
+            ("Column", "Colonne"),
+            ("Sensitive", "Sensible"),
+            ("User defined", "Défini par l'utilisateur"),
+            ("Reserved", "Réservé"),
+            ("Subtype", "Sous-type"),
+            ("Input Format", "Format de saisie"),
+            ("Saving will change the field type from {} to {}.", "L'enregistrement changera le type du champ de {} à {}."),
+            ("Press Save Anyway to confirm.", "Cliquez sur Enregistrer quand même pour confirmer."),
+            ("Save Anyway", "Enregistrer quand même"),
+            ("Group connections by host in the sidebar", "Regrouper les connexions par hôte dans la barre latérale"),
+            ("Compact sidebar density", "Densité compacte de la barre latérale"),
+            ("Test All", "Tout tester"),
+            ("OK ({} ms)", "OK ({} ms)"),
+            ("Failed after {} ms: {}", "Échec après {} ms : {}"),
+            ("Skipped: the password is requested on connect", "Ignorée : le mot de passe est demandé à la connexion"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "Le champ _id ne peut pas être modifié sur place. Activez « Autoriser la modification de _id » pour remplacer le document."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Autoriser la modification de _id (le document est réinséré, pas mis à jour)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "Le _id a changé. L'enregistrement insère un nouveau document et supprime l'original ; ce n'est pas une mise à jour sur place. Appuyez sur Remplacer le document pour confirmer."),
+            ("Replace Document", "Remplacer le document"),
+            ("Rollback failed: {}", "Échec de l'annulation : {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Supprimer et recréer la collection (plus rapide pour les grandes collections)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "La collection est supprimée puis recréée avec les mêmes options, puis ses index sont reconstruits. D'autres clients peuvent brièvement constater son absence."),
+            ("Collection \"{}\" not found.", "Collection « {} » introuvable."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Seules les collections ordinaires peuvent être vidées en les recréant. Utilisez la suppression classique."),
+            ("Text Search...", "Recherche textuelle..."),
+            ("Text Search", "Recherche textuelle"),
+            ("Checking text indexes...", "Vérification des index textuels..."),
+            ("This collection has no text index. Create one to use text search.", "Cette collection n'a pas d'index textuel. Créez-en un pour utiliser la recherche textuelle."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Rechercher dans la collection « {} » avec l'index textuel « {} ». Les résultats sont triés par pertinence."),
+            ("Search terms", "Termes de recherche"),
+            ("Read-only (block all write operations)", "Lecture seule (bloquer toutes les opérations d'écriture)"),
+            ("Read-only", "Lecture seule"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "La connexion « {} » est en lecture seule. Les opérations d'écriture sont bloquées."),
+            ("Color accent", "Couleur d'accent"),
+            ("None", "Aucune"),
+            ("Reconnect automatically when running a query on a closed connection", "Se reconnecter automatiquement lors de l'exécution d'une requête sur une connexion fermée"),
+            ("Validation Rules...", "Règles de validation..."),
+            ("Validation Rules", "Règles de validation"),
+            ("Loading validation rules...", "Chargement des règles de validation..."),
+            ("Applying validation rules...", "Application des règles de validation..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Modifiez validator, validationLevel et validationAction. L'enregistrement les applique avec collMod ; un validator vide supprime la validation."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Champ non pris en charge \"{}\". Seuls validator, validationLevel et validationAction peuvent être modifiés."),
+            ("The validator must be a document.", "Le validator doit être un document."),
+            ("Insert Document...", "Insérer un document..."),
+            ("By name", "Par nom"),
+            ("By size (largest first)", "Par taille (les plus grands d'abord)"),
+            ("Sort databases and collections", "Trier les bases de données et collections"),
+            ("Copy Results as Markdown Table", "Copier les résultats en tableau Markdown"),
+            ("The results are not a list of documents and cannot be shown as a table.", "Les résultats ne sont pas une liste de documents et ne peuvent pas être affichés sous forme de tableau."),
+            ("All fields", "Tous les champs"),
+            ("Preset name cannot be empty.", "Le nom du préréglage ne peut pas être vide."),
+            ("Projection Preset", "Préréglage de projection"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Les requêtes find sans projection propre ne renvoient que ces champs. Enregistrer sous un nom existant remplace ce préréglage."),
+            ("Fields", "Champs"),
+            ("summary", "résumé"),
+            ("Invalid field path \"{}\".", "Chemin de champ invalide \"{}\"."),
+            ("Enter at least one field to project.", "Saisissez au moins un champ à projeter."),
+            ("Refresh All Connections", "Actualiser toutes les connexions"),
+            ("Copy Selected Rows", "Copier les lignes sélectionnées"),
+            ("Shortcuts", "Raccourcis"),
+            ("Send query", "Envoyer la requête"),
+            ("Rerun last query", "Relancer la dernière requête"),
+            ("Close tab", "Fermer l'onglet"),
+            ("Next tab", "Onglet suivant"),
+            ("Previous tab", "Onglet précédent"),
+            ("Toggle sidebar", "Afficher/masquer la barre latérale"),
+            ("Table view", "Vue tableau"),
+            ("Text view", "Vue texte"),
+            ("Shortcut must end with a key.", "Le raccourci doit se terminer par une touche."),
+            ("Unknown modifier \"{}\".", "Modificateur inconnu \"{}\"."),
+            ("Unknown key \"{}\".", "Touche inconnue \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" et \"{}\" utilisent le même raccourci."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Combinez Ctrl, Shift ou Alt avec une touche, par exemple Ctrl+Shift+Tab. Sous macOS, Ctrl correspond à Cmd."),
+            ("Conflicts with \"{}\".", "Entre en conflit avec \"{}\"."),
+            ("Default Shortcuts", "Raccourcis par défaut"),
+            ("Show Side by Side with Active Tab", "Afficher à côté de l'onglet actif"),
+            ("Synchronize scrolling", "Synchroniser le défilement"),
+            ("Close Side by Side", "Fermer la vue côte à côte"),
+            ("Render at most", "Afficher au plus"),
+            ("0 = no cap", "0 = sans limite"),
+            ("documents per result", "documents par résultat"),
+            ("Showing the first {} of {} returned items.", "Affichage des {} premiers éléments sur {} renvoyés."),
+            ("Find This Document", "Rechercher ce document"),
+            ("Up to stage {} of {}", "Jusqu'à l'étape {} sur {}"),
+            ("All {} stages", "Toutes les étapes ({})"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "L'opération bulkWrite '{}' à l'index {} n'est pas prise en charge. Autorisées : insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Exporter les résultats en JSON..."),
+            ("Export Results to CSV...", "Exporter les résultats en CSV..."),
+            ("Results saved to {}", "Résultats enregistrés dans {}"),
+            ("Failed to export results: {}", "Impossible d'exporter les résultats : {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} onglets n'ont pas été restaurés car leurs connexions n'existent plus."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Verbosité explain inconnue '{}'. Autorisées : queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain attend une chaîne de verbosité : queryPlanner, executionStats ou allPlansExecution."),
+            ("Append Array Element...", "Ajouter un élément au tableau..."),
+            ("Delete Array Element", "Supprimer l'élément du tableau"),
+            ("A new element will be appended to the array", "Un nouvel élément sera ajouté au tableau"),
+            ("Failed to delete the array element: {}", "Impossible de supprimer l'élément du tableau : {}"),
+            ("Read", "Lecture"),
+            ("Read preference", "Préférence de lecture"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Les préférences de lecture secondaires évitent de charger le primaire avec les requêtes d'analyse."),
+            ("Use SRV record (mongodb+srv://)", "Utiliser un enregistrement SRV (mongodb+srv://)"),
+            ("From DNS", "Depuis le DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "Les connexions SRV obtiennent le port depuis le DNS ; laissez le port vide"),
+            ("An SRV connection takes a single host name without a port", "Une connexion SRV accepte un seul nom d'hôte sans port"),
+            ("SSH tunnel cannot be used with an SRV connection", "Le tunnel SSH ne peut pas être utilisé avec une connexion SRV"),
+            ("Insert Template...", "Modèle d'insertion..."),
+            ("Insert Document", "Insérer un document"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Saisissez le nouveau document. Un _id est généré si le document n'en définit pas."),
+            ("Inserting document...", "Insertion du document..."),
+            ("Inserted document with _id {}", "Document inséré avec _id {}"),
+            ("Highlight query syntax in the editor", "Colorer la syntaxe des requêtes dans l'éditeur"),
+            ("Query cancelled. The server may still finish the operation.", "Requête annulée. Le serveur peut tout de même terminer l'opération."),
+            ("Filter by key or value", "Filtrer par clé ou valeur"),
+            ("Match case", "Respecter la casse"),
+            ("{} matches", "{} correspondances"),
+            ("TLS", "TLS"),
+            ("CA file", "Fichier CA"),
+            ("Path to PEM file", "Chemin du fichier PEM"),
+            ("Client certificate and key file", "Fichier de certificat et de clé client"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Autoriser les certificats et noms d'hôte non valides (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Chacune de ces options active TLS pour la connexion."),
+            ("TLS CA file not found: {}", "Fichier CA TLS introuvable : {}"),
+            ("TLS certificate key file not found: {}", "Fichier de certificat et de clé TLS introuvable : {}"),
+            ("Pin to Top", "Épingler en haut"),
+            ("Unpin Collection", "Désépingler la collection"),
+            ("Expand All opens at most", "« Tout développer » ouvre au plus"),
+            ("0 = all levels", "0 = tous les niveaux"),
+            ("levels", "niveaux"),
+            ("Expand All", "Tout développer"),
+            ("Collapse All", "Tout réduire"),
+            ("Saved Queries", "Requêtes enregistrées"),
+            ("Save Query", "Enregistrer la requête"),
+            ("Edit Saved Query", "Modifier la requête enregistrée"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "Aucune requête enregistrée. Utilisez « Enregistrer la requête » dans un onglet de collection pour en ajouter une."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} et {{db}} sont remplacés par les noms de l'onglet dans lequel la requête est chargée."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Utilisez {{collection}} et {{db}} pour les noms de l'onglet dans lequel la requête est chargée."),
+            ("Load", "Charger"),
+            ("Target", "Cible"),
+            ("Query", "Requête"),
+            ("Database (optional)", "Base de données (facultatif)"),
+            ("Collection (optional)", "Collection (facultatif)"),
+            ("Slow operations", "Opérations lentes"),
+            ("Query name cannot be empty.", "Le nom de la requête ne peut pas être vide."),
+            ("Query text cannot be empty.", "Le texte de la requête ne peut pas être vide."),
+            ("A saved query with this name already exists.", "Une requête enregistrée portant ce nom existe déjà."),
+            ("Loaded a query saved for {}.", "Requête enregistrée pour {} chargée."),
+            ("Saved query \"{}\".", "Requête « {} » enregistrée."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce attend les fonctions map et reduce et un objet d'options facultatif."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' doit être une fonction."),
+            ("mapReduce options must be a JSON object.", "Les options de mapReduce doivent être un objet JSON."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' doit être un nom de collection ou un document."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' doit être un document."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' doit être un entier positif ou nul."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "Le paramètre '{}' n'est pas pris en charge dans les options de mapReduce. Autorisés : out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection nécessite un document avec les champs à renvoyer."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Extended JSON canonique"),
+            ("Show Tree", "Afficher l'arbre"),
+            ("Run a query to see its extended JSON.", "Exécutez une requête pour voir son Extended JSON."),
+            ("Group", "Groupe"),
+            ("Ungrouped", "Sans groupe"),
+            ("Auto", "Auto"),
+            ("Save As", "Enregistrer comme"),
+            ("Value must be true or false.", "La valeur doit être true ou false."),
+            ("Clear the text or enter null.", "Effacez le texte ou saisissez null."),
+            ("Cannot convert the value to {}: {}", "Impossible de convertir la valeur en {} : {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "Impossible de convertir la valeur en {} : elle est lue comme {}."),
+            ("Copy as Node.js", "Copier en Node.js"),
+            ("Query copied as a Node.js driver call.", "Requête copiée en appel du pilote Node.js."),
+            ("Cannot copy the query as Node.js: {}", "Impossible de copier la requête en Node.js : {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "Les assistants de replica set n'ont pas d'équivalent dans le pilote Node.js."),
+            ("Unsupported update in bulkWrite.", "Mise à jour non prise en charge dans bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Opération non prise en charge dans bulkWrite."),
+            ("(none)", "(aucun)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Les collections partitionnées ne peuvent pas être vidées en les recréant. Utilisez la suppression normale."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "La collection a été supprimée mais n'a pas pu être recréée : {}. Options perdues : {}. Index perdus : {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "La collection a été recréée mais ses index n'ont pas pu être restaurés : {}. Index perdus : {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "Le paramètre '{}' n'est pas pris en charge dans les options de find. Autorisés : sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "L'élément {} sera supprimé du document. Cette action est irréversible."),
+            ("Diagnostic report saved to {}", "Rapport de diagnostic enregistré dans {}"),
+            ("Failed to save diagnostic report: {}", "Impossible d'enregistrer le rapport de diagnostic : {}"),
         ])
     })
 }
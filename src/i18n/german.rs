@@ -29,7 +29,7 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
             ("Hide Index", "Index ausblenden"),
             ("Unhide Index", "Index einblenden"),
             ("comment expects a value.", "comment erwartet einen Wert."),
-            ("explain must be followed by find(...).", "explain muss von find(...) gefolgt werden."),
+            ("explain must be followed by find(...) or aggregate(...).", "explain muss von find(...) oder aggregate(...) gefolgt werden."),
             ("finish does not take any arguments.", "finish akzeptiert keine Argumente."),
             (
                 "No methods are supported after finish().",
@@ -328,10 +328,6 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "Der Index \"{}\" der Sammlung \"{}\" in der Datenbank \"{}\" wird gelöscht. Diese Aktion kann nicht rückgängig gemacht werden.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Löschen des Index bestätigen, Namen \"{}\" eingeben.",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne erwartet einen Filter, ein Update und ein optionales Options-Objekt.",
@@ -373,8 +369,8 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany akzeptiert einen Filter und ein optionales Options-Objekt.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "Methode {} wird nicht unterstützt. Verfügbare Methoden: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Methode {} wird nicht unterstützt. Verfügbare Methoden: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -778,10 +774,6 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
                 "The second argument to Code must be an object.",
                 "Das zweite Argument von Code muss ein Objekt sein.",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "Genauen Indexnamen zur Bestätigung eingeben.",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "Genauen Sammlungsnamen zur Bestätigung eingeben.",
@@ -979,8 +971,8 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
                 "Parameter '{}' wird in watch-Optionen nicht unterstützt. Resume-Tokens werden nicht unterstützt.",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "Parameter '{}' wird in aggregate-Optionen nicht unterstützt. Zulässig: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "Parameter '{}' wird in aggregate-Optionen nicht unterstützt. Zulässig: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1113,6 +1105,330 @@ pub(crate) fn german_map() -> &'static HashMap<&'static str, &'static str> {
                 "Pipeline element at index {} must be a JSON object.",
                 "Das Pipeline-Element am Index {} muss ein JSON-Objekt sein.",
             ),
+            ("Select for Compare", "Zum Vergleich auswählen"),
+            ("Compare with Selected", "Mit Auswahl vergleichen"),
+            ("Compare Documents", "Dokumente vergleichen"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Hinzugefügt: {}, entfernt: {}, geändert: {}, unverändert: {}"),
+            ("Documents are identical", "Die Dokumente sind identisch"),
+            ("Field", "Feld"),
+            ("Show unchanged fields", "Unveränderte Felder anzeigen"),
+            ("Document without _id", "Dokument ohne _id"),
+            ("Connections: {} of {}", "Verbindungen: {} von {}"),
+            ("Tabs: {}", "Tabs: {}"),
+            ("Theme: {}", "Design: {}"),
+            ("Language: {}", "Sprache: {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "Skip {} liegt hinter den {} passenden Dokumenten. Die letzte Seite beginnt bei Skip {}."),
+            ("Sample Documents", "Stichprobe von Dokumenten"),
+            ("Query Log", "Abfrageprotokoll"),
+            ("Filter queries", "Abfragen filtern"),
+            ("Clear", "Leeren"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Fehler: {}"),
+            ("No queries executed yet", "Noch keine Abfragen ausgeführt"),
+            ("Invalid port in host address '{}'", "Ungültiger Port in Host-Adresse '{}'"),
+            ("Invalid host address '{}'", "Ungültige Host-Adresse '{}'"),
+            ("Direct connection supports only a single host", "Direkte Verbindung unterstützt nur einen einzelnen Host"),
+            ("SSH tunnel supports only a single host", "SSH-Tunnel unterstützt nur einen einzelnen Host"),
+            ("Optional", "Optional"),
+            ("Replica set name", "Replikatsatz-Name"),
+            ("Replica set name requires the ReplicaSet connection type", "Der Replikatsatz-Name erfordert den Verbindungstyp ReplicaSet"),
+            ("Server default", "Serverstandard"),
+            ("Read concern", "Read Concern"),
+            ("Write concern (w)", "Write Concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "Write Concern muss eine Zahl, \"majority\" oder ein Tag-Set-Name sein"),
+            ("Preview", "Vorschau"),
+            ("Effective query:", "Effektive Abfrage:"),
+            ("Skip and limit do not apply to this operation", "Skip und Limit gelten nicht für diese Operation"),
+            ("Query timeout", "Abfrage-Timeout"),
+            ("0 = no timeout", "0 = kein Timeout"),
+            ("Large document ({}), expand to load", "Großes Dokument ({}), zum Laden aufklappen"),
+            ("Deeply nested document ({} levels), expand to load", "Tief verschachteltes Dokument ({} Ebenen), zum Laden aufklappen"),
+            ("No methods are supported after explain().aggregate(...).", "Nach explain().aggregate(...) werden keine Methoden unterstützt."),
+            ("Method '{}' is not supported after aggregate(...).", "Methode '{}' wird nach aggregate(...) nicht unterstützt."),
+            ("Sidebar", "Seitenleiste"),
+            ("Pin Tab", "Tab anheften"),
+            ("Unpin Tab", "Tab lösen"),
+            ("Reuse the current unpinned tab when opening a collection", "Aktuellen nicht angehefteten Tab beim Öffnen einer Collection wiederverwenden"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "Der Positionsoperator '$[{}]' hat keinen passenden Eintrag in arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "Der arrayFilters-Eintrag für den Bezeichner '{}' wird im Update nicht verwendet."),
+            ("Show relative time next to dates", "Relative Zeit neben Datumswerten anzeigen"),
+            ("just now", "gerade eben"),
+            ("{} s", "{} s"),
+            ("{} min", "{} Min."),
+            ("{} h", "{} Std."),
+            ("{} d", "{} T."),
+            ("{} mo", "{} Mon."),
+            ("{} y", "{} J."),
+            ("{} ago", "vor {}"),
+            ("in {}", "in {}"),
+            ("No documents matched the query.", "Keine Dokumente entsprechen der Abfrage."),
+            ("No distinct values found.", "Keine eindeutigen Werte gefunden."),
+            ("Adjust the filter or pipeline and run the query again.", "Passen Sie Filter oder Pipeline an und führen Sie die Abfrage erneut aus."),
+            ("Copy _id", "_id kopieren"),
+            ("Enter the exact index name or key specification to confirm.", "Geben Sie zur Bestätigung den genauen Indexnamen oder die Schlüsselspezifikation ein."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Bestätigen Sie das Löschen des Index durch Eingabe seines Namens \"{}\" oder seiner Schlüsselspezifikation."),
+            ("Index Builder", "Index-Generator"),
+            ("Index Builder...", "Index-Generator..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Index für die Collection \"{}\" in der Datenbank \"{}\" erstellen."),
+            ("Add Field", "Feld hinzufügen"),
+            ("Index name", "Indexname"),
+            ("Generated from fields", "Aus den Feldern erzeugt"),
+            ("TTL (seconds)", "TTL (Sekunden)"),
+            ("No expiration", "Kein Ablauf"),
+            ("Partial filter", "Teilfilter"),
+            ("Unique", "Eindeutig"),
+            ("Sparse", "Sparse"),
+            ("Open in Editor", "Im Editor öffnen"),
+            ("Choose at least one field for the index.", "Wählen Sie mindestens ein Feld für den Index."),
+            ("Field '{}' is used more than once.", "Das Feld '{}' wird mehrfach verwendet."),
+            ("TTL must be a non-negative number of seconds.", "TTL muss eine nicht negative Anzahl von Sekunden sein."),
+            ("TTL indexes must have exactly one field.", "TTL-Indizes müssen genau ein Feld haben."),
+            ("Loading existing indexes...", "Vorhandene Indizes werden geladen..."),
+            ("Existing indexes are unavailable: {}", "Vorhandene Indizes sind nicht verfügbar: {}"),
+            ("Existing indexes", "Vorhandene Indizes"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "Der Index \"{}\" verwendet bereits dieses Schlüsselmuster. Sie können den Index trotzdem erstellen."),
+            ("Export Diagnostic Report", "Diagnosebericht exportieren"),
+            ("Run", "Ausführen"),
+            ("Confirm Aggregation Output", "Ausgabe der Aggregation bestätigen"),
+            ("The pipeline writes its results with {} into {}.", "Die Pipeline schreibt ihre Ergebnisse mit {} nach {}."),
+            ("The target differs from the source collection {}.", "Das Ziel unterscheidet sich von der Quellsammlung {}."),
+            ("$out replaces all documents in the target collection.", "$out ersetzt alle Dokumente in der Zielsammlung."),
+            ("$merge inserts or updates documents in the target collection.", "$merge fügt Dokumente in die Zielsammlung ein oder aktualisiert sie."),
+            ("Open Response in External Editor", "Antwort in externem Editor öffnen"),
+            ("Tab title", "Tab-Titel"),
+            ("Collection", "Sammlung"),
+            ("Database / Collection", "Datenbank / Sammlung"),
+            ("Connection / Collection", "Verbindung / Sammlung"),
+            ("Connection / Database / Collection", "Verbindung / Datenbank / Sammlung"),
+            ("Ask before closing tabs with edited queries that were not run", "Vor dem Schließen von Tabs mit bearbeiteten, nicht ausgeführten Abfragen nachfragen"),
+            ("Close Anyway", "Trotzdem schließen"),
+            ("Close Tab", "Tab schließen"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "Die Abfrage im Tab \"{}\" wurde bearbeitet und nicht ausgeführt. Beim Schließen des Tabs geht sie verloren."),
+            ("Rename Tab", "Tab umbenennen"),
+            ("Prefill new tabs with a find query", "Neue Tabs mit einer find-Abfrage vorbelegen"),
+            ("Count Documents", "Dokumente zählen"),
+            ("Estimated Count", "Geschätzte Anzahl"),
+            ("Counting documents in {}...", "Dokumente in {} werden gezählt..."),
+            ("{}: about {} documents (estimated)", "{}: etwa {} Dokumente (geschätzt)"),
+            ("{}: {} documents", "{}: {} Dokumente"),
+            ("{}: unexpected count result", "{}: unerwartetes Zählergebnis"),
+            ("Failed to count documents in {}: {}", "Dokumente in {} konnten nicht gezählt werden: {}"),
+            ("Database name cannot be empty.", "Der Datenbankname darf nicht leer sein."),
+            ("Database name cannot contain null characters.", "Der Datenbankname darf keine Nullzeichen enthalten."),
+            ("Database name cannot contain the character '{}'.", "Der Datenbankname darf das Zeichen '{}' nicht enthalten."),
+            ("Database name must be at most {} bytes long.", "Der Datenbankname darf höchstens {} Bytes lang sein."),
+            ("Collection name cannot contain null characters.", "Der Sammlungsname darf keine Nullzeichen enthalten."),
+            ("Collection name cannot contain the character '{}'.", "Der Sammlungsname darf das Zeichen '{}' nicht enthalten."),
+            ("Collection name cannot start with 'system.'.", "Der Sammlungsname darf nicht mit 'system.' beginnen."),
+            ("Namespace '{}' exceeds {} bytes.", "Der Namespace '{}' überschreitet {} Bytes."),
+            ("readConcern requires a document with a level.", "readConcern erfordert ein Dokument mit einem Level."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern muss ein Dokument mit einem einzigen Zeichenfolgenfeld 'level' sein."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Nicht unterstütztes readConcern-Level '{}'. Erlaubt: local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "Die Methode '{}' wird nach find(...) nicht unterstützt. Unterstützt: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "Die Pipeline-Stufe an Index {} ist leer."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "Die Pipeline-Stufe an Index {} muss mit '$' beginnen, gefunden wurde '{}'. Schließen Sie den Ausdruck in eine Stufe wie $match ein."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "Die Pipeline-Stufe an Index {} muss genau einen Operator enthalten, gefunden: {}."),
+            ("Date Format Preview", "Datumsformat-Vorschau"),
+            ("Date field", "Datumsfeld"),
+            ("Sample date", "Beispieldatum"),
+            ("Format", "Format"),
+            ("Timezone", "Zeitzone"),
+            ("Result", "Ergebnis"),
+            ("Copy Expression", "Ausdruck kopieren"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Platzhalter: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Geben Sie das Beispieldatum im ISO-8601-Format ein, z. B. 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Nicht unterstützte Zeitzone '{}'. Verwenden Sie UTC oder einen Versatz wie +03:00."),
+            ("Unmatched '%' at the end of the format string.", "Einzelnes '%' am Ende der Formatzeichenfolge."),
+            ("Invalid format character '%{}' in the format string.", "Ungültiges Formatzeichen '%{}' in der Formatzeichenfolge."),
+            ("Value must be a valid base64 string.", "Der Wert muss eine gültige base64-Zeichenfolge sein."),
+            ("Generic", "Generisch"),
+            ("Function", "Funktion"),
+            ("Binary (old)", "Binary (alt)"),
+            ("UUID (old)", "UUID (alt)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Verschlüsselt"),
+            ("Column", "Spalte"),
+            ("Sensitive", "Sensibel"),
+            ("User defined", "Benutzerdefiniert"),
+            ("Reserved", "Reserviert"),
+            ("Subtype", "Untertyp"),
+            ("Input Format", "Eingabeformat"),
+            ("Saving will change the field type from {} to {}.", "Beim Speichern ändert sich der Feldtyp von {} zu {}."),
+            ("Press Save Anyway to confirm.", "Klicken Sie zur Bestätigung auf Trotzdem speichern."),
+            ("Save Anyway", "Trotzdem speichern"),
+            ("Group connections by host in the sidebar", "Verbindungen in der Seitenleiste nach Host gruppieren"),
+            ("Compact sidebar density", "Kompakte Seitenleiste"),
+            ("Test All", "Alle testen"),
+            ("OK ({} ms)", "OK ({} ms)"),
+            ("Failed after {} ms: {}", "Fehlgeschlagen nach {} ms: {}"),
+            ("Skipped: the password is requested on connect", "Übersprungen: Das Passwort wird beim Verbinden abgefragt"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "Das Feld _id kann nicht direkt geändert werden. Aktivieren Sie „Ändern von _id erlauben“, um das Dokument zu ersetzen."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Ändern von _id erlauben (das Dokument wird neu eingefügt, nicht aktualisiert)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "Die _id wurde geändert. Beim Speichern wird ein neues Dokument eingefügt und das ursprüngliche gelöscht; dies ist keine direkte Aktualisierung. Klicken Sie zur Bestätigung auf „Dokument ersetzen“."),
+            ("Replace Document", "Dokument ersetzen"),
+            ("Rollback failed: {}", "Rückgängigmachen fehlgeschlagen: {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Sammlung löschen und neu erstellen (schneller bei großen Sammlungen)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "Die Sammlung wird gelöscht und mit denselben Optionen neu erstellt, danach werden ihre Indizes neu aufgebaut. Andere Clients sehen die Sammlung eventuell kurzzeitig nicht."),
+            ("Collection \"{}\" not found.", "Sammlung „{}“ nicht gefunden."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Nur normale Sammlungen können durch Neuerstellen geleert werden. Verwenden Sie stattdessen das normale Löschen."),
+            ("Text Search...", "Textsuche..."),
+            ("Text Search", "Textsuche"),
+            ("Checking text indexes...", "Textindizes werden geprüft..."),
+            ("This collection has no text index. Create one to use text search.", "Diese Sammlung hat keinen Textindex. Erstellen Sie einen, um die Textsuche zu verwenden."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Sammlung „{}“ mit dem Textindex „{}“ durchsuchen. Die Ergebnisse werden nach Relevanz sortiert."),
+            ("Search terms", "Suchbegriffe"),
+            ("Read-only (block all write operations)", "Schreibgeschützt (alle Schreibvorgänge blockieren)"),
+            ("Read-only", "Schreibgeschützt"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "Die Verbindung „{}“ ist schreibgeschützt. Schreibvorgänge sind blockiert."),
+            ("Color accent", "Akzentfarbe"),
+            ("None", "Keine"),
+            ("Reconnect automatically when running a query on a closed connection", "Beim Ausführen einer Abfrage auf einer geschlossenen Verbindung automatisch neu verbinden"),
+            ("Validation Rules...", "Validierungsregeln..."),
+            ("Validation Rules", "Validierungsregeln"),
+            ("Loading validation rules...", "Validierungsregeln werden geladen..."),
+            ("Applying validation rules...", "Validierungsregeln werden angewendet..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Bearbeiten Sie validator, validationLevel und validationAction. Beim Speichern werden sie mit collMod angewendet; ein leerer validator entfernt die Validierung."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Nicht unterstütztes Feld \"{}\". Nur validator, validationLevel und validationAction können geändert werden."),
+            ("The validator must be a document.", "Der validator muss ein Dokument sein."),
+            ("Insert Document...", "Dokument einfügen..."),
+            ("By name", "Nach Name"),
+            ("By size (largest first)", "Nach Größe (größte zuerst)"),
+            ("Sort databases and collections", "Datenbanken und Collections sortieren"),
+            ("Copy Results as Markdown Table", "Ergebnisse als Markdown-Tabelle kopieren"),
+            ("The results are not a list of documents and cannot be shown as a table.", "Die Ergebnisse sind keine Dokumentliste und können nicht als Tabelle dargestellt werden."),
+            ("All fields", "Alle Felder"),
+            ("Preset name cannot be empty.", "Der Vorlagenname darf nicht leer sein."),
+            ("Projection Preset", "Projektionsvorlage"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Find-Abfragen ohne eigene Projektion liefern nur diese Felder. Speichern unter einem vorhandenen Namen ersetzt diese Vorlage."),
+            ("Fields", "Felder"),
+            ("summary", "übersicht"),
+            ("Invalid field path \"{}\".", "Ungültiger Feldpfad \"{}\"."),
+            ("Enter at least one field to project.", "Geben Sie mindestens ein Feld für die Projektion an."),
+            ("Refresh All Connections", "Alle Verbindungen aktualisieren"),
+            ("Copy Selected Rows", "Ausgewählte Zeilen kopieren"),
+            ("Shortcuts", "Tastenkürzel"),
+            ("Send query", "Abfrage senden"),
+            ("Rerun last query", "Letzte Abfrage wiederholen"),
+            ("Close tab", "Tab schließen"),
+            ("Next tab", "Nächster Tab"),
+            ("Previous tab", "Vorheriger Tab"),
+            ("Toggle sidebar", "Seitenleiste ein-/ausblenden"),
+            ("Table view", "Tabellenansicht"),
+            ("Text view", "Textansicht"),
+            ("Shortcut must end with a key.", "Das Tastenkürzel muss mit einer Taste enden."),
+            ("Unknown modifier \"{}\".", "Unbekannte Zusatztaste \"{}\"."),
+            ("Unknown key \"{}\".", "Unbekannte Taste \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" und \"{}\" verwenden dasselbe Tastenkürzel."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Kombinieren Sie Ctrl, Shift oder Alt mit einer Taste, zum Beispiel Ctrl+Shift+Tab. Unter macOS steht Ctrl für Cmd."),
+            ("Conflicts with \"{}\".", "Kollidiert mit \"{}\"."),
+            ("Default Shortcuts", "Standard-Tastenkürzel"),
+            ("Show Side by Side with Active Tab", "Neben aktivem Tab anzeigen"),
+            ("Synchronize scrolling", "Scrollen synchronisieren"),
+            ("Close Side by Side", "Nebeneinander-Ansicht schließen"),
+            ("Render at most", "Höchstens anzeigen"),
+            ("0 = no cap", "0 = unbegrenzt"),
+            ("documents per result", "Dokumente pro Ergebnis"),
+            ("Showing the first {} of {} returned items.", "Die ersten {} von {} zurückgegebenen Elementen werden angezeigt."),
+            ("Find This Document", "Dieses Dokument suchen"),
+            ("Up to stage {} of {}", "Bis Stufe {} von {}"),
+            ("All {} stages", "Alle {} Stufen"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "Die bulkWrite-Operation '{}' an Index {} wird nicht unterstützt. Erlaubt: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Ergebnisse als JSON exportieren..."),
+            ("Export Results to CSV...", "Ergebnisse als CSV exportieren..."),
+            ("Results saved to {}", "Ergebnisse gespeichert unter {}"),
+            ("Failed to export results: {}", "Ergebnisse konnten nicht exportiert werden: {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} Tabs wurden nicht wiederhergestellt, weil ihre Verbindungen nicht mehr existieren."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Unbekannte explain-Ausführlichkeit '{}'. Erlaubt: queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain erwartet eine Ausführlichkeitsangabe: queryPlanner, executionStats oder allPlansExecution."),
+            ("Append Array Element...", "Array-Element anhängen..."),
+            ("Delete Array Element", "Array-Element löschen"),
+            ("A new element will be appended to the array", "Ein neues Element wird an das Array angehängt"),
+            ("Failed to delete the array element: {}", "Array-Element konnte nicht gelöscht werden: {}"),
+            ("Read", "Lesen"),
+            ("Read preference", "Lesepräferenz"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Sekundäre Lesepräferenzen halten Analyseabfragen vom Primary fern."),
+            ("Use SRV record (mongodb+srv://)", "SRV-Eintrag verwenden (mongodb+srv://)"),
+            ("From DNS", "Aus DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "SRV-Verbindungen beziehen den Port aus dem DNS; lassen Sie den Port leer"),
+            ("An SRV connection takes a single host name without a port", "Eine SRV-Verbindung akzeptiert nur einen Hostnamen ohne Port"),
+            ("SSH tunnel cannot be used with an SRV connection", "Ein SSH-Tunnel kann nicht mit einer SRV-Verbindung verwendet werden"),
+            ("Insert Template...", "Einfüge-Vorlage..."),
+            ("Insert Document", "Dokument einfügen"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Geben Sie das neue Dokument ein. Ohne eigenes _id wird eines erzeugt."),
+            ("Inserting document...", "Dokument wird eingefügt..."),
+            ("Inserted document with _id {}", "Dokument mit _id {} eingefügt"),
+            ("Highlight query syntax in the editor", "Abfragesyntax im Editor hervorheben"),
+            ("Query cancelled. The server may still finish the operation.", "Abfrage abgebrochen. Der Server kann den Vorgang trotzdem abschließen."),
+            ("Filter by key or value", "Nach Schlüssel oder Wert filtern"),
+            ("Match case", "Groß-/Kleinschreibung beachten"),
+            ("{} matches", "{} Treffer"),
+            ("TLS", "TLS"),
+            ("CA file", "CA-Datei"),
+            ("Path to PEM file", "Pfad zur PEM-Datei"),
+            ("Client certificate and key file", "Datei mit Client-Zertifikat und Schlüssel"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Ungültige Zertifikate und Hostnamen zulassen (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Jede dieser Optionen aktiviert TLS für die Verbindung."),
+            ("TLS CA file not found: {}", "TLS-CA-Datei nicht gefunden: {}"),
+            ("TLS certificate key file not found: {}", "TLS-Zertifikat-/Schlüsseldatei nicht gefunden: {}"),
+            ("Pin to Top", "Oben anheften"),
+            ("Unpin Collection", "Sammlung lösen"),
+            ("Expand All opens at most", "„Alle aufklappen“ öffnet höchstens"),
+            ("0 = all levels", "0 = alle Ebenen"),
+            ("levels", "Ebenen"),
+            ("Expand All", "Alle aufklappen"),
+            ("Collapse All", "Alle zuklappen"),
+            ("Saved Queries", "Gespeicherte Abfragen"),
+            ("Save Query", "Abfrage speichern"),
+            ("Edit Saved Query", "Gespeicherte Abfrage bearbeiten"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "Keine gespeicherten Abfragen. Verwenden Sie „Abfrage speichern“ in einem Sammlungs-Tab, um eine hinzuzufügen."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} und {{db}} werden durch die Namen des Tabs ersetzt, in den die Abfrage geladen wird."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Verwenden Sie {{collection}} und {{db}} für die Namen des Tabs, in den die Abfrage geladen wird."),
+            ("Load", "Laden"),
+            ("Target", "Ziel"),
+            ("Query", "Abfrage"),
+            ("Database (optional)", "Datenbank (optional)"),
+            ("Collection (optional)", "Sammlung (optional)"),
+            ("Slow operations", "Langsame Operationen"),
+            ("Query name cannot be empty.", "Der Abfragename darf nicht leer sein."),
+            ("Query text cannot be empty.", "Der Abfragetext darf nicht leer sein."),
+            ("A saved query with this name already exists.", "Eine gespeicherte Abfrage mit diesem Namen existiert bereits."),
+            ("Loaded a query saved for {}.", "Für {} gespeicherte Abfrage geladen."),
+            ("Saved query \"{}\".", "Abfrage „{}“ gespeichert."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce erwartet map- und reduce-Funktionen und ein optionales Optionsobjekt."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' muss eine Funktion sein."),
+            ("mapReduce options must be a JSON object.", "mapReduce-Optionen müssen ein JSON-Objekt sein."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' muss ein Sammlungsname oder ein Dokument sein."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' muss ein Dokument sein."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' muss eine nicht negative ganze Zahl sein."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "Parameter '{}' wird in mapReduce-Optionen nicht unterstützt. Erlaubt: out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection erfordert ein Dokument mit den zurückzugebenden Feldern."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Kanonisches Extended JSON"),
+            ("Show Tree", "Baum anzeigen"),
+            ("Run a query to see its extended JSON.", "Führen Sie eine Abfrage aus, um ihr Extended JSON zu sehen."),
+            ("Group", "Gruppe"),
+            ("Ungrouped", "Ohne Gruppe"),
+            ("Auto", "Auto"),
+            ("Save As", "Speichern als"),
+            ("Value must be true or false.", "Der Wert muss true oder false sein."),
+            ("Clear the text or enter null.", "Leeren Sie den Text oder geben Sie null ein."),
+            ("Cannot convert the value to {}: {}", "Der Wert kann nicht in {} umgewandelt werden: {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "Der Wert kann nicht in {} umgewandelt werden: er wird als {} gelesen."),
+            ("Copy as Node.js", "Als Node.js kopieren"),
+            ("Query copied as a Node.js driver call.", "Abfrage als Node.js-Treiberaufruf kopiert."),
+            ("Cannot copy the query as Node.js: {}", "Die Abfrage kann nicht als Node.js kopiert werden: {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "Replica-Set-Hilfsfunktionen haben keine Entsprechung im Node.js-Treiber."),
+            ("Unsupported update in bulkWrite.", "Nicht unterstützte Aktualisierung in bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Nicht unterstützte Operation in bulkWrite."),
+            ("(none)", "(keine)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Geshardete Collections können nicht durch Neuerstellen geleert werden. Verwenden Sie das normale Löschen."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "Die Collection wurde gelöscht, konnte aber nicht neu erstellt werden: {}. Verlorene Optionen: {}. Verlorene Indizes: {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "Die Collection wurde neu erstellt, aber ihre Indizes konnten nicht wiederhergestellt werden: {}. Verlorene Indizes: {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "Der Parameter '{}' wird in den find-Optionen nicht unterstützt. Erlaubt: sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "Das Element {} wird aus dem Dokument entfernt. Dies kann nicht rückgängig gemacht werden."),
+            ("Diagnostic report saved to {}", "Diagnosebericht gespeichert unter {}"),
+            ("Failed to save diagnostic report: {}", "Diagnosebericht konnte nicht gespeichert werden: {}"),
         ])
     })
 }
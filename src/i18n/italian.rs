@@ -29,7 +29,7 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
             ("Hide Index", "Nascondi indice"),
             ("Unhide Index", "Mostra indice"),
             ("comment expects a value.", "comment richiede un valore."),
-            ("explain must be followed by find(...).", "explain deve essere seguito da find(...)."),
+            ("explain must be followed by find(...) or aggregate(...).", "explain deve essere seguito da find(...) o aggregate(...)."),
             ("finish does not take any arguments.", "finish non accetta argomenti."),
             (
                 "No methods are supported after finish().",
@@ -307,10 +307,6 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "L'indice \"{}\" della collezione \"{}\" nel database \"{}\" sarà eliminato. Questa azione non può essere annullata.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Conferma l'eliminazione dell'indice inserendo il nome \"{}\".",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne richiede un filter, un update e un oggetto options opzionale.",
@@ -352,8 +348,8 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany accetta un filter e un oggetto options opzionale.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "Il metodo {} non è supportato. Metodi disponibili: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Il metodo {} non è supportato. Metodi disponibili: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -745,10 +741,6 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
                 "The second argument to Code must be an object.",
                 "Il secondo argomento di Code deve essere un oggetto.",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "Inserisci il nome esatto dell'indice per confermare.",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "Inserisci il nome esatto della collezione per confermare.",
@@ -949,8 +941,8 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
                 "Il parametro '{}' non è supportato nelle opzioni watch. I resume token non sono supportati.",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "Il parametro '{}' non è supportato nelle opzioni aggregate. Consentiti: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "Il parametro '{}' non è supportato nelle opzioni aggregate. Consentiti: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1080,6 +1072,330 @@ pub(crate) fn italian_map() -> &'static HashMap<&'static str, &'static str> {
                 "Pipeline element at index {} must be a JSON object.",
                 "L'elemento pipeline all'indice {} deve essere un oggetto JSON.",
             ),
+            ("Select for Compare", "Seleziona per il confronto"),
+            ("Compare with Selected", "Confronta con il selezionato"),
+            ("Compare Documents", "Confronta documenti"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Aggiunti: {}, rimossi: {}, modificati: {}, invariati: {}"),
+            ("Documents are identical", "I documenti sono identici"),
+            ("Field", "Campo"),
+            ("Show unchanged fields", "Mostra campi invariati"),
+            ("Document without _id", "Documento senza _id"),
+            ("Connections: {} of {}", "Connessioni: {} di {}"),
+            ("Tabs: {}", "Schede: {}"),
+            ("Theme: {}", "Tema: {}"),
+            ("Language: {}", "Lingua: {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "Lo skip {} supera i {} documenti corrispondenti. L'ultima pagina inizia con skip {}."),
+            ("Sample Documents", "Documenti di esempio"),
+            ("Query Log", "Registro query"),
+            ("Filter queries", "Filtra query"),
+            ("Clear", "Cancella"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Errore: {}"),
+            ("No queries executed yet", "Nessuna query ancora eseguita"),
+            ("Invalid port in host address '{}'", "Porta non valida nell'indirizzo host '{}'"),
+            ("Invalid host address '{}'", "Indirizzo host non valido '{}'"),
+            ("Direct connection supports only a single host", "La connessione diretta supporta un solo host"),
+            ("SSH tunnel supports only a single host", "Il tunnel SSH supporta un solo host"),
+            ("Optional", "Facoltativo"),
+            ("Replica set name", "Nome del replica set"),
+            ("Replica set name requires the ReplicaSet connection type", "Il nome del replica set richiede il tipo di connessione ReplicaSet"),
+            ("Server default", "Predefinito del server"),
+            ("Read concern", "Read concern"),
+            ("Write concern (w)", "Write concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "Il write concern deve essere un numero, \"majority\" o il nome di un tag set"),
+            ("Preview", "Anteprima"),
+            ("Effective query:", "Query effettiva:"),
+            ("Skip and limit do not apply to this operation", "Skip e limit non si applicano a questa operazione"),
+            ("Query timeout", "Timeout della query"),
+            ("0 = no timeout", "0 = nessun timeout"),
+            ("Large document ({}), expand to load", "Documento grande ({}), espandi per caricare"),
+            ("Deeply nested document ({} levels), expand to load", "Documento profondamente annidato ({} livelli), espandi per caricare"),
+            ("No methods are supported after explain().aggregate(...).", "Nessun metodo è supportato dopo explain().aggregate(...)."),
+            ("Method '{}' is not supported after aggregate(...).", "Il metodo '{}' non è supportato dopo aggregate(...)."),
+            ("Sidebar", "Barra laterale"),
+            ("Pin Tab", "Fissa scheda"),
+            ("Unpin Tab", "Sblocca scheda"),
+            ("Reuse the current unpinned tab when opening a collection", "Riutilizza la scheda corrente non fissata all'apertura di una collezione"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "L'operatore posizionale '$[{}]' non ha una voce corrispondente in arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "La voce arrayFilters per l'identificatore '{}' non è usata nell'aggiornamento."),
+            ("Show relative time next to dates", "Mostra il tempo relativo accanto alle date"),
+            ("just now", "proprio ora"),
+            ("{} s", "{} s"),
+            ("{} min", "{} min"),
+            ("{} h", "{} h"),
+            ("{} d", "{} g"),
+            ("{} mo", "{} mesi"),
+            ("{} y", "{} a"),
+            ("{} ago", "{} fa"),
+            ("in {}", "tra {}"),
+            ("No documents matched the query.", "Nessun documento corrisponde alla query."),
+            ("No distinct values found.", "Nessun valore distinto trovato."),
+            ("Adjust the filter or pipeline and run the query again.", "Modifica il filtro o la pipeline ed esegui di nuovo la query."),
+            ("Copy _id", "Copia _id"),
+            ("Enter the exact index name or key specification to confirm.", "Inserisci il nome esatto dell'indice o la specifica delle chiavi per confermare."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Conferma l'eliminazione dell'indice inserendo il nome \"{}\" o la specifica delle chiavi."),
+            ("Index Builder", "Generatore di indici"),
+            ("Index Builder...", "Generatore di indici..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Crea un indice sulla collezione \"{}\" del database \"{}\"."),
+            ("Add Field", "Aggiungi campo"),
+            ("Index name", "Nome indice"),
+            ("Generated from fields", "Generato dai campi"),
+            ("TTL (seconds)", "TTL (secondi)"),
+            ("No expiration", "Nessuna scadenza"),
+            ("Partial filter", "Filtro parziale"),
+            ("Unique", "Univoco"),
+            ("Sparse", "Sparso"),
+            ("Open in Editor", "Apri nell'editor"),
+            ("Choose at least one field for the index.", "Scegli almeno un campo per l'indice."),
+            ("Field '{}' is used more than once.", "Il campo '{}' è usato più di una volta."),
+            ("TTL must be a non-negative number of seconds.", "Il TTL deve essere un numero non negativo di secondi."),
+            ("TTL indexes must have exactly one field.", "Gli indici TTL devono avere esattamente un campo."),
+            ("Loading existing indexes...", "Caricamento degli indici esistenti..."),
+            ("Existing indexes are unavailable: {}", "Gli indici esistenti non sono disponibili: {}"),
+            ("Existing indexes", "Indici esistenti"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "L'indice \"{}\" usa già questo schema di chiavi. È comunque possibile creare l'indice."),
+            ("Export Diagnostic Report", "Esporta rapporto diagnostico"),
+            ("Run", "Esegui"),
+            ("Confirm Aggregation Output", "Conferma output dell'aggregazione"),
+            ("The pipeline writes its results with {} into {}.", "La pipeline scrive i risultati con {} in {}."),
+            ("The target differs from the source collection {}.", "La destinazione è diversa dalla collezione di origine {}."),
+            ("$out replaces all documents in the target collection.", "$out sostituisce tutti i documenti nella collezione di destinazione."),
+            ("$merge inserts or updates documents in the target collection.", "$merge inserisce o aggiorna documenti nella collezione di destinazione."),
+            ("Open Response in External Editor", "Apri risposta in un editor esterno"),
+            ("Tab title", "Titolo della scheda"),
+            ("Collection", "Collezione"),
+            ("Database / Collection", "Database / Collezione"),
+            ("Connection / Collection", "Connessione / Collezione"),
+            ("Connection / Database / Collection", "Connessione / Database / Collezione"),
+            ("Ask before closing tabs with edited queries that were not run", "Chiedi prima di chiudere le schede con query modificate non eseguite"),
+            ("Close Anyway", "Chiudi comunque"),
+            ("Close Tab", "Chiudi scheda"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "La query nella scheda \"{}\" è stata modificata e non eseguita. Chiudendo la scheda andrà persa."),
+            ("Rename Tab", "Rinomina scheda"),
+            ("Prefill new tabs with a find query", "Precompila le nuove schede con una query find"),
+            ("Count Documents", "Conta documenti"),
+            ("Estimated Count", "Conteggio stimato"),
+            ("Counting documents in {}...", "Conteggio dei documenti in {}..."),
+            ("{}: about {} documents (estimated)", "{}: circa {} documenti (stima)"),
+            ("{}: {} documents", "{}: {} documenti"),
+            ("{}: unexpected count result", "{}: risultato del conteggio inatteso"),
+            ("Failed to count documents in {}: {}", "Impossibile contare i documenti in {}: {}"),
+            ("Database name cannot be empty.", "Il nome del database non può essere vuoto."),
+            ("Database name cannot contain null characters.", "Il nome del database non può contenere caratteri nulli."),
+            ("Database name cannot contain the character '{}'.", "Il nome del database non può contenere il carattere '{}'."),
+            ("Database name must be at most {} bytes long.", "Il nome del database deve essere lungo al massimo {} byte."),
+            ("Collection name cannot contain null characters.", "Il nome della collezione non può contenere caratteri nulli."),
+            ("Collection name cannot contain the character '{}'.", "Il nome della collezione non può contenere il carattere '{}'."),
+            ("Collection name cannot start with 'system.'.", "Il nome della collezione non può iniziare con 'system.'."),
+            ("Namespace '{}' exceeds {} bytes.", "Lo spazio dei nomi '{}' supera i {} byte."),
+            ("readConcern requires a document with a level.", "readConcern richiede un documento con un livello."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern deve essere un documento con un solo campo stringa 'level'."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Livello readConcern non supportato '{}'. Consentiti: local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "Il metodo '{}' non è supportato dopo find(...). Supportati: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "La fase della pipeline all'indice {} è vuota."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "La fase della pipeline all'indice {} deve iniziare con '$', trovato '{}'. Racchiudi l'espressione in una fase come $match."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "La fase della pipeline all'indice {} deve contenere esattamente un operatore, trovato: {}."),
+            ("Date Format Preview", "Anteprima formato data"),
+            ("Date field", "Campo data"),
+            ("Sample date", "Data di esempio"),
+            ("Format", "Formato"),
+            ("Timezone", "Fuso orario"),
+            ("Result", "Risultato"),
+            ("Copy Expression", "Copia espressione"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Specificatori: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Inserisci la data di esempio in formato ISO 8601, ad esempio 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Fuso orario non supportato '{}'. Usa UTC o uno scostamento come +03:00."),
+            ("Unmatched '%' at the end of the format string.", "'%' senza corrispondenza alla fine della stringa di formato."),
+            ("Invalid format character '%{}' in the format string.", "Carattere di formato non valido '%{}' nella stringa di formato."),
+            ("Value must be a valid base64 string.", "Il valore deve essere una stringa base64 valida."),
+            ("Generic", "Generico"),
+            ("Function", "Funzione"),
+            ("Binary (old)", "Binary (vecchio)"),
+            ("UUID (old)", "UUID (vecchio)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Cifrato"),
+            ("Column", "Colonna"),
+            ("Sensitive", "Sensibile"),
+            ("User defined", "Definito dall'utente"),
+            ("Reserved", "Riservato"),
+            ("Subtype", "Sottotipo"),
+            ("Input Format", "Formato di input"),
+            ("Saving will change the field type from {} to {}.", "Il salvataggio cambierà il tipo del campo da {} a {}."),
+            ("Press Save Anyway to confirm.", "Premi Salva comunque per confermare."),
+            ("Save Anyway", "Salva comunque"),
+            ("Group connections by host in the sidebar", "Raggruppa le connessioni per host nella barra laterale"),
+            ("Compact sidebar density", "Densità compatta della barra laterale"),
+            ("Test All", "Testa tutte"),
+            ("OK ({} ms)", "OK ({} ms)"),
+            ("Failed after {} ms: {}", "Fallito dopo {} ms: {}"),
+            ("Skipped: the password is requested on connect", "Saltata: la password viene richiesta alla connessione"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "Il campo _id non può essere modificato sul posto. Attiva \"Consenti la modifica di _id\" per sostituire il documento."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Consenti la modifica di _id (il documento viene reinserito, non aggiornato)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "L'_id è cambiato. Il salvataggio inserisce un nuovo documento ed elimina quello originale; non è un aggiornamento sul posto. Premi Sostituisci documento per confermare."),
+            ("Replace Document", "Sostituisci documento"),
+            ("Rollback failed: {}", "Rollback non riuscito: {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Elimina e ricrea la collezione (più veloce su collezioni grandi)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "La collezione viene eliminata e ricreata con le stesse opzioni, poi i suoi indici vengono ricostruiti. Altri client potrebbero brevemente non vederla."),
+            ("Collection \"{}\" not found.", "Collezione \"{}\" non trovata."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Solo le collezioni normali possono essere svuotate ricreandole. Usa l'eliminazione normale."),
+            ("Text Search...", "Ricerca testuale..."),
+            ("Text Search", "Ricerca testuale"),
+            ("Checking text indexes...", "Controllo degli indici testuali..."),
+            ("This collection has no text index. Create one to use text search.", "Questa collezione non ha un indice testuale. Creane uno per usare la ricerca testuale."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Cerca nella collezione \"{}\" usando l'indice testuale \"{}\". I risultati sono ordinati per pertinenza."),
+            ("Search terms", "Termini di ricerca"),
+            ("Read-only (block all write operations)", "Sola lettura (blocca tutte le operazioni di scrittura)"),
+            ("Read-only", "Sola lettura"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "La connessione \"{}\" è in sola lettura. Le operazioni di scrittura sono bloccate."),
+            ("Color accent", "Colore di accento"),
+            ("None", "Nessuno"),
+            ("Reconnect automatically when running a query on a closed connection", "Riconnetti automaticamente quando si esegue una query su una connessione chiusa"),
+            ("Validation Rules...", "Regole di convalida..."),
+            ("Validation Rules", "Regole di convalida"),
+            ("Loading validation rules...", "Caricamento delle regole di convalida..."),
+            ("Applying validation rules...", "Applicazione delle regole di convalida..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Modifica validator, validationLevel e validationAction. Il salvataggio li applica con collMod; un validator vuoto rimuove la convalida."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Campo non supportato \"{}\". Si possono modificare solo validator, validationLevel e validationAction."),
+            ("The validator must be a document.", "Il validator deve essere un documento."),
+            ("Insert Document...", "Inserisci documento..."),
+            ("By name", "Per nome"),
+            ("By size (largest first)", "Per dimensione (prima i più grandi)"),
+            ("Sort databases and collections", "Ordina database e collezioni"),
+            ("Copy Results as Markdown Table", "Copia risultati come tabella Markdown"),
+            ("The results are not a list of documents and cannot be shown as a table.", "I risultati non sono un elenco di documenti e non possono essere mostrati come tabella."),
+            ("All fields", "Tutti i campi"),
+            ("Preset name cannot be empty.", "Il nome del preset non può essere vuoto."),
+            ("Projection Preset", "Preset di proiezione"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Le query find senza una propria proiezione restituiscono solo questi campi. Salvare con un nome esistente sostituisce quel preset."),
+            ("Fields", "Campi"),
+            ("summary", "riepilogo"),
+            ("Invalid field path \"{}\".", "Percorso del campo non valido \"{}\"."),
+            ("Enter at least one field to project.", "Inserisci almeno un campo da proiettare."),
+            ("Refresh All Connections", "Aggiorna tutte le connessioni"),
+            ("Copy Selected Rows", "Copia righe selezionate"),
+            ("Shortcuts", "Scorciatoie"),
+            ("Send query", "Invia query"),
+            ("Rerun last query", "Riesegui l'ultima query"),
+            ("Close tab", "Chiudi scheda"),
+            ("Next tab", "Scheda successiva"),
+            ("Previous tab", "Scheda precedente"),
+            ("Toggle sidebar", "Mostra/nascondi barra laterale"),
+            ("Table view", "Vista tabella"),
+            ("Text view", "Vista testo"),
+            ("Shortcut must end with a key.", "La scorciatoia deve terminare con un tasto."),
+            ("Unknown modifier \"{}\".", "Modificatore sconosciuto \"{}\"."),
+            ("Unknown key \"{}\".", "Tasto sconosciuto \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" e \"{}\" usano la stessa scorciatoia."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Combina Ctrl, Shift o Alt con un tasto, ad esempio Ctrl+Shift+Tab. Su macOS Ctrl corrisponde a Cmd."),
+            ("Conflicts with \"{}\".", "In conflitto con \"{}\"."),
+            ("Default Shortcuts", "Scorciatoie predefinite"),
+            ("Show Side by Side with Active Tab", "Mostra affiancata alla scheda attiva"),
+            ("Synchronize scrolling", "Sincronizza scorrimento"),
+            ("Close Side by Side", "Chiudi vista affiancata"),
+            ("Render at most", "Mostra al massimo"),
+            ("0 = no cap", "0 = nessun limite"),
+            ("documents per result", "documenti per risultato"),
+            ("Showing the first {} of {} returned items.", "Vengono mostrati i primi {} di {} elementi restituiti."),
+            ("Find This Document", "Trova questo documento"),
+            ("Up to stage {} of {}", "Fino alla fase {} di {}"),
+            ("All {} stages", "Tutte le {} fasi"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "L'operazione bulkWrite '{}' all'indice {} non è supportata. Consentite: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Esporta risultati in JSON..."),
+            ("Export Results to CSV...", "Esporta risultati in CSV..."),
+            ("Results saved to {}", "Risultati salvati in {}"),
+            ("Failed to export results: {}", "Impossibile esportare i risultati: {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} schede non sono state ripristinate perché le loro connessioni non esistono più."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Verbosità explain sconosciuta '{}'. Consentite: queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain richiede una stringa di verbosità: queryPlanner, executionStats o allPlansExecution."),
+            ("Append Array Element...", "Aggiungi elemento all'array..."),
+            ("Delete Array Element", "Elimina elemento dell'array"),
+            ("A new element will be appended to the array", "Un nuovo elemento verrà aggiunto all'array"),
+            ("Failed to delete the array element: {}", "Impossibile eliminare l'elemento dell'array: {}"),
+            ("Read", "Lettura"),
+            ("Read preference", "Preferenza di lettura"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Le preferenze di lettura secondarie tengono le query di analisi lontane dal primario."),
+            ("Use SRV record (mongodb+srv://)", "Usa record SRV (mongodb+srv://)"),
+            ("From DNS", "Da DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "Le connessioni SRV ricavano la porta dal DNS; lasciare vuota la porta"),
+            ("An SRV connection takes a single host name without a port", "Una connessione SRV accetta un solo nome host senza porta"),
+            ("SSH tunnel cannot be used with an SRV connection", "Il tunnel SSH non può essere usato con una connessione SRV"),
+            ("Insert Template...", "Modello di inserimento..."),
+            ("Insert Document", "Inserisci documento"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Inserisci il nuovo documento. Se non definisce un _id, ne viene generato uno."),
+            ("Inserting document...", "Inserimento del documento..."),
+            ("Inserted document with _id {}", "Documento inserito con _id {}"),
+            ("Highlight query syntax in the editor", "Evidenzia la sintassi delle query nell'editor"),
+            ("Query cancelled. The server may still finish the operation.", "Query annullata. Il server potrebbe comunque completare l'operazione."),
+            ("Filter by key or value", "Filtra per chiave o valore"),
+            ("Match case", "Maiuscole/minuscole"),
+            ("{} matches", "{} corrispondenze"),
+            ("TLS", "TLS"),
+            ("CA file", "File CA"),
+            ("Path to PEM file", "Percorso del file PEM"),
+            ("Client certificate and key file", "File di certificato e chiave del client"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Consenti certificati e nomi host non validi (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Ognuna di queste opzioni attiva TLS per la connessione."),
+            ("TLS CA file not found: {}", "File CA TLS non trovato: {}"),
+            ("TLS certificate key file not found: {}", "File di certificato e chiave TLS non trovato: {}"),
+            ("Pin to Top", "Fissa in alto"),
+            ("Unpin Collection", "Sblocca collezione"),
+            ("Expand All opens at most", "\"Espandi tutto\" apre al massimo"),
+            ("0 = all levels", "0 = tutti i livelli"),
+            ("levels", "livelli"),
+            ("Expand All", "Espandi tutto"),
+            ("Collapse All", "Comprimi tutto"),
+            ("Saved Queries", "Query salvate"),
+            ("Save Query", "Salva query"),
+            ("Edit Saved Query", "Modifica query salvata"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "Nessuna query salvata. Usa \"Salva query\" in una scheda della collezione per aggiungerne una."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} e {{db}} vengono sostituiti con i nomi della scheda in cui viene caricata la query."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Usa {{collection}} e {{db}} per i nomi della scheda in cui viene caricata la query."),
+            ("Load", "Carica"),
+            ("Target", "Destinazione"),
+            ("Query", "Query"),
+            ("Database (optional)", "Database (facoltativo)"),
+            ("Collection (optional)", "Collezione (facoltativo)"),
+            ("Slow operations", "Operazioni lente"),
+            ("Query name cannot be empty.", "Il nome della query non può essere vuoto."),
+            ("Query text cannot be empty.", "Il testo della query non può essere vuoto."),
+            ("A saved query with this name already exists.", "Esiste già una query salvata con questo nome."),
+            ("Loaded a query saved for {}.", "Caricata una query salvata per {}."),
+            ("Saved query \"{}\".", "Query \"{}\" salvata."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce richiede le funzioni map e reduce e un oggetto options facoltativo."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' deve essere una funzione."),
+            ("mapReduce options must be a JSON object.", "Le opzioni di mapReduce devono essere un oggetto JSON."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' deve essere un nome di collezione o un documento."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' deve essere un documento."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' deve essere un intero non negativo."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "Il parametro '{}' non è supportato nelle opzioni di mapReduce. Consentiti: out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection richiede un documento con i campi da restituire."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Extended JSON canonico"),
+            ("Show Tree", "Mostra albero"),
+            ("Run a query to see its extended JSON.", "Esegui una query per vederne l'Extended JSON."),
+            ("Group", "Gruppo"),
+            ("Ungrouped", "Senza gruppo"),
+            ("Auto", "Auto"),
+            ("Save As", "Salva come"),
+            ("Value must be true or false.", "Il valore deve essere true o false."),
+            ("Clear the text or enter null.", "Cancella il testo o inserisci null."),
+            ("Cannot convert the value to {}: {}", "Impossibile convertire il valore in {}: {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "Impossibile convertire il valore in {}: viene letto come {}."),
+            ("Copy as Node.js", "Copia come Node.js"),
+            ("Query copied as a Node.js driver call.", "Query copiata come chiamata del driver Node.js."),
+            ("Cannot copy the query as Node.js: {}", "Impossibile copiare la query come Node.js: {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "Gli helper del replica set non hanno un equivalente nel driver Node.js."),
+            ("Unsupported update in bulkWrite.", "Aggiornamento non supportato in bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Operazione non supportata in bulkWrite."),
+            ("(none)", "(nessuno)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Le collezioni shardate non possono essere svuotate ricreandole. Usa l'eliminazione normale."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "La collezione è stata eliminata ma non è stato possibile ricrearla: {}. Opzioni perse: {}. Indici persi: {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "La collezione è stata ricreata ma non è stato possibile ripristinarne gli indici: {}. Indici persi: {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "Il parametro '{}' non è supportato nelle opzioni di find. Consentiti: sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "L'elemento {} verrà rimosso dal documento. L'operazione non può essere annullata."),
+            ("Diagnostic report saved to {}", "Report diagnostico salvato in {}"),
+            ("Failed to save diagnostic report: {}", "Impossibile salvare il report diagnostico: {}"),
         ])
     })
 }
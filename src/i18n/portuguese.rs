@@ -30,8 +30,8 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
             ("Unhide Index", "Mostrar índice"),
             ("comment expects a value.", "comment espera um valor."),
             (
-                "explain must be followed by find(...).",
-                "explain deve ser seguido por find(...).",
+                "explain must be followed by find(...) or aggregate(...).",
+                "explain deve ser seguido por find(...) ou aggregate(...).",
             ),
             ("finish does not take any arguments.", "finish não aceita argumentos."),
             (
@@ -355,10 +355,6 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "O índice \"{}\" da coleção \"{}\" no banco de dados \"{}\" será excluído. Esta ação não pode ser desfeita.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Confirmar a exclusão do índice inserindo o nome \"{}\".",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne espera um filtro, um update e um objeto de opções opcional.",
@@ -400,8 +396,8 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany aceita um filtro e um objeto de opções opcional.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "O método {} não é suportado. Métodos disponíveis: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "O método {} não é suportado. Métodos disponíveis: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -808,10 +804,6 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
                 "The second argument to Code must be an object.",
                 "O segundo argumento de Code deve ser um objeto.",
             ),
-            (
-                "Enter the exact index name to confirm.",
-                "Inserir o nome exato do índice para confirmar.",
-            ),
             (
                 "Enter the exact collection name to confirm.",
                 "Inserir o nome exato da coleção para confirmar.",
@@ -1018,8 +1010,8 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
                 "O parâmetro '{}' não é suportado nas opções de watch. Resume tokens não são suportados.",
             ),
             (
-                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
-                "O parâmetro '{}' não é suportado nas opções de aggregate. Permitidos: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
+                "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
+                "O parâmetro '{}' não é suportado nas opções de aggregate. Permitidos: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
             ),
             (
                 "Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.",
@@ -1155,6 +1147,330 @@ pub(crate) fn portuguese_map() -> &'static HashMap<&'static str, &'static str> {
                 "Pipeline element at index {} must be a JSON object.",
                 "O elemento do pipeline no índice {} deve ser um objeto JSON.",
             ),
+            ("Select for Compare", "Selecionar para comparar"),
+            ("Compare with Selected", "Comparar com o selecionado"),
+            ("Compare Documents", "Comparar documentos"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Adicionados: {}, removidos: {}, alterados: {}, inalterados: {}"),
+            ("Documents are identical", "Os documentos são idênticos"),
+            ("Field", "Campo"),
+            ("Show unchanged fields", "Mostrar campos inalterados"),
+            ("Document without _id", "Documento sem _id"),
+            ("Connections: {} of {}", "Conexões: {} de {}"),
+            ("Tabs: {}", "Abas: {}"),
+            ("Theme: {}", "Tema: {}"),
+            ("Language: {}", "Idioma: {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "O salto {} ultrapassa os {} documentos correspondentes. A última página começa no salto {}."),
+            ("Sample Documents", "Amostra de documentos"),
+            ("Query Log", "Registro de consultas"),
+            ("Filter queries", "Filtrar consultas"),
+            ("Clear", "Limpar"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Erro: {}"),
+            ("No queries executed yet", "Nenhuma consulta executada ainda"),
+            ("Invalid port in host address '{}'", "Porta inválida no endereço do host '{}'"),
+            ("Invalid host address '{}'", "Endereço de host inválido '{}'"),
+            ("Direct connection supports only a single host", "A conexão direta suporta apenas um único host"),
+            ("SSH tunnel supports only a single host", "O túnel SSH suporta apenas um único host"),
+            ("Optional", "Opcional"),
+            ("Replica set name", "Nome do conjunto de réplicas"),
+            ("Replica set name requires the ReplicaSet connection type", "O nome do conjunto de réplicas requer o tipo de conexão ReplicaSet"),
+            ("Server default", "Padrão do servidor"),
+            ("Read concern", "Read concern"),
+            ("Write concern (w)", "Write concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "O write concern deve ser um número, \"majority\" ou um nome de conjunto de tags"),
+            ("Preview", "Pré-visualização"),
+            ("Effective query:", "Consulta efetiva:"),
+            ("Skip and limit do not apply to this operation", "Skip e limit não se aplicam a esta operação"),
+            ("Query timeout", "Tempo limite da consulta"),
+            ("0 = no timeout", "0 = sem limite"),
+            ("Large document ({}), expand to load", "Documento grande ({}), expanda para carregar"),
+            ("Deeply nested document ({} levels), expand to load", "Documento profundamente aninhado ({} níveis), expanda para carregar"),
+            ("No methods are supported after explain().aggregate(...).", "Nenhum método é suportado após explain().aggregate(...)."),
+            ("Method '{}' is not supported after aggregate(...).", "O método '{}' não é suportado após aggregate(...)."),
+            ("Sidebar", "Barra lateral"),
+            ("Pin Tab", "Fixar aba"),
+            ("Unpin Tab", "Desafixar aba"),
+            ("Reuse the current unpinned tab when opening a collection", "Reutilizar a aba atual não fixada ao abrir uma coleção"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "O operador posicional '$[{}]' não tem uma entrada correspondente em arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "A entrada de arrayFilters para o identificador '{}' não é usada na atualização."),
+            ("Show relative time next to dates", "Mostrar o tempo relativo ao lado das datas"),
+            ("just now", "agora mesmo"),
+            ("{} s", "{} s"),
+            ("{} min", "{} min"),
+            ("{} h", "{} h"),
+            ("{} d", "{} d"),
+            ("{} mo", "{} mês"),
+            ("{} y", "{} a"),
+            ("{} ago", "há {}"),
+            ("in {}", "em {}"),
+            ("No documents matched the query.", "Nenhum documento corresponde à consulta."),
+            ("No distinct values found.", "Nenhum valor distinto encontrado."),
+            ("Adjust the filter or pipeline and run the query again.", "Ajuste o filtro ou o pipeline e execute a consulta novamente."),
+            ("Copy _id", "Copiar _id"),
+            ("Enter the exact index name or key specification to confirm.", "Digite o nome exato do índice ou a especificação de chaves para confirmar."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Confirme a exclusão do índice digitando seu nome \"{}\" ou sua especificação de chaves."),
+            ("Index Builder", "Construtor de índices"),
+            ("Index Builder...", "Construtor de índices..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Criar um índice na coleção \"{}\" do banco de dados \"{}\"."),
+            ("Add Field", "Adicionar campo"),
+            ("Index name", "Nome do índice"),
+            ("Generated from fields", "Gerado a partir dos campos"),
+            ("TTL (seconds)", "TTL (segundos)"),
+            ("No expiration", "Sem expiração"),
+            ("Partial filter", "Filtro parcial"),
+            ("Unique", "Único"),
+            ("Sparse", "Esparso"),
+            ("Open in Editor", "Abrir no editor"),
+            ("Choose at least one field for the index.", "Escolha pelo menos um campo para o índice."),
+            ("Field '{}' is used more than once.", "O campo '{}' é usado mais de uma vez."),
+            ("TTL must be a non-negative number of seconds.", "O TTL deve ser um número não negativo de segundos."),
+            ("TTL indexes must have exactly one field.", "Índices TTL devem ter exatamente um campo."),
+            ("Loading existing indexes...", "Carregando índices existentes..."),
+            ("Existing indexes are unavailable: {}", "Os índices existentes não estão disponíveis: {}"),
+            ("Existing indexes", "Índices existentes"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "O índice \"{}\" já usa este padrão de chaves. Você ainda pode criar o índice."),
+            ("Export Diagnostic Report", "Exportar relatório de diagnóstico"),
+            ("Run", "Executar"),
+            ("Confirm Aggregation Output", "Confirmar saída da agregação"),
+            ("The pipeline writes its results with {} into {}.", "O pipeline grava seus resultados com {} em {}."),
+            ("The target differs from the source collection {}.", "O destino é diferente da coleção de origem {}."),
+            ("$out replaces all documents in the target collection.", "$out substitui todos os documentos da coleção de destino."),
+            ("$merge inserts or updates documents in the target collection.", "$merge insere ou atualiza documentos na coleção de destino."),
+            ("Open Response in External Editor", "Abrir resposta no editor externo"),
+            ("Tab title", "Título da aba"),
+            ("Collection", "Coleção"),
+            ("Database / Collection", "Banco de dados / Coleção"),
+            ("Connection / Collection", "Conexão / Coleção"),
+            ("Connection / Database / Collection", "Conexão / Banco de dados / Coleção"),
+            ("Ask before closing tabs with edited queries that were not run", "Perguntar antes de fechar abas com consultas editadas não executadas"),
+            ("Close Anyway", "Fechar mesmo assim"),
+            ("Close Tab", "Fechar aba"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "A consulta da aba \"{}\" foi editada e não executada. Fechar a aba a descarta."),
+            ("Rename Tab", "Renomear aba"),
+            ("Prefill new tabs with a find query", "Preencher novas abas com uma consulta find"),
+            ("Count Documents", "Contar documentos"),
+            ("Estimated Count", "Contagem estimada"),
+            ("Counting documents in {}...", "Contando documentos em {}..."),
+            ("{}: about {} documents (estimated)", "{}: cerca de {} documentos (estimado)"),
+            ("{}: {} documents", "{}: {} documentos"),
+            ("{}: unexpected count result", "{}: resultado de contagem inesperado"),
+            ("Failed to count documents in {}: {}", "Falha ao contar documentos em {}: {}"),
+            ("Database name cannot be empty.", "O nome do banco de dados não pode estar vazio."),
+            ("Database name cannot contain null characters.", "O nome do banco de dados não pode conter caracteres nulos."),
+            ("Database name cannot contain the character '{}'.", "O nome do banco de dados não pode conter o caractere '{}'."),
+            ("Database name must be at most {} bytes long.", "O nome do banco de dados deve ter no máximo {} bytes."),
+            ("Collection name cannot contain null characters.", "O nome da coleção não pode conter caracteres nulos."),
+            ("Collection name cannot contain the character '{}'.", "O nome da coleção não pode conter o caractere '{}'."),
+            ("Collection name cannot start with 'system.'.", "O nome da coleção não pode começar com 'system.'."),
+            ("Namespace '{}' exceeds {} bytes.", "O namespace '{}' excede {} bytes."),
+            ("readConcern requires a document with a level.", "readConcern requer um documento com um nível."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern deve ser um documento com um único campo de texto 'level'."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Nível de readConcern não suportado '{}'. Permitidos: local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "O método '{}' não é suportado após find(...). Suportados: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "A etapa do pipeline no índice {} está vazia."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "A etapa do pipeline no índice {} deve começar com '$', encontrado '{}'. Envolva a expressão em uma etapa como $match."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "A etapa do pipeline no índice {} deve conter exatamente um operador, encontrado: {}."),
+            ("Date Format Preview", "Pré-visualização do formato de data"),
+            ("Date field", "Campo de data"),
+            ("Sample date", "Data de exemplo"),
+            ("Format", "Formato"),
+            ("Timezone", "Fuso horário"),
+            ("Result", "Resultado"),
+            ("Copy Expression", "Copiar expressão"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Especificadores: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Informe a data de exemplo em ISO 8601, por exemplo 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Fuso horário não suportado '{}'. Use UTC ou um deslocamento como +03:00."),
+            ("Unmatched '%' at the end of the format string.", "'%' sem par no final da string de formato."),
+            ("Invalid format character '%{}' in the format string.", "Caractere de formato inválido '%{}' na string de formato."),
+            ("Value must be a valid base64 string.", "O valor deve ser uma string base64 válida."),
+            ("Generic", "Genérico"),
+            ("Function", "Função"),
+            ("Binary (old)", "Binary (antigo)"),
+            ("UUID (old)", "UUID (antigo)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Criptografado"),
+            ("Column", "Coluna"),
+            ("Sensitive", "Sensível"),
+            ("User defined", "Definido pelo usuário"),
+            ("Reserved", "Reservado"),
+            ("Subtype", "Subtipo"),
+            ("Input Format", "Formato de entrada"),
+            ("Saving will change the field type from {} to {}.", "Salvar mudará o tipo do campo de {} para {}."),
+            ("Press Save Anyway to confirm.", "Clique em Salvar mesmo assim para confirmar."),
+            ("Save Anyway", "Salvar mesmo assim"),
+            ("Group connections by host in the sidebar", "Agrupar conexões por host na barra lateral"),
+            ("Compact sidebar density", "Densidade compacta da barra lateral"),
+            ("Test All", "Testar todas"),
+            ("OK ({} ms)", "OK ({} ms)"),
+            ("Failed after {} ms: {}", "Falhou após {} ms: {}"),
+            ("Skipped: the password is requested on connect", "Ignorada: a senha é solicitada ao conectar"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "O campo _id não pode ser alterado no local. Ative \"Permitir alterar _id\" para substituir o documento."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Permitir alterar _id (o documento é reinserido, não atualizado)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "O _id foi alterado. Salvar insere um novo documento e exclui o original; isto não é uma atualização no local. Pressione Substituir documento para confirmar."),
+            ("Replace Document", "Substituir documento"),
+            ("Rollback failed: {}", "Falha ao reverter: {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Excluir e recriar a coleção (mais rápido em coleções grandes)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "A coleção é excluída e criada novamente com as mesmas opções e, em seguida, seus índices são reconstruídos. Outros clientes podem ver brevemente a coleção ausente."),
+            ("Collection \"{}\" not found.", "Coleção \"{}\" não encontrada."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Apenas coleções comuns podem ser esvaziadas recriando-as. Use a exclusão normal."),
+            ("Text Search...", "Pesquisa de texto..."),
+            ("Text Search", "Pesquisa de texto"),
+            ("Checking text indexes...", "Verificando índices de texto..."),
+            ("This collection has no text index. Create one to use text search.", "Esta coleção não tem índice de texto. Crie um para usar a pesquisa de texto."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Pesquisar a coleção \"{}\" usando o índice de texto \"{}\". Os resultados são ordenados por relevância."),
+            ("Search terms", "Termos de pesquisa"),
+            ("Read-only (block all write operations)", "Somente leitura (bloquear todas as operações de escrita)"),
+            ("Read-only", "Somente leitura"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "A conexão \"{}\" é somente leitura. As operações de escrita estão bloqueadas."),
+            ("Color accent", "Cor de destaque"),
+            ("None", "Nenhuma"),
+            ("Reconnect automatically when running a query on a closed connection", "Reconectar automaticamente ao executar uma consulta numa conexão fechada"),
+            ("Validation Rules...", "Regras de validação..."),
+            ("Validation Rules", "Regras de validação"),
+            ("Loading validation rules...", "Carregando regras de validação..."),
+            ("Applying validation rules...", "Aplicando regras de validação..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Edite validator, validationLevel e validationAction. Ao salvar, são aplicados com collMod; um validator vazio remove a validação."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Campo não suportado \"{}\". Apenas validator, validationLevel e validationAction podem ser alterados."),
+            ("The validator must be a document.", "O validator deve ser um documento."),
+            ("Insert Document...", "Inserir documento..."),
+            ("By name", "Por nome"),
+            ("By size (largest first)", "Por tamanho (maiores primeiro)"),
+            ("Sort databases and collections", "Ordenar bancos de dados e coleções"),
+            ("Copy Results as Markdown Table", "Copiar resultados como tabela Markdown"),
+            ("The results are not a list of documents and cannot be shown as a table.", "Os resultados não são uma lista de documentos e não podem ser exibidos como tabela."),
+            ("All fields", "Todos os campos"),
+            ("Preset name cannot be empty.", "O nome da predefinição não pode estar vazio."),
+            ("Projection Preset", "Predefinição de projeção"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Consultas find sem projeção própria retornam apenas estes campos. Salvar com um nome existente substitui essa predefinição."),
+            ("Fields", "Campos"),
+            ("summary", "resumo"),
+            ("Invalid field path \"{}\".", "Caminho de campo inválido \"{}\"."),
+            ("Enter at least one field to project.", "Informe pelo menos um campo para projetar."),
+            ("Refresh All Connections", "Atualizar todas as conexões"),
+            ("Copy Selected Rows", "Copiar linhas selecionadas"),
+            ("Shortcuts", "Atalhos"),
+            ("Send query", "Enviar consulta"),
+            ("Rerun last query", "Repetir a última consulta"),
+            ("Close tab", "Fechar aba"),
+            ("Next tab", "Próxima aba"),
+            ("Previous tab", "Aba anterior"),
+            ("Toggle sidebar", "Mostrar/ocultar barra lateral"),
+            ("Table view", "Visualização em tabela"),
+            ("Text view", "Visualização em texto"),
+            ("Shortcut must end with a key.", "O atalho deve terminar com uma tecla."),
+            ("Unknown modifier \"{}\".", "Modificador desconhecido \"{}\"."),
+            ("Unknown key \"{}\".", "Tecla desconhecida \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" e \"{}\" usam o mesmo atalho."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Combine Ctrl, Shift ou Alt com uma tecla, por exemplo Ctrl+Shift+Tab. No macOS, Ctrl corresponde a Cmd."),
+            ("Conflicts with \"{}\".", "Conflita com \"{}\"."),
+            ("Default Shortcuts", "Atalhos padrão"),
+            ("Show Side by Side with Active Tab", "Mostrar lado a lado com a aba ativa"),
+            ("Synchronize scrolling", "Sincronizar rolagem"),
+            ("Close Side by Side", "Fechar lado a lado"),
+            ("Render at most", "Exibir no máximo"),
+            ("0 = no cap", "0 = sem limite"),
+            ("documents per result", "documentos por resultado"),
+            ("Showing the first {} of {} returned items.", "Exibindo os primeiros {} de {} itens retornados."),
+            ("Find This Document", "Buscar este documento"),
+            ("Up to stage {} of {}", "Até o estágio {} de {}"),
+            ("All {} stages", "Todos os {} estágios"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "A operação bulkWrite '{}' no índice {} não é suportada. Permitidas: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Exportar resultados para JSON..."),
+            ("Export Results to CSV...", "Exportar resultados para CSV..."),
+            ("Results saved to {}", "Resultados salvos em {}"),
+            ("Failed to export results: {}", "Falha ao exportar os resultados: {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} abas não foram restauradas porque suas conexões não existem mais."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Verbosidade de explain desconhecida '{}'. Permitidas: queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain espera uma string de verbosidade: queryPlanner, executionStats ou allPlansExecution."),
+            ("Append Array Element...", "Adicionar elemento ao array..."),
+            ("Delete Array Element", "Excluir elemento do array"),
+            ("A new element will be appended to the array", "Um novo elemento será adicionado ao array"),
+            ("Failed to delete the array element: {}", "Falha ao excluir o elemento do array: {}"),
+            ("Read", "Leitura"),
+            ("Read preference", "Preferência de leitura"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Preferências de leitura secundárias mantêm as consultas de análise fora do primário."),
+            ("Use SRV record (mongodb+srv://)", "Usar registro SRV (mongodb+srv://)"),
+            ("From DNS", "Do DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "Conexões SRV obtêm a porta do DNS; deixe a porta vazia"),
+            ("An SRV connection takes a single host name without a port", "Uma conexão SRV aceita um único nome de host sem porta"),
+            ("SSH tunnel cannot be used with an SRV connection", "O túnel SSH não pode ser usado com uma conexão SRV"),
+            ("Insert Template...", "Modelo de inserção..."),
+            ("Insert Document", "Inserir documento"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Insira o novo documento. Se ele não definir um _id, um será gerado."),
+            ("Inserting document...", "Inserindo documento..."),
+            ("Inserted document with _id {}", "Documento inserido com _id {}"),
+            ("Highlight query syntax in the editor", "Destacar a sintaxe da consulta no editor"),
+            ("Query cancelled. The server may still finish the operation.", "Consulta cancelada. O servidor ainda pode concluir a operação."),
+            ("Filter by key or value", "Filtrar por chave ou valor"),
+            ("Match case", "Diferenciar maiúsculas"),
+            ("{} matches", "{} correspondências"),
+            ("TLS", "TLS"),
+            ("CA file", "Arquivo de CA"),
+            ("Path to PEM file", "Caminho do arquivo PEM"),
+            ("Client certificate and key file", "Arquivo de certificado e chave do cliente"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Permitir certificados e nomes de host inválidos (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Qualquer uma destas opções ativa o TLS para a conexão."),
+            ("TLS CA file not found: {}", "Arquivo de CA do TLS não encontrado: {}"),
+            ("TLS certificate key file not found: {}", "Arquivo de certificado e chave TLS não encontrado: {}"),
+            ("Pin to Top", "Fixar no topo"),
+            ("Unpin Collection", "Desafixar coleção"),
+            ("Expand All opens at most", "\"Expandir tudo\" abre no máximo"),
+            ("0 = all levels", "0 = todos os níveis"),
+            ("levels", "níveis"),
+            ("Expand All", "Expandir tudo"),
+            ("Collapse All", "Recolher tudo"),
+            ("Saved Queries", "Consultas salvas"),
+            ("Save Query", "Salvar consulta"),
+            ("Edit Saved Query", "Editar consulta salva"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "Nenhuma consulta salva. Use \"Salvar consulta\" em uma aba de coleção para adicionar uma."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} e {{db}} são substituídos pelos nomes da aba em que a consulta é carregada."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Use {{collection}} e {{db}} para os nomes da aba em que a consulta é carregada."),
+            ("Load", "Carregar"),
+            ("Target", "Destino"),
+            ("Query", "Consulta"),
+            ("Database (optional)", "Banco de dados (opcional)"),
+            ("Collection (optional)", "Coleção (opcional)"),
+            ("Slow operations", "Operações lentas"),
+            ("Query name cannot be empty.", "O nome da consulta não pode estar vazio."),
+            ("Query text cannot be empty.", "O texto da consulta não pode estar vazio."),
+            ("A saved query with this name already exists.", "Já existe uma consulta salva com este nome."),
+            ("Loaded a query saved for {}.", "Carregada uma consulta salva para {}."),
+            ("Saved query \"{}\".", "Consulta \"{}\" salva."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce espera as funções map e reduce e um objeto de opções opcional."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' deve ser uma função."),
+            ("mapReduce options must be a JSON object.", "As opções de mapReduce devem ser um objeto JSON."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' deve ser um nome de coleção ou um documento."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' deve ser um documento."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' deve ser um inteiro não negativo."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "O parâmetro '{}' não é suportado nas opções de mapReduce. Permitidos: out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection requer um documento com os campos a retornar."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Extended JSON canônico"),
+            ("Show Tree", "Mostrar árvore"),
+            ("Run a query to see its extended JSON.", "Execute uma consulta para ver o seu Extended JSON."),
+            ("Group", "Grupo"),
+            ("Ungrouped", "Sem grupo"),
+            ("Auto", "Auto"),
+            ("Save As", "Salvar como"),
+            ("Value must be true or false.", "O valor deve ser true ou false."),
+            ("Clear the text or enter null.", "Limpe o texto ou digite null."),
+            ("Cannot convert the value to {}: {}", "Não é possível converter o valor para {}: {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "Não é possível converter o valor para {}: ele é lido como {}."),
+            ("Copy as Node.js", "Copiar como Node.js"),
+            ("Query copied as a Node.js driver call.", "Consulta copiada como chamada do driver Node.js."),
+            ("Cannot copy the query as Node.js: {}", "Não é possível copiar a consulta como Node.js: {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "Os auxiliares de replica set não têm equivalente no driver Node.js."),
+            ("Unsupported update in bulkWrite.", "Atualização não suportada em bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Operação não suportada em bulkWrite."),
+            ("(none)", "(nenhum)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Coleções fragmentadas não podem ser esvaziadas recriando-as. Use a exclusão normal."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "A coleção foi removida, mas não pôde ser recriada: {}. Opções perdidas: {}. Índices perdidos: {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "A coleção foi recriada, mas seus índices não puderam ser restaurados: {}. Índices perdidos: {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "O parâmetro '{}' não é suportado nas opções de find. Permitidos: sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "O elemento {} será removido do documento. Esta ação não pode ser desfeita."),
+            ("Diagnostic report saved to {}", "Relatório de diagnóstico salvo em {}"),
+            ("Failed to save diagnostic report: {}", "Falha ao salvar o relatório de diagnóstico: {}"),
         ])
     })
 }
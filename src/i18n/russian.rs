@@ -26,7 +26,7 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
             ("Hide Index", "Спрятать индекс"),
             ("Unhide Index", "Не прятать индекс"),
             ("comment expects a value.", "comment ожидает значение."),
-            ("explain must be followed by find(...).", "explain должен сопровождаться find(...)."),
+            ("explain must be followed by find(...) or aggregate(...).", "explain должен сопровождаться find(...) или aggregate(...)."),
             ("finish does not take any arguments.", "finish не принимает аргументов."),
             ("No methods are supported after finish().", "После finish() методы не поддерживаются."),
             ("Edit Index...", "Изменить индекс..."),
@@ -329,10 +329,6 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "Индекс \"{}\" коллекции \"{}\" базы \"{}\" будет удалён. Это действие нельзя отменить.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Подтвердите удаление индекса введя его имя \"{}\".",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne принимает фильтр, обновление и необязательный объект options.",
@@ -374,8 +370,8 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany принимает фильтр и необязательный объект options.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "Метод {} не поддерживается. Доступны: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Метод {} не поддерживается. Доступны: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -647,7 +643,6 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
             ("Index argument must be a string with the index name or an object with keys.", "Аргумент индекса должен быть строкой с именем индекса или объектом с ключами."),
             ("Update argument must be an object with operators or an array of stages.", "Аргумент обновления должен быть объектом с операторами или массивом стадий."),
             ("The second argument to Code must be an object.", "Второй аргумент Code должен быть объектом."),
-            ("Enter the exact index name to confirm.", "Для подтверждения введите точное имя индекса."),
             ("Enter the exact collection name to confirm.", "Для подтверждения введите точное имя коллекции."),
             ("Document must be a JSON object.", "Документ должен быть JSON-объектом."),
             ("NumberInt value is out of the Int32 range.", "Значение NumberInt выходит за диапазон Int32."),
@@ -709,7 +704,7 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
             ("Parameter '{}' is not supported in countDocuments options. Allowed: limit, skip, hint, maxTimeMS.", "Параметр '{other}' не поддерживается в options countDocuments. Доступны: limit, skip, hint, maxTimeMS."),
             ("Parameter '{}' is not supported in watch options. Allowed: fullDocument, fullDocumentBeforeChange, maxAwaitTimeMS, batchSize, collation, showExpandedEvents, comment, startAtOperationTime.", "Параметр '{other}' не поддерживается в options watch. Доступны: fullDocument, fullDocumentBeforeChange, maxAwaitTimeMS, batchSize, collation, showExpandedEvents, comment, startAtOperationTime."),
             ("Parameter '{}' is not supported in watch options. Resume tokens are not supported.", "Параметр '{other}' не поддерживается в options watch. Resume tokens не поддерживаются."),
-            ("Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.", "Параметр '{other}' не поддерживается в options aggregate. Доступны: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor."),
+            ("Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.", "Параметр '{other}' не поддерживается в options aggregate. Доступны: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor."),
             ("Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.", "Параметр '{other}' не поддерживается в options cursor для aggregate. Доступен: batchSize."),
             ("Parameter '{}' is not supported in distinct options. Allowed: maxTimeMS, collation.", "Параметр '{other}' не поддерживается в options distinct. Доступны: maxTimeMS, collation."),
             ("Parameter '{}' is not supported in deleteOne/deleteMany options. Allowed: writeConcern, collation, hint.", "Параметр '{other}' не поддерживается в options deleteOne/deleteMany. Доступны: writeConcern, collation, hint."),
@@ -748,6 +743,330 @@ pub(crate) fn russian_map() -> &'static HashMap<&'static str, &'static str> {
             ("Function is missing a closing brace.", "Функция не содержит закрывающую фигурную скобку."),
             ("arrayFilters element at index {} must be a JSON object.", "Элемент arrayFilters с индексом {index} должен быть JSON-объектом."),
             ("Pipeline element at index {} must be a JSON object.", "Элемент pipeline под индексом {index} должен быть JSON-объектом."),
+            ("Select for Compare", "Выбрать для сравнения"),
+            ("Compare with Selected", "Сравнить с выбранным"),
+            ("Compare Documents", "Сравнение документов"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Добавлено: {}, удалено: {}, изменено: {}, без изменений: {}"),
+            ("Documents are identical", "Документы идентичны"),
+            ("Field", "Поле"),
+            ("Show unchanged fields", "Показывать неизменённые поля"),
+            ("Document without _id", "Документ без _id"),
+            ("Connections: {} of {}", "Подключения: {} из {}"),
+            ("Tabs: {}", "Вкладки: {}"),
+            ("Theme: {}", "Тема: {}"),
+            ("Language: {}", "Язык: {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "Пропуск {} превышает количество подходящих документов ({}). Последняя страница начинается с пропуска {}."),
+            ("Sample Documents", "Случайные документы"),
+            ("Query Log", "Журнал запросов"),
+            ("Filter queries", "Фильтр запросов"),
+            ("Clear", "Очистить"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Ошибка: {}"),
+            ("No queries executed yet", "Запросы ещё не выполнялись"),
+            ("Invalid port in host address '{}'", "Неверный порт в адресе хоста '{}'"),
+            ("Invalid host address '{}'", "Неверный адрес хоста '{}'"),
+            ("Direct connection supports only a single host", "Прямое подключение поддерживает только один хост"),
+            ("SSH tunnel supports only a single host", "SSH-туннель поддерживает только один хост"),
+            ("Optional", "Необязательно"),
+            ("Replica set name", "Имя набора реплик"),
+            ("Replica set name requires the ReplicaSet connection type", "Имя набора реплик требует тип подключения ReplicaSet"),
+            ("Server default", "По умолчанию сервера"),
+            ("Read concern", "Read concern"),
+            ("Write concern (w)", "Write concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "Write concern должен быть числом, \"majority\" или именем набора тегов"),
+            ("Preview", "Предпросмотр"),
+            ("Effective query:", "Итоговый запрос:"),
+            ("Skip and limit do not apply to this operation", "Skip и limit не применяются к этой операции"),
+            ("Query timeout", "Таймаут запроса"),
+            ("0 = no timeout", "0 = без таймаута"),
+            ("Large document ({}), expand to load", "Большой документ ({}), разверните для загрузки"),
+            ("Deeply nested document ({} levels), expand to load", "Глубоко вложенный документ ({} уровней), разверните для загрузки"),
+            ("No methods are supported after explain().aggregate(...).", "После explain().aggregate(...) методы не поддерживаются."),
+            ("Method '{}' is not supported after aggregate(...).", "Метод '{}' не поддерживается после aggregate(...)."),
+            ("Sidebar", "Боковая панель"),
+            ("Pin Tab", "Закрепить вкладку"),
+            ("Unpin Tab", "Открепить вкладку"),
+            ("Reuse the current unpinned tab when opening a collection", "Переиспользовать текущую незакреплённую вкладку при открытии коллекции"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "Позиционный оператор '$[{}]' не имеет соответствующей записи в arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "Запись arrayFilters для идентификатора '{}' не используется в обновлении."),
+            ("Show relative time next to dates", "Показывать относительное время рядом с датами"),
+            ("just now", "только что"),
+            ("{} s", "{} с"),
+            ("{} min", "{} мин"),
+            ("{} h", "{} ч"),
+            ("{} d", "{} дн."),
+            ("{} mo", "{} мес."),
+            ("{} y", "{} г."),
+            ("{} ago", "{} назад"),
+            ("in {}", "через {}"),
+            ("No documents matched the query.", "Запрос не вернул ни одного документа."),
+            ("No distinct values found.", "Уникальные значения не найдены."),
+            ("Adjust the filter or pipeline and run the query again.", "Измените фильтр или конвейер и выполните запрос снова."),
+            ("Copy _id", "Копировать _id"),
+            ("Enter the exact index name or key specification to confirm.", "Введите точное имя индекса или его ключи для подтверждения."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Подтвердите удаление индекса, введя его имя \"{}\" или его ключи."),
+            ("Index Builder", "Конструктор индекса"),
+            ("Index Builder...", "Конструктор индекса..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Создание индекса для коллекции \"{}\" в базе данных \"{}\"."),
+            ("Add Field", "Добавить поле"),
+            ("Index name", "Имя индекса"),
+            ("Generated from fields", "Формируется из полей"),
+            ("TTL (seconds)", "TTL (секунды)"),
+            ("No expiration", "Без срока действия"),
+            ("Partial filter", "Частичный фильтр"),
+            ("Unique", "Уникальный"),
+            ("Sparse", "Разреженный"),
+            ("Open in Editor", "Открыть в редакторе"),
+            ("Choose at least one field for the index.", "Выберите хотя бы одно поле для индекса."),
+            ("Field '{}' is used more than once.", "Поле '{}' используется более одного раза."),
+            ("TTL must be a non-negative number of seconds.", "TTL должен быть неотрицательным числом секунд."),
+            ("TTL indexes must have exactly one field.", "TTL-индекс должен содержать ровно одно поле."),
+            ("Loading existing indexes...", "Загрузка существующих индексов..."),
+            ("Existing indexes are unavailable: {}", "Существующие индексы недоступны: {}"),
+            ("Existing indexes", "Существующие индексы"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "Индекс \"{}\" уже использует этот набор ключей. Индекс всё равно можно создать."),
+            ("Export Diagnostic Report", "Экспорт диагностического отчёта"),
+            ("Run", "Выполнить"),
+            ("Confirm Aggregation Output", "Подтверждение записи результата агрегации"),
+            ("The pipeline writes its results with {} into {}.", "Конвейер записывает результаты через {} в {}."),
+            ("The target differs from the source collection {}.", "Цель отличается от исходной коллекции {}."),
+            ("$out replaces all documents in the target collection.", "$out заменяет все документы в целевой коллекции."),
+            ("$merge inserts or updates documents in the target collection.", "$merge вставляет или обновляет документы в целевой коллекции."),
+            ("Open Response in External Editor", "Открыть ответ во внешнем редакторе"),
+            ("Tab title", "Заголовок вкладки"),
+            ("Collection", "Коллекция"),
+            ("Database / Collection", "База данных / Коллекция"),
+            ("Connection / Collection", "Подключение / Коллекция"),
+            ("Connection / Database / Collection", "Подключение / База данных / Коллекция"),
+            ("Ask before closing tabs with edited queries that were not run", "Спрашивать перед закрытием вкладок с изменёнными, но не выполненными запросами"),
+            ("Close Anyway", "Всё равно закрыть"),
+            ("Close Tab", "Закрыть вкладку"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "Запрос во вкладке \"{}\" изменён и не выполнен. При закрытии вкладки он будет потерян."),
+            ("Rename Tab", "Переименовать вкладку"),
+            ("Prefill new tabs with a find query", "Заполнять новые вкладки запросом find"),
+            ("Count Documents", "Подсчитать документы"),
+            ("Estimated Count", "Приблизительное количество"),
+            ("Counting documents in {}...", "Подсчёт документов в {}..."),
+            ("{}: about {} documents (estimated)", "{}: около {} документов (оценка)"),
+            ("{}: {} documents", "{}: документов: {}"),
+            ("{}: unexpected count result", "{}: неожиданный результат подсчёта"),
+            ("Failed to count documents in {}: {}", "Не удалось подсчитать документы в {}: {}"),
+            ("Database name cannot be empty.", "Имя базы данных не может быть пустым."),
+            ("Database name cannot contain null characters.", "Имя базы данных не может содержать нулевые символы."),
+            ("Database name cannot contain the character '{}'.", "Имя базы данных не может содержать символ '{}'."),
+            ("Database name must be at most {} bytes long.", "Имя базы данных должно быть не длиннее {} байт."),
+            ("Collection name cannot contain null characters.", "Имя коллекции не может содержать нулевые символы."),
+            ("Collection name cannot contain the character '{}'.", "Имя коллекции не может содержать символ '{}'."),
+            ("Collection name cannot start with 'system.'.", "Имя коллекции не может начинаться с 'system.'."),
+            ("Namespace '{}' exceeds {} bytes.", "Пространство имён '{}' превышает {} байт."),
+            ("readConcern requires a document with a level.", "readConcern требует документ с полем level."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern должен быть документом с единственным строковым полем 'level'."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Неподдерживаемый уровень readConcern '{}'. Допустимо: local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "Метод '{}' не поддерживается после find(...). Поддерживаются: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "Стадия конвейера с индексом {} пуста."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "Стадия конвейера с индексом {} должна начинаться с '$', найдено '{}'. Оберните выражение в стадию, например $match."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "Стадия конвейера с индексом {} должна содержать ровно один оператор, найдено: {}."),
+            ("Date Format Preview", "Предпросмотр формата даты"),
+            ("Date field", "Поле даты"),
+            ("Sample date", "Пример даты"),
+            ("Format", "Формат"),
+            ("Timezone", "Часовой пояс"),
+            ("Result", "Результат"),
+            ("Copy Expression", "Копировать выражение"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Спецификаторы: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Введите пример даты в формате ISO 8601, например 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Неподдерживаемый часовой пояс '{}'. Используйте UTC или смещение, например +03:00."),
+            ("Unmatched '%' at the end of the format string.", "Непарный '%' в конце строки формата."),
+            ("Invalid format character '%{}' in the format string.", "Недопустимый символ формата '%{}' в строке формата."),
+            ("Value must be a valid base64 string.", "Значение должно быть корректной строкой base64."),
+            ("Generic", "Общий"),
+            ("Function", "Функция"),
+            ("Binary (old)", "Binary (устар.)"),
+            ("UUID (old)", "UUID (устар.)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Зашифровано"),
+            ("Column", "Колонка"),
+            ("Sensitive", "Конфиденциально"),
+            ("User defined", "Пользовательский"),
+            ("Reserved", "Зарезервировано"),
+            ("Subtype", "Подтип"),
+            ("Input Format", "Формат ввода"),
+            ("Saving will change the field type from {} to {}.", "Сохранение изменит тип поля с {} на {}."),
+            ("Press Save Anyway to confirm.", "Нажмите «Всё равно сохранить» для подтверждения."),
+            ("Save Anyway", "Всё равно сохранить"),
+            ("Group connections by host in the sidebar", "Группировать подключения по хосту на боковой панели"),
+            ("Compact sidebar density", "Компактная боковая панель"),
+            ("Test All", "Проверить все"),
+            ("OK ({} ms)", "OK ({} мс)"),
+            ("Failed after {} ms: {}", "Ошибка через {} мс: {}"),
+            ("Skipped: the password is requested on connect", "Пропущено: пароль запрашивается при подключении"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "Поле _id нельзя изменить на месте. Включите «Разрешить изменение _id», чтобы заменить документ."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Разрешить изменение _id (документ вставляется заново, а не обновляется)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "_id изменён. При сохранении будет вставлен новый документ, а исходный удалён; это не обновление на месте. Нажмите «Заменить документ» для подтверждения."),
+            ("Replace Document", "Заменить документ"),
+            ("Rollback failed: {}", "Откат не удался: {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Удалить и заново создать коллекцию (быстрее для больших коллекций)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "Коллекция удаляется и создаётся заново с теми же параметрами, затем индексы строятся повторно. Другие клиенты могут ненадолго не видеть коллекцию."),
+            ("Collection \"{}\" not found.", "Коллекция «{}» не найдена."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Пересоздать можно только обычную коллекцию. Используйте обычное удаление."),
+            ("Text Search...", "Текстовый поиск..."),
+            ("Text Search", "Текстовый поиск"),
+            ("Checking text indexes...", "Проверка текстовых индексов..."),
+            ("This collection has no text index. Create one to use text search.", "У этой коллекции нет текстового индекса. Создайте его, чтобы использовать текстовый поиск."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Поиск в коллекции «{}» по текстовому индексу «{}». Результаты сортируются по релевантности."),
+            ("Search terms", "Поисковые слова"),
+            ("Read-only (block all write operations)", "Только чтение (блокировать все операции записи)"),
+            ("Read-only", "Только чтение"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "Подключение «{}» доступно только для чтения. Операции записи заблокированы."),
+            ("Color accent", "Цветовой акцент"),
+            ("None", "Нет"),
+            ("Reconnect automatically when running a query on a closed connection", "Автоматически переподключаться при запуске запроса на закрытом соединении"),
+            ("Validation Rules...", "Правила валидации..."),
+            ("Validation Rules", "Правила валидации"),
+            ("Loading validation rules...", "Загрузка правил валидации..."),
+            ("Applying validation rules...", "Применение правил валидации..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Измените validator, validationLevel и validationAction. Сохранение применяет их через collMod; пустой validator отключает валидацию."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Неподдерживаемое поле \"{}\". Можно изменить только validator, validationLevel и validationAction."),
+            ("The validator must be a document.", "validator должен быть документом."),
+            ("Insert Document...", "Вставить документ..."),
+            ("By name", "По имени"),
+            ("By size (largest first)", "По размеру (сначала крупные)"),
+            ("Sort databases and collections", "Сортировка баз данных и коллекций"),
+            ("Copy Results as Markdown Table", "Копировать результаты как таблицу Markdown"),
+            ("The results are not a list of documents and cannot be shown as a table.", "Результаты не являются списком документов и не могут быть показаны в виде таблицы."),
+            ("All fields", "Все поля"),
+            ("Preset name cannot be empty.", "Имя пресета не может быть пустым."),
+            ("Projection Preset", "Пресет проекции"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Запросы find без собственной проекции возвращают только эти поля. Сохранение под существующим именем заменяет пресет."),
+            ("Fields", "Поля"),
+            ("summary", "сводка"),
+            ("Invalid field path \"{}\".", "Недопустимый путь поля \"{}\"."),
+            ("Enter at least one field to project.", "Укажите хотя бы одно поле для проекции."),
+            ("Refresh All Connections", "Обновить все подключения"),
+            ("Copy Selected Rows", "Копировать выбранные строки"),
+            ("Shortcuts", "Сочетания клавиш"),
+            ("Send query", "Выполнить запрос"),
+            ("Rerun last query", "Повторить последний запрос"),
+            ("Close tab", "Закрыть вкладку"),
+            ("Next tab", "Следующая вкладка"),
+            ("Previous tab", "Предыдущая вкладка"),
+            ("Toggle sidebar", "Показать/скрыть боковую панель"),
+            ("Table view", "Табличный вид"),
+            ("Text view", "Текстовый вид"),
+            ("Shortcut must end with a key.", "Сочетание должно заканчиваться клавишей."),
+            ("Unknown modifier \"{}\".", "Неизвестный модификатор \"{}\"."),
+            ("Unknown key \"{}\".", "Неизвестная клавиша \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" и \"{}\" используют одно и то же сочетание."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Сочетайте Ctrl, Shift или Alt с клавишей, например Ctrl+Shift+Tab. На macOS Ctrl означает Cmd."),
+            ("Conflicts with \"{}\".", "Конфликтует с \"{}\"."),
+            ("Default Shortcuts", "Сочетания по умолчанию"),
+            ("Show Side by Side with Active Tab", "Показать рядом с активной вкладкой"),
+            ("Synchronize scrolling", "Синхронная прокрутка"),
+            ("Close Side by Side", "Закрыть сравнение"),
+            ("Render at most", "Отображать не более"),
+            ("0 = no cap", "0 = без ограничения"),
+            ("documents per result", "документов на результат"),
+            ("Showing the first {} of {} returned items.", "Показаны первые {} из {} полученных элементов."),
+            ("Find This Document", "Найти этот документ"),
+            ("Up to stage {} of {}", "До стадии {} из {}"),
+            ("All {} stages", "Все стадии ({})"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "Операция bulkWrite '{}' с индексом {} не поддерживается. Допустимо: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Экспортировать результаты в JSON..."),
+            ("Export Results to CSV...", "Экспортировать результаты в CSV..."),
+            ("Results saved to {}", "Результаты сохранены в {}"),
+            ("Failed to export results: {}", "Не удалось экспортировать результаты: {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "Вкладок не восстановлено: {}, так как их подключения больше не существуют."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Неизвестный уровень детализации explain '{}'. Допустимо: queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain ожидает строку уровня детализации: queryPlanner, executionStats или allPlansExecution."),
+            ("Append Array Element...", "Добавить элемент массива..."),
+            ("Delete Array Element", "Удалить элемент массива"),
+            ("A new element will be appended to the array", "В массив будет добавлен новый элемент"),
+            ("Failed to delete the array element: {}", "Не удалось удалить элемент массива: {}"),
+            ("Read", "Чтение"),
+            ("Read preference", "Предпочтение чтения"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Чтение с вторичных узлов снимает аналитическую нагрузку с основного."),
+            ("Use SRV record (mongodb+srv://)", "Использовать SRV-запись (mongodb+srv://)"),
+            ("From DNS", "Из DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "SRV-подключения получают порт из DNS; оставьте поле порта пустым"),
+            ("An SRV connection takes a single host name without a port", "SRV-подключение принимает одно имя хоста без порта"),
+            ("SSH tunnel cannot be used with an SRV connection", "SSH-туннель нельзя использовать с SRV-подключением"),
+            ("Insert Template...", "Шаблон вставки..."),
+            ("Insert Document", "Вставка документа"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Введите новый документ. Если поле _id не задано, оно будет сгенерировано."),
+            ("Inserting document...", "Вставка документа..."),
+            ("Inserted document with _id {}", "Вставлен документ с _id {}"),
+            ("Highlight query syntax in the editor", "Подсвечивать синтаксис запроса в редакторе"),
+            ("Query cancelled. The server may still finish the operation.", "Запрос отменён. Сервер может всё же завершить операцию."),
+            ("Filter by key or value", "Фильтр по ключу или значению"),
+            ("Match case", "Учитывать регистр"),
+            ("{} matches", "Совпадений: {}"),
+            ("TLS", "TLS"),
+            ("CA file", "Файл CA"),
+            ("Path to PEM file", "Путь к PEM-файлу"),
+            ("Client certificate and key file", "Файл сертификата и ключа клиента"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Разрешить недействительные сертификаты и имена хостов (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Любой из этих параметров включает TLS для подключения."),
+            ("TLS CA file not found: {}", "Файл CA для TLS не найден: {}"),
+            ("TLS certificate key file not found: {}", "Файл сертификата и ключа TLS не найден: {}"),
+            ("Pin to Top", "Закрепить сверху"),
+            ("Unpin Collection", "Открепить коллекцию"),
+            ("Expand All opens at most", "«Развернуть все» открывает не более"),
+            ("0 = all levels", "0 = все уровни"),
+            ("levels", "уровней"),
+            ("Expand All", "Развернуть все"),
+            ("Collapse All", "Свернуть все"),
+            ("Saved Queries", "Сохранённые запросы"),
+            ("Save Query", "Сохранить запрос"),
+            ("Edit Saved Query", "Изменить сохранённый запрос"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "Нет сохранённых запросов. Используйте «Сохранить запрос» во вкладке коллекции, чтобы добавить."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} и {{db}} заменяются именами вкладки, в которую загружается запрос."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Используйте {{collection}} и {{db}} для имён вкладки, в которую загружается запрос."),
+            ("Load", "Загрузить"),
+            ("Target", "Назначение"),
+            ("Query", "Запрос"),
+            ("Database (optional)", "База данных (необязательно)"),
+            ("Collection (optional)", "Коллекция (необязательно)"),
+            ("Slow operations", "Медленные операции"),
+            ("Query name cannot be empty.", "Имя запроса не может быть пустым."),
+            ("Query text cannot be empty.", "Текст запроса не может быть пустым."),
+            ("A saved query with this name already exists.", "Сохранённый запрос с таким именем уже существует."),
+            ("Loaded a query saved for {}.", "Загружен запрос, сохранённый для {}."),
+            ("Saved query \"{}\".", "Запрос «{}» сохранён."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce принимает функции map и reduce и необязательный объект options."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' должен быть функцией."),
+            ("mapReduce options must be a JSON object.", "Опции mapReduce должны быть JSON-объектом."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' должен быть именем коллекции или документом."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' должен быть документом."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' должен быть неотрицательным целым числом."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "Параметр '{}' не поддерживается в options mapReduce. Доступны: out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection требует документ с возвращаемыми полями."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Канонический Extended JSON"),
+            ("Show Tree", "Показать дерево"),
+            ("Run a query to see its extended JSON.", "Выполните запрос, чтобы увидеть его Extended JSON."),
+            ("Group", "Группа"),
+            ("Ungrouped", "Без группы"),
+            ("Auto", "Авто"),
+            ("Save As", "Сохранить как"),
+            ("Value must be true or false.", "Значение должно быть true или false."),
+            ("Clear the text or enter null.", "Очистите текст или введите null."),
+            ("Cannot convert the value to {}: {}", "Не удалось преобразовать значение в {}: {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "Не удалось преобразовать значение в {}: оно читается как {}."),
+            ("Copy as Node.js", "Копировать как Node.js"),
+            ("Query copied as a Node.js driver call.", "Запрос скопирован как вызов драйвера Node.js."),
+            ("Cannot copy the query as Node.js: {}", "Не удалось скопировать запрос как Node.js: {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "У помощников набора реплик нет аналога в драйвере Node.js."),
+            ("Unsupported update in bulkWrite.", "Неподдерживаемое обновление в bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Неподдерживаемая операция в bulkWrite."),
+            ("(none)", "(нет)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Шардированную коллекцию нельзя очистить пересозданием. Используйте обычное удаление."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "Коллекция удалена, но её не удалось создать заново: {}. Потерянные параметры: {}. Потерянные индексы: {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "Коллекция создана заново, но её индексы не удалось восстановить: {}. Потерянные индексы: {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "Параметр '{}' не поддерживается в опциях find. Допустимы: sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "Элемент {} будет удалён из документа. Это действие нельзя отменить."),
+            ("Diagnostic report saved to {}", "Диагностический отчёт сохранён в {}"),
+            ("Failed to save diagnostic report: {}", "Не удалось сохранить диагностический отчёт: {}"),
         ])
     })
 }
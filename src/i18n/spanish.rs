@@ -29,7 +29,7 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
             ("Hide Index", "Ocultar índice"),
             ("Unhide Index", "Mostrar índice"),
             ("comment expects a value.", "comment espera un valor."),
-            ("explain must be followed by find(...).", "explain debe ir seguido de find(...)."),
+            ("explain must be followed by find(...) or aggregate(...).", "explain debe ir seguido de find(...) o aggregate(...)."),
             ("finish does not take any arguments.", "finish no acepta argumentos."),
             ("No methods are supported after finish().", "No se admiten métodos después de finish()."),
             ("Edit Index...", "Editar índice..."),
@@ -447,10 +447,6 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
                 "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
                 "El índice \"{}\" de la colección \"{}\" en la base \"{}\" se eliminará. Esta acción no se puede deshacer.",
             ),
-            (
-                "Confirm index deletion by entering its name \"{}\".",
-                "Confirme la eliminación del índice introduciendo su nombre \"{}\".",
-            ),
             (
                 "updateOne expects a filter, an update, and an optional options object.",
                 "updateOne espera un filtro, una actualización y un objeto options opcional.",
@@ -492,8 +488,8 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
                 "deleteMany acepta un filtro y un objeto options opcional.",
             ),
             (
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
-                "El método {} no es compatible. Métodos disponibles: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "El método {} no es compatible. Métodos disponibles: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
             ),
             (
                 "watch accepts at most one argument (the pipeline array).",
@@ -744,7 +740,6 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
             ("Index argument must be a string with the index name or an object with keys.", "El argumento de índice debe ser una cadena con el nombre del índice o un objeto con claves."),
             ("Update argument must be an object with operators or an array of stages.", "El argumento de actualización debe ser un objeto con operadores o un array de etapas."),
             ("The second argument to Code must be an object.", "El segundo argumento de Code debe ser un objeto."),
-            ("Enter the exact index name to confirm.", "Introduzca el nombre exacto del índice para confirmar."),
             ("Enter the exact collection name to confirm.", "Introduzca el nombre exacto de la colección para confirmar."),
             ("Document must be a JSON object.", "El documento debe ser un objeto JSON."),
             ("NumberInt value is out of the Int32 range.", "El valor de NumberInt está fuera del rango Int32."),
@@ -803,7 +798,7 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
             ("Parameter '{}' is not supported in countDocuments options. Allowed: limit, skip, hint, maxTimeMS.", "El parámetro '{}' no es compatible en options de countDocuments. Permitidos: limit, skip, hint, maxTimeMS."),
             ("Parameter '{}' is not supported in watch options. Allowed: fullDocument, fullDocumentBeforeChange, maxAwaitTimeMS, batchSize, collation, showExpandedEvents, comment, startAtOperationTime.", "El parámetro '{}' no es compatible en options de watch. Permitidos: fullDocument, fullDocumentBeforeChange, maxAwaitTimeMS, batchSize, collation, showExpandedEvents, comment, startAtOperationTime."),
             ("Parameter '{}' is not supported in watch options. Resume tokens are not supported.", "El parámetro '{}' no es compatible en options de watch. Los resume tokens no son compatibles."),
-            ("Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.", "El parámetro '{}' no es compatible en options de aggregate. Permitidos: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor."),
+            ("Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.", "El parámetro '{}' no es compatible en options de aggregate. Permitidos: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor."),
             ("Parameter '{}' is not supported in aggregate cursor options. Allowed: batchSize.", "El parámetro '{}' no es compatible en options de cursor de aggregate. Permitido: batchSize."),
             ("Parameter '{}' is not supported in distinct options. Allowed: maxTimeMS, collation.", "El parámetro '{}' no es compatible en options de distinct. Permitidos: maxTimeMS, collation."),
             ("Parameter '{}' is not supported in deleteOne/deleteMany options. Allowed: writeConcern, collation, hint.", "El parámetro '{}' no es compatible en options de deleteOne/deleteMany. Permitidos: writeConcern, collation, hint."),
@@ -842,6 +837,329 @@ pub(crate) fn spanish_map() -> &'static HashMap<&'static str, &'static str> {
             ("Function is missing a closing brace.", "A la función le falta una llave de cierre."),
             ("arrayFilters element at index {} must be a JSON object.", "El elemento arrayFilters en el índice {} debe ser un objeto JSON."),
             ("Pipeline element at index {} must be a JSON object.", "El elemento pipeline en el índice {} debe ser un objeto JSON."),
+            ("Select for Compare", "Seleccionar para comparar"),
+            ("Compare with Selected", "Comparar con el seleccionado"),
+            ("Compare Documents", "Comparar documentos"),
+            ("Added: {}, removed: {}, changed: {}, unchanged: {}", "Añadidos: {}, eliminados: {}, modificados: {}, sin cambios: {}"),
+            ("Documents are identical", "Los documentos son idénticos"),
+            ("Field", "Campo"),
+            ("Show unchanged fields", "Mostrar campos sin cambios"),
+            ("Document without _id", "Documento sin _id"),
+            ("Connections: {} of {}", "Conexiones: {} de {}"),
+            ("Tabs: {}", "Pestañas: {}"),
+            ("Theme: {}", "Tema: {}"),
+            ("Language: {}", "Idioma: {}"),
+            ("Skip {} is beyond the {} matching documents. The last page starts at skip {}.", "El salto {} supera los {} documentos coincidentes. La última página empieza en el salto {}."),
+            ("Sample Documents", "Documentos de muestra"),
+            ("Query Log", "Registro de consultas"),
+            ("Filter queries", "Filtrar consultas"),
+            ("Clear", "Limpiar"),
+            ("OK ({})", "OK ({})"),
+            ("Error: {}", "Error: {}"),
+            ("No queries executed yet", "Aún no se han ejecutado consultas"),
+            ("Invalid port in host address '{}'", "Puerto no válido en la dirección del host '{}'"),
+            ("Invalid host address '{}'", "Dirección de host no válida '{}'"),
+            ("Direct connection supports only a single host", "La conexión directa solo admite un único host"),
+            ("SSH tunnel supports only a single host", "El túnel SSH solo admite un único host"),
+            ("Optional", "Opcional"),
+            ("Replica set name", "Nombre del conjunto de réplicas"),
+            ("Replica set name requires the ReplicaSet connection type", "El nombre del conjunto de réplicas requiere el tipo de conexión ReplicaSet"),
+            ("Server default", "Predeterminado del servidor"),
+            ("Read concern", "Read concern"),
+            ("Write concern (w)", "Write concern (w)"),
+            ("Write concern must be a number, \"majority\" or a tag set name", "El write concern debe ser un número, \"majority\" o un nombre de conjunto de etiquetas"),
+            ("Preview", "Vista previa"),
+            ("Effective query:", "Consulta efectiva:"),
+            ("Skip and limit do not apply to this operation", "Skip y limit no se aplican a esta operación"),
+            ("Query timeout", "Tiempo de espera de consulta"),
+            ("0 = no timeout", "0 = sin límite"),
+            ("Large document ({}), expand to load", "Documento grande ({}), expanda para cargar"),
+            ("Deeply nested document ({} levels), expand to load", "Documento profundamente anidado ({} niveles), expanda para cargar"),
+            ("No methods are supported after explain().aggregate(...).", "No se admiten métodos después de explain().aggregate(...)."),
+            ("Method '{}' is not supported after aggregate(...).", "El método '{}' no es compatible después de aggregate(...)."),
+            ("Sidebar", "Barra lateral"),
+            ("Pin Tab", "Fijar pestaña"),
+            ("Unpin Tab", "Desfijar pestaña"),
+            ("Reuse the current unpinned tab when opening a collection", "Reutilizar la pestaña actual no fijada al abrir una colección"),
+            ("Positional operator '$[{}]' has no matching entry in arrayFilters.", "El operador posicional '$[{}]' no tiene una entrada correspondiente en arrayFilters."),
+            ("arrayFilters entry for identifier '{}' is not used in the update.", "La entrada de arrayFilters para el identificador '{}' no se usa en la actualización."),
+            ("Show relative time next to dates", "Mostrar el tiempo relativo junto a las fechas"),
+            ("just now", "justo ahora"),
+            ("{} s", "{} s"),
+            ("{} min", "{} min"),
+            ("{} h", "{} h"),
+            ("{} d", "{} d"),
+            ("{} mo", "{} mes."),
+            ("{} y", "{} a"),
+            ("{} ago", "hace {}"),
+            ("in {}", "en {}"),
+            ("No documents matched the query.", "Ningún documento coincide con la consulta."),
+            ("No distinct values found.", "No se encontraron valores distintos."),
+            ("Adjust the filter or pipeline and run the query again.", "Ajuste el filtro o el pipeline y vuelva a ejecutar la consulta."),
+            ("Copy _id", "Copiar _id"),
+            ("Enter the exact index name or key specification to confirm.", "Introduzca el nombre exacto del índice o su especificación de claves para confirmar."),
+            ("Confirm index deletion by entering its name \"{}\" or its key specification.", "Confirme la eliminación del índice introduciendo su nombre \"{}\" o su especificación de claves."),
+            ("Index Builder", "Constructor de índices"),
+            ("Index Builder...", "Constructor de índices..."),
+            ("Create an index on collection \"{}\" in database \"{}\".", "Crear un índice en la colección \"{}\" de la base de datos \"{}\"."),
+            ("Add Field", "Añadir campo"),
+            ("Index name", "Nombre del índice"),
+            ("Generated from fields", "Generado a partir de los campos"),
+            ("TTL (seconds)", "TTL (segundos)"),
+            ("No expiration", "Sin caducidad"),
+            ("Partial filter", "Filtro parcial"),
+            ("Unique", "Único"),
+            ("Sparse", "Disperso"),
+            ("Open in Editor", "Abrir en el editor"),
+            ("Choose at least one field for the index.", "Elija al menos un campo para el índice."),
+            ("Field '{}' is used more than once.", "El campo '{}' se usa más de una vez."),
+            ("TTL must be a non-negative number of seconds.", "El TTL debe ser un número no negativo de segundos."),
+            ("TTL indexes must have exactly one field.", "Los índices TTL deben tener exactamente un campo."),
+            ("Loading existing indexes...", "Cargando índices existentes..."),
+            ("Existing indexes are unavailable: {}", "Los índices existentes no están disponibles: {}"),
+            ("Existing indexes", "Índices existentes"),
+            ("Index \"{}\" already uses this key pattern. You can still create the index.", "El índice \"{}\" ya usa este patrón de claves. Aún puede crear el índice."),
+            ("Export Diagnostic Report", "Exportar informe de diagnóstico"),
+            ("Run", "Ejecutar"),
+            ("Confirm Aggregation Output", "Confirmar salida de la agregación"),
+            ("The pipeline writes its results with {} into {}.", "El pipeline escribe sus resultados con {} en {}."),
+            ("The target differs from the source collection {}.", "El destino es distinto de la colección de origen {}."),
+            ("$out replaces all documents in the target collection.", "$out reemplaza todos los documentos de la colección de destino."),
+            ("$merge inserts or updates documents in the target collection.", "$merge inserta o actualiza documentos en la colección de destino."),
+            ("Open Response in External Editor", "Abrir respuesta en editor externo"),
+            ("Tab title", "Título de la pestaña"),
+            ("Database / Collection", "Base de datos / Colección"),
+            ("Connection / Collection", "Conexión / Colección"),
+            ("Connection / Database / Collection", "Conexión / Base de datos / Colección"),
+            ("Ask before closing tabs with edited queries that were not run", "Preguntar antes de cerrar pestañas con consultas editadas sin ejecutar"),
+            ("Close Anyway", "Cerrar de todos modos"),
+            ("Close Tab", "Cerrar pestaña"),
+            ("The query in tab \"{}\" was edited and not run. Closing the tab discards it.", "La consulta de la pestaña \"{}\" se editó y no se ejecutó. Al cerrar la pestaña se descartará."),
+            ("Rename Tab", "Renombrar pestaña"),
+            ("Prefill new tabs with a find query", "Rellenar las pestañas nuevas con una consulta find"),
+            ("Count Documents", "Contar documentos"),
+            ("Estimated Count", "Recuento estimado"),
+            ("Counting documents in {}...", "Contando documentos en {}..."),
+            ("{}: about {} documents (estimated)", "{}: unos {} documentos (estimado)"),
+            ("{}: {} documents", "{}: {} documentos"),
+            ("{}: unexpected count result", "{}: resultado de recuento inesperado"),
+            ("Failed to count documents in {}: {}", "No se pudieron contar los documentos en {}: {}"),
+            ("Database name cannot be empty.", "El nombre de la base de datos no puede estar vacío."),
+            ("Database name cannot contain null characters.", "El nombre de la base de datos no puede contener caracteres nulos."),
+            ("Database name cannot contain the character '{}'.", "El nombre de la base de datos no puede contener el carácter '{}'."),
+            ("Database name must be at most {} bytes long.", "El nombre de la base de datos debe tener como máximo {} bytes."),
+            ("Collection name cannot contain null characters.", "El nombre de la colección no puede contener caracteres nulos."),
+            ("Collection name cannot contain the character '{}'.", "El nombre de la colección no puede contener el carácter '{}'."),
+            ("Collection name cannot start with 'system.'.", "El nombre de la colección no puede comenzar con 'system.'."),
+            ("Namespace '{}' exceeds {} bytes.", "El espacio de nombres '{}' supera los {} bytes."),
+            ("readConcern requires a document with a level.", "readConcern requiere un documento con un nivel."),
+            ("readConcern must be a document with a single string field 'level'.", "readConcern debe ser un documento con un único campo de texto 'level'."),
+            ("Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.", "Nivel de readConcern no admitido '{}'. Permitidos: local, majority, linearizable, available, snapshot."),
+            ("Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.", "El método '{}' no es compatible después de find(...). Compatibles: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments."),
+            ("Pipeline stage at index {} is empty.", "La etapa del pipeline en el índice {} está vacía."),
+            ("Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.", "La etapa del pipeline en el índice {} debe comenzar con '$', se encontró '{}'. Envuelva la expresión en una etapa como $match."),
+            ("Pipeline stage at index {} must contain exactly one operator, found: {}.", "La etapa del pipeline en el índice {} debe contener exactamente un operador, se encontró: {}."),
+            ("Date Format Preview", "Vista previa del formato de fecha"),
+            ("Date field", "Campo de fecha"),
+            ("Sample date", "Fecha de ejemplo"),
+            ("Format", "Formato"),
+            ("Timezone", "Zona horaria"),
+            ("Result", "Resultado"),
+            ("Copy Expression", "Copiar expresión"),
+            ("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%.", "Especificadores: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            ("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.", "Introduzca la fecha de ejemplo en ISO 8601, por ejemplo 2024-03-15T13:45:30Z."),
+            ("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", "Zona horaria no admitida '{}'. Use UTC o un desplazamiento como +03:00."),
+            ("Unmatched '%' at the end of the format string.", "'%' sin pareja al final de la cadena de formato."),
+            ("Invalid format character '%{}' in the format string.", "Carácter de formato no válido '%{}' en la cadena de formato."),
+            ("Value must be a valid base64 string.", "El valor debe ser una cadena base64 válida."),
+            ("Generic", "Genérico"),
+            ("Function", "Función"),
+            ("Binary (old)", "Binary (antiguo)"),
+            ("UUID (old)", "UUID (antiguo)"),
+            ("UUID", "UUID"),
+            ("MD5", "MD5"),
+            ("Encrypted", "Cifrado"),
+            ("Column", "Columna"),
+            ("Sensitive", "Sensible"),
+            ("User defined", "Definido por el usuario"),
+            ("Reserved", "Reservado"),
+            ("Subtype", "Subtipo"),
+            ("Input Format", "Formato de entrada"),
+            ("Saving will change the field type from {} to {}.", "Al guardar, el tipo del campo cambiará de {} a {}."),
+            ("Press Save Anyway to confirm.", "Pulse Guardar de todos modos para confirmar."),
+            ("Save Anyway", "Guardar de todos modos"),
+            ("Group connections by host in the sidebar", "Agrupar las conexiones por host en la barra lateral"),
+            ("Compact sidebar density", "Densidad compacta de la barra lateral"),
+            ("Test All", "Probar todas"),
+            ("OK ({} ms)", "OK ({} ms)"),
+            ("Failed after {} ms: {}", "Falló tras {} ms: {}"),
+            ("Skipped: the password is requested on connect", "Omitida: la contraseña se solicita al conectar"),
+            ("The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.", "El campo _id no se puede cambiar en el lugar. Active \"Permitir cambiar _id\" para reemplazar el documento."),
+            ("Allow changing _id (the document is re-inserted, not updated)", "Permitir cambiar _id (el documento se vuelve a insertar, no se actualiza)"),
+            ("The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.", "El _id ha cambiado. Al guardar se inserta un documento nuevo y se elimina el original; no es una actualización en el lugar. Pulse Reemplazar documento para confirmar."),
+            ("Replace Document", "Reemplazar documento"),
+            ("Rollback failed: {}", "La reversión falló: {}"),
+            ("Drop and recreate the collection (faster on large collections)", "Eliminar y volver a crear la colección (más rápido en colecciones grandes)"),
+            ("The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.", "La colección se elimina y se vuelve a crear con las mismas opciones y luego se reconstruyen sus índices. Otros clientes pueden ver brevemente que la colección no existe."),
+            ("Collection \"{}\" not found.", "No se encontró la colección \"{}\"."),
+            ("Only regular collections can be truncated by recreating them. Use the regular deletion instead.", "Solo las colecciones normales se pueden vaciar recreándolas. Use la eliminación normal."),
+            ("Text Search...", "Búsqueda de texto..."),
+            ("Text Search", "Búsqueda de texto"),
+            ("Checking text indexes...", "Comprobando índices de texto..."),
+            ("This collection has no text index. Create one to use text search.", "Esta colección no tiene un índice de texto. Cree uno para usar la búsqueda de texto."),
+            ("Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.", "Buscar en la colección \"{}\" con el índice de texto \"{}\". Los resultados se ordenan por relevancia."),
+            ("Search terms", "Términos de búsqueda"),
+            ("Read-only (block all write operations)", "Solo lectura (bloquear todas las operaciones de escritura)"),
+            ("Read-only", "Solo lectura"),
+            ("Connection \"{}\" is read-only. Write operations are blocked.", "La conexión \"{}\" es de solo lectura. Las operaciones de escritura están bloqueadas."),
+            ("Color accent", "Color de acento"),
+            ("None", "Ninguno"),
+            ("Reconnect automatically when running a query on a closed connection", "Reconectar automáticamente al ejecutar una consulta en una conexión cerrada"),
+            ("Validation Rules...", "Reglas de validación..."),
+            ("Validation Rules", "Reglas de validación"),
+            ("Loading validation rules...", "Cargando reglas de validación..."),
+            ("Applying validation rules...", "Aplicando reglas de validación..."),
+            ("Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.", "Edite validator, validationLevel y validationAction. Al guardar se aplican con collMod; un validator vacío elimina la validación."),
+            ("Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.", "Campo no admitido \"{}\". Solo se pueden cambiar validator, validationLevel y validationAction."),
+            ("The validator must be a document.", "El validator debe ser un documento."),
+            ("Insert Document...", "Insertar documento..."),
+            ("By name", "Por nombre"),
+            ("By size (largest first)", "Por tamaño (primero los más grandes)"),
+            ("Sort databases and collections", "Ordenar bases de datos y colecciones"),
+            ("Copy Results as Markdown Table", "Copiar resultados como tabla Markdown"),
+            ("The results are not a list of documents and cannot be shown as a table.", "Los resultados no son una lista de documentos y no se pueden mostrar como tabla."),
+            ("All fields", "Todos los campos"),
+            ("Preset name cannot be empty.", "El nombre del ajuste predefinido no puede estar vacío."),
+            ("Projection Preset", "Proyección predefinida"),
+            ("Find queries without their own projection return only these fields. Saving an existing name replaces that preset.", "Las consultas find sin proyección propia devuelven solo estos campos. Guardar con un nombre existente reemplaza ese ajuste."),
+            ("Fields", "Campos"),
+            ("summary", "resumen"),
+            ("Invalid field path \"{}\".", "Ruta de campo no válida \"{}\"."),
+            ("Enter at least one field to project.", "Introduzca al menos un campo para proyectar."),
+            ("Refresh All Connections", "Actualizar todas las conexiones"),
+            ("Copy Selected Rows", "Copiar filas seleccionadas"),
+            ("Shortcuts", "Atajos"),
+            ("Send query", "Enviar consulta"),
+            ("Rerun last query", "Repetir la última consulta"),
+            ("Close tab", "Cerrar pestaña"),
+            ("Next tab", "Pestaña siguiente"),
+            ("Previous tab", "Pestaña anterior"),
+            ("Toggle sidebar", "Mostrar u ocultar la barra lateral"),
+            ("Table view", "Vista de tabla"),
+            ("Text view", "Vista de texto"),
+            ("Shortcut must end with a key.", "El atajo debe terminar con una tecla."),
+            ("Unknown modifier \"{}\".", "Modificador desconocido \"{}\"."),
+            ("Unknown key \"{}\".", "Tecla desconocida \"{}\"."),
+            ("\"{}\" and \"{}\" use the same shortcut.", "\"{}\" y \"{}\" usan el mismo atajo."),
+            ("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS.", "Combine Ctrl, Shift o Alt con una tecla, por ejemplo Ctrl+Shift+Tab. En macOS Ctrl equivale a Cmd."),
+            ("Conflicts with \"{}\".", "Entra en conflicto con \"{}\"."),
+            ("Default Shortcuts", "Atajos predeterminados"),
+            ("Show Side by Side with Active Tab", "Mostrar junto a la pestaña activa"),
+            ("Synchronize scrolling", "Sincronizar desplazamiento"),
+            ("Close Side by Side", "Cerrar vista lado a lado"),
+            ("Render at most", "Mostrar como máximo"),
+            ("0 = no cap", "0 = sin límite"),
+            ("documents per result", "documentos por resultado"),
+            ("Showing the first {} of {} returned items.", "Se muestran los primeros {} de {} elementos devueltos."),
+            ("Find This Document", "Buscar este documento"),
+            ("Up to stage {} of {}", "Hasta la etapa {} de {}"),
+            ("All {} stages", "Todas las etapas ({})"),
+            ("bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.", "La operación bulkWrite '{}' en el índice {} no es compatible. Permitidas: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany."),
+            ("Export Results to JSON...", "Exportar resultados a JSON..."),
+            ("Export Results to CSV...", "Exportar resultados a CSV..."),
+            ("Results saved to {}", "Resultados guardados en {}"),
+            ("Failed to export results: {}", "No se pudieron exportar los resultados: {}"),
+            ("{} tabs were not restored because their connections no longer exist.", "{} pestañas no se restauraron porque sus conexiones ya no existen."),
+            ("Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.", "Verbosidad de explain desconocida '{}'. Permitidas: queryPlanner, executionStats, allPlansExecution."),
+            ("explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.", "explain espera una cadena de verbosidad: queryPlanner, executionStats o allPlansExecution."),
+            ("Append Array Element...", "Añadir elemento al array..."),
+            ("Delete Array Element", "Eliminar elemento del array"),
+            ("A new element will be appended to the array", "Se añadirá un nuevo elemento al array"),
+            ("Failed to delete the array element: {}", "No se pudo eliminar el elemento del array: {}"),
+            ("Read", "Lectura"),
+            ("Read preference", "Preferencia de lectura"),
+            ("Secondary read preferences keep analysis queries off the primary.", "Las preferencias de lectura secundarias mantienen las consultas de análisis fuera del primario."),
+            ("Use SRV record (mongodb+srv://)", "Usar registro SRV (mongodb+srv://)"),
+            ("From DNS", "Desde DNS"),
+            ("SRV connections take the port from DNS; leave the port empty", "Las conexiones SRV obtienen el puerto del DNS; deje el puerto vacío"),
+            ("An SRV connection takes a single host name without a port", "Una conexión SRV admite un único nombre de host sin puerto"),
+            ("SSH tunnel cannot be used with an SRV connection", "El túnel SSH no se puede usar con una conexión SRV"),
+            ("Insert Template...", "Plantilla de inserción..."),
+            ("Insert Document", "Insertar documento"),
+            ("Enter the new document. An _id is generated when the document does not set one.", "Introduzca el nuevo documento. Si no define un _id, se generará uno."),
+            ("Inserting document...", "Insertando documento..."),
+            ("Inserted document with _id {}", "Documento insertado con _id {}"),
+            ("Highlight query syntax in the editor", "Resaltar la sintaxis de la consulta en el editor"),
+            ("Query cancelled. The server may still finish the operation.", "Consulta cancelada. Es posible que el servidor termine la operación igualmente."),
+            ("Filter by key or value", "Filtrar por clave o valor"),
+            ("Match case", "Coincidir mayúsculas"),
+            ("{} matches", "{} coincidencias"),
+            ("TLS", "TLS"),
+            ("CA file", "Archivo de CA"),
+            ("Path to PEM file", "Ruta al archivo PEM"),
+            ("Client certificate and key file", "Archivo de certificado y clave del cliente"),
+            ("Allow invalid certificates and host names (tlsInsecure)", "Permitir certificados y nombres de host no válidos (tlsInsecure)"),
+            ("Setting any of these options enables TLS for the connection.", "Cualquiera de estas opciones activa TLS para la conexión."),
+            ("TLS CA file not found: {}", "No se encontró el archivo de CA de TLS: {}"),
+            ("TLS certificate key file not found: {}", "No se encontró el archivo de certificado y clave TLS: {}"),
+            ("Pin to Top", "Fijar arriba"),
+            ("Unpin Collection", "Desfijar colección"),
+            ("Expand All opens at most", "«Expandir todo» abre como máximo"),
+            ("0 = all levels", "0 = todos los niveles"),
+            ("levels", "niveles"),
+            ("Expand All", "Expandir todo"),
+            ("Collapse All", "Contraer todo"),
+            ("Saved Queries", "Consultas guardadas"),
+            ("Save Query", "Guardar consulta"),
+            ("Edit Saved Query", "Editar consulta guardada"),
+            ("No saved queries. Use \"Save Query\" in a collection tab to add one.", "No hay consultas guardadas. Use \"Guardar consulta\" en una pestaña de colección para añadir una."),
+            ("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into.", "{{collection}} y {{db}} se sustituyen por los nombres de la pestaña en la que se carga la consulta."),
+            ("Use {{collection}} and {{db}} for the names of the tab the query is loaded into.", "Use {{collection}} y {{db}} para los nombres de la pestaña en la que se carga la consulta."),
+            ("Load", "Cargar"),
+            ("Target", "Destino"),
+            ("Query", "Consulta"),
+            ("Database (optional)", "Base de datos (opcional)"),
+            ("Collection (optional)", "Colección (opcional)"),
+            ("Slow operations", "Operaciones lentas"),
+            ("Query name cannot be empty.", "El nombre de la consulta no puede estar vacío."),
+            ("Query text cannot be empty.", "El texto de la consulta no puede estar vacío."),
+            ("A saved query with this name already exists.", "Ya existe una consulta guardada con este nombre."),
+            ("Loaded a query saved for {}.", "Se cargó una consulta guardada para {}."),
+            ("Saved query \"{}\".", "Consulta \"{}\" guardada."),
+            ("mapReduce expects map and reduce functions and an optional options object.", "mapReduce espera las funciones map y reduce y un objeto de opciones opcional."),
+            ("mapReduce '{}' must be a function.", "mapReduce '{}' debe ser una función."),
+            ("mapReduce options must be a JSON object.", "Las opciones de mapReduce deben ser un objeto JSON."),
+            ("mapReduce 'out' must be a collection name or a document.", "mapReduce 'out' debe ser un nombre de colección o un documento."),
+            ("mapReduce '{}' must be a document.", "mapReduce '{}' debe ser un documento."),
+            ("mapReduce 'limit' must be a non-negative integer.", "mapReduce 'limit' debe ser un entero no negativo."),
+            ("Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.", "El parámetro '{}' no es compatible con las opciones de mapReduce. Permitidos: out, query, sort, limit, finalize."),
+            ("projection requires a document with the fields to return.", "projection requiere un documento con los campos a devolver."),
+            ("Extended JSON", "Extended JSON"),
+            ("Canonical Extended JSON", "Extended JSON canónico"),
+            ("Show Tree", "Mostrar árbol"),
+            ("Run a query to see its extended JSON.", "Ejecute una consulta para ver su Extended JSON."),
+            ("Group", "Grupo"),
+            ("Ungrouped", "Sin grupo"),
+            ("Auto", "Auto"),
+            ("Save As", "Guardar como"),
+            ("Value must be true or false.", "El valor debe ser true o false."),
+            ("Clear the text or enter null.", "Borre el texto o escriba null."),
+            ("Cannot convert the value to {}: {}", "No se puede convertir el valor a {}: {}"),
+            ("Cannot convert the value to {}: it reads as {}.", "No se puede convertir el valor a {}: se interpreta como {}."),
+            ("Copy as Node.js", "Copiar como Node.js"),
+            ("Query copied as a Node.js driver call.", "Consulta copiada como llamada del controlador de Node.js."),
+            ("Cannot copy the query as Node.js: {}", "No se puede copiar la consulta como Node.js: {}"),
+            ("Replica set helpers have no Node.js driver equivalent.", "Los asistentes de replica set no tienen equivalente en el controlador de Node.js."),
+            ("Unsupported update in bulkWrite.", "Actualización no admitida en bulkWrite."),
+            ("Unsupported operation in bulkWrite.", "Operación no admitida en bulkWrite."),
+            ("(none)", "(ninguno)"),
+            ("Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.", "Las colecciones fragmentadas no se pueden vaciar recreándolas. Use el borrado normal."),
+            ("The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.", "La colección se eliminó pero no se pudo recrear: {}. Opciones perdidas: {}. Índices perdidos: {}."),
+            ("The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.", "La colección se recreó pero no se pudieron restaurar sus índices: {}. Índices perdidos: {}."),
+            ("Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.", "El parámetro '{}' no es compatible con las opciones de find. Permitidos: sort, hint, skip, limit, maxTimeMS, comment, collation, let."),
+            ("The element {} is removed from the document. This cannot be undone.", "El elemento {} se eliminará del documento. Esta acción no se puede deshacer."),
+            ("Diagnostic report saved to {}", "Informe de diagnóstico guardado en {}"),
+            ("Failed to save diagnostic report: {}", "No se pudo guardar el informe de diagnóstico: {}"),
         ])
     })
 }
@@ -1,3 +1,4 @@
+use crate::mongo::connection::mask_uri_credentials;
 use crate::settings::DEFAULT_LOG_FILE_NAME;
 use chrono::Local;
 use log::{LevelFilter, Log, Metadata, Record};
@@ -19,6 +20,11 @@ impl LoggingConfig {
     pub fn new(enabled: bool, level: LevelFilter, file_path: PathBuf) -> Self {
         Self { enabled, level, file_path }
     }
+
+    /// Log lines are mirrored to stderr only when debug logging is explicitly enabled.
+    fn echo_to_console(&self) -> bool {
+        self.level >= LevelFilter::Debug
+    }
 }
 
 struct FileState {
@@ -88,7 +94,9 @@ impl Logger {
     }
 
     fn write_line(&self, line: &str, config: &LoggingConfig) {
-        let _ = io::stderr().write_all(line.as_bytes());
+        if config.echo_to_console() {
+            let _ = io::stderr().write_all(line.as_bytes());
+        }
 
         let mut guard = self.file_state.lock().expect("logger file state lock poisoned");
         let state = match guard.as_mut() {
@@ -132,11 +140,8 @@ impl Log for Logger {
         }
 
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
-        let line = format!(
-            "{timestamp} [{level}] {message}\n",
-            level = record.level(),
-            message = record.args()
-        );
+        let message = mask_uri_credentials(&record.args().to_string());
+        let line = format!("{timestamp} [{level}] {message}\n", level = record.level());
         self.write_line(&line, &config);
     }
 
@@ -18,8 +18,9 @@ use iced::font::Weight;
 use iced::futures::stream;
 use iced::keyboard::{self, key};
 use iced::theme::{Base, Mode};
+use iced::widget::checkbox::Checkbox;
 use iced::widget::image::Handle;
-use iced::widget::operation::{focus, snap_to};
+use iced::widget::operation::{focus, select_all, snap_to};
 use iced::widget::pane_grid::ResizeEvent;
 use iced::widget::scrollable;
 use iced::widget::text::Wrapping;
@@ -27,8 +28,8 @@ use iced::widget::text_editor::{
     self, Action as TextEditorAction, Binding as TextEditorBinding, Content as TextEditorContent,
 };
 use iced::widget::{
-    Button, Column, Container, Id, Image, Row, Scrollable, Space, button, container, mouse_area,
-    pane_grid, text_input,
+    Button, Column, Container, Id, Image, PickList, Row, Scrollable, Space, button, container,
+    mouse_area, pane_grid, text_input,
 };
 use iced::window;
 use iced::{
@@ -36,42 +37,72 @@ use iced::{
     clipboard,
 };
 use iced_aw::{ColorPicker, ContextMenu};
-use mongo::bson_edit::ValueEditKind;
+use mongo::bson_diff::{DiffEntry, DiffKind, DiffSummary, diff_documents};
+use mongo::bson_edit::{
+    BINARY_INPUT_FORMATS, BINARY_SUBTYPE_CHOICES, BinaryInputFormat, BinarySubtypeChoice,
+    VALUE_TYPE_CHOICES, ValueEditKind, ValueTypeChoice,
+};
 use mongo::bson_tree::{
     BsonTree, BsonTreeOptions, BsonTreeStats, is_supported_reference_id_type,
     related_collection_name_candidates,
 };
 use mongo::connection::{
-    ConnectionBootstrap, OMDBConnection, connect_and_discover, fetch_collections, filter_databases,
+    ConnectionBootstrap, OMDBConnection, connect_and_discover, fetch_collection_counts,
+    fetch_collection_sizes, fetch_collections, fetch_database_sizes, filter_databases,
+    format_byte_size, mask_uri_credentials, test_connection,
+};
+use mongo::date_format::{
+    DEFAULT_DATE_TO_STRING_FORMAT, date_to_string_expression, preview_date_to_string,
 };
+use mongo::export;
 use mongo::query::{
-    QueryOperation, QueryResult, ReplicaSetCommand, WatchParsedOptions, WatchTarget,
-    open_change_stream, parse_collection_query_with_collection, run_collection_query,
+    AggregateWriteTarget, INDEX_KEY_KINDS, IndexBuilderSpec, IndexKeyKind, QueryOperation,
+    QueryResult, ReplicaSetCommand, WatchParsedOptions, WatchTarget, array_element_removal_updates,
+    describe_effective_query, find_text_index, insert_template_query, open_change_stream,
+    parse_collection_query_with_collection, parse_projection_fields, projection_from_fields,
+    resolve_drop_index_target, run_collection_query, schema_insert_template, text_search_query,
+    validate_collection_name, validate_database_name, validation_coll_mod_command,
+    validation_rules_document,
 };
 use mongo::shell;
 use mongo::ssh_tunnel::SshTunnel;
 use mongodb::bson::{self, Bson, Document, doc};
 use mongodb::change_stream::event::ChangeStreamEvent;
 use mongodb::options::ReturnDocument;
-use mongodb::sync::Client;
+use mongodb::sync::{Client, Collection};
 use rfd::FileDialog;
-use settings::{AppSettings, LogLevel, ThemeChoice, ThemePalette};
-use std::collections::{HashMap, HashSet};
+use settings::{
+    AppSettings, CollectionPaging, KeyCombo, LogLevel, ProjectionPreset, RgbaColor, SessionState,
+    SessionTab, ShortcutAction, TabTitleFormat, ThemeChoice, ThemePalette, TimeoutUnit,
+    TreeSortMode, collection_paging_key, default_key_bindings,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use ui::connections::{
-    AuthMechanismChoice, ConnectionEntry, ConnectionFormMode, ConnectionFormState,
-    ConnectionFormTab, ConnectionType, ConnectionsWindowState, ListClick, PasswordStorage,
-    SshAuthMethod, TestFeedback, connection_form_view, connections_view,
-    load_connections_from_disk, save_connections_to_disk,
+    AuthMechanismChoice, BatchTest, BatchTestRow, BatchTestStatus, CONNECTIONS_LIST_SCROLL_ID,
+    ConnectionEntry, ConnectionFormMode, ConnectionFormState, ConnectionFormTab, ConnectionType,
+    ConnectionsWindowState, ListClick, PasswordStorage, ReadConcernChoice, ReadPreferenceChoice,
+    SshAuthMethod, TestFeedback, TlsFileField, connection_form_key_binding, connection_form_view,
+    connections_view, load_connections_from_disk, save_connections_to_disk,
 };
 use ui::help_docs::{HelpDocsState, help_docs_view};
 use ui::menues::{
     self, CollectionContextAction, ConnectionContextAction, DatabaseContextAction, MenuEntry,
     TopMenu,
 };
-use ui::modal::{color_luminance, error_accent_color, modal_layout, success_accent_color};
+use ui::modal::{
+    accent_swatch, color_luminance, error_accent_color, modal_layout, success_accent_color,
+    warning_accent_color,
+};
+use ui::query_highlighter::{self, QueryHighlightColors, QueryHighlighter};
+use ui::saved_queries::{
+    SavedQueriesWindowState, SavedQuery, SavedQueryFormState, load_saved_queries_from_disk,
+    save_saved_queries_to_disk, saved_queries_view, saved_query_form_view,
+};
 use ui::settings::{SettingsTab, SettingsWindowState, ThemeColorField, settings_view};
 pub(crate) type TabId = u32;
 pub(crate) type ClientId = u32;
@@ -84,6 +115,7 @@ const PANE_GRID_MIN_SIZE: f32 = 0.0;
 const MAIN_PANEL_PADDING: f32 = 8.0;
 const TAB_HEADER_PADDING_X: f32 = 4.0;
 const TAB_ROW_SPACING: f32 = 8.0;
+const TAB_TITLE_INPUT_ID: &str = "tab-title-input";
 const TAB_INNER_SPACING: f32 = 4.0;
 const TAB_TITLE_PADDING_X: f32 = 12.0;
 const TAB_CONTAINER_PADDING_X: f32 = 8.0;
@@ -92,9 +124,25 @@ const TAB_CHAR_WIDTH_FACTOR: f32 = 0.6;
 const TAB_WIDTH_TOLERANCE: f32 = 12.0;
 const TAB_SCROLLBAR_PADDING: f32 = 10.0;
 const MODAL_OUTER_MARGIN_X: f32 = 24.0;
+const QUERY_LOG_CAPACITY: usize = 200;
+const QUERY_LOG_PANEL_HEIGHT: f32 = 180.0;
 const MODAL_TARGET_WIDTH_RATIO: f32 = 0.5;
 const DOCUMENT_MODAL_MIN_WIDTH: f32 = 600.0;
 const VALUE_EDIT_MODAL_MIN_WIDTH: f32 = 480.0;
+const SIDEBAR_COMFORTABLE: SidebarDensity = SidebarDensity {
+    item_spacing: 4.0,
+    header_spacing: 8.0,
+    row_spacing: 6.0,
+    button_padding: [4, 4],
+    icon_scale: 1.5,
+};
+const SIDEBAR_COMPACT: SidebarDensity = SidebarDensity {
+    item_spacing: 1.0,
+    header_spacing: 4.0,
+    row_spacing: 4.0,
+    button_padding: [1, 4],
+    icon_scale: 1.1,
+};
 const WINDOW_ICON_BYTES: &[u8] = include_bytes!("../assests/icons/oxide_mongo_256x256.png");
 pub(crate) const ICON_NETWORK_BYTES: &[u8] = include_bytes!("../assests/icons/network_115x128.png");
 const ICON_DATABASE_BYTES: &[u8] = include_bytes!("../assests/icons/database_105x128.png");
@@ -105,6 +153,10 @@ static ICON_DATABASE_HANDLE: OnceLock<Handle> = OnceLock::new();
 static ICON_COLLECTION_HANDLE: OnceLock<Handle> = OnceLock::new();
 static PERF_DIAGNOSTICS_ENABLED: OnceLock<bool> = OnceLock::new();
 
+fn expand_all_depth_limit(settings: &AppSettings) -> Option<usize> {
+    usize::try_from(settings.expand_all_depth).ok().filter(|depth| *depth > 0)
+}
+
 pub(crate) fn perf_diagnostics_enabled() -> bool {
     *PERF_DIAGNOSTICS_ENABLED.get_or_init(|| {
         std::env::var("OXIDE_MONGO_PERF_DIAGNOSTICS")
@@ -122,8 +174,10 @@ fn main() -> iced::Result {
     let mut window_settings = window::Settings::default();
     window_settings.icon = Some(icon);
     window_settings.size.width += 280.0;
+    // Closing goes through `window::Event::CloseRequested` so the open tabs can be saved first.
+    window_settings.exit_on_close_request = false;
 
-    application(App::init, App::update, App::view)
+    let result = application(App::init, App::update, App::view)
         .title("Oxide Mongo")
         .subscription(App::subscription)
         .theme(App::theme)
@@ -133,7 +187,36 @@ fn main() -> iced::Result {
         .font(FIRACODE_FONT_BYTES)
         .font(FIRACODE_MEDIUM_FONT_BYTES)
         .window(window_settings)
-        .run()
+        .run();
+
+    let external_dir = external_response_dir();
+    if external_dir.exists()
+        && let Err(error) = std::fs::remove_dir_all(&external_dir)
+    {
+        log::warn!("Failed to remove {}: {error}", external_dir.display());
+    }
+
+    result
+}
+
+/// Responses opened in an external editor are written here and removed when the app exits.
+fn external_response_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("oxide_mongo-{}", std::process::id()))
+}
+
+fn open_with_system_handler(path: &Path) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    command.arg(path).spawn().map(|_| ()).map_err(|error| error.to_string())
 }
 
 pub(crate) struct App {
@@ -145,6 +228,8 @@ pub(crate) struct App {
     next_client_id: ClientId,
     last_collection_click: Option<CollectionClick>,
     connections: Vec<ConnectionEntry>,
+    collection_paging: BTreeMap<String, CollectionPaging>,
+    projection_presets: BTreeMap<String, Vec<ProjectionPreset>>,
     settings: AppSettings,
     mode: AppMode,
     connections_window: Option<ConnectionsWindowState>,
@@ -156,8 +241,30 @@ pub(crate) struct App {
     database_modal: Option<DatabaseModalState>,
     document_modal: Option<DocumentModalState>,
     value_edit_modal: Option<ValueEditModalState>,
+    document_diff_modal: Option<DocumentDiffModalState>,
+    index_builder_modal: Option<IndexBuilderModalState>,
+    aggregate_write_modal: Option<AggregateWriteModalState>,
+    date_format_preview: Option<DateFormatPreviewState>,
+    text_search_modal: Option<TextSearchModalState>,
+    projection_preset_modal: Option<ProjectionPresetModalState>,
+    saved_queries: Vec<SavedQuery>,
+    saved_queries_window: Option<SavedQueriesWindowState>,
+    saved_query_form: Option<SavedQueryFormState>,
+    tab_close_confirmation: Option<TabId>,
+    array_element_delete: Option<ArrayElementDeleteState>,
+    tab_title_edit: Option<(TabId, String)>,
+    query_log: Vec<QueryLogEntry>,
+    query_log_visible: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    query_log_filter: String,
+    sidebar_collapsed: bool,
+    status_notice: Option<String>,
+    /// Whether a `QueryProgressTick` is already scheduled.
+    query_progress_ticking: bool,
+    last_connection_selection: Option<usize>,
     window_size: Option<Size>,
     tab_color_picker: Option<TabId>,
+    side_by_side: Option<SideBySideState>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -166,12 +273,24 @@ enum PaneContent {
     Main,
 }
 
+/// Spacing used by the sidebar tree; switched by the compact sidebar setting.
+#[derive(Debug, Clone, Copy)]
+struct SidebarDensity {
+    item_spacing: f32,
+    header_spacing: f32,
+    row_spacing: f32,
+    button_padding: [u16; 2],
+    icon_scale: f32,
+}
+
 #[derive(Debug)]
 struct TabData {
     id: TabId,
     title: String,
     collection: CollectionTab,
     color: Option<Color>,
+    pinned: bool,
+    custom_title: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -191,7 +310,25 @@ pub(crate) enum Message {
     TabSelected(TabId),
     TabClosed(TabId),
     CloseActiveTab,
+    RerunActiveQuery,
+    ToggleSidebar,
     DuplicateTab(TabId),
+    TabOpenExternal(TabId),
+    SideBySideOpen(TabId),
+    SideBySideClose,
+    SideBySideResized(pane_grid::ResizeEvent),
+    SideBySideSyncScrollToggled(bool),
+    TabOpenExternalCompleted(Result<(), String>),
+    TabPinToggled(TabId),
+    TabRenameStarted(TabId),
+    TabTitleChanged(String),
+    TabRenameSubmitted,
+    TabRenameCancelled,
+    SelectAdjacentTab {
+        forward: bool,
+    },
+    TabCloseConfirmed,
+    TabCloseCancelled,
     TabColorPickerOpened(TabId),
     TabColorPickerCanceled,
     TabColorChanged {
@@ -206,6 +343,26 @@ pub(crate) enum Message {
         client_id: ClientId,
         result: Result<ConnectionBootstrap, String>,
     },
+    QueryReconnectCompleted {
+        tab_id: TabId,
+        client_id: ClientId,
+        write_confirmed: bool,
+        result: Result<ConnectionBootstrap, String>,
+    },
+    DatabaseSizesLoaded {
+        client_id: ClientId,
+        result: Result<Vec<(String, u64)>, String>,
+    },
+    CollectionSizesLoaded {
+        client_id: ClientId,
+        db_name: String,
+        sizes: Vec<(String, u64)>,
+    },
+    CollectionCountsLoaded {
+        client_id: ClientId,
+        db_name: String,
+        counts: Vec<(String, u64)>,
+    },
     ToggleClient(ClientId),
     ToggleDatabase {
         client_id: ClientId,
@@ -225,6 +382,7 @@ pub(crate) enum Message {
         db_name: String,
         collection: String,
     },
+    CollectionQueryPreviewToggled(TabId),
     CollectionEditorAction {
         tab_id: TabId,
         action: TextEditorAction,
@@ -235,9 +393,32 @@ pub(crate) enum Message {
         tab_id: TabId,
         node_id: usize,
     },
+    CollectionTreeFilterChanged {
+        tab_id: TabId,
+        value: String,
+    },
+    CollectionTreeFilterCaseToggled {
+        tab_id: TabId,
+        value: bool,
+    },
+    CollectionTreeRowClicked {
+        tab_id: TabId,
+        node_id: usize,
+    },
     CollectionTextCopyJson {
         tab_id: TabId,
     },
+    CollectionTextCopyMarkdown {
+        tab_id: TabId,
+    },
+    CollectionExtendedJsonCopy {
+        tab_id: TabId,
+    },
+    CollectionCopyAsNode(TabId),
+    CollectionResponseViewModeSelected {
+        tab_id: TabId,
+        mode: ResponseViewMode,
+    },
     CollectionTableScrolled {
         tab_id: TabId,
         offset_y: f32,
@@ -251,12 +432,91 @@ pub(crate) enum Message {
         node_id: usize,
     },
     ValueEditModalEditorAction(TextEditorAction),
+    ValueEditModalBinarySubtypeChanged(BinarySubtypeChoice),
+    ValueEditModalBinaryFormatChanged(BinaryInputFormat),
+    ValueEditModalTypeChanged(ValueTypeChoice),
     ValueEditModalSave,
     ValueEditModalCancel,
+    DocumentDiffModalToggleUnchanged(bool),
+    DocumentDiffModalClose,
+    IndexBuilderFieldChanged(usize, String),
+    IndexBuilderKindChanged(usize, IndexKeyKind),
+    IndexBuilderAddKey,
+    IndexBuilderRemoveKey(usize),
+    IndexBuilderNameChanged(String),
+    IndexBuilderUniqueToggled(bool),
+    IndexBuilderSparseToggled(bool),
+    IndexBuilderTtlChanged(String),
+    IndexBuilderPartialFilterChanged(String),
+    IndexBuilderSubmit {
+        run: bool,
+    },
+    IndexBuilderCancel,
+    AggregateWriteConfirm,
+    AggregateWriteCancel,
+    DateFormatPreviewFieldChanged(String),
+    DateFormatPreviewSampleChanged(String),
+    DateFormatPreviewFormatChanged(String),
+    DateFormatPreviewTimezoneChanged(String),
+    DateFormatPreviewCopyExpression,
+    DateFormatPreviewClose,
+    TextSearchIndexesLoaded {
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        result: Result<Vec<Document>, String>,
+    },
+    TextSearchTermsChanged(String),
+    TextSearchRun,
+    TextSearchCancel,
+    CollectionProjectionSelected {
+        tab_id: TabId,
+        choice: ProjectionChoice,
+    },
+    CollectionProjectionPresetDelete(TabId),
+    CollectionPipelineStageSelected {
+        tab_id: TabId,
+        stages: Option<usize>,
+    },
+    ProjectionPresetModalOpen(TabId),
+    ProjectionPresetNameChanged(String),
+    ProjectionPresetFieldsChanged(String),
+    ProjectionPresetSave,
+    ProjectionPresetCancel,
+    SavedQueriesOpen,
+    SavedQueriesSelect(usize),
+    SavedQueriesLoad,
+    SavedQueriesEdit,
+    SavedQueriesDelete,
+    SavedQueriesDeleteConfirmed,
+    SavedQueriesDeleteCancelled,
+    SavedQueriesClose,
+    SavedQueryFormOpen(TabId),
+    SavedQueryFormNameChanged(String),
+    SavedQueryFormDbChanged(String),
+    SavedQueryFormCollectionChanged(String),
+    SavedQueryFormEditorAction(TextEditorAction),
+    SavedQueryFormSave,
+    SavedQueryFormCancel,
+    IndexBuilderIndexesLoaded {
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        result: Result<Vec<Document>, String>,
+    },
+    QueryLogFilterChanged(String),
+    QueryLogEntrySelected(usize),
+    QueryLogClear,
     ValueEditModalCompleted {
         tab_id: TabId,
         result: Result<Document, String>,
     },
+    ArrayElementDeleteConfirmed,
+    ArrayElementDeleteCancelled,
+    ArrayElementRemoved {
+        tab_id: TabId,
+        result: Result<(), String>,
+    },
     AboutModalClose,
     LicensesModalClose,
     HelpDocsClose,
@@ -269,6 +529,11 @@ pub(crate) enum Message {
         collection: String,
         action: CollectionContextAction,
     },
+    CollectionCountCompleted {
+        namespace: String,
+        estimated: bool,
+        result: Result<QueryResult, String>,
+    },
     DatabaseContextMenu {
         client_id: ClientId,
         db_name: String,
@@ -298,20 +563,46 @@ pub(crate) enum Message {
         result: Result<QueryResult, String>,
         duration: Duration,
     },
+    CollectionQueryCancel(TabId),
+    CollectionQueryDiscarded(TabId),
+    QueryProgressTick,
+    CollectionTotalCountCompleted {
+        tab_id: TabId,
+        result: Result<u64, String>,
+    },
     ConnectionsSelect(usize),
+    ConnectionsGroupToggled(Option<String>),
     ConnectionsQuickConnect(usize),
     ConnectionsCreate,
     ConnectionsEdit,
     ConnectionsDelete,
     ConnectionsDeleteConfirmed,
     ConnectionsDeleteCancelled,
+    ConnectionsTestAll,
+    ConnectionsTestAllResult {
+        started_at: Instant,
+        index: usize,
+        result: Result<(), String>,
+        elapsed: Duration,
+    },
     ConnectionsConnect,
     ConnectionsCancel,
     ConnectionFormTabChanged(ConnectionFormTab),
     ConnectionFormNameChanged(String),
+    ConnectionFormGroupChanged(String),
     ConnectionFormHostChanged(String),
     ConnectionFormPortChanged(String),
+    ConnectionFormSrvChanged(bool),
     ConnectionFormTypeChanged(ConnectionType),
+    ConnectionFormReplicaSetChanged(String),
+    ConnectionFormReadPreferenceChanged(ReadPreferenceChoice),
+    ConnectionFormReadConcernChanged(ReadConcernChoice),
+    ConnectionFormWriteConcernChanged(String),
+    ConnectionFormReadOnlyChanged(bool),
+    ConnectionFormColorPickerOpened,
+    ConnectionFormColorPickerCanceled,
+    ConnectionFormColorChanged(Color),
+    ConnectionFormColorCleared,
     ConnectionFormAuthUseChanged(bool),
     ConnectionFormAuthLoginChanged(String),
     ConnectionFormAuthPasswordChanged(String),
@@ -327,6 +618,18 @@ pub(crate) enum Message {
     ConnectionFormSshPrivateKeyChanged(String),
     ConnectionFormSshPrivateKeyBrowse,
     ConnectionFormSshPrivateKeyPicked(Option<String>),
+    ConnectionFormTlsFileChanged {
+        field: TlsFileField,
+        value: String,
+    },
+    ConnectionFormTlsFileBrowse(TlsFileField),
+    ConnectionFormTlsFilePicked {
+        field: TlsFileField,
+        path: Option<String>,
+    },
+    ConnectionFormTlsInsecureChanged(bool),
+    DiagnosticReportSaved(Result<Option<String>, String>),
+    ResultsExported(Result<Option<String>, String>),
     ConnectionFormSshPassphraseChanged(String),
     ConnectionFormIncludeAction(TextEditorAction),
     ConnectionFormExcludeAction(TextEditorAction),
@@ -337,11 +640,24 @@ pub(crate) enum Message {
     ConnectionFormCancel,
     SettingsOpen,
     SettingsTabChanged(SettingsTab),
+    SettingsTogglePrefillFindTemplate(bool),
     SettingsToggleExpandFirstResult(bool),
     SettingsQueryTimeoutChanged(String),
+    SettingsMaxRenderDocumentsChanged(String),
+    SettingsExpandAllDepthChanged(String),
+    SettingsQueryTimeoutUnitChanged(TimeoutUnit),
     SettingsToggleSortFields(bool),
     SettingsToggleSortIndexes(bool),
     SettingsToggleCloseTabsOnDbClose(bool),
+    SettingsToggleRelativeDates(bool),
+    SettingsToggleReuseUnpinnedTab(bool),
+    SettingsToggleGroupConnectionsByHost(bool),
+    SettingsToggleCompactSidebar(bool),
+    SettingsToggleHighlightQueries(bool),
+    SettingsToggleReconnectOnQuery(bool),
+    SettingsTabTitleFormatChanged(TabTitleFormat),
+    SettingsTreeSortModeChanged(TreeSortMode),
+    SettingsToggleConfirmCloseEditedTabs(bool),
     SettingsToggleStrictDeleteConfirmation(bool),
     SettingsToggleLogging(bool),
     SettingsLogLevelChanged(LogLevel),
@@ -361,6 +677,8 @@ pub(crate) enum Message {
     SettingsColorPickerCanceled,
     SettingsColorChanged(ThemeColorField, Color),
     SettingsThemeColorsReset,
+    SettingsKeyBindingChanged(ShortcutAction, String),
+    SettingsKeyBindingsReset,
     SettingsApply,
     SettingsSave,
     SettingsCancel,
@@ -372,6 +690,7 @@ pub(crate) enum Message {
         action: TableContextAction,
     },
     CollectionModalInputChanged(String),
+    CollectionModalToggleRecreate(bool),
     CollectionModalConfirm,
     CollectionModalCancel,
     CollectionDeleteAllCompleted {
@@ -438,10 +757,23 @@ pub(crate) enum Message {
         result: Result<(), String>,
     },
     DocumentModalEditorAction(TextEditorAction),
+    DocumentModalToggleIdChange(bool),
     DocumentModalSave,
     DocumentModalCancel,
     DocumentModalCompleted {
-        tab_id: TabId,
+        tab_id: Option<TabId>,
+        result: Result<Document, String>,
+    },
+    ValidationRulesLoaded {
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        result: Result<Document, String>,
+    },
+    InsertTemplateLoaded {
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
         result: Result<Document, String>,
     },
     DatabasesRefreshed {
@@ -453,10 +785,18 @@ pub(crate) enum Message {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TableContextAction {
     CopyJson,
+    CopyMarkdownTable,
+    ExportJson,
+    ExportCsv,
+    CopyId,
     CopyKey,
     CopyValue,
     CopyPath,
+    CopySelectedRows,
+    FindById,
     EditValue,
+    AppendArrayElement,
+    DeleteArrayElement,
     GoToRelatedDocument,
     DeleteIndex,
     HideIndex,
@@ -465,6 +805,8 @@ enum TableContextAction {
     CollapseHierarchy,
     ExpandHierarchyAll,
     CollapseHierarchyAll,
+    SelectForCompare,
+    CompareWithSelected,
 }
 
 #[derive(Debug)]
@@ -492,6 +834,7 @@ struct DatabaseNode {
     expanded: bool,
     state: DatabaseState,
     collections: Vec<CollectionNode>,
+    size: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -505,6 +848,9 @@ enum DatabaseState {
 #[derive(Debug, Clone)]
 struct CollectionNode {
     name: String,
+    size: Option<u64>,
+    /// Estimated document count; stays `None` when the collection cannot be counted.
+    count: Option<u64>,
 }
 
 impl CollectionModalState {
@@ -518,6 +864,7 @@ impl CollectionModalState {
             error: None,
             processing: false,
             origin_tab: None,
+            recreate_on_truncate: false,
         }
     }
 
@@ -531,6 +878,7 @@ impl CollectionModalState {
             error: None,
             processing: false,
             origin_tab: None,
+            recreate_on_truncate: false,
         }
     }
 
@@ -544,6 +892,7 @@ impl CollectionModalState {
             error: None,
             processing: false,
             origin_tab: None,
+            recreate_on_truncate: false,
         }
     }
 
@@ -557,6 +906,7 @@ impl CollectionModalState {
             error: None,
             processing: false,
             origin_tab: None,
+            recreate_on_truncate: false,
         }
     }
 
@@ -566,16 +916,18 @@ impl CollectionModalState {
         db_name: String,
         collection: String,
         index_name: String,
+        index_keys: Option<Document>,
     ) -> Self {
         Self {
             client_id,
             db_name,
             collection,
-            kind: CollectionModalKind::DropIndex { index_name },
+            kind: CollectionModalKind::DropIndex { index_name, index_keys },
             input: String::new(),
             error: None,
             processing: false,
             origin_tab: Some(tab_id),
+            recreate_on_truncate: false,
         }
     }
 }
@@ -617,7 +969,7 @@ impl DocumentModalState {
         let text = shell::format_bson_shell(&Bson::Document(document.clone()));
 
         Some(Self {
-            tab_id,
+            tab_id: Some(tab_id),
             client_id,
             db_name,
             collection,
@@ -625,6 +977,8 @@ impl DocumentModalState {
             editor: TextEditorContent::with_text(&text),
             error: None,
             processing: false,
+            allow_id_change: false,
+            id_change_confirmed: false,
         })
     }
 
@@ -642,7 +996,7 @@ impl DocumentModalState {
         let text = shell::format_bson_shell(&Bson::Document(document.clone()));
 
         Some(Self {
-            tab_id,
+            tab_id: Some(tab_id),
             client_id,
             db_name,
             collection,
@@ -650,8 +1004,46 @@ impl DocumentModalState {
             editor: TextEditorContent::with_text(&text),
             error: None,
             processing: false,
+            allow_id_change: false,
+            id_change_confirmed: false,
         })
     }
+
+    fn new_insert(
+        tab_id: Option<TabId>,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+    ) -> Self {
+        Self {
+            tab_id,
+            client_id,
+            db_name,
+            collection,
+            kind: DocumentModalKind::NewDocument,
+            editor: TextEditorContent::with_text("{ }"),
+            error: None,
+            processing: false,
+            allow_id_change: false,
+            id_change_confirmed: false,
+        }
+    }
+
+    /// Starts in the processing state until the current rules arrive from `listCollections`.
+    fn new_validation(client_id: ClientId, db_name: String, collection: String) -> Self {
+        Self {
+            tab_id: None,
+            client_id,
+            db_name,
+            collection,
+            kind: DocumentModalKind::Validation,
+            editor: TextEditorContent::new(),
+            error: None,
+            processing: true,
+            allow_id_change: false,
+            id_change_confirmed: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -668,15 +1060,25 @@ pub(crate) enum AppMode {
     DatabaseModal,
     DocumentModal,
     ValueEditModal,
+    DocumentDiffModal,
+    IndexBuilderModal,
+    AggregateWriteModal,
+    DateFormatPreview,
+    TextSearchModal,
+    ProjectionPresetModal,
+    SavedQueries,
+    SavedQueryForm,
+    TabCloseConfirmation,
+    ArrayElementDeleteConfirmation,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 enum CollectionModalKind {
     CreateCollection,
     DeleteAllDocuments,
     DeleteCollection,
     RenameCollection,
-    DropIndex { index_name: String },
+    DropIndex { index_name: String, index_keys: Option<Document> },
 }
 
 #[derive(Debug, Clone)]
@@ -689,6 +1091,8 @@ struct CollectionModalState {
     error: Option<String>,
     processing: bool,
     origin_tab: Option<TabId>,
+    /// Delete-all by dropping and recreating the collection instead of `deleteMany({})`.
+    recreate_on_truncate: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -709,7 +1113,8 @@ enum DatabaseModalMode {
 
 #[derive(Debug)]
 struct DocumentModalState {
-    tab_id: TabId,
+    /// Tab refreshed after a successful save; `None` when opened from the sidebar.
+    tab_id: Option<TabId>,
     client_id: ClientId,
     db_name: String,
     collection: String,
@@ -717,12 +1122,228 @@ struct DocumentModalState {
     editor: TextEditorContent,
     error: Option<String>,
     processing: bool,
+    /// Lets a changed `_id` be saved by inserting the new document and deleting the old one.
+    allow_id_change: bool,
+    id_change_confirmed: bool,
 }
 
 #[derive(Debug, Clone)]
 enum DocumentModalKind {
     CollectionDocument { filter: Document, original_id: Bson },
     Index { name: String },
+    Validation,
+    NewDocument,
+}
+
+/// Array element waiting for the user to confirm its removal.
+#[derive(Debug)]
+struct ArrayElementDeleteState {
+    tab_id: TabId,
+    context: ValueEditContext,
+}
+
+#[derive(Debug)]
+struct AggregateWriteModalState {
+    tab_id: TabId,
+    source_db: String,
+    source_collection: String,
+    target: AggregateWriteTarget,
+}
+
+#[derive(Debug)]
+struct DateFormatPreviewState {
+    field: String,
+    sample: String,
+    format: String,
+    timezone: String,
+}
+
+impl Default for DateFormatPreviewState {
+    fn default() -> Self {
+        Self {
+            field: String::from("createdAt"),
+            sample: String::from("2024-03-15T13:45:30.123Z"),
+            format: String::from(DEFAULT_DATE_TO_STRING_FORMAT),
+            timezone: String::from("UTC"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ProjectionPresetModalState {
+    tab_id: TabId,
+    name: String,
+    fields: String,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProjectionChoice {
+    AllFields,
+    Preset(String),
+}
+
+impl std::fmt::Display for ProjectionChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectionChoice::AllFields => f.write_str(tr("All fields")),
+            ProjectionChoice::Preset(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Two tabs shown next to each other, for example the same collection on two connections.
+#[derive(Debug)]
+struct SideBySideState {
+    panes: pane_grid::State<TabId>,
+    sync_scroll: bool,
+}
+
+impl SideBySideState {
+    fn new(left: TabId, right: TabId) -> Self {
+        let (mut panes, first) = pane_grid::State::new(left);
+        panes.split(pane_grid::Axis::Vertical, first, right);
+        Self { panes, sync_scroll: true }
+    }
+
+    fn contains(&self, tab_id: TabId) -> bool {
+        self.panes.iter().any(|(_, id)| *id == tab_id)
+    }
+
+    fn partner(&self, tab_id: TabId) -> Option<TabId> {
+        if !self.contains(tab_id) {
+            return None;
+        }
+        self.panes.iter().map(|(_, id)| *id).find(|id| *id != tab_id)
+    }
+}
+
+/// Where the last `find` page ended, so the next page can continue after its `_id` instead of
+/// skipping over the previous ones.
+#[derive(Debug, Clone)]
+struct CursorState {
+    query: String,
+    next_skip: u64,
+    last_id: Option<Bson>,
+}
+
+/// Entry of the aggregation step selector; `None` runs the whole pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PipelineStageChoice {
+    stages: Option<usize>,
+    total: usize,
+}
+
+impl std::fmt::Display for PipelineStageChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.total.to_string();
+        let label = match self.stages {
+            Some(stages) => tr_format("Up to stage {} of {}", &[&stages.to_string(), &total]),
+            None => tr_format("All {} stages", &[&total]),
+        };
+        f.write_str(&label)
+    }
+}
+
+#[derive(Debug)]
+struct TextSearchModalState {
+    client_id: ClientId,
+    db_name: String,
+    collection: String,
+    terms: String,
+    /// `None` while the indexes are loading, then the text index name or why search is unavailable.
+    text_index: Option<Result<String, String>>,
+}
+
+#[derive(Debug)]
+struct IndexBuilderModalState {
+    client_id: ClientId,
+    db_name: String,
+    collection: String,
+    spec: IndexBuilderSpec,
+    existing_indexes: Option<Result<Vec<Document>, String>>,
+    error: Option<String>,
+}
+
+impl IndexBuilderModalState {
+    fn new(client_id: ClientId, db_name: String, collection: String) -> Self {
+        Self {
+            client_id,
+            db_name,
+            collection,
+            spec: IndexBuilderSpec::default(),
+            existing_indexes: None,
+            error: None,
+        }
+    }
+
+    fn duplicate_index_name(&self) -> Option<&str> {
+        match &self.existing_indexes {
+            Some(Ok(indexes)) => self.spec.find_matching_index(indexes),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DocumentDiffModalState {
+    left_title: String,
+    right_title: String,
+    entries: Vec<DiffEntry>,
+    summary: DiffSummary,
+    show_unchanged: bool,
+}
+
+impl DocumentDiffModalState {
+    fn new(left: &Document, right: &Document) -> Self {
+        let entries = diff_documents(left, right);
+        let summary = DiffSummary::from_entries(&entries);
+        Self {
+            left_title: Self::document_title(left),
+            right_title: Self::document_title(right),
+            entries,
+            summary,
+            show_unchanged: false,
+        }
+    }
+
+    fn document_title(document: &Document) -> String {
+        document
+            .get("_id")
+            .map(|id| format!("_id: {}", shell::format_shell_value(id)))
+            .unwrap_or_else(|| String::from(tr("Document without _id")))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct QueryLogEntry {
+    timestamp: String,
+    client_id: ClientId,
+    db_name: String,
+    collection: String,
+    query: String,
+    duration: Duration,
+    outcome: Result<usize, String>,
+}
+
+impl QueryLogEntry {
+    fn namespace(&self) -> String {
+        format!("{}.{}", self.db_name, self.collection)
+    }
+
+    fn matches_filter(&self, filter: &str) -> bool {
+        let needle = filter.trim().to_lowercase();
+        if needle.is_empty() {
+            return true;
+        }
+        self.query.to_lowercase().contains(&needle)
+            || self.namespace().to_lowercase().contains(&needle)
+            || self
+                .outcome
+                .as_ref()
+                .err()
+                .is_some_and(|error| error.to_lowercase().contains(&needle))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -730,6 +1351,8 @@ struct ValueEditContext {
     path: String,
     filter: Document,
     current_value: Bson,
+    /// The value is an element of an array, so it can be removed by index.
+    in_array: bool,
 }
 
 #[derive(Debug)]
@@ -744,15 +1367,51 @@ struct ValueEditModalState {
     value_editor: TextEditorContent,
     value_kind: ValueEditKind,
     value_label: String,
+    /// Type picked in the dropdown; inference from the text is skipped while it is set.
+    forced_kind: Option<ValueEditKind>,
+    binary: Option<ValueEditBinaryState>,
+    decimal_mode: bool,
+    original_type: &'static str,
+    type_change_warning: Option<String>,
     error: Option<String>,
     processing: bool,
+    /// Pushes the value onto the array at `path` instead of replacing it.
+    append: bool,
+}
+
+/// Subtype and payload encoding used while a binary value is edited as raw base64 or hex.
+#[derive(Debug, Clone, Copy)]
+struct ValueEditBinaryState {
+    subtype: BinarySubtypeChoice,
+    format: BinaryInputFormat,
+}
+
+impl ValueEditBinaryState {
+    fn label(&self) -> String {
+        format!("{} {}", tr("Binary"), self.subtype)
+    }
 }
 
 impl ValueEditModalState {
     fn new(tab_id: TabId, collection: &CollectionTab, context: ValueEditContext) -> Self {
-        let value_input = Self::initial_value_input(&context.current_value);
+        let binary = match &context.current_value {
+            Bson::Binary(binary) => Some(ValueEditBinaryState {
+                subtype: BinarySubtypeChoice::from(binary.subtype),
+                format: BinaryInputFormat::Base64,
+            }),
+            _ => None,
+        };
+        let decimal_mode = matches!(context.current_value, Bson::Decimal128(_));
+        let value_input = match (&context.current_value, &binary) {
+            (Bson::Binary(value), Some(binary)) => binary.format.encode(&value.bytes),
+            (Bson::Decimal128(value), _) => value.to_string(),
+            (value, _) => Self::initial_value_input(value),
+        };
         let value_kind = ValueEditKind::from_bson(&context.current_value);
-        let value_label = shell::bson_type_name(&context.current_value).to_string();
+        let value_label = match &binary {
+            Some(binary) => binary.label(),
+            None => shell::bson_type_name(&context.current_value).to_string(),
+        };
         Self {
             tab_id,
             client_id: collection.client_id,
@@ -764,11 +1423,63 @@ impl ValueEditModalState {
             value_input,
             value_kind,
             value_label,
+            forced_kind: None,
+            binary,
+            decimal_mode,
+            original_type: shell::bson_type_name(&context.current_value),
+            type_change_warning: None,
             error: None,
             processing: false,
+            append: false,
+        }
+    }
+
+    /// Starts from a copy of the last element so the new one gets the same type by default.
+    fn new_append(tab_id: TabId, collection: &CollectionTab, context: ValueEditContext) -> Self {
+        let template = match &context.current_value {
+            Bson::Array(items) => items.last().cloned().unwrap_or(Bson::String(String::new())),
+            _ => Bson::String(String::new()),
+        };
+        let mut state =
+            Self::new(tab_id, collection, ValueEditContext { current_value: template, ..context });
+        state.append = true;
+        state
+    }
+
+    fn set_binary_subtype(&mut self, subtype: BinarySubtypeChoice) {
+        if let Some(binary) = self.binary.as_mut() {
+            binary.subtype = subtype;
+            self.value_label = binary.label();
+            self.error = None;
+        }
+    }
+
+    fn set_binary_format(&mut self, format: BinaryInputFormat) {
+        let Some(binary) = self.binary.as_mut() else {
+            return;
+        };
+        if binary.format == format {
+            return;
+        }
+
+        match binary.format.decode(&self.value_input) {
+            Ok(bytes) => {
+                binary.format = format;
+                self.value_input = format.encode(&bytes);
+                self.value_editor = TextEditorContent::with_text(&self.value_input);
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error),
         }
     }
 
+    fn set_forced_kind(&mut self, kind: Option<ValueEditKind>) {
+        self.forced_kind = kind;
+        self.type_change_warning = None;
+        self.error = None;
+        self.recalculate_kind_and_label();
+    }
+
     fn initial_value_input(value: &Bson) -> String {
         match value {
             Bson::String(text) => text.clone(),
@@ -777,23 +1488,72 @@ impl ValueEditModalState {
     }
 
     fn apply_editor_action(&mut self, action: TextEditorAction) {
+        let is_edit = action.is_edit();
         self.value_editor.perform(action);
         self.value_input = self.value_editor.text().to_string();
         self.recalculate_kind_and_label();
+        if is_edit {
+            self.type_change_warning = None;
+        }
         self.error = None;
     }
 
+    /// Returns a warning the first time the new value has a different BSON type than the
+    /// original; saving again after the warning is shown counts as acknowledgment.
+    fn unacknowledged_type_change(&mut self, new_value: &Bson) -> Option<String> {
+        let new_type = shell::bson_type_name(new_value);
+        if new_type == self.original_type || self.type_change_warning.is_some() {
+            return None;
+        }
+
+        let warning = tr_format(
+            "Saving will change the field type from {} to {}.",
+            &[self.original_type, new_type],
+        );
+        self.type_change_warning = Some(warning.clone());
+        Some(warning)
+    }
+
     fn recalculate_kind_and_label(&mut self) {
-        if let Ok(bson) = shell::parse_shell_bson_value(&self.value_input) {
-            self.value_kind = ValueEditKind::from_bson(&bson);
-            self.value_label = shell::bson_type_name(&bson).to_string();
-        } else if let Some(kind) = ValueEditKind::infer(&self.value_input) {
+        if self.binary.is_some() {
+            return;
+        }
+        if let Some(kind) = self.forced_kind {
+            self.value_kind = kind;
+            self.value_label = kind.label().to_string();
+            return;
+        }
+        if self.decimal_mode && ValueEditKind::parse_decimal128(&self.value_input).is_ok() {
+            self.value_kind = ValueEditKind::Decimal128;
+            self.value_label = ValueEditKind::Decimal128.label().to_string();
+            return;
+        }
+        if let Ok(bson) = shell::parse_shell_bson_value(&self.value_input) {
+            self.value_kind = ValueEditKind::from_bson(&bson);
+            self.value_label = shell::bson_type_name(&bson).to_string();
+        } else if let Some(kind) = ValueEditKind::infer(&self.value_input) {
             self.value_kind = kind;
             self.value_label = kind.label().to_string();
         }
     }
 
     fn prepare_value(&mut self) -> Result<Bson, String> {
+        if let Some(binary) = self.binary {
+            return ValueEditKind::parse_binary(&self.value_input, binary.subtype, binary.format);
+        }
+
+        if let Some(kind) = self.forced_kind {
+            return kind.parse_as(&self.value_input);
+        }
+
+        if self.decimal_mode
+            && let Ok(bson) = ValueEditKind::parse_decimal128(&self.value_input)
+        {
+            self.value_kind = ValueEditKind::Decimal128;
+            self.value_label = ValueEditKind::Decimal128.label().to_string();
+            return Ok(bson);
+        }
+
         if let Ok(bson) = shell::parse_shell_bson_value(&self.value_input) {
             self.value_kind = ValueEditKind::from_bson(&bson);
             self.value_label = shell::bson_type_name(&bson).to_string();
@@ -831,6 +1591,7 @@ struct WatchStreamState {
     started: Instant,
     finished: bool,
     error: Option<String>,
+    cancel: Arc<AtomicBool>,
 }
 
 #[derive(Debug)]
@@ -849,13 +1610,47 @@ struct CollectionTab {
     bson_tree: BsonTree,
     response_view_mode: ResponseViewMode,
     text_result: Option<TextResultView>,
+    extended_json: Option<String>,
     table_scroll_offset: f32,
     text_scroll_offset: f32,
     skip_input: String,
     limit_input: String,
+    kind: TabKind,
     query_in_progress: bool,
+    /// Set by the Cancel button. The sync driver cannot be interrupted, so the running task only
+    /// checks it once the call returns and drops the result.
+    query_cancel: Option<Arc<AtomicBool>>,
+    query_started: Option<Instant>,
+    /// Substring the result tree is narrowed to; kept when a new result arrives.
+    tree_filter: String,
+    tree_filter_case_sensitive: bool,
     last_query_duration: Option<Duration>,
     last_result: Option<QueryResult>,
+    total_count: Option<u64>,
+    total_count_filter: Option<Document>,
+    running_query: Option<String>,
+    query_preview: Option<String>,
+    modified: bool,
+    last_executed_text: Option<String>,
+    empty_result_message: Option<String>,
+    last_error: Option<String>,
+    /// Set when the result was cut to the "Render at most" setting.
+    render_cap_note: Option<String>,
+    pending_write_target: Option<AggregateWriteTarget>,
+    /// Inherited from the connection; write operations are refused before they are sent.
+    read_only: bool,
+    accent_color: Option<Color>,
+    send_shortcut: KeyCombo,
+    highlight_queries: bool,
+    expand_all_depth: Option<usize>,
+    projection_presets: Vec<ProjectionPreset>,
+    /// Preset projected by `find` queries that do not specify a projection themselves.
+    active_projection: Option<String>,
+    /// Stage count of the last executed aggregation, zero for other operations.
+    pipeline_stage_count: usize,
+    /// Runs only this many leading stages of the pipeline when set.
+    pipeline_stage_limit: Option<usize>,
+    cursor_state: Option<CursorState>,
     palette: ThemePalette,
 }
 
@@ -904,6 +1699,147 @@ impl TextResultView {
     }
 }
 
+fn read_only_connection_error(connection_name: &str) -> String {
+    tr_format("Connection \"{}\" is read-only. Write operations are blocked.", &[connection_name])
+}
+
+fn read_only_client_error(clients: &[OMDBClient], client_id: ClientId) -> Option<String> {
+    clients
+        .iter()
+        .find(|client| client.id == client_id && client.entry.read_only)
+        .map(|client| read_only_connection_error(&client.name))
+}
+
+/// Empties a collection by dropping it and creating it again with the same options and
+/// indexes. Returns the estimated number of documents that were removed.
+fn truncate_by_recreating(
+    client: &Client,
+    db_name: &str,
+    collection_name: &str,
+) -> Result<u64, String> {
+    let to_string = |error: mongodb::error::Error| error.to_string();
+    let database = client.database(db_name);
+    let first_batch = |reply: &Document| -> Vec<Document> {
+        reply
+            .get_document("cursor")
+            .and_then(|cursor| cursor.get_array("firstBatch"))
+            .map(|batch| batch.iter().filter_map(|item| item.as_document().cloned()).collect())
+            .unwrap_or_default()
+    };
+
+    let reply = database
+        .run_command(doc! { "listCollections": 1, "filter": { "name": collection_name } })
+        .run()
+        .map_err(to_string)?;
+    let Some(info) = first_batch(&reply).into_iter().next() else {
+        return Err(tr_format("Collection \"{}\" not found.", &[collection_name]));
+    };
+    let options = info.get_document("options").cloned().unwrap_or_default();
+    if info.get_str("type").unwrap_or("collection") != "collection"
+        || collection_name.starts_with("system.")
+        || options.contains_key("encryptedFields")
+    {
+        return Err(String::from(tr(
+            "Only regular collections can be truncated by recreating them. Use the regular deletion instead.",
+        )));
+    }
+
+    // Dropping a sharded collection also removes its sharding, which recreating cannot restore.
+    let hello = database.run_command(doc! { "hello": 1 }).run().map_err(to_string)?;
+    if hello.get_str("msg").ok() == Some("isdbgrid") {
+        let sharded = client
+            .database("config")
+            .collection::<Document>("collections")
+            .find_one(doc! { "_id": format!("{db_name}.{collection_name}") })
+            .run()
+            .map_err(to_string)?
+            .is_some_and(|entry| entry.get_bool("dropped") != Ok(true));
+        if sharded {
+            return Err(String::from(tr(
+                "Sharded collections cannot be truncated by recreating them. Use the regular deletion instead.",
+            )));
+        }
+    }
+
+    let reply =
+        database.run_command(doc! { "listIndexes": collection_name }).run().map_err(to_string)?;
+    let indexes: Vec<Bson> = first_batch(&reply)
+        .into_iter()
+        .filter(|index| index.get_str("name").ok() != Some("_id_"))
+        .map(|mut index| {
+            index.remove("v");
+            index.remove("ns");
+            Bson::Document(index)
+        })
+        .collect();
+
+    let collection = database.collection::<Document>(collection_name);
+    let removed = collection.estimated_document_count().run().map_err(to_string)?;
+    collection.drop().run().map_err(to_string)?;
+
+    let index_names = indexes
+        .iter()
+        .filter_map(|index| index.as_document()?.get_str("name").ok())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let none = || String::from(tr("(none)"));
+    let lost_indexes = if index_names.is_empty() { none() } else { index_names };
+
+    let lost_options = if options.is_empty() {
+        none()
+    } else {
+        Bson::Document(options.clone()).into_relaxed_extjson().to_string()
+    };
+    let mut create = doc! { "create": collection_name };
+    create.extend(options);
+    if let Err(error) = database.run_command(create).run() {
+        return Err(tr_format(
+            "The collection was dropped but could not be recreated: {}. Lost options: {}. Lost indexes: {}.",
+            &[&error.to_string(), &lost_options, &lost_indexes],
+        ));
+    }
+    if !indexes.is_empty()
+        && let Err(error) = database
+            .run_command(doc! { "createIndexes": collection_name, "indexes": indexes })
+            .run()
+    {
+        return Err(tr_format(
+            "The collection was recreated but its indexes could not be restored: {}. Lost indexes: {}.",
+            &[&error.to_string(), &lost_indexes],
+        ));
+    }
+
+    Ok(removed)
+}
+
+/// Saves a document whose `_id` was edited: inserts the new document first, then deletes the
+/// original. If the original cannot be deleted, the inserted copy is removed again.
+fn replace_document_with_new_id(
+    collection: &Collection<Document>,
+    filter: Document,
+    replacement: Document,
+) -> Result<Document, String> {
+    collection.insert_one(&replacement).run().map_err(|error| error.to_string())?;
+
+    let rollback = |message: String| {
+        let new_id = replacement.get("_id").cloned().unwrap_or(Bson::Null);
+        match collection.delete_one(doc! { "_id": new_id }).run() {
+            Ok(_) => message,
+            Err(error) => {
+                format!("{message} {}", tr_format("Rollback failed: {}", &[&error.to_string()]))
+            }
+        }
+    };
+
+    match collection.delete_one(filter).run() {
+        Ok(result) if result.deleted_count == 1 => Ok(replacement),
+        Ok(_) => Err(rollback(String::from(tr(
+            "Document not found. It may have been deleted or the change was not applied.",
+        )))),
+        Err(error) => Err(rollback(error.to_string())),
+    }
+}
+
 fn position_cursor_in_find(editor: &mut TextEditorContent, text: &str) {
     let Some(index) = text.find("{}") else {
         return;
@@ -916,9 +1852,30 @@ fn position_cursor_in_find(editor: &mut TextEditorContent, text: &str) {
 }
 
 impl CollectionTab {
+    fn has_document_result(&self) -> bool {
+        match &self.last_result {
+            Some(QueryResult::Documents(values) | QueryResult::Indexes(values)) => {
+                !values.is_empty()
+            }
+            Some(QueryResult::SingleDocument { .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// Prepares the text rendering of `result` needed by the current view mode and drops the other.
     fn build_text_result(&mut self, result: &QueryResult) -> Duration {
         let start = Instant::now();
-        self.text_result = TextResultView::from_query_result(result);
+        self.text_result = None;
+        self.extended_json = None;
+        match self.response_view_mode {
+            ResponseViewMode::Table => {}
+            ResponseViewMode::Text => {
+                self.text_result = TextResultView::from_query_result(result);
+            }
+            ResponseViewMode::ExtendedJson => {
+                self.extended_json = Some(shell::format_extended_json(result));
+            }
+        }
         start.elapsed()
     }
 }
@@ -933,6 +1890,7 @@ enum CollectionPane {
 pub(crate) enum ResponseViewMode {
     Table,
     Text,
+    ExtendedJson,
 }
 
 impl ResponseViewMode {
@@ -940,10 +1898,27 @@ impl ResponseViewMode {
         match self {
             ResponseViewMode::Table => tr("Table"),
             ResponseViewMode::Text => tr("Text"),
+            ResponseViewMode::ExtendedJson => tr("Extended JSON"),
         }
     }
 }
 
+/// Describes how a tab was opened, so rendering and behavior can be specialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabKind {
+    Find,
+    Indexes,
+    CollectionStats,
+    DatabaseStats,
+    ServerStatus,
+}
+
+impl TabKind {
+    fn supports_pagination(self) -> bool {
+        matches!(self, TabKind::Find)
+    }
+}
+
 impl CollectionTab {
     const REQUEST_EDITOR_LINES: f32 = 2.0;
     const REQUEST_VERTICAL_CHROME: f32 = 16.0;
@@ -992,10 +1967,14 @@ impl CollectionTab {
         let palette = settings.active_palette().clone();
         let options = BsonTreeOptions::from(settings);
         let bson_tree = BsonTree::from_values(&values, options);
-        let editor_text = format!(
-            "db.getCollection('{collection_name}').find({{}})",
-            collection_name = collection.as_str()
-        );
+        let editor_text = if settings.prefill_find_template {
+            format!(
+                "db.getCollection('{collection_name}').find({{}})",
+                collection_name = collection.as_str()
+            )
+        } else {
+            String::new()
+        };
         let text_result = None;
         let mut editor = TextEditorContent::with_text(&editor_text);
         position_cursor_in_find(&mut editor, &editor_text);
@@ -1015,13 +1994,39 @@ impl CollectionTab {
             bson_tree,
             response_view_mode: ResponseViewMode::Table,
             text_result,
+            extended_json: None,
             table_scroll_offset: 0.0,
             text_scroll_offset: 0.0,
             skip_input: DEFAULT_RESULT_SKIP.to_string(),
             limit_input: DEFAULT_RESULT_LIMIT.to_string(),
+            kind: TabKind::Find,
             query_in_progress: false,
+            query_cancel: None,
+            query_started: None,
+            tree_filter: String::new(),
+            tree_filter_case_sensitive: false,
             last_query_duration: None,
             last_result: Some(QueryResult::Documents(values)),
+            total_count: None,
+            total_count_filter: None,
+            running_query: None,
+            query_preview: None,
+            modified: false,
+            last_executed_text: None,
+            empty_result_message: None,
+            last_error: None,
+            render_cap_note: None,
+            pending_write_target: None,
+            read_only: false,
+            accent_color: None,
+            send_shortcut: settings.key_binding(ShortcutAction::SendQuery),
+            highlight_queries: settings.highlight_queries,
+            expand_all_depth: expand_all_depth_limit(settings),
+            projection_presets: Vec::new(),
+            active_projection: None,
+            pipeline_stage_count: 0,
+            pipeline_stage_limit: None,
+            cursor_state: None,
             palette,
         };
 
@@ -1057,7 +2062,12 @@ impl CollectionTab {
         let skip_next_tab_id = tab_id;
 
         let duration_text = if self.query_in_progress {
-            String::from(tr("Executing..."))
+            match self.query_started {
+                Some(started) => {
+                    format!("{} {:.1}", tr("Executing..."), started.elapsed().as_secs_f64())
+                }
+                None => String::from(tr("Executing...")),
+            }
         } else {
             self.last_query_duration
                 .map(Self::format_duration)
@@ -1094,13 +2104,109 @@ impl CollectionTab {
                 move |_, status| palette.subtle_button_style(4.0, status)
             });
 
-        let navigation = Row::new()
+        let preview_button = Button::new(fonts::primary_text(tr("Preview"), Some(-1.0)))
+            .on_press(Message::CollectionQueryPreviewToggled(tab_id))
+            .padding([4, 8])
+            .style({
+                let palette = self.palette.clone();
+                let active = self.query_preview.is_some();
+                move |_, status| {
+                    if active {
+                        palette.primary_button_style(4.0, status)
+                    } else {
+                        palette.subtle_button_style(4.0, status)
+                    }
+                }
+            });
+
+        let mut projection_choices = vec![ProjectionChoice::AllFields];
+        projection_choices.extend(
+            self.projection_presets
+                .iter()
+                .map(|preset| ProjectionChoice::Preset(preset.name.clone())),
+        );
+        let selected_projection = Some(
+            self.active_projection
+                .clone()
+                .map_or(ProjectionChoice::AllFields, ProjectionChoice::Preset),
+        );
+        let projection_picker =
+            PickList::new(projection_choices, selected_projection, move |choice| {
+                Message::CollectionProjectionSelected { tab_id, choice }
+            })
+            .text_size(fonts::active_fonts().primary_size - 1.0)
+            .padding([3, 8]);
+        let save_preset_button = Button::new(fonts::primary_text(tr("+"), Some(-1.0)))
+            .on_press(Message::ProjectionPresetModalOpen(tab_id))
+            .padding([4, 8])
+            .style({
+                let palette = self.palette.clone();
+                move |_, status| palette.subtle_button_style(4.0, status)
+            });
+        let mut delete_preset_button =
+            Button::new(fonts::primary_text(tr("×"), Some(-1.0))).padding([4, 8]).style({
+                let palette = self.palette.clone();
+                move |_, status| palette.subtle_button_style(4.0, status)
+            });
+        if self.active_projection.is_some() {
+            delete_preset_button =
+                delete_preset_button.on_press(Message::CollectionProjectionPresetDelete(tab_id));
+        }
+
+        let save_query_button = Button::new(fonts::primary_text(tr("Save Query"), Some(-1.0)))
+            .on_press(Message::SavedQueryFormOpen(tab_id))
+            .padding([4, 8])
+            .style({
+                let palette = self.palette.clone();
+                move |_, status| palette.subtle_button_style(4.0, status)
+            });
+        let saved_queries_button =
+            Button::new(fonts::primary_text(tr("Saved Queries"), Some(-1.0)))
+                .on_press(Message::SavedQueriesOpen)
+                .padding([4, 8])
+                .style({
+                    let palette = self.palette.clone();
+                    move |_, status| palette.subtle_button_style(4.0, status)
+                });
+
+        let copy_node_button = Button::new(fonts::primary_text(tr("Copy as Node.js"), Some(-1.0)))
+            .on_press(Message::CollectionCopyAsNode(tab_id))
+            .padding([4, 8])
+            .style({
+                let palette = self.palette.clone();
+                move |_, status| palette.subtle_button_style(4.0, status)
+            });
+
+        let mut navigation = Row::new()
             .spacing(8)
             .align_y(Vertical::Center)
+            .push(save_query_button)
+            .push(saved_queries_button)
+            .push(copy_node_button)
+            .push(projection_picker)
+            .push(save_preset_button)
+            .push(delete_preset_button)
             .push(skip_prev)
             .push(skip_input)
             .push(limit_input)
-            .push(skip_next);
+            .push(skip_next)
+            .push(preview_button);
+
+        if self.pipeline_stage_count > 1 {
+            let total = self.pipeline_stage_count;
+            let mut stage_choices: Vec<PipelineStageChoice> = (1..total)
+                .map(|stages| PipelineStageChoice { stages: Some(stages), total })
+                .collect();
+            stage_choices.push(PipelineStageChoice { stages: None, total });
+            let selected = PipelineStageChoice { stages: self.pipeline_stage_limit, total };
+            navigation = navigation.push(
+                PickList::new(stage_choices, Some(selected), move |choice| {
+                    Message::CollectionPipelineStageSelected { tab_id, stages: choice.stages }
+                })
+                .text_size(fonts::active_fonts().primary_size - 1.0)
+                .padding([3, 8]),
+            );
+        }
 
         let connection_label = Row::new()
             .spacing(6)
@@ -1111,6 +2217,10 @@ impl CollectionTab {
                     .height(Length::Fixed(icon_size)),
             )
             .push(fonts::primary_text(self.client_name.clone(), None));
+        let connection_label = match self.accent_color {
+            Some(color) => connection_label.push(accent_swatch(color, 4.0, icon_size)),
+            None => connection_label,
+        };
 
         let database_label = Row::new()
             .spacing(6)
@@ -1132,26 +2242,72 @@ impl CollectionTab {
             )
             .push(fonts::primary_text(self.collection.clone(), None));
 
-        let info_labels = Row::new()
+        let mut info_labels = Row::new()
             .spacing(12)
             .align_y(Vertical::Center)
             .push(connection_label)
             .push(database_label)
             .push(collection_label)
             .push(fonts::primary_text(format!("{} {}", tr("Duration:"), duration_text), None));
+        if self.query_in_progress {
+            info_labels = info_labels.push(
+                Button::new(fonts::primary_text(tr("Cancel"), Some(-1.0)))
+                    .on_press(Message::CollectionQueryCancel(tab_id))
+                    .padding([2, 8])
+                    .style({
+                        let palette = self.palette.clone();
+                        move |_, status| palette.subtle_button_style(4.0, status)
+                    }),
+            );
+        }
 
-        let info_row = Row::new()
+        let mut info_row = Row::new()
             .spacing(16)
             .align_y(Vertical::Center)
             .width(Length::Fill)
-            .push(Container::new(info_labels).width(Length::Fill).padding([0, 4]))
-            .push(navigation);
+            .push(Container::new(info_labels).width(Length::Fill).padding([0, 4]));
+        if self.kind.supports_pagination() {
+            info_row = info_row.push(navigation);
+        }
 
         let panel_bg = self.palette.widget_background_color();
         let panel_border = self.palette.widget_border_color();
 
+        let mut info_column = Column::new().spacing(6).width(Length::Fill).push(info_row);
+        if let Some(preview) =
+            self.query_preview.as_ref().filter(|_| self.kind.supports_pagination())
+        {
+            info_column = info_column.push(
+                Row::new()
+                    .spacing(8)
+                    .push(
+                        fonts::primary_text(tr("Effective query:"), Some(-1.0))
+                            .color(self.palette.text_muted.to_color()),
+                    )
+                    .push(
+                        fonts::result_text(preview.clone(), Some(-1.0))
+                            .wrapping(Wrapping::WordOrGlyph)
+                            .width(Length::Fill),
+                    ),
+            );
+        }
+        if let Some(note) = &self.render_cap_note {
+            info_column = info_column.push(
+                fonts::primary_text(note.clone(), Some(-1.0))
+                    .color(warning_accent_color(&self.palette))
+                    .wrapping(Wrapping::Word),
+            );
+        }
+        if let Some(warning) = self.unreachable_page_warning() {
+            info_column = info_column.push(
+                fonts::primary_text(warning, Some(-1.0))
+                    .color(warning_accent_color(&self.palette))
+                    .wrapping(Wrapping::Word),
+            );
+        }
+
         let info_panel =
-            Container::new(info_row).width(Length::Fill).padding([8, 12]).style(move |_| {
+            Container::new(info_column).width(Length::Fill).padding([8, 12]).style(move |_| {
                 iced::widget::container::Style {
                     background: Some(panel_bg.into()),
                     border: border::rounded(6).width(1).color(panel_border),
@@ -1185,18 +2341,39 @@ impl CollectionTab {
             TableContextAction::CopyJson => {
                 self.bson_tree.node_bson(node_id).map(|bson| shell::format_bson_shell(&bson))
             }
+            TableContextAction::CopyMarkdownTable => self.results_markdown_table(),
+            TableContextAction::CopyId => self.bson_tree.node_document_id(node_id),
             TableContextAction::CopyKey => self.bson_tree.node_display_key(node_id),
             TableContextAction::CopyValue => self.bson_tree.node_value_display(node_id),
             TableContextAction::CopyPath => self.bson_tree.node_path(node_id),
-            TableContextAction::EditValue => None,
+            TableContextAction::CopySelectedRows => self.bson_tree.selected_rows_text(),
+            TableContextAction::EditValue
+            | TableContextAction::AppendArrayElement
+            | TableContextAction::DeleteArrayElement => None,
             TableContextAction::GoToRelatedDocument => None,
+            TableContextAction::FindById => None,
+            TableContextAction::ExportJson | TableContextAction::ExportCsv => None,
             TableContextAction::DeleteIndex
             | TableContextAction::HideIndex
             | TableContextAction::UnhideIndex
             | TableContextAction::ExpandHierarchy
             | TableContextAction::CollapseHierarchy
             | TableContextAction::ExpandHierarchyAll
-            | TableContextAction::CollapseHierarchyAll => None,
+            | TableContextAction::CollapseHierarchyAll
+            | TableContextAction::SelectForCompare
+            | TableContextAction::CompareWithSelected => None,
+        }
+    }
+
+    fn results_markdown_table(&self) -> Option<String> {
+        match self.last_result.as_ref()? {
+            QueryResult::Documents(values) | QueryResult::Indexes(values) => {
+                Some(export::markdown_table(values))
+            }
+            QueryResult::SingleDocument { document } => {
+                Some(export::markdown_table(&[Bson::Document(document.clone())]))
+            }
+            _ => None,
         }
     }
 
@@ -1206,15 +2383,17 @@ impl CollectionTab {
 
     fn request_view(&self, tab_id: TabId) -> Element<'_, Message> {
         let send_tab_id = tab_id;
+        let send_shortcut = self.send_shortcut.clone();
         let editor_fonts = fonts::active_fonts();
         let editor = text_editor::TextEditor::new(&self.editor)
             .id(self.editor_id.clone())
             .font(editor_fonts.editor_font)
             .size(editor_fonts.editor_size)
             .key_binding(move |key_press| {
-                let is_enter = matches!(key_press.key, keyboard::Key::Named(key::Named::Enter));
+                let is_send = KeyCombo::from_key_press(&key_press.key, key_press.modifiers)
+                    .is_some_and(|combo| combo == send_shortcut);
                 let is_delete = matches!(key_press.key, keyboard::Key::Named(key::Named::Delete));
-                if is_enter && key_press.modifiers.command() {
+                if is_send {
                     Some(TextEditorBinding::Custom(Message::CollectionSend(send_tab_id)))
                 } else if is_delete {
                     Some(TextEditorBinding::Delete)
@@ -1223,6 +2402,10 @@ impl CollectionTab {
                 }
             })
             .on_action(move |action| Message::CollectionEditorAction { tab_id, action })
+            .highlight_with::<QueryHighlighter>(
+                QueryHighlightColors::from_palette(&self.palette, self.highlight_queries),
+                query_highlighter::to_format,
+            )
             .height(Length::Fill);
 
         let send_content = Container::new(fonts::primary_text(tr("Send"), None))
@@ -1282,10 +2465,16 @@ impl CollectionTab {
     }
 
     fn response_view(&self, tab_id: TabId) -> Element<'_, Message> {
+        if let Some(message) = &self.empty_result_message {
+            return self.empty_result_view(message);
+        }
+
         match self.response_view_mode {
             ResponseViewMode::Table => {
                 let started = Instant::now();
-                let view = self.bson_tree.view(tab_id, &self.known_collections);
+                let tree = self.bson_tree.view(tab_id, &self.known_collections);
+                let view =
+                    Column::new().spacing(6).push(self.tree_filter_bar(tab_id)).push(tree).into();
                 let elapsed = started.elapsed();
                 if perf_diagnostics_enabled() && elapsed >= Duration::from_millis(16) {
                     log::debug!(
@@ -1297,7 +2486,156 @@ impl CollectionTab {
                 view
             }
             ResponseViewMode::Text => self.text_result_view(tab_id),
+            ResponseViewMode::ExtendedJson => self.extended_json_view(tab_id),
+        }
+    }
+
+    fn tree_filter_bar(&self, tab_id: TabId) -> Element<'_, Message> {
+        let fonts_state = fonts::active_fonts();
+        let tree_button = |label: &'static str, message: Option<Message>| {
+            let palette = self.palette.clone();
+            Button::new(fonts::primary_text(tr(label), Some(-1.0)))
+                .padding([4, 10])
+                .on_press_maybe(message)
+                .style(move |_, status| palette.subtle_button_style(6.0, status))
+        };
+        let expand_all = tree_button(
+            "Expand All",
+            (!self.bson_tree.is_fully_expanded(self.expand_all_depth)).then_some(
+                Message::TableContextMenu {
+                    tab_id,
+                    node_id: 0,
+                    action: TableContextAction::ExpandHierarchyAll,
+                },
+            ),
+        );
+        let collapse_all = tree_button(
+            "Collapse All",
+            self.bson_tree.has_expanded_nodes().then_some(Message::TableContextMenu {
+                tab_id,
+                node_id: 0,
+                action: TableContextAction::CollapseHierarchyAll,
+            }),
+        );
+        let mut row = Row::new()
+            .spacing(8)
+            .align_y(Vertical::Center)
+            .push(
+                text_input(tr("Filter by key or value"), &self.tree_filter)
+                    .on_input(move |value| Message::CollectionTreeFilterChanged { tab_id, value })
+                    .padding([4, 8])
+                    .width(Length::Fill),
+            )
+            .push(
+                Checkbox::new(self.tree_filter_case_sensitive)
+                    .label(tr("Match case"))
+                    .font(fonts_state.primary_font)
+                    .text_size(fonts_state.primary_size - 1.0)
+                    .on_toggle(move |value| Message::CollectionTreeFilterCaseToggled {
+                        tab_id,
+                        value,
+                    }),
+            );
+        if let Some(count) = self.bson_tree.filter_match_count() {
+            row = row.push(
+                fonts::primary_text(tr_format("{} matches", &[&count.to_string()]), Some(-1.0))
+                    .color(self.palette.text_muted.to_color()),
+            );
         }
+        let extended_json = tree_button(
+            "Extended JSON",
+            self.last_result.as_ref().map(|_| Message::CollectionResponseViewModeSelected {
+                tab_id,
+                mode: ResponseViewMode::ExtendedJson,
+            }),
+        );
+        row.push(expand_all).push(collapse_all).push(extended_json).into()
+    }
+
+    fn extended_json_view(&self, tab_id: TabId) -> Element<'_, Message> {
+        let palette = self.palette.clone();
+        let copy_palette = self.palette.clone();
+        let bar = Row::new()
+            .spacing(8)
+            .align_y(Vertical::Center)
+            .push(
+                fonts::primary_text(tr("Canonical Extended JSON"), Some(-1.0))
+                    .color(self.palette.text_muted.to_color()),
+            )
+            .push(Space::new().width(Length::Fill))
+            .push(
+                Button::new(fonts::primary_text(tr("Copy JSON"), Some(-1.0)))
+                    .padding([4, 10])
+                    .on_press_maybe(
+                        self.extended_json
+                            .as_ref()
+                            .map(|_| Message::CollectionExtendedJsonCopy { tab_id }),
+                    )
+                    .style(move |_, status| copy_palette.subtle_button_style(6.0, status)),
+            )
+            .push(
+                Button::new(fonts::primary_text(tr("Show Tree"), Some(-1.0)))
+                    .padding([4, 10])
+                    .on_press(Message::CollectionResponseViewModeSelected {
+                        tab_id,
+                        mode: ResponseViewMode::Table,
+                    })
+                    .style(move |_, status| palette.subtle_button_style(6.0, status)),
+            );
+
+        let body: Element<'_, Message> = match &self.extended_json {
+            Some(json) => Scrollable::new(
+                Container::new(fonts::result_text(json.as_str(), None).wrapping(Wrapping::Word))
+                    .padding([8, 10])
+                    .width(Length::Fill)
+                    .style(move |_| container::Style {
+                        border: border::rounded(6.0).width(1),
+                        ..Default::default()
+                    }),
+            )
+            .id(App::text_body_scroll_id(tab_id))
+            .on_scroll(move |viewport| Message::CollectionTextScrolled {
+                tab_id,
+                offset_y: viewport.relative_offset().y,
+            })
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into(),
+            None => Container::new(
+                fonts::result_text(tr("Run a query to see its extended JSON."), None)
+                    .color(self.palette.text_muted.to_color()),
+            )
+            .padding([8, 12])
+            .width(Length::Fill)
+            .into(),
+        };
+
+        Column::new().spacing(6).push(bar).push(body).into()
+    }
+
+    fn empty_result_view(&self, message: &str) -> Element<'_, Message> {
+        let content = Column::new()
+            .spacing(6)
+            .align_x(Horizontal::Center)
+            .push(
+                fonts::primary_text(message.to_owned(), None)
+                    .color(self.palette.text_primary.to_color()),
+            )
+            .push(
+                fonts::primary_text(
+                    tr("Adjust the filter or pipeline and run the query again."),
+                    Some(-1.0),
+                )
+                .color(self.palette.text_muted.to_color()),
+            );
+
+        Container::new(content)
+            .padding(16)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .into()
     }
 
     fn text_result_view(&self, tab_id: TabId) -> Element<'_, Message> {
@@ -1326,11 +2664,17 @@ impl CollectionTab {
             let menu = move || {
                 let item_palette = menu_palette.clone();
                 let border_palette = menu_palette_border.clone();
+                let markdown_palette = item_palette.clone();
                 let button = Button::new(fonts::primary_text(tr("Copy JSON"), None))
                     .padding([4, 8])
                     .on_press(Message::CollectionTextCopyJson { tab_id })
                     .style(move |_, status| item_palette.menu_button_style(6.0, status));
-                Container::new(button)
+                let markdown_button =
+                    Button::new(fonts::primary_text(tr("Copy Results as Markdown Table"), None))
+                        .padding([4, 8])
+                        .on_press(Message::CollectionTextCopyMarkdown { tab_id })
+                        .style(move |_, status| markdown_palette.menu_button_style(6.0, status));
+                Container::new(Column::new().spacing(2).push(button).push(markdown_button))
                     .style(move |_| iced::widget::container::Style {
                         background: Some(border_palette.menu.background.to_color().into()),
                         border: border::rounded(6.0)
@@ -1363,12 +2707,48 @@ impl CollectionTab {
         self.bson_tree.toggle(node_id);
     }
 
+    fn apply_paging(&mut self, paging: CollectionPaging) {
+        self.skip_input = Self::format_numeric(paging.skip);
+        self.limit_input = Self::format_numeric(paging.limit);
+    }
+
     fn update_skip(&mut self, value: String) {
         self.skip_input = Self::sanitize_numeric(value);
+        self.refresh_query_preview();
     }
 
     fn update_limit(&mut self, value: String) {
         self.limit_input = Self::sanitize_numeric(value);
+        self.refresh_query_preview();
+    }
+
+    fn has_unrun_changes(&self) -> bool {
+        self.modified && self.last_executed_text.as_deref() != Some(self.editor.text().as_str())
+    }
+
+    fn toggle_query_preview(&mut self) {
+        if self.query_preview.take().is_none() {
+            self.query_preview = Some(self.effective_query_preview());
+        }
+    }
+
+    fn refresh_query_preview(&mut self) {
+        if self.query_preview.is_some() {
+            self.query_preview = Some(self.effective_query_preview());
+        }
+    }
+
+    fn effective_query_preview(&self) -> String {
+        match self.parse_query(&self.editor.text()) {
+            Ok((_, operation)) => {
+                let operation = self.apply_pipeline_stage_limit(operation);
+                describe_effective_query(&operation, self.skip_value(), self.limit_value())
+                    .unwrap_or_else(|| {
+                        String::from(tr("Skip and limit do not apply to this operation"))
+                    })
+            }
+            Err(error) => error,
+        }
     }
 
     fn decrement_skip_by_limit(&mut self) {
@@ -1380,6 +2760,7 @@ impl CollectionTab {
         let skip = self.parse_skip_u64();
         let new_skip = skip.saturating_sub(limit);
         self.skip_input = Self::format_numeric(new_skip);
+        self.refresh_query_preview();
     }
 
     fn increment_skip_by_limit(&mut self) {
@@ -1391,18 +2772,65 @@ impl CollectionTab {
         let skip = self.parse_skip_u64();
         let new_skip = skip.saturating_add(limit);
         self.skip_input = Self::format_numeric(new_skip);
+        self.refresh_query_preview();
     }
 
     fn skip_value(&self) -> u64 {
         self.parse_skip_u64()
     }
 
+    fn unreachable_page_warning(&self) -> Option<String> {
+        let total = self.total_count?;
+        let skip = self.skip_value();
+        if self.query_in_progress || skip < total {
+            return None;
+        }
+
+        let limit = self.limit_value();
+        let last_page_skip = match (total, limit) {
+            (0, _) => 0,
+            (_, 0) => total - 1,
+            _ => ((total - 1) / limit) * limit,
+        };
+
+        Some(tr_format(
+            "Skip {} is beyond the {} matching documents. The last page starts at skip {}.",
+            &[&skip.to_string(), &total.to_string(), &last_page_skip.to_string()],
+        ))
+    }
+
     fn limit_value(&self) -> u64 {
         self.parse_limit_u64()
     }
 
     fn parse_query(&self, text: &str) -> Result<(String, QueryOperation), String> {
-        parse_collection_query_with_collection(&self.db_name, &self.collection, text)
+        parse_collection_query_with_collection(&self.db_name, &self.collection, text).map(
+            |(collection, operation)| {
+                let operation = match self.active_projection_preset() {
+                    Some(preset) if collection == self.collection => {
+                        operation.with_default_projection(projection_from_fields(&preset.fields))
+                    }
+                    _ => operation,
+                };
+                (collection, operation)
+            },
+        )
+    }
+
+    fn apply_pipeline_stage_limit(&self, operation: QueryOperation) -> QueryOperation {
+        match self.pipeline_stage_limit {
+            Some(stages) => operation.with_stage_limit(stages),
+            None => operation,
+        }
+    }
+
+    fn active_projection_preset(&self) -> Option<&ProjectionPreset> {
+        let name = self.active_projection.as_ref()?;
+        self.projection_presets.iter().find(|preset| &preset.name == name)
+    }
+
+    fn paging_key(&self) -> String {
+        collection_paging_key(&self.client_name, &self.db_name, &self.collection)
     }
 
     fn sanitize_numeric<S: AsRef<str>>(value: S) -> String {
@@ -1435,23 +2863,35 @@ impl CollectionTab {
         }
     }
 
-    fn set_query_result(&mut self, result: QueryResult, settings: &AppSettings) {
+    fn set_query_result(&mut self, mut result: QueryResult, settings: &AppSettings) {
         let total_started = Instant::now();
         self.palette = settings.active_palette().clone();
         self.table_scroll_offset = 0.0;
         self.text_scroll_offset = 0.0;
 
+        let max_render = usize::try_from(settings.max_render_documents).unwrap_or(usize::MAX);
+        self.render_cap_note = result.truncate_to(max_render).map(|returned| {
+            tr_format(
+                "Showing the first {} of {} returned items.",
+                &[&max_render.to_string(), &returned.to_string()],
+            )
+        });
+
         let cached = result.clone();
         self.last_result = Some(cached);
-
-        let text_format_ms = if self.response_view_mode == ResponseViewMode::Text {
-            let duration = self.build_text_result(&result);
-            duration.as_secs_f64() * 1000.0
-        } else {
-            self.text_result = None;
-            0.0
+        self.last_error = None;
+        self.empty_result_message = match &result {
+            QueryResult::Documents(values) if values.is_empty() => {
+                Some(String::from(tr("No documents matched the query.")))
+            }
+            QueryResult::Distinct { values, .. } if values.is_empty() => {
+                Some(String::from(tr("No distinct values found.")))
+            }
+            _ => None,
         };
 
+        let text_format_ms = self.build_text_result(&result).as_secs_f64() * 1000.0;
+
         let tree_build_started = Instant::now();
         let options = BsonTreeOptions::from(settings);
 
@@ -1469,6 +2909,7 @@ impl CollectionTab {
         self.bson_tree = tree;
         let apply_started = Instant::now();
         self.apply_behavior_settings(settings);
+        self.bson_tree.apply_filter(&self.tree_filter, self.tree_filter_case_sensitive);
         let apply_ms = apply_started.elapsed().as_secs_f64() * 1000.0;
 
         if perf_diagnostics_enabled() {
@@ -1507,15 +2948,18 @@ impl CollectionTab {
 
     fn set_tree_error(&mut self, error: String) {
         log::error!("{error}");
+        self.last_error = Some(error.clone());
         self.table_scroll_offset = 0.0;
         self.text_scroll_offset = 0.0;
         self.bson_tree = BsonTree::from_error(error);
+        self.empty_result_message = None;
         self.bson_tree.set_table_colors(self.palette.table.clone());
         self.bson_tree.set_menu_colors(self.palette.menu.clone());
         self.bson_tree.set_text_color(self.palette.text_primary);
         self.bson_tree.set_button_colors(self.palette.subtle_buttons.clone());
         self.last_result = None;
         self.text_result = None;
+        self.extended_json = None;
     }
 
     fn apply_behavior_settings(&mut self, settings: &AppSettings) {
@@ -1528,13 +2972,19 @@ impl CollectionTab {
 
     fn refresh_with_settings(&mut self, settings: &AppSettings) {
         self.palette = settings.active_palette().clone();
+        self.send_shortcut = settings.key_binding(ShortcutAction::SendQuery);
+        self.highlight_queries = settings.highlight_queries;
+        self.expand_all_depth = expand_all_depth_limit(settings);
         self.bson_tree.set_table_colors(self.palette.table.clone());
         self.bson_tree.set_menu_colors(self.palette.menu.clone());
         self.bson_tree.set_text_color(self.palette.text_primary);
         self.bson_tree.set_button_colors(self.palette.subtle_buttons.clone());
         if let Some(result) = self.last_result.clone() {
-            if self.response_view_mode == ResponseViewMode::Text {
+            if self.response_view_mode != ResponseViewMode::Table {
+                // The cached result is already capped, so keep the note from the original run.
+                let render_cap_note = self.render_cap_note.take();
                 self.set_query_result(result, settings);
+                self.render_cap_note = self.render_cap_note.take().or(render_cap_note);
             }
         } else if settings.expand_first_result {
             if let Some(root_id) = self.bson_tree.first_root_id() {
@@ -1592,29 +3042,140 @@ impl App {
 
         match tab.collection.response_view_mode {
             ResponseViewMode::Table => self.restore_table_scroll_for_tab(tab_id),
-            ResponseViewMode::Text => self.restore_text_scroll_for_tab(tab_id),
+            ResponseViewMode::Text | ResponseViewMode::ExtendedJson => {
+                self.restore_text_scroll_for_tab(tab_id)
+            }
         }
     }
 
-    fn restore_active_response_scroll(&self) -> Task<Message> {
-        if let Some(tab_id) = self.active_tab {
-            return self.restore_response_scroll_for_tab(tab_id);
-        }
-
-        Task::none()
+    /// Scrolls the other side-by-side tab to the same relative offset as `tab_id`.
+    fn sync_side_by_side_scroll(&mut self, tab_id: TabId, mode: ResponseViewMode) -> Task<Message> {
+        let Some(partner_id) = self
+            .side_by_side
+            .as_ref()
+            .filter(|state| state.sync_scroll)
+            .and_then(|state| state.partner(tab_id))
+        else {
+            return Task::none();
+        };
+        let Some(offset) = self.tabs.iter().find(|tab| tab.id == tab_id).map(|tab| match mode {
+            ResponseViewMode::Table => tab.collection.table_scroll_offset,
+            ResponseViewMode::Text | ResponseViewMode::ExtendedJson => {
+                tab.collection.text_scroll_offset
+            }
+        }) else {
+            return Task::none();
+        };
+        let Some(partner) = self.tabs.iter_mut().find(|tab| tab.id == partner_id) else {
+            return Task::none();
+        };
+        let partner_offset = match mode {
+            ResponseViewMode::Table => &mut partner.collection.table_scroll_offset,
+            ResponseViewMode::Text | ResponseViewMode::ExtendedJson => {
+                &mut partner.collection.text_scroll_offset
+            }
+        };
+        // The snap below reports a scroll event back; equal offsets end the exchange.
+        if (*partner_offset - offset).abs() < f32::EPSILON {
+            return Task::none();
+        }
+        *partner_offset = offset;
+        match mode {
+            ResponseViewMode::Table => self.restore_table_scroll_for_tab(partner_id),
+            ResponseViewMode::Text | ResponseViewMode::ExtendedJson => {
+                self.restore_text_scroll_for_tab(partner_id)
+            }
+        }
+    }
+
+    fn set_response_view_mode(&mut self, tab_id: TabId, mode: ResponseViewMode) -> Task<Message> {
+        let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+            return Task::none();
+        };
+        tab.collection.response_view_mode = mode;
+        log::debug!("View mode set to {:?} tab_id={}", mode, tab_id);
+        if let Some(result) = tab.collection.last_result.clone() {
+            tab.collection.build_text_result(&result);
+        }
+        self.restore_response_scroll_for_tab(tab_id)
+    }
+
+    fn restore_active_response_scroll(&self) -> Task<Message> {
+        if let Some(tab_id) = self.active_tab {
+            return self.restore_response_scroll_for_tab(tab_id);
+        }
+
+        Task::none()
+    }
+
+    /// Message that dismisses the modal for the current mode, as its Cancel/Close button would.
+    fn modal_cancel_message(&self) -> Option<Message> {
+        match self.mode {
+            AppMode::Main | AppMode::SettingsLoadError => None,
+            AppMode::Connections => {
+                if self.connections_window.as_ref().is_some_and(|state| state.confirm_delete) {
+                    Some(Message::ConnectionsDeleteCancelled)
+                } else {
+                    Some(Message::ConnectionsCancel)
+                }
+            }
+            AppMode::ConnectionForm => Some(Message::ConnectionFormCancel),
+            AppMode::Settings => Some(Message::SettingsCancel),
+            AppMode::About => Some(Message::AboutModalClose),
+            AppMode::Licenses => Some(Message::LicensesModalClose),
+            AppMode::HelpDocs => Some(Message::HelpDocsClose),
+            AppMode::CollectionModal => Some(Message::CollectionModalCancel),
+            AppMode::DatabaseModal => Some(Message::DatabaseModalCancel),
+            AppMode::DocumentModal => Some(Message::DocumentModalCancel),
+            AppMode::ValueEditModal => Some(Message::ValueEditModalCancel),
+            AppMode::DocumentDiffModal => Some(Message::DocumentDiffModalClose),
+            AppMode::IndexBuilderModal => Some(Message::IndexBuilderCancel),
+            AppMode::AggregateWriteModal => Some(Message::AggregateWriteCancel),
+            AppMode::DateFormatPreview => Some(Message::DateFormatPreviewClose),
+            AppMode::TextSearchModal => Some(Message::TextSearchCancel),
+            AppMode::ProjectionPresetModal => Some(Message::ProjectionPresetCancel),
+            AppMode::SavedQueries => {
+                if self.saved_queries_window.as_ref().is_some_and(|state| state.confirm_delete) {
+                    Some(Message::SavedQueriesDeleteCancelled)
+                } else {
+                    Some(Message::SavedQueriesClose)
+                }
+            }
+            AppMode::SavedQueryForm => Some(Message::SavedQueryFormCancel),
+            AppMode::TabCloseConfirmation => Some(Message::TabCloseCancelled),
+            AppMode::ArrayElementDeleteConfirmation => Some(Message::ArrayElementDeleteCancelled),
+        }
     }
 
-    fn handle_hotkey(key: keyboard::Key, modifiers: keyboard::Modifiers) -> Option<Message> {
+    fn handle_hotkey(&self, key: keyboard::Key, modifiers: keyboard::Modifiers) -> Option<Message> {
+        if let Some(action) = self.settings.shortcut_for(&key, modifiers) {
+            return match action {
+                // Sending is bound in the query editor, which needs focus to know the tab.
+                ShortcutAction::SendQuery => None,
+                ShortcutAction::RerunQuery => Some(Message::RerunActiveQuery),
+                ShortcutAction::CloseTab => Some(Message::CloseActiveTab),
+                ShortcutAction::NextTab => Some(Message::SelectAdjacentTab { forward: true }),
+                ShortcutAction::PreviousTab => Some(Message::SelectAdjacentTab { forward: false }),
+                ShortcutAction::ToggleSidebar => Some(Message::ToggleSidebar),
+                ShortcutAction::TableView => Some(Message::MenuItemSelected(
+                    TopMenu::View,
+                    MenuEntry::ViewMode(ResponseViewMode::Table),
+                )),
+                ShortcutAction::TextView => Some(Message::MenuItemSelected(
+                    TopMenu::View,
+                    MenuEntry::ViewMode(ResponseViewMode::Text),
+                )),
+            };
+        }
+
         match key.as_ref() {
-            keyboard::Key::Named(key::Named::F2) => Some(Message::MenuItemSelected(
-                TopMenu::View,
-                MenuEntry::ViewMode(ResponseViewMode::Table),
-            )),
-            keyboard::Key::Named(key::Named::F4) => Some(Message::MenuItemSelected(
-                TopMenu::View,
-                MenuEntry::ViewMode(ResponseViewMode::Text),
-            )),
-            keyboard::Key::Character("w") if modifiers.command() => Some(Message::CloseActiveTab),
+            keyboard::Key::Named(key::Named::Escape) => Some(Message::TabRenameCancelled),
+            keyboard::Key::Named(key::Named::PageDown) if modifiers.command() => {
+                Some(Message::SelectAdjacentTab { forward: true })
+            }
+            keyboard::Key::Named(key::Named::PageUp) if modifiers.command() => {
+                Some(Message::SelectAdjacentTab { forward: false })
+            }
             _ => None,
         }
     }
@@ -1638,6 +3199,18 @@ impl App {
             log::warn!("Failed to load connections: {error}");
             Vec::new()
         });
+        let collection_paging = settings::load_collection_paging().unwrap_or_else(|error| {
+            log::warn!("Failed to load collection paging: {error}");
+            BTreeMap::new()
+        });
+        let projection_presets = settings::load_projection_presets().unwrap_or_else(|error| {
+            log::warn!("Failed to load projection presets: {error}");
+            BTreeMap::new()
+        });
+        let saved_queries = load_saved_queries_from_disk().unwrap_or_else(|error| {
+            log::warn!("Failed to load saved queries: {error}");
+            Vec::new()
+        });
 
         Self {
             panes,
@@ -1648,6 +3221,8 @@ impl App {
             next_client_id: 1,
             last_collection_click: None,
             connections,
+            collection_paging,
+            projection_presets,
             settings,
             mode: AppMode::Main,
             connections_window: None,
@@ -1659,8 +3234,29 @@ impl App {
             database_modal: None,
             document_modal: None,
             value_edit_modal: None,
+            document_diff_modal: None,
+            index_builder_modal: None,
+            aggregate_write_modal: None,
+            date_format_preview: None,
+            text_search_modal: None,
+            projection_preset_modal: None,
+            saved_queries,
+            saved_queries_window: None,
+            saved_query_form: None,
+            tab_close_confirmation: None,
+            array_element_delete: None,
+            tab_title_edit: None,
+            query_log: Vec::new(),
+            query_log_visible: false,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            query_log_filter: String::new(),
+            sidebar_collapsed: false,
+            status_notice: None,
+            query_progress_ticking: false,
+            last_connection_selection: None,
             window_size: None,
             tab_color_picker: None,
+            side_by_side: None,
         }
     }
 
@@ -1697,48 +3293,158 @@ impl App {
             app.mode = AppMode::SettingsLoadError;
         }
 
-        (app, Task::none())
+        let restore_task = app.restore_session();
+        (app, restore_task)
+    }
+
+    fn save_session(&self) {
+        // Indexes, stats and serverStatus tabs hold command results rather than a query, so only
+        // Find tabs are persisted.
+        let database_marker = tr("(database)");
+        let tabs = self
+            .tabs
+            .iter()
+            .filter(|tab| tab.collection.kind == TabKind::Find)
+            .map(|tab| {
+                let database = tab.collection.collection == database_marker;
+                SessionTab {
+                    id: tab.id,
+                    client_name: tab.collection.client_name.clone(),
+                    db_name: tab.collection.db_name.clone(),
+                    collection: if database {
+                        String::new()
+                    } else {
+                        tab.collection.collection.clone()
+                    },
+                    editor_text: tab.collection.editor.text().to_string(),
+                    skip: tab.collection.skip_value(),
+                    limit: tab.collection.limit_value(),
+                    database,
+                    custom_title: tab.custom_title.clone(),
+                }
+            })
+            .collect();
+        let session = SessionState { tabs, active_tab: self.active_tab };
+        if let Err(error) = settings::save_session(&session) {
+            log::error!("Failed to save session: {error}");
+        }
+    }
+
+    /// Reopens the tabs of the previous run and connects the clients they belong to. Tabs whose
+    /// connection was deleted in the meantime are skipped.
+    fn restore_session(&mut self) -> Task<Message> {
+        let session = match settings::load_session() {
+            Ok(session) => session,
+            Err(error) => {
+                log::warn!("Failed to load session: {error}");
+                return Task::none();
+            }
+        };
+
+        let mut tasks = Vec::new();
+        let mut dropped = 0;
+        for saved in session.tabs {
+            let existing = self.clients.iter().find(|client| client.name == saved.client_name);
+            let client_id = match existing {
+                Some(client) => client.id,
+                None => {
+                    let Some(entry) = self
+                        .connections
+                        .iter()
+                        .find(|entry| entry.name == saved.client_name)
+                        .cloned()
+                    else {
+                        log::warn!(
+                            "Tab {}.{} was not restored: connection '{}' no longer exists",
+                            saved.db_name,
+                            saved.collection,
+                            saved.client_name
+                        );
+                        dropped += 1;
+                        continue;
+                    };
+                    let client_id = self.next_client_id;
+                    match self.add_connection_from_entry(entry) {
+                        Ok(task) => tasks.push(task),
+                        Err(error) => {
+                            log::warn!("Failed to reconnect '{}': {error}", saved.client_name);
+                            dropped += 1;
+                            continue;
+                        }
+                    }
+                    client_id
+                }
+            };
+
+            if self.tabs.iter().any(|tab| tab.id == saved.id) {
+                continue;
+            }
+            let collection =
+                if saved.database { String::from(tr("(database)")) } else { saved.collection };
+            let mut tab =
+                self.build_collection_tab(saved.id, client_id, saved.db_name.clone(), collection);
+            if saved.database {
+                tab.title = saved.db_name;
+            }
+            tab.custom_title = saved.custom_title;
+            tab.collection.editor = TextEditorContent::with_text(&saved.editor_text);
+            tab.collection.update_skip(saved.skip.to_string());
+            tab.collection.update_limit(saved.limit.to_string());
+            self.next_tab_id = self.next_tab_id.max(saved.id + 1);
+            self.tabs.push(tab);
+        }
+
+        self.active_tab = session
+            .active_tab
+            .filter(|id| self.tabs.iter().any(|tab| tab.id == *id))
+            .or_else(|| self.tabs.first().map(|tab| tab.id));
+        if dropped > 0 {
+            self.status_notice = Some(tr_format(
+                "{} tabs were not restored because their connections no longer exist.",
+                &[&dropped.to_string()],
+            ));
+        }
+        Task::batch(tasks)
     }
 
     pub(crate) fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::MenuItemSelected(menu, entry) => {
-                let mut restore_scroll_for_tab = None;
                 match entry {
                     MenuEntry::Action(label) => {
                         if menu == TopMenu::File && label == "Connections" {
                             self.open_connections_window();
+                            return self.scroll_to_selected_connection();
                         } else if menu == TopMenu::Help && label == "Documentation" {
                             self.open_help_docs_window();
                         } else if menu == TopMenu::Help && label == "About" {
                             self.open_about_modal();
                         } else if menu == TopMenu::Help && label == "Licenses" {
                             self.open_licenses_modal();
+                        } else if menu == TopMenu::Help && label == "Export Diagnostic Report" {
+                            return self.export_diagnostic_report_task();
+                        } else if menu == TopMenu::View && label == "Saved Queries" {
+                            return self.update(Message::SavedQueriesOpen);
+                        } else if menu == TopMenu::View && label == "Query Log" {
+                            self.query_log_visible = !self.query_log_visible;
+                        } else if menu == TopMenu::View && label == "Sidebar" {
+                            self.sidebar_collapsed = !self.sidebar_collapsed;
+                        } else if menu == TopMenu::View && label == "Date Format Preview" {
+                            self.date_format_preview.get_or_insert_with(Default::default);
+                            self.mode = AppMode::DateFormatPreview;
+                        } else if menu == TopMenu::View && label == "Refresh All Connections" {
+                            return self.refresh_all_connections();
                         } else {
                             log::debug!("Menu '{menu:?}' entry '{label}' clicked");
                         }
                     }
                     MenuEntry::ViewMode(mode) => {
                         if let Some(active_id) = self.active_tab {
-                            if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == active_id)
-                            {
-                                tab.collection.response_view_mode = mode;
-                                log::debug!("View mode set to {:?} tab_id={}", mode, active_id);
-                                if mode == ResponseViewMode::Text {
-                                    if let Some(result) = tab.collection.last_result.clone() {
-                                        tab.collection.build_text_result(&result);
-                                    }
-                                }
-                                restore_scroll_for_tab = Some(active_id);
-                            }
+                            return self.set_response_view_mode(active_id, mode);
                         }
                     }
                 }
-                if let Some(tab_id) = restore_scroll_for_tab {
-                    self.restore_response_scroll_for_tab(tab_id)
-                } else {
-                    Task::none()
-                }
+                Task::none()
             }
             Message::TabSelected(id) => {
                 if self.tabs.iter().any(|tab| tab.id == id) {
@@ -1749,41 +3455,169 @@ impl App {
                 Task::none()
             }
             Message::TabClosed(id) => {
-                if let Some(position) = self.tabs.iter().position(|tab| tab.id == id) {
-                    let was_active = self.active_tab == Some(id);
-                    self.tabs.remove(position);
-                    if self.tab_color_picker == Some(id) {
-                        self.tab_color_picker = None;
-                    }
-                    if was_active {
-                        self.active_tab = self
-                            .tabs
-                            .get(position.saturating_sub(1))
-                            .or_else(|| self.tabs.get(position))
-                            .map(|tab| tab.id);
-                    }
-                    log::debug!("Tab closed id={}", id);
-                    if was_active {
-                        return self.restore_active_response_scroll();
-                    }
+                let needs_confirmation = self.settings.confirm_close_edited_tabs
+                    && self
+                        .tabs
+                        .iter()
+                        .any(|tab| tab.id == id && tab.collection.has_unrun_changes());
+                if needs_confirmation {
+                    self.tab_close_confirmation = Some(id);
+                    self.mode = AppMode::TabCloseConfirmation;
+                    return Task::none();
+                }
+                self.close_tab(id)
+            }
+            Message::TabCloseConfirmed => {
+                self.mode = AppMode::Main;
+                match self.tab_close_confirmation.take() {
+                    Some(id) => self.close_tab(id),
+                    None => Task::none(),
                 }
+            }
+            Message::TabCloseCancelled => {
+                self.tab_close_confirmation = None;
+                self.mode = AppMode::Main;
                 Task::none()
             }
-            Message::CloseActiveTab => {
-                if let Some(active_id) = self.active_tab {
-                    if let Some(position) = self.tabs.iter().position(|tab| tab.id == active_id) {
-                        self.tabs.remove(position);
-                        if self.tab_color_picker == Some(active_id) {
-                            self.tab_color_picker = None;
-                        }
-                        self.active_tab = self
-                            .tabs
-                            .get(position.saturating_sub(1))
-                            .or_else(|| self.tabs.get(position))
-                            .map(|tab| tab.id);
-                        log::debug!("Tab closed id={}", active_id);
-                        return self.restore_active_response_scroll();
-                    }
+            Message::RerunActiveQuery => {
+                if self.mode != AppMode::Main {
+                    return Task::none();
+                }
+                let Some(active_id) = self.active_tab else {
+                    return Task::none();
+                };
+                let idle = self
+                    .tabs
+                    .iter()
+                    .any(|tab| tab.id == active_id && !tab.collection.query_in_progress);
+                if idle { self.collection_query_task(active_id) } else { Task::none() }
+            }
+            Message::ToggleSidebar => {
+                if self.mode == AppMode::Main {
+                    self.sidebar_collapsed = !self.sidebar_collapsed;
+                }
+                Task::none()
+            }
+            Message::TabRenameStarted(tab_id) => {
+                let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let current = tab.custom_title.clone().unwrap_or_else(|| tab.title.clone());
+                self.tab_title_edit = Some((tab_id, current));
+                self.active_tab = Some(tab_id);
+                Task::batch([
+                    focus::<Message>(Id::new(TAB_TITLE_INPUT_ID)),
+                    select_all::<Message>(Id::new(TAB_TITLE_INPUT_ID)),
+                ])
+            }
+            Message::TabTitleChanged(value) => {
+                if let Some((_, title)) = self.tab_title_edit.as_mut() {
+                    *title = value;
+                }
+                Task::none()
+            }
+            Message::TabRenameSubmitted => {
+                if let Some((tab_id, title)) = self.tab_title_edit.take()
+                    && let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id)
+                {
+                    let title = title.trim();
+                    // An empty title brings back the automatic one.
+                    tab.custom_title = (!title.is_empty()).then(|| title.to_string());
+                }
+                Task::none()
+            }
+            Message::TabRenameCancelled => {
+                self.tab_title_edit = None;
+                Task::none()
+            }
+            Message::SelectAdjacentTab { forward } => {
+                if self.mode != AppMode::Main || self.tabs.is_empty() {
+                    return Task::none();
+                }
+                let count = self.tabs.len();
+                let next = match self
+                    .active_tab
+                    .and_then(|id| self.tabs.iter().position(|tab| tab.id == id))
+                {
+                    Some(position) if forward => (position + 1) % count,
+                    Some(position) => (position + count - 1) % count,
+                    None => 0,
+                };
+                let tab_id = self.tabs[next].id;
+                self.update(Message::TabSelected(tab_id))
+            }
+            Message::CloseActiveTab => match self.active_tab {
+                Some(active_id) => self.update(Message::TabClosed(active_id)),
+                None => Task::none(),
+            },
+            Message::SideBySideOpen(tab_id) => {
+                let Some(active_id) = self.active_tab.filter(|id| *id != tab_id) else {
+                    return Task::none();
+                };
+                if !self.tabs.iter().any(|tab| tab.id == tab_id) {
+                    return Task::none();
+                }
+                log::debug!("Side by side opened left={} right={}", active_id, tab_id);
+                self.side_by_side = Some(SideBySideState::new(active_id, tab_id));
+                Task::batch([
+                    self.restore_response_scroll_for_tab(active_id),
+                    self.restore_response_scroll_for_tab(tab_id),
+                ])
+            }
+            Message::SideBySideClose => {
+                self.side_by_side = None;
+                self.restore_active_response_scroll()
+            }
+            Message::SideBySideResized(event) => {
+                if let Some(state) = self.side_by_side.as_mut() {
+                    state.panes.resize(event.split, event.ratio);
+                }
+                Task::none()
+            }
+            Message::SideBySideSyncScrollToggled(value) => {
+                if let Some(state) = self.side_by_side.as_mut() {
+                    state.sync_scroll = value;
+                }
+                Task::none()
+            }
+            Message::TabOpenExternal(tab_id) => {
+                let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let Some(content) = tab
+                    .collection
+                    .last_result
+                    .as_ref()
+                    .and_then(TextResultView::from_query_result)
+                    .map(|text_result| text_result.as_json_list())
+                else {
+                    return Task::none();
+                };
+
+                let name: String = tab
+                    .collection
+                    .collection
+                    .chars()
+                    .map(|ch| if ch.is_alphanumeric() || ch == '-' { ch } else { '_' })
+                    .collect();
+                let file_name = format!(
+                    "{name}-{tab_id}-{}.json",
+                    chrono::Local::now().format("%Y%m%d-%H%M%S")
+                );
+                Task::perform(
+                    async move {
+                        let dir = external_response_dir();
+                        std::fs::create_dir_all(&dir).map_err(|error| error.to_string())?;
+                        let path = dir.join(file_name);
+                        std::fs::write(&path, content).map_err(|error| error.to_string())?;
+                        open_with_system_handler(&path)
+                    },
+                    Message::TabOpenExternalCompleted,
+                )
+            }
+            Message::TabOpenExternalCompleted(result) => {
+                if let Err(error) = result {
+                    log::error!("Failed to open response in external editor: {error}");
                 }
                 Task::none()
             }
@@ -1792,6 +3626,12 @@ impl App {
                 self.duplicate_collection_tab(tab_id);
                 Task::none()
             }
+            Message::TabPinToggled(tab_id) => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.pinned = !tab.pinned;
+                }
+                Task::none()
+            }
             Message::TabColorPickerOpened(tab_id) => {
                 self.tab_color_picker = Some(tab_id);
                 Task::none()
@@ -1829,13 +3669,33 @@ impl App {
                         }
                         self.window_size = Some(size);
                     }
+                    window::Event::CloseRequested => {
+                        self.save_session();
+                        return iced::exit();
+                    }
                     _ => {}
                 }
                 Task::none()
             }
             Message::KeyboardEvent(event) => {
+                if let keyboard::Event::ModifiersChanged(modifiers) = &event {
+                    self.keyboard_modifiers = *modifiers;
+                }
                 if let keyboard::Event::KeyPressed { key, modifiers, .. } = event {
-                    if let Some(message) = Self::handle_hotkey(key, modifiers) {
+                    if self.mode == AppMode::ConnectionForm
+                        && let Some(message) = connection_form_key_binding(&key)
+                    {
+                        return self.update(message);
+                    }
+                    if matches!(key.as_ref(), keyboard::Key::Named(key::Named::Escape))
+                        && self.mode != AppMode::Main
+                    {
+                        return match self.modal_cancel_message() {
+                            Some(message) => self.update(message),
+                            None => Task::none(),
+                        };
+                    }
+                    if let Some(message) = self.handle_hotkey(key, modifiers) {
                         return self.update(message);
                     }
                 }
@@ -1846,33 +3706,82 @@ impl App {
                 Task::none()
             }
             Message::ConnectionCompleted { client_id, result } => {
+                if self.apply_connection_result(client_id, result) {
+                    self.database_sizes_task(client_id)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::DatabaseSizesLoaded { client_id, result } => {
+                let sizes: HashMap<String, u64> = match result {
+                    Ok(sizes) => sizes.into_iter().collect(),
+                    Err(error) => {
+                        log::error!("{error}");
+                        return Task::none();
+                    }
+                };
+                let mode = self.settings.tree_sort_mode;
                 if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
-                    match result {
-                        Ok(ConnectionBootstrap { handle, mut databases, ssh_tunnel }) => {
-                            databases.sort_unstable();
-                            log::debug!(
-                                "Connection established client_id={} databases={}",
-                                client_id,
-                                databases.len()
-                            );
-                            client.status = ConnectionStatus::Ready;
-                            client.handle = Some(handle);
-                            client.databases =
-                                databases.into_iter().map(DatabaseNode::new).collect();
-                            client.expanded = true;
-                            client.ssh_tunnel = ssh_tunnel;
-                        }
-                        Err(error) => {
-                            log::error!("{error}");
-                            client.status = ConnectionStatus::Failed(error);
-                            client.databases.clear();
-                            client.handle = None;
-                            client.ssh_tunnel = None;
-                        }
+                    for database in &mut client.databases {
+                        database.size = sizes.get(&database.name).copied();
                     }
+                    sort_database_nodes(&mut client.databases, mode);
                 }
                 Task::none()
             }
+            Message::CollectionSizesLoaded { client_id, db_name, sizes } => {
+                let sizes: HashMap<String, u64> = sizes.into_iter().collect();
+                let mode = self.settings.tree_sort_mode;
+                if let Some(database) = self
+                    .clients
+                    .iter_mut()
+                    .find(|c| c.id == client_id)
+                    .and_then(|client| client.databases.iter_mut().find(|d| d.name == db_name))
+                {
+                    for collection in &mut database.collections {
+                        collection.size = sizes.get(&collection.name).copied();
+                    }
+                    sort_collection_nodes(&mut database.collections, mode);
+                }
+                Task::none()
+            }
+            Message::CollectionCountsLoaded { client_id, db_name, counts } => {
+                let counts: HashMap<String, u64> = counts.into_iter().collect();
+                if let Some(database) = self
+                    .clients
+                    .iter_mut()
+                    .find(|c| c.id == client_id)
+                    .and_then(|client| client.databases.iter_mut().find(|d| d.name == db_name))
+                {
+                    for collection in &mut database.collections {
+                        collection.count = counts.get(&collection.name).copied();
+                    }
+                }
+                Task::none()
+            }
+            Message::QueryReconnectCompleted { tab_id, client_id, write_confirmed, result } => {
+                if let Err(error) = &result
+                    && let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id)
+                {
+                    tab.collection.set_tree_error(error.clone());
+                    tab.collection.query_in_progress = false;
+                }
+                let still_waiting = self
+                    .tabs
+                    .iter()
+                    .any(|tab| tab.id == tab_id && tab.collection.query_in_progress);
+                if self.apply_connection_result(client_id, result) {
+                    if !still_waiting {
+                        return self.database_sizes_task(client_id);
+                    }
+                    Task::batch([
+                        self.start_collection_query(tab_id, write_confirmed),
+                        self.database_sizes_task(client_id),
+                    ])
+                } else {
+                    Task::none()
+                }
+            }
             Message::ToggleClient(client_id) => {
                 if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
                     client.expanded = !client.expanded;
@@ -1941,7 +3850,10 @@ impl App {
                     }
                 }
                 self.sync_known_collections_for_tabs(client_id, &db_name);
-                Task::none()
+                Task::batch([
+                    self.collection_counts_task(client_id, db_name.clone()),
+                    self.collection_sizes_task(client_id, db_name),
+                ])
             }
             Message::ConnectionContextMenu { client_id, action } => match action {
                 ConnectionContextAction::CreateDatabase => {
@@ -1982,7 +3894,17 @@ impl App {
 
                 if is_double {
                     self.last_collection_click = None;
-                    let tab_id = self.open_collection_tab(client_id, db_name, collection);
+                    let reusable_tab =
+                        self.active_tab.filter(|_| self.settings.reuse_unpinned_tab).filter(|id| {
+                            self.tabs.iter().any(|tab| tab.id == *id && tab.is_reusable())
+                        });
+                    let tab_id = match reusable_tab {
+                        Some(tab_id) => {
+                            self.replace_collection_tab(tab_id, client_id, db_name, collection);
+                            tab_id
+                        }
+                        None => self.open_collection_tab(client_id, db_name, collection),
+                    };
                     self.schedule_collection_editor_focus(tab_id)
                 } else {
                     self.last_collection_click =
@@ -1993,7 +3915,18 @@ impl App {
             Message::FocusCollectionEditor(tab_id) => self.focus_collection_editor(tab_id),
             Message::CollectionEditorAction { tab_id, action } => {
                 if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    let is_edit = action.is_edit();
                     tab.collection.editor.perform(action);
+                    if is_edit {
+                        tab.collection.modified = true;
+                        tab.collection.refresh_query_preview();
+                    }
+                }
+                Task::none()
+            }
+            Message::CollectionQueryPreviewToggled(tab_id) => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.toggle_query_preview();
                 }
                 Task::none()
             }
@@ -2005,6 +3938,58 @@ impl App {
                 }
                 Task::none()
             }
+            Message::CollectionExtendedJsonCopy { tab_id } => {
+                match self
+                    .tabs
+                    .iter()
+                    .find(|tab| tab.id == tab_id)
+                    .and_then(|tab| tab.collection.extended_json.clone())
+                {
+                    Some(json) => clipboard::write(json),
+                    None => Task::none(),
+                }
+            }
+            Message::CollectionCopyAsNode(tab_id) => {
+                let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let collection = &tab.collection;
+                let code = collection.parse_query(&collection.editor.text()).and_then(
+                    |(collection_name, operation)| {
+                        shell::format_node_driver_call(
+                            &collection.db_name,
+                            &collection_name,
+                            &operation,
+                        )
+                    },
+                );
+                match code {
+                    Ok(code) => {
+                        self.status_notice =
+                            Some(String::from(tr("Query copied as a Node.js driver call.")));
+                        clipboard::write(code)
+                    }
+                    Err(error) => {
+                        self.status_notice =
+                            Some(tr_format("Cannot copy the query as Node.js: {}", &[&error]));
+                        Task::none()
+                    }
+                }
+            }
+            Message::CollectionResponseViewModeSelected { tab_id, mode } => {
+                self.set_response_view_mode(tab_id, mode)
+            }
+            Message::CollectionTextCopyMarkdown { tab_id } => {
+                match self
+                    .tabs
+                    .iter()
+                    .find(|tab| tab.id == tab_id)
+                    .and_then(|tab| tab.collection.results_markdown_table())
+                {
+                    Some(table) => clipboard::write(table),
+                    None => Task::none(),
+                }
+            }
             Message::DatabaseContextMenu { client_id, db_name, action } => match action {
                 DatabaseContextAction::OpenEmptyTab => {
                     let tab_id = self.open_database_empty_tab(client_id, db_name);
@@ -2043,6 +4028,24 @@ impl App {
                         let focus_task = self.schedule_collection_editor_focus(tab_id);
                         Task::batch([query_task, focus_task])
                     }
+                    CollectionContextAction::SampleDocuments => {
+                        let tab_id = self.open_collection_tab(
+                            client_id,
+                            db_name.clone(),
+                            collection.clone(),
+                        );
+                        if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                            let query = format!(
+                                "db.getCollection('{collection_name}').aggregate([{{ $sample: {{ size: {size} }} }}])",
+                                collection_name = collection,
+                                size = tab.collection.limit_value()
+                            );
+                            tab.collection.editor = TextEditorContent::with_text(&query);
+                        }
+                        let query_task = self.collection_query_task(tab_id);
+                        let focus_task = self.schedule_collection_editor_focus(tab_id);
+                        Task::batch([query_task, focus_task])
+                    }
                     CollectionContextAction::ChangeStream => {
                         let tab_id = self.open_collection_tab(
                             client_id,
@@ -2090,6 +4093,17 @@ impl App {
                         self.mode = AppMode::CollectionModal;
                         Task::none()
                     }
+                    CollectionContextAction::CountDocuments => {
+                        let operation = QueryOperation::CountDocuments {
+                            filter: Document::new(),
+                            options: None,
+                        };
+                        self.collection_count_task(client_id, db_name, collection, operation)
+                    }
+                    CollectionContextAction::EstimatedCount => {
+                        let operation = QueryOperation::EstimatedDocumentCount { options: None };
+                        self.collection_count_task(client_id, db_name, collection, operation)
+                    }
                     CollectionContextAction::Stats => {
                         let tab_id = self.open_collection_stats_tab(
                             client_id,
@@ -2106,8 +4120,106 @@ impl App {
                         );
                         Task::none()
                     }
-                    CollectionContextAction::Indexes => {
-                        let tab_id = self.open_collection_indexes_tab(
+                    CollectionContextAction::TextSearch => {
+                        self.text_search_modal = Some(TextSearchModalState {
+                            client_id,
+                            db_name: db_name.clone(),
+                            collection: collection.clone(),
+                            terms: String::new(),
+                            text_index: None,
+                        });
+                        self.mode = AppMode::TextSearchModal;
+                        let message_db = db_name.clone();
+                        let message_collection = collection.clone();
+                        self.load_collection_indexes_task(
+                            client_id,
+                            db_name,
+                            collection,
+                            move |result| Message::TextSearchIndexesLoaded {
+                                client_id,
+                                db_name: message_db,
+                                collection: message_collection,
+                                result,
+                            },
+                        )
+                    }
+                    CollectionContextAction::ValidationRules => {
+                        self.document_modal = Some(DocumentModalState::new_validation(
+                            client_id,
+                            db_name.clone(),
+                            collection.clone(),
+                        ));
+                        self.mode = AppMode::DocumentModal;
+                        let message_db = db_name.clone();
+                        let message_collection = collection.clone();
+                        self.load_collection_options_task(
+                            client_id,
+                            db_name,
+                            collection,
+                            move |result| Message::ValidationRulesLoaded {
+                                client_id,
+                                db_name: message_db,
+                                collection: message_collection,
+                                result,
+                            },
+                        )
+                    }
+                    CollectionContextAction::TogglePin => {
+                        if let Some(client) =
+                            self.clients.iter_mut().find(|client| client.id == client_id)
+                        {
+                            client.entry.toggle_collection_pin(&db_name, &collection);
+                            let name = client.entry.name.clone();
+                            let pins = client.entry.pinned_collections.clone();
+                            self.store_collection_pins(&name, pins);
+                        }
+                        Task::none()
+                    }
+                    CollectionContextAction::InsertDocument => {
+                        let tab_id = self
+                            .active_tab
+                            .into_iter()
+                            .chain(self.tabs.iter().map(|tab| tab.id))
+                            .find(|tab_id| {
+                                self.tabs.iter().any(|tab| {
+                                    tab.id == *tab_id
+                                        && tab.collection.client_id == client_id
+                                        && tab.collection.db_name == db_name
+                                        && tab.collection.collection == collection
+                                })
+                            });
+                        self.document_modal = Some(DocumentModalState::new_insert(
+                            tab_id, client_id, db_name, collection,
+                        ));
+                        self.mode = AppMode::DocumentModal;
+                        Task::none()
+                    }
+                    CollectionContextAction::InsertTemplate => {
+                        let message_db = db_name.clone();
+                        let message_collection = collection.clone();
+                        self.load_collection_options_task(
+                            client_id,
+                            db_name,
+                            collection,
+                            move |result| Message::InsertTemplateLoaded {
+                                client_id,
+                                db_name: message_db,
+                                collection: message_collection,
+                                result,
+                            },
+                        )
+                    }
+                    CollectionContextAction::IndexBuilder => {
+                        self.index_builder_modal = Some(IndexBuilderModalState::new(
+                            client_id,
+                            db_name.clone(),
+                            collection.clone(),
+                        ));
+                        self.mode = AppMode::IndexBuilderModal;
+                        self.index_builder_load_indexes_task(client_id, db_name, collection)
+                    }
+                    CollectionContextAction::Indexes => {
+                        let tab_id = self.open_collection_indexes_tab(
                             client_id,
                             db_name.clone(),
                             collection.clone(),
@@ -2124,6 +4236,13 @@ impl App {
                 }
                 Task::none()
             }
+            Message::CollectionModalToggleRecreate(value) => {
+                if let Some(modal) = self.collection_modal.as_mut() {
+                    modal.recreate_on_truncate = value;
+                    modal.error = None;
+                }
+                Task::none()
+            }
             Message::CollectionModalCancel => {
                 self.collection_modal = None;
                 self.mode = AppMode::Main;
@@ -2138,11 +4257,18 @@ impl App {
                     return Task::none();
                 }
 
+                if let Some(message) = read_only_client_error(&self.clients, modal.client_id) {
+                    log::error!("{message}");
+                    modal.error = Some(message);
+                    return Task::none();
+                }
+
                 let trimmed_input = modal.input.trim().to_string();
                 match modal.kind {
                     CollectionModalKind::CreateCollection => {
-                        if trimmed_input.is_empty() {
-                            let message = String::from(tr("Collection name cannot be empty."));
+                        if let Err(message) =
+                            validate_collection_name(&modal.db_name, &trimmed_input)
+                        {
                             log::error!("{message}");
                             modal.error = Some(message);
                             return Task::none();
@@ -2176,12 +4302,27 @@ impl App {
                             modal.error = Some(message);
                             return Task::none();
                         }
+
+                        if let Err(message) =
+                            validate_collection_name(&modal.db_name, &trimmed_input)
+                        {
+                            log::error!("{message}");
+                            modal.error = Some(message);
+                            return Task::none();
+                        }
                     }
-                    CollectionModalKind::DropIndex { ref index_name } => {
-                        if self.settings.strict_delete_confirmation && trimmed_input != *index_name
+                    CollectionModalKind::DropIndex { ref index_name, ref index_keys } => {
+                        if self.settings.strict_delete_confirmation
+                            && resolve_drop_index_target(
+                                &trimmed_input,
+                                index_name,
+                                index_keys.as_ref(),
+                            )
+                            .is_none()
                         {
-                            let message =
-                                String::from(tr("Enter the exact index name to confirm."));
+                            let message = String::from(tr(
+                                "Enter the exact index name or key specification to confirm.",
+                            ));
                             log::error!("{message}");
                             modal.error = Some(message);
                             return Task::none();
@@ -2266,14 +4407,23 @@ impl App {
                         let future_collection = collection.clone();
                         let message_db = db_name.clone();
                         let message_collection = collection.clone();
+                        let recreate = modal.recreate_on_truncate;
                         log::debug!(
-                            "Delete all documents requested db={} collection={}",
+                            "Delete all documents requested db={} collection={} recreate={}",
                             db_name,
-                            collection
+                            collection,
+                            recreate
                         );
                         let handle_task = handle.clone();
                         Task::perform(
                             async move {
+                                if recreate {
+                                    return truncate_by_recreating(
+                                        &handle_task,
+                                        &future_db,
+                                        &future_collection,
+                                    );
+                                }
                                 let database = handle_task.database(&future_db);
                                 let coll = database.collection::<Document>(&future_collection);
                                 coll.delete_many(Document::new())
@@ -2352,7 +4502,7 @@ impl App {
                             },
                         )
                     }
-                    CollectionModalKind::DropIndex { index_name } => {
+                    CollectionModalKind::DropIndex { index_name, index_keys } => {
                         let Some(tab_id_value) = origin_tab else {
                             modal.processing = false;
                             let message =
@@ -2364,7 +4514,16 @@ impl App {
 
                         let future_db = db_name.clone();
                         let future_collection = collection.clone();
-                        let future_index = index_name.clone();
+                        let future_index = if self.settings.strict_delete_confirmation {
+                            resolve_drop_index_target(
+                                &trimmed_input,
+                                &index_name,
+                                index_keys.as_ref(),
+                            )
+                            .unwrap_or_else(|| Bson::String(index_name.clone()))
+                        } else {
+                            Bson::String(index_name.clone())
+                        };
                         let message_db = db_name.clone();
                         let message_collection = collection.clone();
                         let message_index = index_name.clone();
@@ -2372,7 +4531,7 @@ impl App {
                             "Drop index requested db={} collection={} index={}",
                             db_name,
                             collection,
-                            index_name
+                            future_index
                         );
                         let handle_task = handle.clone();
 
@@ -2618,6 +4777,12 @@ impl App {
                     return Task::none();
                 }
 
+                if let Some(message) = read_only_client_error(&self.clients, modal.client_id) {
+                    log::error!("{message}");
+                    modal.error = Some(message);
+                    return Task::none();
+                }
+
                 let client_id = modal.client_id;
                 match &modal.mode {
                     DatabaseModalMode::Drop { db_name } => {
@@ -2685,6 +4850,14 @@ impl App {
                             return Task::none();
                         }
 
+                        if let Err(message) = validate_database_name(db_name_input).and_then(|_| {
+                            validate_collection_name(db_name_input, collection_name_input)
+                        }) {
+                            log::error!("{message}");
+                            modal.error = Some(message);
+                            return Task::none();
+                        }
+
                         let (handle, exists) = self
                             .clients
                             .iter()
@@ -2788,10 +4961,21 @@ impl App {
             }
             Message::DocumentModalEditorAction(action) => {
                 if let Some(modal) = self.document_modal.as_mut() {
+                    if action.is_edit() {
+                        modal.id_change_confirmed = false;
+                    }
                     modal.editor.perform(action);
                 }
                 Task::none()
             }
+            Message::DocumentModalToggleIdChange(value) => {
+                if let Some(modal) = self.document_modal.as_mut() {
+                    modal.allow_id_change = value;
+                    modal.id_change_confirmed = false;
+                    modal.error = None;
+                }
+                Task::none()
+            }
             Message::DocumentModalCancel => {
                 self.document_modal = None;
                 self.mode = AppMode::Main;
@@ -2806,6 +4990,12 @@ impl App {
                     return Task::none();
                 }
 
+                if let Some(message) = read_only_client_error(&self.clients, modal.client_id) {
+                    log::error!("{message}");
+                    modal.error = Some(message);
+                    return Task::none();
+                }
+
                 let editor_text = modal.editor.text().to_string();
                 let document = match shell::parse_shell_json_value(&editor_text) {
                     Ok(value) => {
@@ -2848,6 +5038,24 @@ impl App {
                     return Task::none();
                 };
 
+                if let DocumentModalKind::CollectionDocument { original_id, .. } = &modal.kind
+                    && document.get("_id").is_some_and(|id| id != original_id)
+                {
+                    if !modal.allow_id_change {
+                        let message = String::from(tr(
+                            "The _id field cannot be changed in place. Enable \"Allow changing _id\" to replace the document.",
+                        ));
+                        log::error!("{message}");
+                        modal.error = Some(message);
+                        return Task::none();
+                    }
+                    if !modal.id_change_confirmed {
+                        modal.id_change_confirmed = true;
+                        modal.error = None;
+                        return Task::none();
+                    }
+                }
+
                 modal.processing = true;
                 modal.error = None;
 
@@ -2858,6 +5066,7 @@ impl App {
 
                 match kind {
                     DocumentModalKind::CollectionDocument { filter, original_id } => {
+                        let id_changed = document.get("_id").is_some_and(|id| *id != original_id);
                         let mut replacement = document.clone();
                         if !replacement.contains_key("_id") {
                             replacement.insert("_id", original_id);
@@ -2874,6 +5083,27 @@ impl App {
                         let db_name_clone = db_name.clone();
                         let collection_clone = collection_name.clone();
 
+                        if id_changed {
+                            log::debug!(
+                                "Document _id change requested db={} collection={}",
+                                db_name,
+                                collection_name
+                            );
+                            return Task::perform(
+                                async move {
+                                    let collection = handle_task
+                                        .database(&db_name_clone)
+                                        .collection::<Document>(&collection_clone);
+                                    replace_document_with_new_id(
+                                        &collection,
+                                        filter_clone,
+                                        replacement_clone,
+                                    )
+                                },
+                                move |result| Message::DocumentModalCompleted { tab_id, result },
+                            );
+                        }
+
                         Task::perform(
                             async move {
                                 let collection = handle_task
@@ -2893,6 +5123,54 @@ impl App {
                             move |result| Message::DocumentModalCompleted { tab_id, result },
                         )
                     }
+                    DocumentModalKind::NewDocument => {
+                        log::debug!(
+                            "Document insert requested db={} collection={}",
+                            db_name,
+                            collection_name
+                        );
+                        Task::perform(
+                            async move {
+                                let collection = handle
+                                    .database(&db_name)
+                                    .collection::<Document>(&collection_name);
+                                let result = collection
+                                    .insert_one(document)
+                                    .run()
+                                    .map_err(|error| error.to_string())?;
+                                Ok(doc! { "_id": result.inserted_id })
+                            },
+                            move |result| Message::DocumentModalCompleted { tab_id, result },
+                        )
+                    }
+                    DocumentModalKind::Validation => {
+                        let command = match validation_coll_mod_command(&collection_name, &document)
+                        {
+                            Ok(command) => command,
+                            Err(error) => {
+                                modal.processing = false;
+                                log::error!("{error}");
+                                modal.error = Some(error);
+                                return Task::none();
+                            }
+                        };
+
+                        log::debug!(
+                            "Validation rules update requested db={} collection={}",
+                            db_name,
+                            collection_name
+                        );
+                        Task::perform(
+                            async move {
+                                handle
+                                    .database(&db_name)
+                                    .run_command(command)
+                                    .run()
+                                    .map_err(|error| error.to_string())
+                            },
+                            move |result| Message::DocumentModalCompleted { tab_id, result },
+                        )
+                    }
                     DocumentModalKind::Index { name } => {
                         let index_doc = document.clone();
                         let Some(name_value) = index_doc
@@ -2947,10 +5225,22 @@ impl App {
                 }
             }
             Message::DocumentModalCompleted { tab_id, result } => match result {
-                Ok(_) => {
+                Ok(document) => {
+                    let inserted = self
+                        .document_modal
+                        .as_ref()
+                        .is_some_and(|modal| matches!(modal.kind, DocumentModalKind::NewDocument));
+                    if inserted && let Some(id) = document.get("_id") {
+                        let id = shell::format_bson_shell(id);
+                        self.status_notice =
+                            Some(tr_format("Inserted document with _id {}", &[id.as_str()]));
+                    }
                     self.document_modal = None;
                     self.mode = AppMode::Main;
-                    return self.collection_query_task(tab_id);
+                    match tab_id {
+                        Some(tab_id) => self.collection_query_task(tab_id),
+                        None => Task::none(),
+                    }
                 }
                 Err(error) => {
                     if let Some(modal) = self.document_modal.as_mut() {
@@ -2961,200 +5251,892 @@ impl App {
                     Task::none()
                 }
             },
+            Message::ValidationRulesLoaded { client_id, db_name, collection, result } => {
+                let Some(modal) = self.document_modal.as_mut().filter(|modal| {
+                    matches!(modal.kind, DocumentModalKind::Validation)
+                        && modal.client_id == client_id
+                        && modal.db_name == db_name
+                        && modal.collection == collection
+                }) else {
+                    return Task::none();
+                };
+
+                modal.processing = false;
+                match result {
+                    Ok(options) => {
+                        let rules = validation_rules_document(&options);
+                        let text = shell::format_bson_shell(&Bson::Document(rules));
+                        modal.editor = TextEditorContent::with_text(&text);
+                    }
+                    Err(error) => {
+                        log::error!("{error}");
+                        modal.error = Some(error);
+                    }
+                }
+                Task::none()
+            }
+            Message::InsertTemplateLoaded { client_id, db_name, collection, result } => {
+                let options = result.unwrap_or_else(|error| {
+                    log::error!("{error}");
+                    Document::new()
+                });
+                let validator = options.get_document("validator").ok();
+                let template =
+                    insert_template_query(&collection, &schema_insert_template(validator));
+                let tab_id = self.open_collection_tab(client_id, db_name, collection);
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.editor = TextEditorContent::with_text(&template);
+                }
+                Task::none()
+            }
             Message::ValueEditModalEditorAction(action) => {
                 if let Some(modal) = self.value_edit_modal.as_mut() {
                     modal.apply_editor_action(action);
                 }
                 Task::none()
             }
+            Message::ValueEditModalBinarySubtypeChanged(subtype) => {
+                if let Some(modal) = self.value_edit_modal.as_mut() {
+                    modal.set_binary_subtype(subtype);
+                }
+                Task::none()
+            }
+            Message::ValueEditModalBinaryFormatChanged(format) => {
+                if let Some(modal) = self.value_edit_modal.as_mut() {
+                    modal.set_binary_format(format);
+                }
+                Task::none()
+            }
+            Message::ValueEditModalTypeChanged(choice) => {
+                if let Some(modal) = self.value_edit_modal.as_mut() {
+                    modal.set_forced_kind(choice.0);
+                }
+                Task::none()
+            }
             Message::ValueEditModalCancel => {
                 self.value_edit_modal = None;
                 self.mode = AppMode::Main;
                 Task::none()
             }
-            Message::ValueEditModalSave => {
-                let Some(modal) = self.value_edit_modal.as_mut() else {
+            Message::DocumentDiffModalToggleUnchanged(value) => {
+                if let Some(modal) = self.document_diff_modal.as_mut() {
+                    modal.show_unchanged = value;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderFieldChanged(index, value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    if let Some(key) = modal.spec.keys.get_mut(index) {
+                        key.0 = value;
+                    }
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderKindChanged(index, kind) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    if let Some(key) = modal.spec.keys.get_mut(index) {
+                        key.1 = kind;
+                    }
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderAddKey => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.keys.push((String::new(), IndexKeyKind::Ascending));
+                }
+                Task::none()
+            }
+            Message::IndexBuilderRemoveKey(index) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    if modal.spec.keys.len() > 1 && index < modal.spec.keys.len() {
+                        modal.spec.keys.remove(index);
+                    }
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderNameChanged(value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.name = value;
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderUniqueToggled(value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.unique = value;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderSparseToggled(value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.sparse = value;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderTtlChanged(value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.ttl_seconds = value;
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderPartialFilterChanged(value) => {
+                if let Some(modal) = self.index_builder_modal.as_mut() {
+                    modal.spec.partial_filter = value;
+                    modal.error = None;
+                }
+                Task::none()
+            }
+            Message::IndexBuilderSubmit { run } => {
+                let Some(modal) = self.index_builder_modal.as_mut() else {
                     return Task::none();
                 };
-
-                if modal.processing {
-                    return Task::none();
-                }
-
-                let new_value = match modal.prepare_value() {
-                    Ok(value) => value,
+                let query = match modal.spec.to_shell(&modal.collection) {
+                    Ok(query) => query,
                     Err(error) => {
-                        log::error!("{error}");
                         modal.error = Some(error);
                         return Task::none();
                     }
                 };
 
-                let Some(handle) = self
-                    .clients
-                    .iter()
-                    .find(|client| client.id == modal.client_id)
-                    .and_then(|client| client.handle.clone())
-                else {
-                    let message = String::from(tr("No active connection."));
-                    log::error!("{message}");
-                    modal.error = Some(message);
-                    return Task::none();
-                };
-
-                let mut set_doc = Document::new();
-                set_doc.insert(modal.path.clone(), new_value);
-
-                let mut update_doc = Document::new();
-                update_doc.insert("$set", Bson::Document(set_doc));
-
-                modal.processing = true;
-                modal.error = None;
-
-                let tab_id = modal.tab_id;
+                let client_id = modal.client_id;
                 let db_name = modal.db_name.clone();
-                let collection_name = modal.collection.clone();
-                let filter_clone = modal.filter.clone();
-                let update_clone = update_doc.clone();
-                let handle_task = handle.clone();
+                let collection = modal.collection.clone();
+                self.index_builder_modal = None;
+                self.mode = AppMode::Main;
 
-                log::debug!(
-                    "Value edit requested db={} collection={} path={}",
-                    db_name,
-                    collection_name,
-                    modal.path
-                );
-                Task::perform(
-                    async move {
-                        let collection =
-                            handle_task.database(&db_name).collection::<Document>(&collection_name);
-                        let result = collection
-                            .find_one_and_update(filter_clone, update_clone)
-                            .return_document(ReturnDocument::After)
-                            .run()
-                            .map_err(|error| error.to_string())?;
-                        result.ok_or_else(|| {
+                let tab_id = self.open_collection_tab(client_id, db_name, collection.clone());
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.editor = TextEditorContent::with_text(&query);
+                    tab.title = format!("{} createIndex", collection);
+                }
+
+                if run {
+                    self.collection_query_task(tab_id)
+                } else {
+                    self.schedule_collection_editor_focus(tab_id)
+                }
+            }
+            Message::IndexBuilderIndexesLoaded { client_id, db_name, collection, result } => {
+                if let Some(modal) = self.index_builder_modal.as_mut()
+                    && modal.client_id == client_id
+                    && modal.db_name == db_name
+                    && modal.collection == collection
+                {
+                    if let Err(error) = &result {
+                        log::warn!("Failed to load indexes for index builder: {error}");
+                    }
+                    modal.existing_indexes = Some(result);
+                }
+                Task::none()
+            }
+            Message::AggregateWriteConfirm => {
+                self.mode = AppMode::Main;
+                match self.aggregate_write_modal.take() {
+                    Some(modal) => self.start_collection_query(modal.tab_id, true),
+                    None => Task::none(),
+                }
+            }
+            Message::AggregateWriteCancel => {
+                self.aggregate_write_modal = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::DateFormatPreviewFieldChanged(value) => {
+                if let Some(state) = self.date_format_preview.as_mut() {
+                    state.field = value;
+                }
+                Task::none()
+            }
+            Message::DateFormatPreviewSampleChanged(value) => {
+                if let Some(state) = self.date_format_preview.as_mut() {
+                    state.sample = value;
+                }
+                Task::none()
+            }
+            Message::DateFormatPreviewFormatChanged(value) => {
+                if let Some(state) = self.date_format_preview.as_mut() {
+                    state.format = value;
+                }
+                Task::none()
+            }
+            Message::DateFormatPreviewTimezoneChanged(value) => {
+                if let Some(state) = self.date_format_preview.as_mut() {
+                    state.timezone = value;
+                }
+                Task::none()
+            }
+            Message::DateFormatPreviewCopyExpression => match &self.date_format_preview {
+                Some(state) => clipboard::write(date_to_string_expression(
+                    &state.field,
+                    &state.format,
+                    &state.timezone,
+                )),
+                None => Task::none(),
+            },
+            Message::DateFormatPreviewClose => {
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::TextSearchIndexesLoaded { client_id, db_name, collection, result } => {
+                if let Some(modal) = self.text_search_modal.as_mut()
+                    && modal.client_id == client_id
+                    && modal.db_name == db_name
+                    && modal.collection == collection
+                {
+                    modal.text_index = Some(result.and_then(|indexes| {
+                        find_text_index(&indexes).ok_or_else(|| {
                             String::from(tr(
-                                "Document not found. It may have been deleted or the change was not applied.",
+                                "This collection has no text index. Create one to use text search.",
                             ))
                         })
-                    },
-                    move |result| Message::ValueEditModalCompleted { tab_id, result },
-                )
+                    }));
+                }
+                Task::none()
             }
-            Message::ValueEditModalCompleted { tab_id, result } => match result {
-                Ok(_) => {
-                    self.value_edit_modal = None;
-                    self.mode = AppMode::Main;
-                    return self.collection_query_task(tab_id);
+            Message::TextSearchTermsChanged(value) => {
+                if let Some(modal) = self.text_search_modal.as_mut() {
+                    modal.terms = value;
                 }
-                Err(error) => {
-                    if let Some(modal) = self.value_edit_modal.as_mut() {
-                        modal.processing = false;
-                        log::error!("{error}");
-                        modal.error = Some(error);
-                    }
-                    Task::none()
+                Task::none()
+            }
+            Message::TextSearchRun => {
+                let Some(modal) = self.text_search_modal.as_ref() else {
+                    return Task::none();
+                };
+                if !matches!(modal.text_index, Some(Ok(_))) || modal.terms.trim().is_empty() {
+                    return Task::none();
                 }
-            },
-            Message::DatabasesRefreshed { client_id, result } => {
-                if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
-                    match result {
-                        Ok(mut names) => {
-                            names.sort_unstable();
-                            client.databases = names.into_iter().map(DatabaseNode::new).collect();
-                        }
-                        Err(error) => {
-                            log::error!("{} {}", tr("Failed to refresh database list:"), error);
-                            for database in &mut client.databases {
-                                database.state = DatabaseState::Error(error.clone());
-                            }
-                        }
-                    }
+
+                let Some(modal) = self.text_search_modal.take() else {
+                    return Task::none();
+                };
+                self.mode = AppMode::Main;
+                let query = text_search_query(&modal.collection, &modal.terms);
+                let tab_id =
+                    self.open_collection_tab(modal.client_id, modal.db_name, modal.collection);
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.editor = TextEditorContent::with_text(&query);
                 }
+                let query_task = self.collection_query_task(tab_id);
+                let focus_task = self.schedule_collection_editor_focus(tab_id);
+                Task::batch([query_task, focus_task])
+            }
+            Message::TextSearchCancel => {
+                self.text_search_modal = None;
+                self.mode = AppMode::Main;
                 Task::none()
             }
-            Message::CollectionSkipChanged { tab_id, value } => {
+            Message::CollectionProjectionSelected { tab_id, choice } => {
                 if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                    tab.collection.update_skip(value);
+                    tab.collection.active_projection = match choice {
+                        ProjectionChoice::AllFields => None,
+                        ProjectionChoice::Preset(name) => Some(name),
+                    };
+                    tab.collection.refresh_query_preview();
                 }
                 Task::none()
             }
-            Message::CollectionLimitChanged { tab_id, value } => {
-                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                    tab.collection.update_limit(value);
+            Message::CollectionPipelineStageSelected { tab_id, stages } => {
+                let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                tab.collection.pipeline_stage_limit = stages;
+                tab.collection.refresh_query_preview();
+                self.collection_query_task(tab_id)
+            }
+            Message::CollectionProjectionPresetDelete(tab_id) => {
+                let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let Some(name) = tab.collection.active_projection.take() else {
+                    return Task::none();
+                };
+                let key = tab.collection.paging_key();
+                tab.collection.refresh_query_preview();
+                if let Some(presets) = self.projection_presets.get_mut(&key) {
+                    presets.retain(|preset| preset.name != name);
+                    if presets.is_empty() {
+                        self.projection_presets.remove(&key);
+                    }
                 }
+                self.store_projection_presets(&key);
                 Task::none()
             }
-            Message::CollectionPaneResized { tab_id, split, ratio } => {
-                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                    tab.collection.resize_split(split, ratio);
+            Message::ProjectionPresetModalOpen(tab_id) => {
+                let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let (name, fields) = tab
+                    .collection
+                    .active_projection_preset()
+                    .map(|preset| (preset.name.clone(), preset.fields.join(", ")))
+                    .unwrap_or_default();
+                self.projection_preset_modal =
+                    Some(ProjectionPresetModalState { tab_id, name, fields, error: None });
+                self.mode = AppMode::ProjectionPresetModal;
+                Task::none()
+            }
+            Message::ProjectionPresetNameChanged(value) => {
+                if let Some(modal) = self.projection_preset_modal.as_mut() {
+                    modal.name = value;
+                    modal.error = None;
                 }
                 Task::none()
             }
-            Message::CollectionTableScrolled { tab_id, offset_y } => {
-                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                    tab.collection.table_scroll_offset = offset_y.clamp(0.0, 1.0);
+            Message::ProjectionPresetFieldsChanged(value) => {
+                if let Some(modal) = self.projection_preset_modal.as_mut() {
+                    modal.fields = value;
+                    modal.error = None;
                 }
                 Task::none()
             }
-            Message::CollectionTextScrolled { tab_id, offset_y } => {
-                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                    tab.collection.text_scroll_offset = offset_y.clamp(0.0, 1.0);
+            Message::ProjectionPresetSave => {
+                let Some(modal) = self.projection_preset_modal.as_mut() else {
+                    return Task::none();
+                };
+                let name = modal.name.trim().to_string();
+                if name.is_empty() {
+                    modal.error = Some(String::from(tr("Preset name cannot be empty.")));
+                    return Task::none();
                 }
+                let fields = match parse_projection_fields(&modal.fields) {
+                    Ok(fields) => fields,
+                    Err(error) => {
+                        modal.error = Some(error);
+                        return Task::none();
+                    }
+                };
+                let tab_id = modal.tab_id;
+                self.projection_preset_modal = None;
+                self.mode = AppMode::Main;
+
+                let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let key = tab.collection.paging_key();
+                tab.collection.active_projection = Some(name.clone());
+                let presets = self.projection_presets.entry(key.clone()).or_default();
+                let preset = ProjectionPreset { name, fields };
+                match presets.iter_mut().find(|existing| existing.name == preset.name) {
+                    Some(existing) => *existing = preset,
+                    None => presets.push(preset),
+                }
+                self.store_projection_presets(&key);
                 Task::none()
             }
-            Message::TableContextMenu { tab_id, node_id, action } => match action {
-                TableContextAction::EditValue => {
-                    let modal_state =
-                        self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
-                            tab.collection.value_edit_context(node_id).map(|context| {
-                                ValueEditModalState::new(tab_id, &tab.collection, context)
-                            })
+            Message::ProjectionPresetCancel => {
+                self.projection_preset_modal = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::SavedQueriesOpen => {
+                let target_tab =
+                    self.active_tab.filter(|id| self.tabs.iter().any(|tab| tab.id == *id));
+                self.saved_queries_window = Some(SavedQueriesWindowState::new(target_tab));
+                self.mode = AppMode::SavedQueries;
+                Task::none()
+            }
+            Message::SavedQueriesSelect(index) => {
+                let mut is_double = false;
+                if let Some(state) = self.saved_queries_window.as_mut() {
+                    if index < self.saved_queries.len() {
+                        let now = Instant::now();
+                        is_double = state.last_click.is_some_and(|last| {
+                            last.index == index
+                                && now.duration_since(last.at) <= DOUBLE_CLICK_INTERVAL
                         });
-
-                    if let Some(state) = modal_state {
-                        self.value_edit_modal = Some(state);
-                        self.mode = AppMode::ValueEditModal;
+                        state.selected = Some(index);
+                        state.confirm_delete = false;
+                        state.feedback = None;
+                        state.last_click =
+                            if is_double { None } else { Some(ListClick { index, at: now }) };
                     }
-
-                    Task::none()
                 }
-                TableContextAction::GoToRelatedDocument => {
-                    if let Some((client_id, db_name, collection, id_value)) =
-                        self.resolve_related_document_target(tab_id, node_id)
-                    {
-                        return self
-                            .open_related_document_tab(client_id, db_name, collection, id_value);
+                if is_double {
+                    return self.update(Message::SavedQueriesLoad);
+                }
+                Task::none()
+            }
+            Message::SavedQueriesLoad => {
+                let Some((index, tab_id)) = self
+                    .saved_queries_window
+                    .as_ref()
+                    .and_then(|state| state.selected.zip(state.target_tab))
+                else {
+                    return Task::none();
+                };
+                let Some(query) = self.saved_queries.get(index).cloned() else {
+                    return Task::none();
+                };
+                let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let collection = &mut tab.collection;
+                let text = query.render(&collection.db_name, &collection.collection);
+                collection.editor = TextEditorContent::with_text(&text);
+                if query.targets_other_collection(&collection.db_name, &collection.collection) {
+                    self.status_notice = Some(tr_format(
+                        "Loaded a query saved for {}.",
+                        &[&query.target_label().unwrap_or_default()],
+                    ));
+                }
+                self.saved_queries_window = None;
+                self.mode = AppMode::Main;
+                self.schedule_collection_editor_focus(tab_id)
+            }
+            Message::SavedQueriesEdit => {
+                let Some(query) = self
+                    .saved_queries_window
+                    .as_ref()
+                    .and_then(|state| state.selected)
+                    .and_then(|index| self.saved_queries.get(index))
+                else {
+                    return Task::none();
+                };
+                self.saved_query_form = Some(SavedQueryFormState::edit(query));
+                self.mode = AppMode::SavedQueryForm;
+                Task::none()
+            }
+            Message::SavedQueriesDelete => {
+                if let Some(state) = self.saved_queries_window.as_mut() {
+                    state.confirm_delete = state.selected.is_some();
+                }
+                Task::none()
+            }
+            Message::SavedQueriesDeleteConfirmed => {
+                let Some(state) = self.saved_queries_window.as_mut() else {
+                    return Task::none();
+                };
+                state.confirm_delete = false;
+                if let Some(index) = state.selected.take() {
+                    if index < self.saved_queries.len() {
+                        self.saved_queries.remove(index);
                     }
-                    Task::none()
+                    state.feedback = save_saved_queries_to_disk(&self.saved_queries)
+                        .err()
+                        .map(|error| format!("{}{}", tr("Save error: "), error));
+                }
+                Task::none()
+            }
+            Message::SavedQueriesDeleteCancelled => {
+                if let Some(state) = self.saved_queries_window.as_mut() {
+                    state.confirm_delete = false;
+                }
+                Task::none()
+            }
+            Message::SavedQueriesClose => {
+                self.saved_queries_window = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::SavedQueryFormOpen(tab_id) => {
+                let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+                    return Task::none();
+                };
+                let collection = &tab.collection;
+                self.saved_query_form = Some(SavedQueryFormState::new(
+                    &collection.editor.text(),
+                    &collection.db_name,
+                    &collection.collection,
+                ));
+                self.mode = AppMode::SavedQueryForm;
+                Task::none()
+            }
+            Message::SavedQueryFormNameChanged(value) => {
+                if let Some(form) = self.saved_query_form.as_mut() {
+                    form.name = value;
+                    form.error = None;
+                }
+                Task::none()
+            }
+            Message::SavedQueryFormDbChanged(value) => {
+                if let Some(form) = self.saved_query_form.as_mut() {
+                    form.db_name = value;
+                    form.error = None;
+                }
+                Task::none()
+            }
+            Message::SavedQueryFormCollectionChanged(value) => {
+                if let Some(form) = self.saved_query_form.as_mut() {
+                    form.collection = value;
+                    form.error = None;
+                }
+                Task::none()
+            }
+            Message::SavedQueryFormEditorAction(action) => {
+                if let Some(form) = self.saved_query_form.as_mut() {
+                    form.editor.perform(action);
+                    form.error = None;
+                }
+                Task::none()
+            }
+            Message::SavedQueryFormSave => {
+                let Some(form) = self.saved_query_form.as_mut() else {
+                    return Task::none();
+                };
+                let query = match form.validate() {
+                    Ok(query) => query,
+                    Err(error) => {
+                        form.error = Some(error);
+                        return Task::none();
+                    }
+                };
+                let position = form.original_name.as_ref().and_then(|original| {
+                    self.saved_queries.iter().position(|saved| &saved.name == original)
+                });
+                let name_taken = self
+                    .saved_queries
+                    .iter()
+                    .enumerate()
+                    .any(|(index, saved)| saved.name == query.name && Some(index) != position);
+                if name_taken {
+                    form.error =
+                        Some(String::from(tr("A saved query with this name already exists.")));
+                    return Task::none();
                 }
-                TableContextAction::DeleteIndex => {
-                    let context = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
-                        if !tab.collection.bson_tree.is_indexes_view() {
-                            return None;
-                        }
-                        let index_name = tab.collection.bson_tree.node_index_name(node_id)?;
-                        Some((
-                            tab.collection.client_id,
-                            tab.collection.db_name.clone(),
-                            tab.collection.collection.clone(),
-                            index_name,
-                        ))
-                    });
 
-                    if let Some((client_id, db_name, collection, index_name)) = context {
-                        if index_name != "_id_" {
-                            self.collection_modal = Some(CollectionModalState::new_drop_index(
-                                tab_id, client_id, db_name, collection, index_name,
-                            ));
-                            self.mode = AppMode::CollectionModal;
-                        }
+                let from_library = form.from_library;
+                let name = query.name.clone();
+                let index = match position {
+                    Some(index) => {
+                        self.saved_queries[index] = query;
+                        index
+                    }
+                    None => {
+                        self.saved_queries.push(query);
+                        self.saved_queries.len() - 1
                     }
+                };
+                let save_error = save_saved_queries_to_disk(&self.saved_queries)
+                    .err()
+                    .map(|error| format!("{}{}", tr("Save error: "), error));
+                self.saved_query_form = None;
 
-                    Task::none()
+                if from_library && let Some(state) = self.saved_queries_window.as_mut() {
+                    state.selected = Some(index);
+                    state.feedback = save_error;
+                    self.mode = AppMode::SavedQueries;
+                } else {
+                    self.status_notice = Some(
+                        save_error.unwrap_or_else(|| tr_format("Saved query \"{}\".", &[&name])),
+                    );
+                    self.mode = AppMode::Main;
                 }
-                TableContextAction::HideIndex => {
+                Task::none()
+            }
+            Message::SavedQueryFormCancel => {
+                let from_library =
+                    self.saved_query_form.take().is_some_and(|form| form.from_library);
+                self.mode = if from_library && self.saved_queries_window.is_some() {
+                    AppMode::SavedQueries
+                } else {
+                    AppMode::Main
+                };
+                Task::none()
+            }
+            Message::IndexBuilderCancel => {
+                self.index_builder_modal = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::DocumentDiffModalClose => {
+                self.document_diff_modal = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::QueryLogFilterChanged(value) => {
+                self.query_log_filter = value;
+                Task::none()
+            }
+            Message::QueryLogEntrySelected(index) => {
+                let Some(entry) = self.query_log.get(index).cloned() else {
+                    return Task::none();
+                };
+                let tab_id =
+                    self.open_collection_tab(entry.client_id, entry.db_name, entry.collection);
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.editor = TextEditorContent::with_text(&entry.query);
+                }
+                self.schedule_collection_editor_focus(tab_id)
+            }
+            Message::QueryLogClear => {
+                self.query_log.clear();
+                Task::none()
+            }
+            Message::ValueEditModalSave => {
+                let Some(modal) = self.value_edit_modal.as_mut() else {
+                    return Task::none();
+                };
+
+                if modal.processing {
+                    return Task::none();
+                }
+
+                if let Some(message) = read_only_client_error(&self.clients, modal.client_id) {
+                    log::error!("{message}");
+                    modal.error = Some(message);
+                    return Task::none();
+                }
+
+                let new_value = match modal.prepare_value() {
+                    Ok(value) => value,
+                    Err(error) => {
+                        log::error!("{error}");
+                        modal.error = Some(error);
+                        return Task::none();
+                    }
+                };
+
+                if !modal.append
+                    && let Some(warning) = modal.unacknowledged_type_change(&new_value)
+                {
+                    log::warn!("{warning}");
+                    return Task::none();
+                }
+
+                let Some(handle) = self
+                    .clients
+                    .iter()
+                    .find(|client| client.id == modal.client_id)
+                    .and_then(|client| client.handle.clone())
+                else {
+                    let message = String::from(tr("No active connection."));
+                    log::error!("{message}");
+                    modal.error = Some(message);
+                    return Task::none();
+                };
+
+                let mut set_doc = Document::new();
+                set_doc.insert(modal.path.clone(), new_value);
+
+                let mut update_doc = Document::new();
+                let operator = if modal.append { "$push" } else { "$set" };
+                update_doc.insert(operator, Bson::Document(set_doc));
+
+                modal.processing = true;
+                modal.error = None;
+
+                let tab_id = modal.tab_id;
+                let db_name = modal.db_name.clone();
+                let collection_name = modal.collection.clone();
+                let filter_clone = modal.filter.clone();
+                let update_clone = update_doc.clone();
+                let handle_task = handle.clone();
+
+                log::debug!(
+                    "Value edit requested db={} collection={} path={}",
+                    db_name,
+                    collection_name,
+                    modal.path
+                );
+                Task::perform(
+                    async move {
+                        let collection =
+                            handle_task.database(&db_name).collection::<Document>(&collection_name);
+                        let result = collection
+                            .find_one_and_update(filter_clone, update_clone)
+                            .return_document(ReturnDocument::After)
+                            .run()
+                            .map_err(|error| error.to_string())?;
+                        result.ok_or_else(|| {
+                            String::from(tr(
+                                "Document not found. It may have been deleted or the change was not applied.",
+                            ))
+                        })
+                    },
+                    move |result| Message::ValueEditModalCompleted { tab_id, result },
+                )
+            }
+            Message::ValueEditModalCompleted { tab_id, result } => match result {
+                Ok(_) => {
+                    self.value_edit_modal = None;
+                    self.mode = AppMode::Main;
+                    return self.collection_query_task(tab_id);
+                }
+                Err(error) => {
+                    if let Some(modal) = self.value_edit_modal.as_mut() {
+                        modal.processing = false;
+                        log::error!("{error}");
+                        modal.error = Some(error);
+                    }
+                    Task::none()
+                }
+            },
+            Message::ArrayElementDeleteConfirmed => {
+                self.mode = AppMode::Main;
+                match self.array_element_delete.take() {
+                    Some(state) => self.delete_array_element_task(state.tab_id, state.context),
+                    None => Task::none(),
+                }
+            }
+            Message::ArrayElementDeleteCancelled => {
+                self.array_element_delete = None;
+                self.mode = AppMode::Main;
+                Task::none()
+            }
+            Message::ArrayElementRemoved { tab_id, result } => match result {
+                Ok(()) => self.collection_query_task(tab_id),
+                Err(error) => {
+                    log::error!("{error}");
+                    self.status_notice =
+                        Some(tr_format("Failed to delete the array element: {}", &[&error]));
+                    Task::none()
+                }
+            },
+            Message::DatabasesRefreshed { client_id, result } => {
+                if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
+                    match result {
+                        Ok(mut names) => {
+                            names.sort_unstable();
+                            client.databases = names.into_iter().map(DatabaseNode::new).collect();
+                        }
+                        Err(error) => {
+                            log::error!("{} {}", tr("Failed to refresh database list:"), error);
+                            for database in &mut client.databases {
+                                database.state = DatabaseState::Error(error.clone());
+                            }
+                            return Task::none();
+                        }
+                    }
+                }
+                self.database_sizes_task(client_id)
+            }
+            Message::CollectionSkipChanged { tab_id, value } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.update_skip(value);
+                }
+                Task::none()
+            }
+            Message::CollectionLimitChanged { tab_id, value } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.update_limit(value);
+                }
+                Task::none()
+            }
+            Message::CollectionPaneResized { tab_id, split, ratio } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.resize_split(split, ratio);
+                }
+                Task::none()
+            }
+            Message::CollectionTableScrolled { tab_id, offset_y } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.table_scroll_offset = offset_y.clamp(0.0, 1.0);
+                }
+                self.sync_side_by_side_scroll(tab_id, ResponseViewMode::Table)
+            }
+            Message::CollectionTextScrolled { tab_id, offset_y } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.text_scroll_offset = offset_y.clamp(0.0, 1.0);
+                }
+                self.sync_side_by_side_scroll(tab_id, ResponseViewMode::Text)
+            }
+            Message::TableContextMenu { tab_id, node_id, action } => match action {
+                TableContextAction::EditValue => {
+                    let modal_state =
+                        self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
+                            tab.collection.value_edit_context(node_id).map(|context| {
+                                ValueEditModalState::new(tab_id, &tab.collection, context)
+                            })
+                        });
+
+                    if let Some(state) = modal_state {
+                        self.value_edit_modal = Some(state);
+                        self.mode = AppMode::ValueEditModal;
+                    }
+
+                    Task::none()
+                }
+                TableContextAction::AppendArrayElement => {
+                    let modal_state =
+                        self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
+                            let context = tab.collection.value_edit_context(node_id)?;
+                            matches!(context.current_value, Bson::Array(_)).then(|| {
+                                ValueEditModalState::new_append(tab_id, &tab.collection, context)
+                            })
+                        });
+
+                    if let Some(state) = modal_state {
+                        self.value_edit_modal = Some(state);
+                        self.mode = AppMode::ValueEditModal;
+                    }
+
+                    Task::none()
+                }
+                TableContextAction::DeleteArrayElement => {
+                    let context = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
+                        tab.collection
+                            .value_edit_context(node_id)
+                            .filter(|context| context.in_array)
+                    });
+                    if let Some(context) = context {
+                        self.array_element_delete =
+                            Some(ArrayElementDeleteState { tab_id, context });
+                        self.mode = AppMode::ArrayElementDeleteConfirmation;
+                    }
+                    Task::none()
+                }
+                TableContextAction::GoToRelatedDocument => {
+                    if let Some((client_id, db_name, collection, id_value)) =
+                        self.resolve_related_document_target(tab_id, node_id)
+                    {
+                        return self
+                            .open_related_document_tab(client_id, db_name, collection, id_value);
+                    }
+                    Task::none()
+                }
+                TableContextAction::FindById => {
+                    let target = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
+                        let id_value = tab.collection.bson_tree.node_root_id_value(node_id)?;
+                        Some((
+                            tab.collection.client_id,
+                            tab.collection.db_name.clone(),
+                            tab.collection.collection.clone(),
+                            id_value,
+                        ))
+                    });
+                    if let Some((client_id, db_name, collection, id_value)) = target {
+                        return self
+                            .open_related_document_tab(client_id, db_name, collection, id_value);
+                    }
+                    Task::none()
+                }
+                TableContextAction::ExportJson | TableContextAction::ExportCsv => {
+                    self.export_results_task(tab_id, action)
+                }
+                TableContextAction::DeleteIndex => {
+                    let context = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
+                        if !tab.collection.bson_tree.is_indexes_view() {
+                            return None;
+                        }
+                        let index_name = tab.collection.bson_tree.node_index_name(node_id)?;
+                        let index_keys = tab.collection.bson_tree.node_index_keys(node_id);
+                        Some((
+                            tab.collection.client_id,
+                            tab.collection.db_name.clone(),
+                            tab.collection.collection.clone(),
+                            index_name,
+                            index_keys,
+                        ))
+                    });
+
+                    if let Some((client_id, db_name, collection, index_name, index_keys)) = context
+                    {
+                        if index_name != "_id_" {
+                            self.collection_modal = Some(CollectionModalState::new_drop_index(
+                                tab_id, client_id, db_name, collection, index_name, index_keys,
+                            ));
+                            self.mode = AppMode::CollectionModal;
+                        }
+                    }
+
+                    Task::none()
+                }
+                TableContextAction::HideIndex => {
                     let context = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
                         if !tab.collection.bson_tree.is_indexes_view() {
                             return None;
@@ -3174,6 +6156,10 @@ impl App {
                         if hidden {
                             return Task::none();
                         }
+                        if let Some(message) = read_only_client_error(&self.clients, client_id) {
+                            log::error!("{message}");
+                            return Task::none();
+                        }
 
                         if let Some(handle) = self
                             .clients
@@ -3235,6 +6221,10 @@ impl App {
                         if !hidden {
                             return Task::none();
                         }
+                        if let Some(message) = read_only_client_error(&self.clients, client_id) {
+                            log::error!("{message}");
+                            return Task::none();
+                        }
 
                         if let Some(handle) = self
                             .clients
@@ -3290,7 +6280,8 @@ impl App {
                 }
                 TableContextAction::ExpandHierarchyAll => {
                     if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-                        tab.collection.bson_tree.expand_all();
+                        let depth = tab.collection.expand_all_depth;
+                        tab.collection.bson_tree.expand_all(depth);
                     }
                     Task::none()
                 }
@@ -3300,7 +6291,26 @@ impl App {
                     }
                     Task::none()
                 }
-                _ => {
+                TableContextAction::SelectForCompare => {
+                    if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                        tab.collection.bson_tree.select_for_compare(node_id);
+                    }
+                    Task::none()
+                }
+                TableContextAction::CompareWithSelected => {
+                    let documents = self
+                        .tabs
+                        .iter()
+                        .find(|tab| tab.id == tab_id)
+                        .and_then(|tab| tab.collection.bson_tree.compare_documents(node_id));
+
+                    if let Some((left, right)) = documents {
+                        self.document_diff_modal = Some(DocumentDiffModalState::new(&left, &right));
+                        self.mode = AppMode::DocumentDiffModal;
+                    }
+                    Task::none()
+                }
+                _ => {
                     let content = self
                         .tabs
                         .iter()
@@ -3329,11 +6339,64 @@ impl App {
                 }
                 Task::none()
             }
+            Message::CollectionQueryCancel(tab_id) => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id)
+                    && tab.collection.query_in_progress
+                {
+                    let collection = &mut tab.collection;
+                    if let Some(cancel) = collection.query_cancel.take() {
+                        cancel.store(true, Ordering::Relaxed);
+                    }
+                    log::debug!("Query cancelled tab_id={}", tab_id);
+                    collection.query_in_progress = false;
+                    collection.query_started = None;
+                    collection.running_query = None;
+                    collection.pending_collection = None;
+                    collection.pending_write_target = None;
+                    collection.cursor_state = None;
+                    self.status_notice = Some(String::from(tr(
+                        "Query cancelled. The server may still finish the operation.",
+                    )));
+                }
+                Task::none()
+            }
+            Message::CollectionQueryDiscarded(tab_id) => {
+                log::debug!("Discarded the result of a cancelled query tab_id={}", tab_id);
+                Task::none()
+            }
+            Message::QueryProgressTick => {
+                self.query_progress_ticking = false;
+                self.query_progress_tick_task()
+            }
             Message::CollectionQueryCompleted { tab_id, result, duration } => {
+                let mut total_count_request = None;
+                let mut log_entry = None;
+                let mut written_target = None;
                 if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
                     let collection = &mut tab.collection;
                     collection.query_in_progress = false;
+                    collection.query_cancel = None;
+                    collection.query_started = None;
+                    let write_target = collection.pending_write_target.take();
+                    if result.is_ok() {
+                        written_target = write_target.map(|target| (collection.client_id, target));
+                    }
                     collection.last_query_duration = Some(duration);
+                    log_entry = collection.running_query.take().map(|query| QueryLogEntry {
+                        timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+                        client_id: collection.client_id,
+                        db_name: collection.db_name.clone(),
+                        collection: collection
+                            .pending_collection
+                            .clone()
+                            .unwrap_or_else(|| collection.collection.clone()),
+                        query,
+                        duration,
+                        outcome: match &result {
+                            Ok(query_result) => Ok(Self::query_result_metrics(query_result).1),
+                            Err(error) => Err(error.clone()),
+                        },
+                    });
                     match result {
                         Ok(query_result) => {
                             if let Some(pending) = collection.pending_collection.take() {
@@ -3352,16 +6415,88 @@ impl App {
                                 count,
                                 duration.as_secs_f64() * 1000.0
                             );
+                            let filter = collection.total_count_filter.take();
+                            if matches!(&query_result, QueryResult::Documents(values) if values.is_empty())
+                            {
+                                total_count_request = filter.map(|filter| {
+                                    (
+                                        collection.client_id,
+                                        collection.db_name.clone(),
+                                        collection.collection.clone(),
+                                        filter,
+                                    )
+                                });
+                            }
+                            if let Some(cursor) = collection.cursor_state.as_mut() {
+                                cursor.last_id = match &query_result {
+                                    QueryResult::Documents(values) => values
+                                        .last()
+                                        .and_then(Bson::as_document)
+                                        .and_then(|document| document.get("_id"))
+                                        .cloned(),
+                                    _ => None,
+                                };
+                            }
                             collection.set_query_result(query_result, &self.settings)
                         }
                         Err(error) => {
                             collection.pending_collection = None;
+                            collection.total_count_filter = None;
+                            collection.cursor_state = None;
                             collection.set_tree_error(error);
                         }
                     }
                 }
+
+                if let Some(entry) = log_entry {
+                    self.push_query_log_entry(entry);
+                }
+
+                let tree_task = match written_target {
+                    Some((client_id, target)) => self.reveal_aggregate_output(client_id, &target),
+                    None => Task::none(),
+                };
+
+                let count_task = match total_count_request {
+                    Some((client_id, db_name, collection, filter)) => self
+                        .collection_total_count_task(
+                            tab_id, client_id, db_name, collection, filter,
+                        ),
+                    None => Task::none(),
+                };
+                Task::batch([tree_task, count_task])
+            }
+            Message::CollectionCountCompleted { namespace, estimated, result } => {
+                let notice = match result {
+                    Ok(QueryResult::Count { value }) => {
+                        let count = shell::format_shell_value(&value);
+                        if estimated {
+                            tr_format("{}: about {} documents (estimated)", &[&namespace, &count])
+                        } else {
+                            tr_format("{}: {} documents", &[&namespace, &count])
+                        }
+                    }
+                    Ok(_) => tr_format("{}: unexpected count result", &[&namespace]),
+                    Err(error) => {
+                        log::error!("{error}");
+                        tr_format("Failed to count documents in {}: {}", &[&namespace, &error])
+                    }
+                };
+                self.status_notice = Some(notice);
                 Task::none()
             }
+            Message::CollectionTotalCountCompleted { tab_id, result } => match result {
+                Ok(total) => {
+                    if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                        tab.collection.total_count = Some(total);
+                    }
+                    Task::none()
+                }
+                Err(error) => {
+                    log::error!("{error}");
+                    Task::none()
+                }
+            },
             Message::AboutModalClose => {
                 self.close_about_modal();
                 Task::none()
@@ -3397,13 +6532,31 @@ impl App {
                 Task::none()
             }
             Message::ConnectionsSelect(index) => {
+                let mut is_double = false;
                 if let Some(state) = self.connections_window.as_mut() {
                     if index < self.connections.len() {
+                        let now = Instant::now();
+                        // The double click is detected here rather than in the view, because the
+                        // view is not rebuilt when the interval simply runs out.
+                        is_double = state.last_click.is_some_and(|last| {
+                            last.index == index
+                                && now.duration_since(last.at) <= DOUBLE_CLICK_INTERVAL
+                        });
                         state.selected = Some(index);
                         state.confirm_delete = false;
-                        state.last_click = Some(ListClick { index, at: Instant::now() });
+                        state.last_click =
+                            if is_double { None } else { Some(ListClick { index, at: now }) };
                     }
                 }
+                if is_double {
+                    return self.update(Message::ConnectionsQuickConnect(index));
+                }
+                Task::none()
+            }
+            Message::ConnectionsGroupToggled(group) => {
+                if let Some(state) = self.connections_window.as_mut() {
+                    state.toggle_group(group);
+                }
                 Task::none()
             }
             Message::ConnectionsQuickConnect(index) => {
@@ -3442,6 +6595,7 @@ impl App {
             }
             Message::ConnectionsDelete => {
                 if let Some(state) = self.connections_window.as_mut() {
+                    state.last_click = None;
                     if state.selected.is_some() {
                         state.confirm_delete = true;
                     }
@@ -3454,6 +6608,61 @@ impl App {
                 }
                 Task::none()
             }
+            Message::ConnectionsTestAll => {
+                let Some(state) = self.connections_window.as_mut() else {
+                    return Task::none();
+                };
+
+                let started_at = Instant::now();
+                let mut rows = Vec::with_capacity(self.connections.len());
+                let mut tasks = Vec::new();
+                for (index, entry) in self.connections.iter().enumerate() {
+                    let status = if entry.auth.use_auth && entry.auth.password.is_none() {
+                        BatchTestStatus::Skipped
+                    } else {
+                        let entry = entry.clone();
+                        tasks.push(Task::perform(
+                            async move {
+                                let test_started = Instant::now();
+                                let result = test_connection(&entry);
+                                (result, test_started.elapsed())
+                            },
+                            move |(result, elapsed)| Message::ConnectionsTestAllResult {
+                                started_at,
+                                index,
+                                result,
+                                elapsed,
+                            },
+                        ));
+                        BatchTestStatus::Running
+                    };
+                    rows.push(BatchTestRow { name: entry.name.clone(), status });
+                }
+
+                log::debug!("Testing {} saved connections", tasks.len());
+                state.batch_test = Some(BatchTest { started_at, rows });
+                Task::batch(tasks)
+            }
+            Message::ConnectionsTestAllResult { started_at, index, result, elapsed } => {
+                let Some(row) = self
+                    .connections_window
+                    .as_mut()
+                    .and_then(|state| state.batch_test.as_mut())
+                    .filter(|batch| batch.started_at == started_at)
+                    .and_then(|batch| batch.rows.get_mut(index))
+                else {
+                    return Task::none();
+                };
+
+                row.status = match result {
+                    Ok(()) => BatchTestStatus::Passed(elapsed),
+                    Err(error) => {
+                        log::error!("Connection test failed name={} error={error}", row.name);
+                        BatchTestStatus::Failed { error, elapsed }
+                    }
+                };
+                Task::none()
+            }
             Message::ConnectionsDeleteConfirmed => {
                 if let Some(state) = self.connections_window.as_mut() {
                     if let Some(index) = state.selected {
@@ -3516,6 +6725,12 @@ impl App {
                 }
                 Task::none()
             }
+            Message::ConnectionFormGroupChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.group = value;
+                }
+                Task::none()
+            }
             Message::ConnectionFormHostChanged(value) => {
                 if let Some(form) = self.connection_form.as_mut() {
                     form.host = value;
@@ -3530,6 +6745,69 @@ impl App {
                 }
                 Task::none()
             }
+            Message::ConnectionFormReplicaSetChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.replica_set = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormReadPreferenceChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.read_preference = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormReadConcernChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.read_concern = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormWriteConcernChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.write_concern = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormReadOnlyChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.read_only = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormColorPickerOpened => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.color_picker_open = true;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormColorPickerCanceled => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.color_picker_open = false;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormColorChanged(color) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.color = Some(color);
+                    form.color_picker_open = false;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormColorCleared => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.color = None;
+                    form.color_picker_open = false;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
             Message::ConnectionFormPortChanged(value) => {
                 if let Some(form) = self.connection_form.as_mut() {
                     let sanitized: String =
@@ -3539,6 +6817,18 @@ impl App {
                 }
                 Task::none()
             }
+            Message::ConnectionFormSrvChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.use_srv = value;
+                    if value {
+                        form.port.clear();
+                    } else if form.port.is_empty() {
+                        form.port = String::from("27017");
+                    }
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
             Message::ConnectionFormAuthUseChanged(value) => {
                 if let Some(form) = self.connection_form.as_mut() {
                     form.auth.use_auth = value;
@@ -3640,6 +6930,36 @@ impl App {
                     Message::ConnectionFormSshPrivateKeyPicked,
                 );
             }
+            Message::ResultsExported(result) => {
+                match result {
+                    Ok(Some(path)) => {
+                        self.status_notice = Some(tr_format("Results saved to {}", &[&path]));
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        log::error!("Failed to export results: {error}");
+                        self.status_notice =
+                            Some(tr_format("Failed to export results: {}", &[&error]));
+                    }
+                }
+                Task::none()
+            }
+            Message::DiagnosticReportSaved(result) => {
+                match result {
+                    Ok(Some(path)) => {
+                        log::info!("Diagnostic report saved to {path}");
+                        self.status_notice =
+                            Some(tr_format("Diagnostic report saved to {}", &[&path]));
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        log::error!("Failed to save diagnostic report: {error}");
+                        self.status_notice =
+                            Some(tr_format("Failed to save diagnostic report: {}", &[&error]));
+                    }
+                }
+                Task::none()
+            }
             Message::ConnectionFormSshPrivateKeyPicked(path) => {
                 if let Some(form) = self.connection_form.as_mut() {
                     if let Some(path) = path {
@@ -3649,6 +6969,41 @@ impl App {
                 }
                 Task::none()
             }
+            Message::ConnectionFormTlsFileChanged { field, value } => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    match field {
+                        TlsFileField::CaFile => form.tls_ca_file = value,
+                        TlsFileField::CertificateKeyFile => form.tls_cert_key_file = value,
+                    }
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormTlsFileBrowse(field) => Task::perform(
+                async {
+                    FileDialog::new().pick_file().map(|path| path.to_string_lossy().to_string())
+                },
+                move |path| Message::ConnectionFormTlsFilePicked { field, path },
+            ),
+            Message::ConnectionFormTlsFilePicked { field, path } => {
+                if let Some(form) = self.connection_form.as_mut()
+                    && let Some(path) = path
+                {
+                    match field {
+                        TlsFileField::CaFile => form.tls_ca_file = path,
+                        TlsFileField::CertificateKeyFile => form.tls_cert_key_file = path,
+                    }
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::ConnectionFormTlsInsecureChanged(value) => {
+                if let Some(form) = self.connection_form.as_mut() {
+                    form.tls_insecure = value;
+                    form.validation_error = None;
+                }
+                Task::none()
+            }
             Message::ConnectionFormSshPassphraseChanged(value) => {
                 if let Some(form) = self.connection_form.as_mut() {
                     form.ssh.passphrase = value;
@@ -3682,52 +7037,14 @@ impl App {
                             form.testing = true;
                             form.test_feedback = None;
                             log::debug!(
-                                "Connection test started name={} host={} port={} ssh_tunnel={}",
+                                "Connection test started name={} uri={} ssh_tunnel={}",
                                 entry.name,
-                                entry.host,
-                                entry.port,
+                                entry.display_uri(),
                                 entry.ssh_tunnel.enabled
                             );
 
-                            let auth_db = if form.auth.use_auth {
-                                form.auth.database.trim()
-                            } else {
-                                "admin"
-                            };
-                            let auth_db = if auth_db.is_empty() { "admin" } else { auth_db };
-                            let auth_db = auth_db.to_string();
-
                             return Task::perform(
-                                async move {
-                                    let tunnel_guard = if entry.ssh_tunnel.enabled {
-                                        Some(SshTunnel::start(
-                                            &entry.ssh_tunnel,
-                                            &entry.host,
-                                            entry.port,
-                                        )?)
-                                    } else {
-                                        None
-                                    };
-
-                                    let uri = if let Some(ref tunnel) = tunnel_guard {
-                                        entry.uri_for_host_port("127.0.0.1", tunnel.local_port())?
-                                    } else {
-                                        entry.uri()?
-                                    };
-
-                                    let client = Client::with_uri_str(&uri)
-                                        .map_err(|err| err.to_string())?;
-                                    let database = client.database(&auth_db);
-                                    database
-                                        .run_command(doc! { "ping": 1 })
-                                        .run()
-                                        .map_err(|err| err.to_string())?;
-                                    database
-                                        .list_collection_names()
-                                        .run()
-                                        .map(|_| ())
-                                        .map_err(|err| err.to_string())
-                                },
+                                async move { test_connection(&entry) },
                                 Message::ConnectionFormTestResult,
                             );
                         }
@@ -3811,7 +7128,7 @@ impl App {
             }
             Message::ConnectionFormCancel => {
                 self.open_connections_window();
-                Task::none()
+                self.scroll_to_selected_connection()
             }
             Message::SettingsOpen => {
                 self.open_settings_window();
@@ -3823,6 +7140,13 @@ impl App {
                 }
                 Task::none()
             }
+            Message::SettingsTogglePrefillFindTemplate(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.prefill_find_template = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
             Message::SettingsToggleExpandFirstResult(value) => {
                 if let Some(state) = self.settings_window.as_mut() {
                     state.expand_first_result = value;
@@ -3832,7 +7156,28 @@ impl App {
             }
             Message::SettingsQueryTimeoutChanged(value) => {
                 if let Some(state) = self.settings_window.as_mut() {
-                    state.query_timeout_secs = value;
+                    state.query_timeout = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsMaxRenderDocumentsChanged(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.max_render_documents = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsExpandAllDepthChanged(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.expand_all_depth = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsQueryTimeoutUnitChanged(unit) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.query_timeout_unit = unit;
                     state.validation_error = None;
                 }
                 Task::none()
@@ -3844,6 +7189,13 @@ impl App {
                 }
                 Task::none()
             }
+            Message::SettingsToggleRelativeDates(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.show_relative_dates = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
             Message::SettingsToggleSortIndexes(value) => {
                 if let Some(state) = self.settings_window.as_mut() {
                     state.sort_index_names_alphabetically = value;
@@ -3858,6 +7210,62 @@ impl App {
                 }
                 Task::none()
             }
+            Message::SettingsToggleReuseUnpinnedTab(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.reuse_unpinned_tab = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsToggleGroupConnectionsByHost(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.group_connections_by_host = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsToggleCompactSidebar(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.compact_sidebar = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsToggleHighlightQueries(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.highlight_queries = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsToggleReconnectOnQuery(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.reconnect_on_query = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsToggleConfirmCloseEditedTabs(value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.confirm_close_edited_tabs = value;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsTabTitleFormatChanged(format) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.tab_title_format = format;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsTreeSortModeChanged(mode) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.tree_sort_mode = mode;
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
             Message::SettingsToggleStrictDeleteConfirmation(value) => {
                 if let Some(state) = self.settings_window.as_mut() {
                     state.strict_delete_confirmation = value;
@@ -4019,6 +7427,23 @@ impl App {
                 }
                 Task::none()
             }
+            Message::SettingsKeyBindingChanged(action, value) => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.key_bindings.insert(action, value);
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
+            Message::SettingsKeyBindingsReset => {
+                if let Some(state) = self.settings_window.as_mut() {
+                    state.key_bindings = default_key_bindings()
+                        .into_iter()
+                        .map(|(action, combo)| (action, combo.to_string()))
+                        .collect();
+                    state.validation_error = None;
+                }
+                Task::none()
+            }
             Message::SettingsApply => {
                 if let Some(mut state) = self.settings_window.take() {
                     if let Err(error) = self.apply_settings_from_state(&mut state) {
@@ -4028,7 +7453,7 @@ impl App {
                     self.settings_window = Some(state);
                 }
                 self.close_settings_window();
-                Task::none()
+                self.resort_tree()
             }
             Message::SettingsSave => {
                 let mut state = match self.settings_window.take() {
@@ -4060,7 +7485,7 @@ impl App {
                     }
                 }
 
-                Task::none()
+                self.resort_tree()
             }
             Message::SettingsCancel => {
                 self.close_settings_window();
@@ -4104,6 +7529,31 @@ impl App {
                 }
                 Task::none()
             }
+            Message::CollectionTreeFilterChanged { tab_id, value } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    let collection = &mut tab.collection;
+                    collection.tree_filter = value;
+                    collection.bson_tree.apply_filter(
+                        &collection.tree_filter,
+                        collection.tree_filter_case_sensitive,
+                    );
+                }
+                Task::none()
+            }
+            Message::CollectionTreeFilterCaseToggled { tab_id, value } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    let collection = &mut tab.collection;
+                    collection.tree_filter_case_sensitive = value;
+                    collection.bson_tree.apply_filter(&collection.tree_filter, value);
+                }
+                Task::none()
+            }
+            Message::CollectionTreeRowClicked { tab_id, node_id } => {
+                if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                    tab.collection.bson_tree.click_row(node_id, self.keyboard_modifiers.command());
+                }
+                Task::none()
+            }
             Message::DocumentEditRequested { tab_id, node_id } => {
                 let doc_state = self.tabs.iter().find(|tab| tab.id == tab_id).and_then(|tab| {
                     if !tab.collection.bson_tree.is_root_node(node_id) {
@@ -4154,16 +7604,360 @@ impl App {
     fn main_view(&self) -> Element<'_, Message> {
         let menu_bar = menues::build_menu_bar(self.active_palette());
 
-        let content_grid =
+        // The split ratio stays in `panes` while the sidebar is hidden, so restoring it
+        // brings back the previous layout.
+        let content_grid: Element<'_, Message> = if self.sidebar_collapsed {
+            Container::new(self.main_panel()).width(Length::Fill).height(Length::Fill).into()
+        } else {
             pane_grid::PaneGrid::new(&self.panes, |_, pane_state, _| match pane_state {
                 PaneContent::Sidebar => pane_grid::Content::new(self.sidebar_panel()),
                 PaneContent::Main => pane_grid::Content::new(self.main_panel()),
             })
             .on_resize(8, Message::PaneResized)
             .spacing(PANE_GRID_SPACING)
-            .height(Length::Fill);
+            .height(Length::Fill)
+            .into()
+        };
+
+        let mut layout = Column::new().push(menu_bar).push(content_grid);
+        if self.query_log_visible {
+            layout = layout.push(self.query_log_panel());
+        }
+
+        layout.push(self.status_bar()).spacing(0).height(Length::Fill).into()
+    }
+
+    /// Collects local state for a bug report. Nothing is sent anywhere; connection URIs,
+    /// queries and error messages pass through the credential redaction of their connection.
+    fn diagnostic_report(&self) -> serde_json::Value {
+        let redact = |client_id: ClientId, text: &str| match self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+        {
+            Some(client) => client.entry.redact_secrets(text),
+            None => mask_uri_credentials(text),
+        };
+
+        let settings = serde_json::to_value(&self.settings).unwrap_or(serde_json::Value::Null);
+
+        let connections: Vec<serde_json::Value> = self
+            .clients
+            .iter()
+            .map(|client| {
+                let (status, error) = match &client.status {
+                    ConnectionStatus::Connecting => ("connecting", None),
+                    ConnectionStatus::Ready => ("ready", None),
+                    ConnectionStatus::Failed(error) => {
+                        ("failed", Some(client.entry.redact_secrets(error)))
+                    }
+                };
+                serde_json::json!({
+                    "name": client.name,
+                    "uri": client.entry.display_uri(),
+                    "status": status,
+                    "error": error,
+                })
+            })
+            .collect();
+
+        let recent_errors: Vec<serde_json::Value> = self
+            .query_log
+            .iter()
+            .filter_map(|entry| {
+                let error = entry.outcome.as_ref().err()?;
+                Some(serde_json::json!({
+                    "time": entry.timestamp,
+                    "namespace": entry.namespace(),
+                    "query": redact(entry.client_id, &entry.query),
+                    "duration_ms": entry.duration.as_millis() as u64,
+                    "error": redact(entry.client_id, error),
+                }))
+            })
+            .collect();
+
+        let active_tab = self.active_tab.and_then(|id| self.tabs.iter().find(|tab| tab.id == id));
+        let failing_query = active_tab.and_then(|tab| {
+            let collection = &tab.collection;
+            let error = collection.last_error.as_ref()?;
+            Some(serde_json::json!({
+                "namespace": format!("{}.{}", collection.db_name, collection.collection),
+                "query": redact(collection.client_id, &collection.editor.text()),
+                "error": redact(collection.client_id, error),
+            }))
+        });
+
+        serde_json::json!({
+            "app_version": env!("CARGO_PKG_VERSION"),
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "generated_at": chrono::Local::now().to_rfc3339(),
+            "settings": settings,
+            "connections": connections,
+            "recent_errors": recent_errors,
+            "failing_query": failing_query,
+        })
+    }
+
+    fn export_diagnostic_report_task(&self) -> Task<Message> {
+        let report = match serde_json::to_string_pretty(&self.diagnostic_report()) {
+            Ok(report) => report,
+            Err(error) => {
+                return Task::done(Message::DiagnosticReportSaved(Err(error.to_string())));
+            }
+        };
+        let file_name = format!(
+            "oxide_mongo-diagnostics-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        );
+
+        Task::perform(
+            async move {
+                let Some(path) = FileDialog::new()
+                    .add_filter("JSON", &["json"])
+                    .set_file_name(file_name)
+                    .save_file()
+                else {
+                    return Ok(None);
+                };
+                std::fs::write(&path, report).map_err(|error| error.to_string())?;
+                Ok(Some(path.to_string_lossy().to_string()))
+            },
+            Message::DiagnosticReportSaved,
+        )
+    }
+
+    fn export_results_task(&self, tab_id: TabId, action: TableContextAction) -> Task<Message> {
+        let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+            return Task::none();
+        };
+        let Some(result) = tab.collection.last_result.as_ref() else {
+            return Task::none();
+        };
+        let (contents, filter, extension) = match action {
+            TableContextAction::ExportCsv => (export::csv_file(result), "CSV", "csv"),
+            _ => (export::json_file(result), "JSON", "json"),
+        };
+        let file_name = format!("{}.{extension}", tab.collection.collection);
+
+        Task::perform(
+            async move {
+                let Some(path) = FileDialog::new()
+                    .add_filter(filter, &[extension])
+                    .set_file_name(file_name)
+                    .save_file()
+                else {
+                    return Ok(None);
+                };
+                std::fs::write(&path, contents).map_err(|error| error.to_string())?;
+                Ok(Some(path.to_string_lossy().to_string()))
+            },
+            Message::ResultsExported,
+        )
+    }
+
+    /// Removes the element by replacing it with a unique marker and pulling that marker. If the
+    /// pull fails, the original value is written back.
+    fn delete_array_element_task(
+        &mut self,
+        tab_id: TabId,
+        context: ValueEditContext,
+    ) -> Task<Message> {
+        let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+            return Task::none();
+        };
+        let collection = &tab.collection;
+        let Some((mark, pull)) = array_element_removal_updates(&context.path) else {
+            return Task::none();
+        };
+        if let Some(message) = read_only_client_error(&self.clients, collection.client_id) {
+            log::error!("{message}");
+            self.status_notice = Some(message);
+            return Task::none();
+        }
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == collection.client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            self.status_notice = Some(String::from(tr("No active connection")));
+            return Task::none();
+        };
+
+        let db_name = collection.db_name.clone();
+        let collection_name = collection.collection.clone();
+        log::debug!(
+            "Array element removal requested db={} collection={} path={}",
+            db_name,
+            collection_name,
+            context.path
+        );
+        Task::perform(
+            async move {
+                let collection = handle.database(&db_name).collection::<Document>(&collection_name);
+                let marked = collection
+                    .update_one(context.filter.clone(), mark)
+                    .run()
+                    .map_err(|error| error.to_string())?;
+                if marked.matched_count == 0 {
+                    return Err(String::from(tr(
+                        "Document not found. It may have been deleted or the change was not applied.",
+                    )));
+                }
+                let Err(error) = collection.update_one(context.filter.clone(), pull).run() else {
+                    return Ok(());
+                };
+                let message = error.to_string();
+                let restore = doc! { "$set": { context.path.as_str(): context.current_value } };
+                match collection.update_one(context.filter, restore).run() {
+                    Ok(_) => Err(message),
+                    Err(error) => Err(format!(
+                        "{message} {}",
+                        tr_format("Rollback failed: {}", &[&error.to_string()])
+                    )),
+                }
+            },
+            move |result| Message::ArrayElementRemoved { tab_id, result },
+        )
+    }
+
+    fn push_query_log_entry(&mut self, entry: QueryLogEntry) {
+        if self.query_log.len() >= QUERY_LOG_CAPACITY {
+            self.query_log.remove(0);
+        }
+        self.query_log.push(entry);
+    }
+
+    fn query_log_panel(&self) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let muted_color = palette.text_muted.to_color();
+        let pane_bg = palette.widget_background_color();
+        let pane_border = palette.widget_border_color();
+        let error_color = error_accent_color(&palette);
+        let success_color = success_accent_color(&palette);
+
+        let filter_input = text_input(tr("Filter queries"), &self.query_log_filter)
+            .on_input(Message::QueryLogFilterChanged)
+            .padding([2, 6])
+            .width(Length::Fixed(240.0));
+
+        let clear_palette = palette.clone();
+        let clear_button = Button::new(fonts::primary_text(tr("Clear"), Some(-1.0)))
+            .padding([2, 10])
+            .on_press(Message::QueryLogClear)
+            .style(move |_, status| clear_palette.subtle_button_style(4.0, status));
+
+        let header = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(fonts::primary_text(tr("Query Log"), None))
+            .push(filter_input)
+            .push(Space::new().width(Length::Fill))
+            .push(clear_button);
+
+        let mut list = Column::new().spacing(2);
+        let mut visible = 0;
+        for (index, entry) in self.query_log.iter().enumerate().rev() {
+            if !entry.matches_filter(&self.query_log_filter) {
+                continue;
+            }
+            visible += 1;
+
+            let (status, status_color) = match &entry.outcome {
+                Ok(count) => (tr_format("OK ({})", &[&count.to_string()]), success_color),
+                Err(error) => (tr_format("Error: {}", &[error]), error_color),
+            };
+            let query_line = entry.query.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+
+            let row = Row::new()
+                .spacing(12)
+                .align_y(Vertical::Center)
+                .push(fonts::primary_text(entry.timestamp.clone(), Some(-1.0)).color(muted_color))
+                .push(fonts::primary_text(entry.namespace(), Some(-1.0)).color(muted_color))
+                .push(
+                    fonts::primary_text(CollectionTab::format_duration(entry.duration), Some(-1.0))
+                        .color(muted_color),
+                )
+                .push(fonts::primary_text(status, Some(-1.0)).color(status_color))
+                .push(
+                    fonts::result_text(query_line, Some(-1.0))
+                        .wrapping(Wrapping::None)
+                        .width(Length::Fill),
+                );
+
+            let entry_palette = palette.clone();
+            list = list.push(
+                Button::new(row)
+                    .padding([2, 6])
+                    .width(Length::Fill)
+                    .on_press(Message::QueryLogEntrySelected(index))
+                    .style(move |_, status| entry_palette.menu_button_style(4.0, status)),
+            );
+        }
+
+        if visible == 0 {
+            list = list.push(
+                fonts::primary_text(tr("No queries executed yet"), Some(-1.0)).color(muted_color),
+            );
+        }
+
+        let content = Column::new()
+            .spacing(6)
+            .push(header)
+            .push(Scrollable::new(list).height(Length::Fill).width(Length::Fill));
+
+        Container::new(content)
+            .padding([6, 12])
+            .width(Length::Fill)
+            .height(Length::Fixed(QUERY_LOG_PANEL_HEIGHT))
+            .style(move |_| container::Style {
+                background: Some(pane_bg.into()),
+                border: border::width(1).color(pane_border),
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn status_bar(&self) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let muted_color = palette.text_muted.to_color();
+        let pane_bg = palette.widget_background_color();
+        let pane_border = palette.widget_border_color();
+
+        let connected = self
+            .clients
+            .iter()
+            .filter(|client| matches!(client.status, ConnectionStatus::Ready))
+            .count();
+
+        let label = |text: String| fonts::primary_text(text, Some(-2.0)).color(muted_color);
+
+        let mut row = Row::new()
+            .spacing(24)
+            .align_y(Vertical::Center)
+            .push(label(tr_format(
+                "Connections: {} of {}",
+                &[&connected.to_string(), &self.clients.len().to_string()],
+            )))
+            .push(label(tr_format("Tabs: {}", &[&self.tabs.len().to_string()])));
+        if let Some(notice) = &self.status_notice {
+            row = row.push(label(notice.clone()));
+        }
+        let row = row
+            .push(Space::new().width(Length::Fill))
+            .push(label(tr_format("Theme: {}", &[tr(self.settings.theme_choice.label())])))
+            .push(label(tr_format("Language: {}", &[self.settings.language.label()])));
 
-        Column::new().push(menu_bar).push(content_grid).spacing(0).height(Length::Fill).into()
+        Container::new(row)
+            .padding([2, 12])
+            .width(Length::Fill)
+            .style(move |_| container::Style {
+                background: Some(pane_bg.into()),
+                border: border::width(1).color(pane_border),
+                ..Default::default()
+            })
+            .into()
     }
 
     fn view(&self) -> Element<'_, Message> {
@@ -4180,7 +7974,23 @@ impl App {
             AppMode::ConnectionForm => {
                 if let Some(state) = &self.connection_form {
                     let palette = self.active_palette();
-                    connection_form_view(state, &palette)
+                    connection_form_view(state, &self.connections, &palette)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::SavedQueries => {
+                if let Some(state) = &self.saved_queries_window {
+                    let palette = self.active_palette();
+                    saved_queries_view(state, &self.saved_queries, &palette)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::SavedQueryForm => {
+                if let Some(state) = &self.saved_query_form {
+                    let palette = self.active_palette();
+                    saved_query_form_view(state, &palette)
                 } else {
                     self.main_view()
                 }
@@ -4244,6 +8054,56 @@ impl App {
                     self.main_view()
                 }
             }
+            AppMode::DocumentDiffModal => {
+                if let Some(state) = &self.document_diff_modal {
+                    self.document_diff_modal_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::IndexBuilderModal => {
+                if let Some(state) = &self.index_builder_modal {
+                    self.index_builder_modal_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::TabCloseConfirmation => match self.tab_close_confirmation {
+                Some(tab_id) => self.tab_close_confirmation_view(tab_id),
+                None => self.main_view(),
+            },
+            AppMode::ArrayElementDeleteConfirmation => match &self.array_element_delete {
+                Some(state) => self.array_element_delete_view(state),
+                None => self.main_view(),
+            },
+            AppMode::AggregateWriteModal => {
+                if let Some(state) = &self.aggregate_write_modal {
+                    self.aggregate_write_modal_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::DateFormatPreview => {
+                if let Some(state) = &self.date_format_preview {
+                    self.date_format_preview_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::TextSearchModal => {
+                if let Some(state) = &self.text_search_modal {
+                    self.text_search_modal_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
+            AppMode::ProjectionPresetModal => {
+                if let Some(state) = &self.projection_preset_modal {
+                    self.projection_preset_modal_view(state)
+                } else {
+                    self.main_view()
+                }
+            }
         }
     }
 
@@ -4342,7 +8202,7 @@ impl App {
                 tr("New Collection Name"),
                 tr("Rename"),
             ),
-            CollectionModalKind::DropIndex { ref index_name } => (
+            CollectionModalKind::DropIndex { ref index_name, .. } => (
                 tr("Delete Index"),
                 tr_format(
                     "Index \"{}\" of collection \"{}\" in database \"{}\" will be deleted. This action cannot be undone.",
@@ -4350,7 +8210,7 @@ impl App {
                 ),
                 if strict_delete_confirmation {
                     Some(tr_format(
-                        "Confirm index deletion by entering its name \"{}\".",
+                        "Confirm index deletion by entering its name \"{}\" or its key specification.",
                         &[index_name.as_str()],
                     ))
                 } else {
@@ -4373,9 +8233,15 @@ impl App {
                 let trimmed = state.input.trim();
                 !trimmed.is_empty() && trimmed != state.collection && !state.processing
             }
-            CollectionModalKind::DropIndex { ref index_name } => {
+            CollectionModalKind::DropIndex { ref index_name, ref index_keys } => {
                 !state.processing
-                    && (!strict_delete_confirmation || state.input.trim() == index_name)
+                    && (!strict_delete_confirmation
+                        || resolve_drop_index_target(
+                            state.input.trim(),
+                            index_name,
+                            index_keys.as_ref(),
+                        )
+                        .is_some())
             }
         };
 
@@ -4384,428 +8250,1392 @@ impl App {
             .push(fonts::primary_text(title, Some(6.0)).color(text_primary))
             .push(fonts::primary_text(warning, None).color(muted_color));
 
-        if let Some(prompt) = prompt {
-            column = column.push(fonts::primary_text(prompt, Some(-1.0)).color(muted_color));
-        }
+        if let Some(prompt) = prompt {
+            column = column.push(fonts::primary_text(prompt, Some(-1.0)).color(muted_color));
+        }
+
+        let show_confirmation_input = match state.kind {
+            CollectionModalKind::DeleteAllDocuments | CollectionModalKind::DeleteCollection => {
+                strict_delete_confirmation
+            }
+            CollectionModalKind::DropIndex { .. } => strict_delete_confirmation,
+            _ => true,
+        };
+
+        if matches!(state.kind, CollectionModalKind::DeleteAllDocuments) {
+            let fonts_state = fonts::active_fonts();
+            column = column.push(
+                Checkbox::new(state.recreate_on_truncate)
+                    .label(tr("Drop and recreate the collection (faster on large collections)"))
+                    .font(fonts_state.primary_font)
+                    .text_size(fonts_state.primary_size)
+                    .on_toggle(Message::CollectionModalToggleRecreate),
+            );
+            if state.recreate_on_truncate {
+                column = column.push(
+                    fonts::primary_text(
+                        tr(
+                            "The collection is dropped and created again with the same options, then its indexes are rebuilt. Other clients may briefly see the collection missing.",
+                        ),
+                        Some(-1.0),
+                    )
+                    .color(warning_accent_color(&palette)),
+                );
+            }
+        }
+
+        if show_confirmation_input {
+            let input_field = text_input(placeholder, &state.input)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::CollectionModalInputChanged);
+            column = column.push(input_field);
+        }
+
+        if let Some(error) = &state.error {
+            column = column.push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
+        }
+
+        if state.processing {
+            column = column
+                .push(fonts::primary_text(tr("Processing..."), Some(-1.0)).color(accent_color));
+        }
+
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::CollectionModalCancel)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+
+        let mut confirm_button =
+            Button::new(fonts::primary_text(confirm_label, None)).padding([6, 16]);
+        if confirm_ready {
+            confirm_button = confirm_button
+                .style({
+                    let palette = palette.clone();
+                    move |_, status| palette.primary_button_style(6.0, status)
+                })
+                .on_press(Message::CollectionModalConfirm);
+        } else {
+            confirm_button = confirm_button.style({
+                let palette = palette.clone();
+                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+            });
+        }
+
+        let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
+
+        column = column.push(buttons);
+
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+    }
+
+    fn database_modal_view(&self, state: &DatabaseModalState) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+        let error_color = error_accent_color(&palette);
+        let accent_color = success_accent_color(&palette);
+
+        let base = match &state.mode {
+            DatabaseModalMode::Drop { db_name } => {
+                let warning = tr_format(
+                    "Database \"{}\" will be deleted along with all collections and documents. This action cannot be undone.",
+                    &[db_name.as_str()],
+                );
+                let prompt = if self.settings.strict_delete_confirmation {
+                    Some(tr_format(
+                        "Confirm deletion of all data by entering the database name \"{}\".",
+                        &[db_name.as_str()],
+                    ))
+                } else {
+                    None
+                };
+
+                let confirm_ready = !state.processing
+                    && (!self.settings.strict_delete_confirmation || state.input.trim() == db_name);
+
+                let mut column = Column::new()
+                    .spacing(16)
+                    .push(fonts::primary_text(tr("Delete Database"), Some(6.0)).color(text_primary))
+                    .push(fonts::primary_text(warning, None).color(muted_color));
+
+                if let Some(prompt) = prompt {
+                    column =
+                        column.push(fonts::primary_text(prompt, Some(-1.0)).color(muted_color));
+
+                    let input_field = text_input(tr("Database name"), &state.input)
+                        .padding([6, 10])
+                        .width(Length::Fill)
+                        .on_input(Message::DatabaseModalInputChanged);
+
+                    column = column.push(input_field);
+                }
+
+                if let Some(error) = &state.error {
+                    column = column
+                        .push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
+                }
+
+                if state.processing {
+                    column = column.push(
+                        fonts::primary_text(tr("Processing..."), Some(-1.0)).color(accent_color),
+                    );
+                }
+
+                let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+                    .padding([6, 16])
+                    .on_press(Message::DatabaseModalCancel)
+                    .style({
+                        let palette = palette.clone();
+                        move |_, status| palette.subtle_button_style(6.0, status)
+                    });
+
+                let mut confirm_button =
+                    Button::new(fonts::primary_text(tr("Confirm Deletion"), None)).padding([6, 16]);
+
+                if confirm_ready {
+                    confirm_button = confirm_button
+                        .style({
+                            let palette = palette.clone();
+                            move |_, status| palette.primary_button_style(6.0, status)
+                        })
+                        .on_press(Message::DatabaseModalConfirm);
+                } else {
+                    confirm_button = confirm_button.style({
+                        let palette = palette.clone();
+                        move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+                    });
+                }
+
+                let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
+                column = column.push(buttons);
+
+                column
+            }
+            DatabaseModalMode::Create => {
+                let confirm_ready = !state.processing
+                    && !state.input.trim().is_empty()
+                    && !state.collection_input.trim().is_empty();
+
+                let mut column = Column::new()
+                    .spacing(16)
+                    .push(fonts::primary_text(tr("Create Database"), Some(6.0)).color(text_primary))
+                    .push(fonts::primary_text(tr(
+                            "MongoDB creates a database only when the first collection is created. Provide the database name and the first collection to create immediately."
+                        ), Some(-1.0)).color(muted_color));
+
+                let input_field = text_input(tr("Database name"), &state.input)
+                    .padding([6, 10])
+                    .width(Length::Fill)
+                    .on_input(Message::DatabaseModalInputChanged);
+
+                let collection_field =
+                    text_input(tr("First collection name"), &state.collection_input)
+                        .padding([6, 10])
+                        .width(Length::Fill)
+                        .on_input(Message::DatabaseModalCollectionInputChanged);
+
+                column = column.push(input_field).push(collection_field);
+
+                if let Some(error) = &state.error {
+                    column = column
+                        .push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
+                }
+
+                if state.processing {
+                    column = column.push(
+                        fonts::primary_text(tr("Creating database..."), Some(-1.0))
+                            .color(accent_color),
+                    );
+                }
+
+                let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+                    .padding([6, 16])
+                    .on_press(Message::DatabaseModalCancel)
+                    .style({
+                        let palette = palette.clone();
+                        move |_, status| palette.subtle_button_style(6.0, status)
+                    });
+
+                let mut confirm_button =
+                    Button::new(fonts::primary_text(tr("Create"), None)).padding([6, 16]);
+
+                if confirm_ready {
+                    confirm_button = confirm_button
+                        .style({
+                            let palette = palette.clone();
+                            move |_, status| palette.primary_button_style(6.0, status)
+                        })
+                        .on_press(Message::DatabaseModalConfirm);
+                } else {
+                    confirm_button = confirm_button.style({
+                        let palette = palette.clone();
+                        move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+                    });
+                }
+
+                let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
+                column = column.push(buttons);
+
+                column
+            }
+        };
+
+        let content: Element<Message> = base.into();
+        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+    }
+
+    fn document_modal_view<'a>(&self, state: &'a DocumentModalState) -> Element<'a, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+        let error_color = error_accent_color(&palette);
+        let accent_color = success_accent_color(&palette);
+
+        let (title_text, hint_text, saving_text) = match &state.kind {
+            DocumentModalKind::CollectionDocument { .. } => (
+                tr("Edit Document"),
+                tr(
+                    "Edit the JSON representation of the document. The document will be fully replaced on save.",
+                ),
+                tr("Saving document..."),
+            ),
+            DocumentModalKind::Validation
+                if state.processing && state.editor.text().trim().is_empty() =>
+            {
+                (
+                    tr("Validation Rules"),
+                    tr("Loading validation rules..."),
+                    tr("Loading validation rules..."),
+                )
+            }
+            DocumentModalKind::Validation => (
+                tr("Validation Rules"),
+                tr(
+                    "Edit validator, validationLevel and validationAction. Saving applies them with collMod; an empty validator removes validation.",
+                ),
+                tr("Applying validation rules..."),
+            ),
+            DocumentModalKind::NewDocument => (
+                tr("Insert Document"),
+                tr(
+                    "Enter the new document. An _id is generated when the document does not set one.",
+                ),
+                tr("Inserting document..."),
+            ),
+            DocumentModalKind::Index { .. } => (
+                tr("Edit TTL Index"),
+                tr(
+                    "Only the \"expireAfterSeconds\" field value can be changed. Other parameters will be ignored.",
+                ),
+                tr("Saving index..."),
+            ),
+        };
+
+        let title = fonts::primary_text(title_text, Some(6.0)).color(text_primary);
+
+        let hint = fonts::primary_text(hint_text, Some(-1.0)).color(muted_color);
+
+        let editor = text_editor::TextEditor::new(&state.editor)
+            .font(MONO_FONT)
+            .wrapping(Wrapping::Glyph)
+            .height(Length::Shrink)
+            .on_action(Message::DocumentModalEditorAction);
+
+        let editor_scroll = Scrollable::new(editor).width(Length::Fill).height(Length::Fill);
+
+        let editor_container =
+            Container::new(editor_scroll).width(Length::Fill).height(Length::Fill).style({
+                let palette = palette.clone();
+                move |_| container::Style {
+                    border: border::rounded(8).width(1).color(palette.widget_border_color()),
+                    background: Some(palette.widget_background_color().into()),
+                    ..Default::default()
+                }
+            });
+
+        let mut column = Column::new().spacing(16).push(title).push(hint).push(editor_container);
+
+        if matches!(state.kind, DocumentModalKind::CollectionDocument { .. }) {
+            let fonts_state = fonts::active_fonts();
+            column = column.push(
+                Checkbox::new(state.allow_id_change)
+                    .label(tr("Allow changing _id (the document is re-inserted, not updated)"))
+                    .font(fonts_state.primary_font)
+                    .text_size(fonts_state.primary_size - 1.0)
+                    .on_toggle(Message::DocumentModalToggleIdChange),
+            );
+        }
+
+        if state.id_change_confirmed {
+            column = column.push(
+                fonts::primary_text(
+                    tr(
+                        "The _id has changed. Saving inserts a new document and deletes the original one; this is not an in-place update. Press Replace Document to confirm.",
+                    ),
+                    Some(-1.0),
+                )
+                .color(warning_accent_color(&palette)),
+            );
+        }
+
+        if let Some(error) = &state.error {
+            column = column.push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
+        }
+
+        if state.processing {
+            column = column.push(fonts::primary_text(saving_text, Some(-1.0)).color(accent_color));
+        }
+
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::DocumentModalCancel)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+
+        let save_label =
+            if state.id_change_confirmed { tr("Replace Document") } else { tr("Save") };
+        let mut save_button = Button::new(fonts::primary_text(save_label, None)).padding([6, 16]);
+        if state.processing {
+            save_button = save_button.style({
+                let palette = palette.clone();
+                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+            });
+        } else {
+            save_button = save_button
+                .style({
+                    let palette = palette.clone();
+                    move |_, status| palette.primary_button_style(6.0, status)
+                })
+                .on_press(Message::DocumentModalSave);
+        }
+
+        let buttons = Row::new().spacing(12).push(cancel_button).push(save_button);
+        column = column.push(buttons);
+        let content: Element<Message> = column.into();
+        let modal_width = self.adaptive_modal_width(DOCUMENT_MODAL_MIN_WIDTH);
+        modal_layout(palette, content, modal_width, 24, 12.0)
+    }
+
+    fn value_edit_modal_view<'a>(&self, state: &'a ValueEditModalState) -> Element<'a, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+        let error_color = error_accent_color(&palette);
+        let accent_color = success_accent_color(&palette);
+        let fonts_state = fonts::active_fonts();
+        let bold_font = Font { weight: Weight::Bold, ..fonts_state.primary_font };
+
+        let description = Column::new()
+            .spacing(4)
+            .push(
+                fonts::primary_text(
+                    if state.append {
+                        tr("A new element will be appended to the array")
+                    } else {
+                        tr("Field value will be modified")
+                    },
+                    None,
+                )
+                .wrapping(Wrapping::Word)
+                .width(Length::Fill),
+            )
+            .push(
+                fonts::primary_text(state.path.clone(), None)
+                    .wrapping(Wrapping::Word)
+                    .width(Length::Fill)
+                    .font(bold_font),
+            );
+
+        let editor = text_editor::TextEditor::new(&state.value_editor)
+            .font(MONO_FONT)
+            .wrapping(Wrapping::Glyph)
+            .height(Length::Shrink)
+            .on_action(Message::ValueEditModalEditorAction);
+
+        let editor_scroll =
+            Scrollable::new(editor).width(Length::Fill).height(Length::Fixed(220.0));
+
+        let value_editor = Container::new(editor_scroll)
+            .width(Length::FillPortion(5))
+            .height(Length::Fixed(220.0))
+            .style({
+                let palette = palette.clone();
+                move |_| container::Style {
+                    border: border::rounded(6).width(1).color(palette.widget_border_color()),
+                    background: Some(palette.widget_background_color().into()),
+                    ..Default::default()
+                }
+            });
+
+        let type_indicator = Container::new(
+            fonts::primary_text(state.value_label.clone(), None)
+                .color(text_primary)
+                .wrapping(Wrapping::Word)
+                .width(Length::Fill),
+        )
+        .padding([6, 10])
+        .width(Length::FillPortion(2))
+        .style({
+            let palette = palette.clone();
+            move |_| container::Style {
+                border: border::rounded(6).width(1).color(palette.widget_border_color()),
+                background: Some(palette.widget_background_color().into()),
+                ..Default::default()
+            }
+        });
+
+        let inputs_row = Row::new().spacing(12).push(value_editor);
+
+        let type_label = Column::new()
+            .spacing(4)
+            .push(
+                fonts::primary_text(tr("Value Type"), None)
+                    .color(muted_color)
+                    .wrapping(Wrapping::Word)
+                    .width(Length::Shrink),
+            )
+            .push(type_indicator);
+
+        let mut type_row = Row::new().spacing(12).push(type_label);
 
-        let show_confirmation_input = match state.kind {
-            CollectionModalKind::DeleteAllDocuments | CollectionModalKind::DeleteCollection => {
-                strict_delete_confirmation
-            }
-            CollectionModalKind::DropIndex { .. } => strict_delete_confirmation,
-            _ => true,
+        let picker_column = |label: &'static str, picker: Element<'a, Message>| {
+            Column::new()
+                .spacing(4)
+                .width(Length::FillPortion(2))
+                .push(fonts::primary_text(tr(label), None).color(muted_color))
+                .push(picker)
         };
 
-        if show_confirmation_input {
-            let input_field = text_input(placeholder, &state.input)
-                .padding([6, 10])
+        if let Some(binary) = &state.binary {
+            type_row = type_row
+                .push(picker_column(
+                    "Subtype",
+                    PickList::new(
+                        BINARY_SUBTYPE_CHOICES,
+                        Some(binary.subtype),
+                        Message::ValueEditModalBinarySubtypeChanged,
+                    )
+                    .width(Length::Fill)
+                    .into(),
+                ))
+                .push(picker_column(
+                    "Input Format",
+                    PickList::new(
+                        BINARY_INPUT_FORMATS,
+                        Some(binary.format),
+                        Message::ValueEditModalBinaryFormatChanged,
+                    )
+                    .width(Length::Fill)
+                    .into(),
+                ));
+        } else {
+            type_row = type_row.push(picker_column(
+                "Save As",
+                PickList::new(
+                    VALUE_TYPE_CHOICES,
+                    Some(ValueTypeChoice(state.forced_kind)),
+                    Message::ValueEditModalTypeChanged,
+                )
                 .width(Length::Fill)
-                .on_input(Message::CollectionModalInputChanged);
-            column = column.push(input_field);
+                .into(),
+            ));
         }
 
+        let mut column =
+            Column::new().spacing(16).push(description).push(inputs_row).push(type_row);
+
         if let Some(error) = &state.error {
             column = column.push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
         }
 
+        if let Some(warning) = &state.type_change_warning {
+            column = column.push(
+                fonts::primary_text(
+                    format!("{} {}", warning, tr("Press Save Anyway to confirm.")),
+                    Some(-1.0),
+                )
+                .color(warning_accent_color(&palette)),
+            );
+        }
+
         if state.processing {
             column = column
-                .push(fonts::primary_text(tr("Processing..."), Some(-1.0)).color(accent_color));
+                .push(fonts::primary_text(tr("Saving value..."), Some(-1.0)).color(accent_color));
         }
 
         let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
             .padding([6, 16])
-            .on_press(Message::CollectionModalCancel)
+            .on_press(Message::ValueEditModalCancel)
             .style({
                 let palette = palette.clone();
                 move |_, status| palette.subtle_button_style(6.0, status)
             });
 
-        let mut confirm_button =
-            Button::new(fonts::primary_text(confirm_label, None)).padding([6, 16]);
-        if confirm_ready {
-            confirm_button = confirm_button
+        let save_label =
+            if state.type_change_warning.is_some() { tr("Save Anyway") } else { tr("Save") };
+        let mut save_button = Button::new(fonts::primary_text(save_label, None)).padding([6, 16]);
+        if state.processing {
+            save_button = save_button.style({
+                let palette = palette.clone();
+                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+            });
+        } else {
+            save_button = save_button
                 .style({
                     let palette = palette.clone();
                     move |_, status| palette.primary_button_style(6.0, status)
                 })
-                .on_press(Message::CollectionModalConfirm);
-        } else {
-            confirm_button = confirm_button.style({
-                let palette = palette.clone();
-                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
-            });
+                .on_press(Message::ValueEditModalSave);
         }
 
-        let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
-
+        let buttons = Row::new()
+            .spacing(12)
+            .push(Space::new().width(Length::Fill))
+            .push(cancel_button)
+            .push(save_button);
         column = column.push(buttons);
 
         let content: Element<Message> = column.into();
-        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+        let modal_width = self.adaptive_modal_width(VALUE_EDIT_MODAL_MIN_WIDTH);
+        modal_layout(palette, content, modal_width, 24, 12.0)
     }
 
-    fn database_modal_view(&self, state: &DatabaseModalState) -> Element<'_, Message> {
+    fn document_diff_modal_view<'a>(
+        &self,
+        state: &'a DocumentDiffModalState,
+    ) -> Element<'a, Message> {
         let palette = self.active_palette();
         let text_primary = palette.text_primary.to_color();
         let muted_color = palette.text_muted.to_color();
-        let error_color = error_accent_color(&palette);
-        let accent_color = success_accent_color(&palette);
+        let removed_color = error_accent_color(&palette);
+        let added_color = success_accent_color(&palette);
+        let changed_color = warning_accent_color(&palette);
+        let fonts_state = fonts::active_fonts();
+        let bold_font = Font { weight: Weight::Bold, ..fonts_state.primary_font };
 
-        let base = match &state.mode {
-            DatabaseModalMode::Drop { db_name } => {
-                let warning = tr_format(
-                    "Database \"{}\" will be deleted along with all collections and documents. This action cannot be undone.",
-                    &[db_name.as_str()],
-                );
-                let prompt = if self.settings.strict_delete_confirmation {
-                    Some(tr_format(
-                        "Confirm deletion of all data by entering the database name \"{}\".",
-                        &[db_name.as_str()],
-                    ))
-                } else {
-                    None
-                };
+        let title = fonts::primary_text(tr("Compare Documents"), Some(6.0)).color(text_primary);
+
+        let summary = &state.summary;
+        let summary_text = if summary.has_differences() {
+            tr_format(
+                "Added: {}, removed: {}, changed: {}, unchanged: {}",
+                &[
+                    &summary.added.to_string(),
+                    &summary.removed.to_string(),
+                    &summary.changed.to_string(),
+                    &summary.unchanged.to_string(),
+                ],
+            )
+        } else {
+            String::from(tr("Documents are identical"))
+        };
+        let hint = fonts::primary_text(summary_text, Some(-1.0)).color(muted_color);
 
-                let confirm_ready = !state.processing
-                    && (!self.settings.strict_delete_confirmation || state.input.trim() == db_name);
+        let cell = |content: String, color: Color, portion: u16, font: Option<Font>| {
+            let mut label = fonts::result_text(content, None)
+                .color(color)
+                .wrapping(Wrapping::Word)
+                .width(Length::Fill);
+            if let Some(font) = font {
+                label = label.font(font);
+            }
+            Container::new(label).width(Length::FillPortion(portion)).padding([4, 8])
+        };
 
-                let mut column = Column::new()
-                    .spacing(16)
-                    .push(fonts::primary_text(tr("Delete Database"), Some(6.0)).color(text_primary))
-                    .push(fonts::primary_text(warning, None).color(muted_color));
+        let header = Row::new()
+            .spacing(0)
+            .push(cell(String::from(tr("Field")), text_primary, 3, Some(bold_font)))
+            .push(cell(state.left_title.clone(), text_primary, 4, Some(bold_font)))
+            .push(cell(state.right_title.clone(), text_primary, 4, Some(bold_font)));
+
+        let mut rows = Column::new().spacing(2).width(Length::Fill);
+        for entry in &state.entries {
+            let (marker, color) = match entry.kind {
+                DiffKind::Added => ("+", added_color),
+                DiffKind::Removed => ("-", removed_color),
+                DiffKind::Changed => ("~", changed_color),
+                DiffKind::Unchanged => {
+                    if !state.show_unchanged {
+                        continue;
+                    }
+                    (" ", muted_color)
+                }
+            };
 
-                if let Some(prompt) = prompt {
-                    column =
-                        column.push(fonts::primary_text(prompt, Some(-1.0)).color(muted_color));
+            let value_text = |value: &Option<Bson>| {
+                value.as_ref().map(shell::format_shell_value).unwrap_or_default()
+            };
 
-                    let input_field = text_input(tr("Database name"), &state.input)
-                        .padding([6, 10])
-                        .width(Length::Fill)
-                        .on_input(Message::DatabaseModalInputChanged);
+            let row = Row::new()
+                .spacing(0)
+                .push(cell(format!("{marker} {}", entry.path), color, 3, None))
+                .push(cell(value_text(&entry.left), color, 4, None))
+                .push(cell(value_text(&entry.right), color, 4, None));
+            rows = rows.push(row);
+        }
 
-                    column = column.push(input_field);
-                }
+        let table = Column::new()
+            .spacing(4)
+            .push(header)
+            .push(Scrollable::new(rows).width(Length::Fill).height(Length::Fixed(360.0)));
 
-                if let Some(error) = &state.error {
-                    column = column
-                        .push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
-                }
+        let table_container = Container::new(table).width(Length::Fill).padding(8).style({
+            let palette = palette.clone();
+            move |_| container::Style {
+                border: border::rounded(8).width(1).color(palette.widget_border_color()),
+                background: Some(palette.widget_background_color().into()),
+                ..Default::default()
+            }
+        });
 
-                if state.processing {
-                    column = column.push(
-                        fonts::primary_text(tr("Processing..."), Some(-1.0)).color(accent_color),
-                    );
-                }
+        let show_unchanged = Checkbox::new(state.show_unchanged)
+            .label(tr("Show unchanged fields"))
+            .font(fonts_state.primary_font)
+            .text_size(fonts_state.primary_size)
+            .on_toggle(Message::DocumentDiffModalToggleUnchanged);
 
-                let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
-                    .padding([6, 16])
-                    .on_press(Message::DatabaseModalCancel)
-                    .style({
-                        let palette = palette.clone();
-                        move |_, status| palette.subtle_button_style(6.0, status)
-                    });
+        let close_button = Button::new(fonts::primary_text(tr("Close"), None))
+            .padding([6, 16])
+            .on_press(Message::DocumentDiffModalClose)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
 
-                let mut confirm_button =
-                    Button::new(fonts::primary_text(tr("Confirm Deletion"), None)).padding([6, 16]);
+        let buttons = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(show_unchanged)
+            .push(Space::new().width(Length::Fill))
+            .push(close_button);
 
-                if confirm_ready {
-                    confirm_button = confirm_button
-                        .style({
-                            let palette = palette.clone();
-                            move |_, status| palette.primary_button_style(6.0, status)
-                        })
-                        .on_press(Message::DatabaseModalConfirm);
-                } else {
-                    confirm_button = confirm_button.style({
-                        let palette = palette.clone();
-                        move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
-                    });
-                }
+        let column =
+            Column::new().spacing(16).push(title).push(hint).push(table_container).push(buttons);
 
-                let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
-                column = column.push(buttons);
+        let content: Element<Message> = column.into();
+        let modal_width = self.adaptive_modal_width(DOCUMENT_MODAL_MIN_WIDTH);
+        modal_layout(palette, content, modal_width, 24, 12.0)
+    }
 
-                column
+    fn close_tab(&mut self, id: TabId) -> Task<Message> {
+        if let Some(position) = self.tabs.iter().position(|tab| tab.id == id) {
+            let was_active = self.active_tab == Some(id);
+            self.tabs.remove(position);
+            if self.tab_color_picker == Some(id) {
+                self.tab_color_picker = None;
             }
-            DatabaseModalMode::Create => {
-                let confirm_ready = !state.processing
-                    && !state.input.trim().is_empty()
-                    && !state.collection_input.trim().is_empty();
+            if self.tab_title_edit.as_ref().is_some_and(|(edit_id, _)| *edit_id == id) {
+                self.tab_title_edit = None;
+            }
+            if self.side_by_side.as_ref().is_some_and(|state| state.contains(id)) {
+                self.side_by_side = None;
+            }
+            if was_active {
+                self.active_tab = self
+                    .tabs
+                    .get(position.saturating_sub(1))
+                    .or_else(|| self.tabs.get(position))
+                    .map(|tab| tab.id);
+            }
+            log::debug!("Tab closed id={}", id);
+            if was_active {
+                return self.restore_active_response_scroll();
+            }
+        }
+        Task::none()
+    }
 
-                let mut column = Column::new()
-                    .spacing(16)
-                    .push(fonts::primary_text(tr("Create Database"), Some(6.0)).color(text_primary))
-                    .push(fonts::primary_text(tr(
-                            "MongoDB creates a database only when the first collection is created. Provide the database name and the first collection to create immediately."
-                        ), Some(-1.0)).color(muted_color));
+    fn tab_close_confirmation_view(&self, tab_id: TabId) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+        let title = self
+            .tabs
+            .iter()
+            .find(|tab| tab.id == tab_id)
+            .map(|tab| tab.title.clone())
+            .unwrap_or_default();
 
-                let input_field = text_input(tr("Database name"), &state.input)
-                    .padding([6, 10])
-                    .width(Length::Fill)
-                    .on_input(Message::DatabaseModalInputChanged);
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::TabCloseCancelled)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let close_button = Button::new(fonts::primary_text(tr("Close Anyway"), None))
+            .padding([6, 16])
+            .on_press(Message::TabCloseConfirmed)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
+            });
 
-                let collection_field =
-                    text_input(tr("First collection name"), &state.collection_input)
-                        .padding([6, 10])
-                        .width(Length::Fill)
-                        .on_input(Message::DatabaseModalCollectionInputChanged);
+        let column = Column::new()
+            .spacing(16)
+            .push(fonts::primary_text(tr("Close Tab"), Some(6.0)).color(text_primary))
+            .push(
+                fonts::primary_text(
+                    tr_format(
+                        "The query in tab \"{}\" was edited and not run. Closing the tab discards it.",
+                        &[&title],
+                    ),
+                    None,
+                )
+                .color(muted_color),
+            )
+            .push(Row::new().spacing(12).push(cancel_button).push(close_button));
 
-                column = column.push(input_field).push(collection_field);
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+    }
 
-                if let Some(error) = &state.error {
-                    column = column
-                        .push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
-                }
+    fn array_element_delete_view(&self, state: &ArrayElementDeleteState) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
 
-                if state.processing {
-                    column = column.push(
-                        fonts::primary_text(tr("Creating database..."), Some(-1.0))
-                            .color(accent_color),
-                    );
-                }
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::ArrayElementDeleteCancelled)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let delete_button = Button::new(fonts::primary_text(tr("Delete"), None))
+            .padding([6, 16])
+            .on_press(Message::ArrayElementDeleteConfirmed)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
+            });
 
-                let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
-                    .padding([6, 16])
-                    .on_press(Message::DatabaseModalCancel)
-                    .style({
-                        let palette = palette.clone();
-                        move |_, status| palette.subtle_button_style(6.0, status)
-                    });
+        let column = Column::new()
+            .spacing(16)
+            .push(fonts::primary_text(tr("Delete Array Element"), Some(6.0)).color(text_primary))
+            .push(
+                fonts::primary_text(
+                    tr_format(
+                        "The element {} is removed from the document. This cannot be undone.",
+                        &[&state.context.path],
+                    ),
+                    None,
+                )
+                .color(muted_color),
+            )
+            .push(Row::new().spacing(12).push(cancel_button).push(delete_button));
+
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+    }
 
-                let mut confirm_button =
-                    Button::new(fonts::primary_text(tr("Create"), None)).padding([6, 16]);
+    fn aggregate_write_modal_view(&self, state: &AggregateWriteModalState) -> Element<'_, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
 
-                if confirm_ready {
-                    confirm_button = confirm_button
-                        .style({
-                            let palette = palette.clone();
-                            move |_, status| palette.primary_button_style(6.0, status)
-                        })
-                        .on_press(Message::DatabaseModalConfirm);
-                } else {
-                    confirm_button = confirm_button.style({
-                        let palette = palette.clone();
-                        move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
-                    });
-                }
+        let target = &state.target;
+        let target_namespace = format!("{}.{}", target.db_name, target.collection);
+        let source_namespace = format!("{}.{}", state.source_db, state.source_collection);
 
-                let buttons = Row::new().spacing(12).push(cancel_button).push(confirm_button);
-                column = column.push(buttons);
+        let mut column = Column::new()
+            .spacing(16)
+            .push(
+                fonts::primary_text(tr("Confirm Aggregation Output"), Some(6.0))
+                    .color(text_primary),
+            )
+            .push(
+                fonts::primary_text(
+                    tr_format(
+                        "The pipeline writes its results with {} into {}.",
+                        &[target.stage, &target_namespace],
+                    ),
+                    None,
+                )
+                .color(text_primary),
+            );
 
-                column
-            }
+        if target_namespace != source_namespace {
+            column = column.push(
+                fonts::primary_text(
+                    tr_format(
+                        "The target differs from the source collection {}.",
+                        &[&source_namespace],
+                    ),
+                    Some(-1.0),
+                )
+                .color(warning_accent_color(&palette)),
+            );
+        }
+
+        let consequence = if target.stage == "$out" {
+            tr("$out replaces all documents in the target collection.")
+        } else {
+            tr("$merge inserts or updates documents in the target collection.")
         };
+        column = column.push(fonts::primary_text(consequence, Some(-1.0)).color(muted_color));
 
-        let content: Element<Message> = base.into();
-        modal_layout(palette, content, Length::Fixed(420.0), 24, 12.0)
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::AggregateWriteCancel)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let confirm_button = Button::new(fonts::primary_text(tr("Run"), None))
+            .padding([6, 16])
+            .on_press(Message::AggregateWriteConfirm)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
+            });
+
+        column = column.push(Row::new().spacing(12).push(cancel_button).push(confirm_button));
+
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(460.0), 24, 12.0)
     }
 
-    fn document_modal_view<'a>(&self, state: &'a DocumentModalState) -> Element<'a, Message> {
+    fn date_format_preview_view<'a>(
+        &self,
+        state: &'a DateFormatPreviewState,
+    ) -> Element<'a, Message> {
         let palette = self.active_palette();
         let text_primary = palette.text_primary.to_color();
         let muted_color = palette.text_muted.to_color();
-        let error_color = error_accent_color(&palette);
-        let accent_color = success_accent_color(&palette);
 
-        let (title_text, hint_text, saving_text) = match &state.kind {
-            DocumentModalKind::CollectionDocument { .. } => (
-                tr("Edit Document"),
-                tr(
-                    "Edit the JSON representation of the document. The document will be fully replaced on save.",
-                ),
-                tr("Saving document..."),
-            ),
-            DocumentModalKind::Index { .. } => (
-                tr("Edit TTL Index"),
-                tr(
-                    "Only the \"expireAfterSeconds\" field value can be changed. Other parameters will be ignored.",
-                ),
-                tr("Saving index..."),
-            ),
+        let labeled = |label: &'static str, input: Element<'a, Message>| {
+            Row::new()
+                .spacing(12)
+                .align_y(Vertical::Center)
+                .push(
+                    Container::new(fonts::primary_text(tr(label), None).color(text_primary))
+                        .width(Length::Fixed(120.0)),
+                )
+                .push(input)
         };
 
-        let title = fonts::primary_text(title_text, Some(6.0)).color(text_primary);
-
-        let hint = fonts::primary_text(hint_text, Some(-1.0)).color(muted_color);
+        let field_row = labeled(
+            "Date field",
+            text_input("createdAt", &state.field)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::DateFormatPreviewFieldChanged)
+                .into(),
+        );
+        let sample_row = labeled(
+            "Sample date",
+            text_input("2024-03-15T13:45:30Z", &state.sample)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::DateFormatPreviewSampleChanged)
+                .into(),
+        );
+        let format_row = labeled(
+            "Format",
+            text_input(DEFAULT_DATE_TO_STRING_FORMAT, &state.format)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::DateFormatPreviewFormatChanged)
+                .into(),
+        );
+        let timezone_row = labeled(
+            "Timezone",
+            text_input("UTC", &state.timezone)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::DateFormatPreviewTimezoneChanged)
+                .into(),
+        );
 
-        let editor = text_editor::TextEditor::new(&state.editor)
-            .font(MONO_FONT)
-            .wrapping(Wrapping::Glyph)
-            .height(Length::Shrink)
-            .on_action(Message::DocumentModalEditorAction);
+        let preview = match preview_date_to_string(&state.sample, &state.format, &state.timezone) {
+            Ok(output) => fonts::result_text(output, None).color(text_primary),
+            Err(error) => {
+                fonts::primary_text(error, Some(-1.0)).color(error_accent_color(&palette))
+            }
+        };
+        let preview_row = labeled("Result", preview.into());
 
-        let editor_scroll = Scrollable::new(editor).width(Length::Fill).height(Length::Fill);
+        let hint = fonts::primary_text(
+            tr("Specifiers: %Y %m %d %H %M %S %L %j %w %u %U %V %G %b %B %z %Z %%."),
+            Some(-1.0),
+        )
+        .color(muted_color);
+        let expression = fonts::result_text(
+            date_to_string_expression(&state.field, &state.format, &state.timezone),
+            Some(-1.0),
+        )
+        .color(muted_color);
 
-        let editor_container =
-            Container::new(editor_scroll).width(Length::Fill).height(Length::Fill).style({
+        let close_button = Button::new(fonts::primary_text(tr("Close"), None))
+            .padding([6, 16])
+            .on_press(Message::DateFormatPreviewClose)
+            .style({
                 let palette = palette.clone();
-                move |_| container::Style {
-                    border: border::rounded(8).width(1).color(palette.widget_border_color()),
-                    background: Some(palette.widget_background_color().into()),
-                    ..Default::default()
-                }
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let copy_button = Button::new(fonts::primary_text(tr("Copy Expression"), None))
+            .padding([6, 16])
+            .on_press(Message::DateFormatPreviewCopyExpression)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
             });
 
-        let mut column = Column::new().spacing(16).push(title).push(hint).push(editor_container);
+        let column = Column::new()
+            .spacing(12)
+            .push(fonts::primary_text(tr("Date Format Preview"), Some(6.0)).color(text_primary))
+            .push(field_row)
+            .push(sample_row)
+            .push(format_row)
+            .push(timezone_row)
+            .push(preview_row)
+            .push(hint)
+            .push(expression)
+            .push(Row::new().spacing(12).push(close_button).push(copy_button));
 
-        if let Some(error) = &state.error {
-            column = column.push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
-        }
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(520.0), 24, 12.0)
+    }
 
-        if state.processing {
-            column = column.push(fonts::primary_text(saving_text, Some(-1.0)).color(accent_color));
-        }
+    fn text_search_modal_view<'a>(&self, state: &'a TextSearchModalState) -> Element<'a, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+
+        let mut column = Column::new()
+            .spacing(16)
+            .push(fonts::primary_text(tr("Text Search"), Some(6.0)).color(text_primary));
+
+        let ready = match &state.text_index {
+            None => {
+                column = column.push(
+                    fonts::primary_text(tr("Checking text indexes..."), Some(-1.0))
+                        .color(muted_color),
+                );
+                false
+            }
+            Some(Err(error)) => {
+                column = column.push(
+                    fonts::primary_text(error.clone(), Some(-1.0))
+                        .color(error_accent_color(&palette)),
+                );
+                false
+            }
+            Some(Ok(index_name)) => {
+                column = column
+                    .push(
+                        fonts::primary_text(
+                            tr_format(
+                                "Search collection \"{}\" using text index \"{}\". Results are sorted by relevance.",
+                                &[state.collection.as_str(), index_name.as_str()],
+                            ),
+                            Some(-1.0),
+                        )
+                        .color(muted_color),
+                    )
+                    .push(
+                        text_input(tr("Search terms"), &state.terms)
+                            .padding([6, 10])
+                            .width(Length::Fill)
+                            .on_input(Message::TextSearchTermsChanged)
+                            .on_submit(Message::TextSearchRun),
+                    );
+                true
+            }
+        };
 
         let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
             .padding([6, 16])
-            .on_press(Message::DocumentModalCancel)
+            .on_press(Message::TextSearchCancel)
             .style({
                 let palette = palette.clone();
                 move |_, status| palette.subtle_button_style(6.0, status)
             });
-
-        let mut save_button = Button::new(fonts::primary_text(tr("Save"), None)).padding([6, 16]);
-        if state.processing {
-            save_button = save_button.style({
+        let mut search_button =
+            Button::new(fonts::primary_text(tr("Search"), None)).padding([6, 16]).style({
                 let palette = palette.clone();
-                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+                move |_, status| palette.primary_button_style(6.0, status)
             });
-        } else {
-            save_button = save_button
-                .style({
-                    let palette = palette.clone();
-                    move |_, status| palette.primary_button_style(6.0, status)
-                })
-                .on_press(Message::DocumentModalSave);
+        if ready && !state.terms.trim().is_empty() {
+            search_button = search_button.on_press(Message::TextSearchRun);
         }
 
-        let buttons = Row::new().spacing(12).push(cancel_button).push(save_button);
-        column = column.push(buttons);
+        column = column.push(Row::new().spacing(12).push(cancel_button).push(search_button));
         let content: Element<Message> = column.into();
-        let modal_width = self.adaptive_modal_width(DOCUMENT_MODAL_MIN_WIDTH);
-        modal_layout(palette, content, modal_width, 24, 12.0)
+        modal_layout(palette, content, Length::Fixed(480.0), 24, 12.0)
     }
 
-    fn value_edit_modal_view<'a>(&self, state: &'a ValueEditModalState) -> Element<'a, Message> {
+    fn projection_preset_modal_view<'a>(
+        &self,
+        state: &'a ProjectionPresetModalState,
+    ) -> Element<'a, Message> {
         let palette = self.active_palette();
         let text_primary = palette.text_primary.to_color();
         let muted_color = palette.text_muted.to_color();
-        let error_color = error_accent_color(&palette);
-        let accent_color = success_accent_color(&palette);
-        let fonts_state = fonts::active_fonts();
-        let bold_font = Font { weight: Weight::Bold, ..fonts_state.primary_font };
 
-        let description = Column::new()
-            .spacing(4)
+        let mut column = Column::new()
+            .spacing(16)
+            .push(fonts::primary_text(tr("Projection Preset"), Some(6.0)).color(text_primary))
             .push(
-                fonts::primary_text(tr("Field value will be modified"), None)
-                    .wrapping(Wrapping::Word)
-                    .width(Length::Fill),
+                fonts::primary_text(
+                    tr(
+                        "Find queries without their own projection return only these fields. Saving an existing name replaces that preset.",
+                    ),
+                    Some(-1.0),
+                )
+                .color(muted_color),
             )
+            .push(fonts::primary_text(tr("Name"), None).color(text_primary))
             .push(
-                fonts::primary_text(state.path.clone(), None)
-                    .wrapping(Wrapping::Word)
+                text_input(tr("summary"), &state.name)
+                    .padding([6, 10])
                     .width(Length::Fill)
-                    .font(bold_font),
+                    .on_input(Message::ProjectionPresetNameChanged)
+                    .on_submit(Message::ProjectionPresetSave),
+            )
+            .push(fonts::primary_text(tr("Fields"), None).color(text_primary))
+            .push(
+                text_input("_id, name, status", &state.fields)
+                    .padding([6, 10])
+                    .width(Length::Fill)
+                    .on_input(Message::ProjectionPresetFieldsChanged)
+                    .on_submit(Message::ProjectionPresetSave),
+            );
+
+        if let Some(error) = &state.error {
+            column = column.push(
+                fonts::primary_text(error.clone(), Some(-1.0)).color(error_accent_color(&palette)),
             );
+        }
+
+        let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+            .padding([6, 16])
+            .on_press(Message::ProjectionPresetCancel)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let save_button = Button::new(fonts::primary_text(tr("Save"), None))
+            .padding([6, 16])
+            .on_press(Message::ProjectionPresetSave)
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
+            });
+
+        column = column.push(Row::new().spacing(12).push(cancel_button).push(save_button));
+        let content: Element<Message> = column.into();
+        modal_layout(palette, content, Length::Fixed(480.0), 24, 12.0)
+    }
+
+    fn index_builder_load_indexes_task(
+        &self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+    ) -> Task<Message> {
+        let message_db = db_name.clone();
+        let message_collection = collection.clone();
+        self.load_collection_indexes_task(client_id, db_name, collection, move |result| {
+            Message::IndexBuilderIndexesLoaded {
+                client_id,
+                db_name: message_db,
+                collection: message_collection,
+                result,
+            }
+        })
+    }
+
+    /// Loads the collection `options` (validator, capped settings, ...) from `listCollections`.
+    fn load_collection_options_task(
+        &self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        on_loaded: impl FnOnce(Result<Document, String>) -> Message + Send + 'static,
+    ) -> Task<Message> {
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            return Task::done(on_loaded(Err(String::from(tr("No active connection.")))));
+        };
+
+        Task::perform(
+            async move {
+                let reply = handle
+                    .database(&db_name)
+                    .run_command(doc! { "listCollections": 1, "filter": { "name": &collection } })
+                    .run()
+                    .map_err(|error| error.to_string())?;
+                let info = reply
+                    .get_document("cursor")
+                    .and_then(|cursor| cursor.get_array("firstBatch"))
+                    .ok()
+                    .and_then(|batch| batch.first())
+                    .and_then(Bson::as_document)
+                    .ok_or_else(|| tr_format("Collection \"{}\" not found.", &[&collection]))?;
+                Ok(info.get_document("options").cloned().unwrap_or_default())
+            },
+            on_loaded,
+        )
+    }
+
+    fn load_collection_indexes_task(
+        &self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        on_loaded: impl FnOnce(Result<Vec<Document>, String>) -> Message + Send + 'static,
+    ) -> Task<Message> {
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            return Task::done(on_loaded(Err(String::from(tr("No active connection.")))));
+        };
+
+        let timeout = self.settings.query_timeout_duration();
+        Task::perform(
+            async move {
+                match run_collection_query(
+                    handle,
+                    db_name,
+                    collection,
+                    QueryOperation::ListIndexes,
+                    0,
+                    0,
+                    timeout,
+                )? {
+                    QueryResult::Indexes(values) => Ok(values
+                        .into_iter()
+                        .filter_map(|value| match value {
+                            Bson::Document(document) => Some(document),
+                            _ => None,
+                        })
+                        .collect()),
+                    _ => Ok(Vec::new()),
+                }
+            },
+            on_loaded,
+        )
+    }
+
+    fn index_builder_modal_view<'a>(
+        &self,
+        state: &'a IndexBuilderModalState,
+    ) -> Element<'a, Message> {
+        let palette = self.active_palette();
+        let text_primary = palette.text_primary.to_color();
+        let muted_color = palette.text_muted.to_color();
+        let error_color = error_accent_color(&palette);
+        let fonts_state = fonts::active_fonts();
 
-        let editor = text_editor::TextEditor::new(&state.value_editor)
-            .font(MONO_FONT)
-            .wrapping(Wrapping::Glyph)
-            .height(Length::Shrink)
-            .on_action(Message::ValueEditModalEditorAction);
+        let title = fonts::primary_text(tr("Index Builder"), Some(6.0)).color(text_primary);
+        let hint = fonts::primary_text(
+            tr_format(
+                "Create an index on collection \"{}\" in database \"{}\".",
+                &[state.collection.as_str(), state.db_name.as_str()],
+            ),
+            Some(-1.0),
+        )
+        .color(muted_color);
 
-        let editor_scroll =
-            Scrollable::new(editor).width(Length::Fill).height(Length::Fixed(220.0));
+        let can_remove = state.spec.keys.len() > 1;
+        let mut keys = Column::new().spacing(8);
+        for (index, (field, kind)) in state.spec.keys.iter().enumerate() {
+            let mut remove_button =
+                Button::new(fonts::primary_text(tr("×"), None)).padding([6, 10]).style({
+                    let palette = palette.clone();
+                    move |_, status| palette.subtle_button_style(6.0, status)
+                });
+            if can_remove {
+                remove_button = remove_button.on_press(Message::IndexBuilderRemoveKey(index));
+            }
 
-        let value_editor = Container::new(editor_scroll)
-            .width(Length::FillPortion(5))
-            .height(Length::Fixed(220.0))
+            let row = Row::new()
+                .spacing(8)
+                .align_y(Vertical::Center)
+                .push(
+                    text_input(tr("Field"), field)
+                        .padding([6, 10])
+                        .width(Length::Fill)
+                        .on_input(move |value| Message::IndexBuilderFieldChanged(index, value)),
+                )
+                .push(
+                    PickList::new(INDEX_KEY_KINDS, Some(*kind), move |kind| {
+                        Message::IndexBuilderKindChanged(index, kind)
+                    })
+                    .width(Length::Fixed(120.0)),
+                )
+                .push(remove_button);
+            keys = keys.push(row);
+        }
+
+        let add_key_button = Button::new(fonts::primary_text(tr("Add Field"), None))
+            .padding([6, 12])
+            .on_press(Message::IndexBuilderAddKey)
             .style({
                 let palette = palette.clone();
-                move |_| container::Style {
-                    border: border::rounded(6).width(1).color(palette.widget_border_color()),
-                    background: Some(palette.widget_background_color().into()),
-                    ..Default::default()
-                }
+                move |_, status| palette.subtle_button_style(6.0, status)
             });
 
-        let type_indicator = Container::new(
-            fonts::primary_text(state.value_label.clone(), None)
-                .color(text_primary)
-                .wrapping(Wrapping::Word)
-                .width(Length::Fill),
-        )
-        .padding([6, 10])
-        .width(Length::FillPortion(2))
-        .style({
-            let palette = palette.clone();
-            move |_| container::Style {
-                border: border::rounded(6).width(1).color(palette.widget_border_color()),
-                background: Some(palette.widget_background_color().into()),
-                ..Default::default()
-            }
-        });
+        let labeled = |label: &'static str, input: Element<'a, Message>| {
+            Row::new()
+                .spacing(12)
+                .align_y(Vertical::Center)
+                .push(
+                    Container::new(fonts::primary_text(tr(label), None).color(text_primary))
+                        .width(Length::Fixed(180.0)),
+                )
+                .push(input)
+        };
 
-        let inputs_row = Row::new().spacing(12).push(value_editor);
+        let name_row = labeled(
+            "Index name",
+            text_input(tr("Generated from fields"), &state.spec.name)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::IndexBuilderNameChanged)
+                .into(),
+        );
+        let ttl_row = labeled(
+            "TTL (seconds)",
+            text_input(tr("No expiration"), &state.spec.ttl_seconds)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::IndexBuilderTtlChanged)
+                .into(),
+        );
+        let partial_row = labeled(
+            "Partial filter",
+            text_input("{ field: { $exists: true } }", &state.spec.partial_filter)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::IndexBuilderPartialFilterChanged)
+                .into(),
+        );
 
-        let type_label = Column::new()
-            .spacing(4)
+        let flags = Row::new()
+            .spacing(24)
             .push(
-                fonts::primary_text(tr("Value Type"), None)
-                    .color(muted_color)
-                    .wrapping(Wrapping::Word)
-                    .width(Length::Shrink),
+                Checkbox::new(state.spec.unique)
+                    .label(tr("Unique"))
+                    .font(fonts_state.primary_font)
+                    .text_size(fonts_state.primary_size)
+                    .on_toggle(Message::IndexBuilderUniqueToggled),
             )
-            .push(type_indicator);
+            .push(
+                Checkbox::new(state.spec.sparse)
+                    .label(tr("Sparse"))
+                    .font(fonts_state.primary_font)
+                    .text_size(fonts_state.primary_size)
+                    .on_toggle(Message::IndexBuilderSparseToggled),
+            );
 
-        let type_row = Row::new().spacing(12).push(type_label);
+        let existing: Element<'a, Message> = match &state.existing_indexes {
+            None => fonts::primary_text(tr("Loading existing indexes..."), Some(-1.0))
+                .color(muted_color)
+                .into(),
+            Some(Err(error)) => fonts::primary_text(
+                tr_format("Existing indexes are unavailable: {}", &[error]),
+                Some(-1.0),
+            )
+            .color(muted_color)
+            .into(),
+            Some(Ok(indexes)) => {
+                let mut list = Column::new()
+                    .spacing(2)
+                    .push(fonts::primary_text(tr("Existing indexes"), None).color(text_primary));
+                for index in indexes {
+                    let name = index.get_str("name").unwrap_or_default();
+                    let keys = index
+                        .get_document("key")
+                        .map(|keys| shell::format_shell_value(&Bson::Document(keys.clone())))
+                        .unwrap_or_default();
+                    list = list.push(
+                        fonts::result_text(format!("{name}  {keys}"), Some(-1.0))
+                            .color(muted_color)
+                            .wrapping(Wrapping::Word),
+                    );
+                }
+                Scrollable::new(list).height(Length::Shrink).into()
+            }
+        };
 
-        let mut column =
-            Column::new().spacing(16).push(description).push(inputs_row).push(type_row);
+        let mut column = Column::new()
+            .spacing(16)
+            .push(title)
+            .push(hint)
+            .push(existing)
+            .push(keys)
+            .push(add_key_button)
+            .push(name_row)
+            .push(flags)
+            .push(ttl_row)
+            .push(partial_row);
+
+        if let Some(name) = state.duplicate_index_name() {
+            column = column.push(
+                fonts::primary_text(
+                    tr_format(
+                        "Index \"{}\" already uses this key pattern. You can still create the index.",
+                        &[name],
+                    ),
+                    Some(-1.0),
+                )
+                .color(warning_accent_color(&palette)),
+            );
+        }
 
         if let Some(error) = &state.error {
             column = column.push(fonts::primary_text(error.clone(), Some(-1.0)).color(error_color));
         }
 
-        if state.processing {
-            column = column
-                .push(fonts::primary_text(tr("Saving value..."), Some(-1.0)).color(accent_color));
-        }
-
         let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
             .padding([6, 16])
-            .on_press(Message::ValueEditModalCancel)
+            .on_press(Message::IndexBuilderCancel)
             .style({
                 let palette = palette.clone();
                 move |_, status| palette.subtle_button_style(6.0, status)
             });
-
-        let mut save_button = Button::new(fonts::primary_text(tr("Save"), None)).padding([6, 16]);
-        if state.processing {
-            save_button = save_button.style({
+        let insert_button = Button::new(fonts::primary_text(tr("Open in Editor"), None))
+            .padding([6, 16])
+            .on_press(Message::IndexBuilderSubmit { run: false })
+            .style({
                 let palette = palette.clone();
-                move |_, _| palette.primary_button_style(6.0, button::Status::Disabled)
+                move |_, status| palette.subtle_button_style(6.0, status)
+            });
+        let create_button = Button::new(fonts::primary_text(tr("Create Index"), None))
+            .padding([6, 16])
+            .on_press(Message::IndexBuilderSubmit { run: true })
+            .style({
+                let palette = palette.clone();
+                move |_, status| palette.primary_button_style(6.0, status)
             });
-        } else {
-            save_button = save_button
-                .style({
-                    let palette = palette.clone();
-                    move |_, status| palette.primary_button_style(6.0, status)
-                })
-                .on_press(Message::ValueEditModalSave);
-        }
 
         let buttons = Row::new()
             .spacing(12)
             .push(Space::new().width(Length::Fill))
             .push(cancel_button)
-            .push(save_button);
+            .push(insert_button)
+            .push(create_button);
         column = column.push(buttons);
 
         let content: Element<Message> = column.into();
-        let modal_width = self.adaptive_modal_width(VALUE_EDIT_MODAL_MIN_WIDTH);
+        let modal_width = self.adaptive_modal_width(DOCUMENT_MODAL_MIN_WIDTH);
         modal_layout(palette, content, modal_width, 24, 12.0)
     }
 
@@ -4845,14 +9675,43 @@ impl App {
         }
     }
 
+    fn sidebar_density(&self) -> SidebarDensity {
+        if self.settings.compact_sidebar { SIDEBAR_COMPACT } else { SIDEBAR_COMFORTABLE }
+    }
+
     fn sidebar_panel(&self) -> Element<'_, Message> {
-        let mut list = Column::new().spacing(4);
+        let mut list = Column::new().spacing(self.sidebar_density().item_spacing);
         let palette = self.active_palette();
         let muted_color = palette.text_muted.to_color();
 
         if self.clients.is_empty() {
             list =
                 list.push(fonts::primary_text(tr("No connections"), Some(6.0)).color(muted_color));
+        } else if self.settings.group_connections_by_host {
+            let mut groups: Vec<(String, Vec<&OMDBClient>)> = Vec::new();
+            for client in &self.clients {
+                let key = OMDBConnection::from_entry(client.entry.clone()).host_key();
+                match groups.iter_mut().find(|(host, _)| *host == key) {
+                    Some((_, clients)) => clients.push(client),
+                    None => groups.push((key, vec![client])),
+                }
+            }
+
+            for (host, clients) in groups {
+                if let [client] = clients.as_slice() {
+                    list = list.push(self.render_client(client));
+                    continue;
+                }
+
+                list = list.push(fonts::primary_text(host, None).color(muted_color));
+                for client in clients {
+                    list = list.push(
+                        Row::new()
+                            .push(Space::new().width(Length::Fixed(16.0)))
+                            .push(self.render_client(client)),
+                    );
+                }
+            }
         } else {
             for client in &self.clients {
                 list = list.push(self.render_client(client));
@@ -4877,7 +9736,8 @@ impl App {
     }
 
     fn render_client<'a>(&'a self, client: &'a OMDBClient) -> Element<'a, Message> {
-        let icon_size = fonts::active_fonts().primary_size * 1.5;
+        let density = self.sidebar_density();
+        let icon_size = fonts::active_fonts().primary_size * density.icon_scale;
         let indicator = if client.expanded { "v" } else { ">" };
         let status_label = match &client.status {
             ConnectionStatus::Connecting => tr("Connecting...").to_owned(),
@@ -4889,8 +9749,8 @@ impl App {
         let text_color = palette.text_primary.to_color();
         let muted_color = palette.text_muted.to_color();
 
-        let header_row = Row::new()
-            .spacing(8)
+        let mut header_row = Row::new()
+            .spacing(density.header_spacing)
             .align_y(Vertical::Center)
             .push(fonts::primary_text(indicator, None).color(muted_color))
             .push(
@@ -4898,7 +9758,15 @@ impl App {
                     .width(Length::Fixed(icon_size))
                     .height(Length::Fixed(icon_size)),
             )
-            .push(fonts::primary_text(client.name.clone(), Some(6.0)).color(text_color))
+            .push(fonts::primary_text(client.name.clone(), Some(6.0)).color(text_color));
+        if let Some(color) = client.entry.color {
+            header_row = header_row.push(accent_swatch(color.to_color(), 4.0, icon_size));
+        }
+        if client.entry.read_only {
+            header_row = header_row
+                .push(fonts::primary_text("🔒", Some(2.0)).color(warning_accent_color(&palette)));
+        }
+        let header_row = header_row
             .push(fonts::primary_text(status_label.clone(), Some(6.0)).color(muted_color));
 
         let base_button =
@@ -4914,7 +9782,7 @@ impl App {
             is_ready,
         );
 
-        let mut column = Column::new().spacing(4).push(menu);
+        let mut column = Column::new().spacing(density.item_spacing).push(menu);
 
         if matches!(client.status, ConnectionStatus::Failed(_)) {
             column = column.push(
@@ -4947,15 +9815,16 @@ impl App {
         client_id: ClientId,
         database: &'a DatabaseNode,
     ) -> Element<'a, Message> {
+        let density = self.sidebar_density();
         let primary_font_size = fonts::active_fonts().primary_size;
         let indicator = if database.expanded { "v" } else { ">" };
-        let icon_size = primary_font_size * 1.5;
+        let icon_size = primary_font_size * density.icon_scale;
         let palette = self.active_palette();
         let text_color = palette.text_primary.to_color();
         let muted_color = palette.text_muted.to_color();
 
         let db_row = Row::new()
-            .spacing(6)
+            .spacing(density.row_spacing)
             .align_y(Vertical::Center)
             .push(fonts::primary_text(indicator, None).color(muted_color))
             .push(
@@ -4964,6 +9833,11 @@ impl App {
                     .height(Length::Fixed(icon_size)),
             )
             .push(fonts::primary_text(database.name.clone(), None).color(text_color));
+        let db_row = match database.size {
+            Some(size) if self.settings.tree_sort_mode == TreeSortMode::Size => db_row
+                .push(fonts::primary_text(format_byte_size(size), Some(-2.0)).color(muted_color)),
+            _ => db_row,
+        };
 
         let base_button = self.sidebar_button(
             db_row,
@@ -4980,7 +9854,7 @@ impl App {
             db_name_owned.clone(),
         );
 
-        let mut column = Column::new().spacing(4).push(menu);
+        let mut column = Column::new().spacing(density.item_spacing).push(menu);
 
         if database.expanded {
             match &database.state {
@@ -5012,11 +9886,32 @@ impl App {
                                 ),
                         );
                     } else {
-                        for collection in &database.collections {
+                        let entry = self
+                            .clients
+                            .iter()
+                            .find(|client| client.id == client_id)
+                            .map(|client| &client.entry);
+                        let is_pinned = |collection: &CollectionNode| {
+                            entry.is_some_and(|entry| {
+                                entry.is_collection_pinned(&database.name, &collection.name)
+                            })
+                        };
+                        let (pinned, others): (Vec<_>, Vec<_>) =
+                            database.collections.iter().partition(|node| is_pinned(node));
+                        for collection in pinned {
+                            column = column.push(self.render_collection(
+                                client_id,
+                                &database.name,
+                                collection,
+                                true,
+                            ));
+                        }
+                        for collection in others {
                             column = column.push(self.render_collection(
                                 client_id,
                                 &database.name,
                                 collection,
+                                false,
                             ));
                         }
                     }
@@ -5032,14 +9927,16 @@ impl App {
         client_id: ClientId,
         db_name: &str,
         collection: &'a CollectionNode,
+        pinned: bool,
     ) -> Element<'a, Message> {
-        let icon_size = fonts::active_fonts().primary_size * 1.5;
+        let density = self.sidebar_density();
+        let icon_size = fonts::active_fonts().primary_size * density.icon_scale;
         let palette = self.active_palette();
         let text_color = palette.text_primary.to_color();
-        let _muted_color = palette.text_muted.to_color();
+        let muted_color = palette.text_muted.to_color();
 
         let row = Row::new()
-            .spacing(6)
+            .spacing(density.row_spacing)
             .align_y(Vertical::Center)
             .push(
                 Image::new(shared_icon_handle(&ICON_COLLECTION_HANDLE, ICON_COLLECTION_BYTES))
@@ -5047,6 +9944,26 @@ impl App {
                     .height(Length::Fixed(icon_size)),
             )
             .push(fonts::primary_text(collection.name.clone(), None).color(text_color));
+        let row = if pinned {
+            row.push(
+                fonts::primary_text("★", Some(-2.0))
+                    .color(palette.primary_buttons.active.to_color()),
+            )
+        } else {
+            row
+        };
+        let row = match collection.count {
+            Some(count) => {
+                row.push(fonts::primary_text(count.to_string(), Some(-2.0)).color(muted_color))
+            }
+            None => row,
+        };
+        let row = match collection.size {
+            Some(size) if self.settings.tree_sort_mode == TreeSortMode::Size => {
+                row.push(fonts::primary_text(format_byte_size(size), Some(-2.0)).color(muted_color))
+            }
+            _ => row,
+        };
 
         let db_name_owned = db_name.to_owned();
         let collection_name = collection.name.clone();
@@ -5073,6 +9990,7 @@ impl App {
             client_id,
             db_name_owned,
             collection_name,
+            pinned,
         )
     }
 
@@ -5085,7 +10003,7 @@ impl App {
     ) -> Element<'a, Message> {
         let palette = self.active_palette();
         let button = Button::new(content)
-            .padding([4, 4])
+            .padding(self.sidebar_density().button_padding)
             .width(Length::Shrink)
             .height(Length::Shrink)
             .style(move |_, status| palette.subtle_button_style(6.0, status))
@@ -5107,6 +10025,11 @@ impl App {
 
     fn tab_bar_available_width(&self) -> Option<f32> {
         let window_size = self.window_size?;
+        if self.sidebar_collapsed {
+            let width =
+                window_size.width - (MAIN_PANEL_PADDING * 2.0) - (TAB_HEADER_PADDING_X * 2.0);
+            return Some(width.max(0.0));
+        }
         let regions =
             self.panes.layout().pane_regions(PANE_GRID_SPACING, PANE_GRID_MIN_SIZE, window_size);
         let main_pane = self
@@ -5131,13 +10054,41 @@ impl App {
         title_width + close_width + padding + TAB_INNER_SPACING
     }
 
+    /// Formats tab titles according to the settings. Titles that still collide get the
+    /// connection and database added, and remaining duplicates are numbered.
+    fn tab_titles(&self) -> Vec<String> {
+        let format = self.settings.tab_title_format;
+        let mut titles: Vec<String> =
+            self.tabs.iter().map(|tab| tab.formatted_title(format)).collect();
+
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        for title in &titles {
+            *occurrences.entry(title.clone()).or_default() += 1;
+        }
+        for (tab, title) in self.tabs.iter().zip(titles.iter_mut()) {
+            if occurrences.get(title.as_str()).copied().unwrap_or_default() > 1 {
+                *title = tab.formatted_title(TabTitleFormat::ConnectionDatabaseCollection);
+            }
+        }
+
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for title in &mut titles {
+            let count = seen.entry(title.clone()).or_default();
+            *count += 1;
+            if *count > 1 {
+                title.push_str(&format!(" ({count})"));
+            }
+        }
+        titles
+    }
+
     fn estimate_tabs_row_width(&self) -> f32 {
         let mut width = 0.0;
-        for (index, tab) in self.tabs.iter().enumerate() {
+        for (index, (tab, title)) in self.tabs.iter().zip(self.tab_titles()).enumerate() {
             if index > 0 {
                 width += TAB_ROW_SPACING;
             }
-            width += self.estimate_tab_width(&tab.title);
+            width += self.estimate_tab_width(&tab.display_title(title));
         }
         width
     }
@@ -5179,8 +10130,9 @@ impl App {
             let inactive_bg = palette.subtle_buttons.active.to_color();
             let border_color = palette.subtle_buttons.border.to_color();
 
-            for tab in &self.tabs {
+            for (tab, title) in self.tabs.iter().zip(self.tab_titles()) {
                 let is_active = active_id == Some(tab.id);
+                let tab_accent = tab.collection.accent_color;
                 let tab_background =
                     tab.color.unwrap_or_else(|| if is_active { active_bg } else { inactive_bg });
                 let tab_text_color = if let Some(custom_color) = tab.color {
@@ -5189,10 +10141,27 @@ impl App {
                     text_color
                 };
 
-                let title_label = Container::new(fonts::primary_text(tab.title.clone(), None))
-                    .padding([4.0, TAB_TITLE_PADDING_X]);
-
-                let title_area = mouse_area(title_label).on_press(Message::TabSelected(tab.id));
+                let title_area: Element<'_, Message> = match &self.tab_title_edit {
+                    Some((edit_id, value)) if *edit_id == tab.id => {
+                        let width = self.estimate_tab_width(value).max(120.0);
+                        text_input(&title, value)
+                            .id(Id::new(TAB_TITLE_INPUT_ID))
+                            .on_input(Message::TabTitleChanged)
+                            .on_submit(Message::TabRenameSubmitted)
+                            .padding([2, 6])
+                            .width(Length::Fixed(width))
+                            .into()
+                    }
+                    _ => {
+                        let title_label =
+                            Container::new(fonts::primary_text(tab.display_title(title), None))
+                                .padding([4.0, TAB_TITLE_PADDING_X]);
+                        mouse_area(title_label)
+                            .on_press(Message::TabSelected(tab.id))
+                            .on_double_click(Message::TabRenameStarted(tab.id))
+                            .into()
+                    }
+                };
 
                 let close_button = Button::new(fonts::primary_text(tr("×"), None))
                     .padding([4.0, TAB_CLOSE_PADDING_X])
@@ -5209,7 +10178,9 @@ impl App {
                     .style(move |_| container::Style {
                         background: Some(tab_background.into()),
                         text_color: Some(tab_text_color),
-                        border: border::rounded(6).width(1).color(border_color),
+                        border: border::rounded(6)
+                            .width(if tab_accent.is_some() { 2 } else { 1 })
+                            .color(tab_accent.unwrap_or(border_color)),
                         ..Default::default()
                     });
                 let tab_container =
@@ -5218,6 +10189,9 @@ impl App {
                 let menu_palette = palette.clone();
                 let menu_border = palette.clone();
                 let menu_tab_id = tab.id;
+                let menu_tab_pinned = tab.pinned;
+                let menu_has_documents = tab.collection.has_document_result();
+                let menu_can_compare = active_id.is_some_and(|id| id != tab.id);
                 let menu = move || {
                     let item_palette = menu_palette.clone();
                     let border_palette = menu_border.clone();
@@ -5226,6 +10200,17 @@ impl App {
                             .padding([4, 8])
                             .on_press(Message::DuplicateTab(menu_tab_id))
                             .style(move |_, status| item_palette.menu_button_style(6.0, status));
+                    let rename_palette = menu_palette.clone();
+                    let rename_button = Button::new(fonts::primary_text(tr("Rename Tab"), None))
+                        .padding([4, 8])
+                        .on_press(Message::TabRenameStarted(menu_tab_id))
+                        .style(move |_, status| rename_palette.menu_button_style(6.0, status));
+                    let pin_palette = menu_palette.clone();
+                    let pin_label = if menu_tab_pinned { tr("Unpin Tab") } else { tr("Pin Tab") };
+                    let pin_button = Button::new(fonts::primary_text(pin_label, None))
+                        .padding([4, 8])
+                        .on_press(Message::TabPinToggled(menu_tab_id))
+                        .style(move |_, status| pin_palette.menu_button_style(6.0, status));
                     let color_palette = menu_palette.clone();
                     let color_button = Button::new(fonts::primary_text(tr("Tab Color"), None))
                         .padding([4, 8])
@@ -5237,11 +10222,35 @@ impl App {
                             .padding([4, 8])
                             .on_press(Message::TabColorReset(menu_tab_id))
                             .style(move |_, status| reset_palette.menu_button_style(6.0, status));
+                    let external_palette = menu_palette.clone();
+                    let external_button = Button::new(fonts::primary_text(
+                        tr("Open Response in External Editor"),
+                        None,
+                    ))
+                    .padding([4, 8])
+                    .on_press_maybe(
+                        menu_has_documents.then_some(Message::TabOpenExternal(menu_tab_id)),
+                    )
+                    .style(move |_, status| external_palette.menu_button_style(6.0, status));
+                    let compare_palette = menu_palette.clone();
+                    let compare_button = Button::new(fonts::primary_text(
+                        tr("Show Side by Side with Active Tab"),
+                        None,
+                    ))
+                    .padding([4, 8])
+                    .on_press_maybe(
+                        menu_can_compare.then_some(Message::SideBySideOpen(menu_tab_id)),
+                    )
+                    .style(move |_, status| compare_palette.menu_button_style(6.0, status));
                     let content = Column::new()
                         .spacing(6)
                         .push(color_button)
                         .push(reset_button)
-                        .push(duplicate_button);
+                        .push(rename_button)
+                        .push(pin_button)
+                        .push(duplicate_button)
+                        .push(compare_button)
+                        .push(external_button);
                     Container::new(content)
                         .style(move |_| iced::widget::container::Style {
                             background: Some(border_palette.menu.background.to_color().into()),
@@ -5292,17 +10301,28 @@ impl App {
                 .width(Length::Fill)
                 .padding([0.0, TAB_HEADER_PADDING_X]);
 
-            let content = active_id
-                .and_then(|id| self.tabs.iter().find(|tab| tab.id == id))
-                .map(|tab| tab.view())
-                .unwrap_or_else(|| {
-                    Container::new(
-                        fonts::primary_text(tr("No active tab"), None).color(muted_color),
-                    )
-                    .center_x(Length::Fill)
-                    .center_y(Length::Fill)
-                    .into()
-                });
+            let side_by_side = self.side_by_side.as_ref().filter(|state| {
+                active_id.is_some_and(|id| state.contains(id))
+                    && state
+                        .panes
+                        .iter()
+                        .all(|(_, tab_id)| self.tabs.iter().any(|tab| tab.id == *tab_id))
+            });
+            let content = if let Some(state) = side_by_side {
+                self.side_by_side_view(state)
+            } else {
+                active_id
+                    .and_then(|id| self.tabs.iter().find(|tab| tab.id == id))
+                    .map(|tab| tab.view())
+                    .unwrap_or_else(|| {
+                        Container::new(
+                            fonts::primary_text(tr("No active tab"), None).color(muted_color),
+                        )
+                        .center_x(Length::Fill)
+                        .center_y(Length::Fill)
+                        .into()
+                    })
+            };
 
             let layout = Column::new()
                 .spacing(8)
@@ -5324,6 +10344,42 @@ impl App {
         }
     }
 
+    fn side_by_side_view<'a>(&'a self, state: &'a SideBySideState) -> Element<'a, Message> {
+        let palette = self.active_palette();
+        let fonts_state = fonts::active_fonts();
+
+        let sync_checkbox = Checkbox::new(state.sync_scroll)
+            .label(tr("Synchronize scrolling"))
+            .font(fonts_state.primary_font)
+            .text_size(fonts_state.primary_size - 1.0)
+            .on_toggle(Message::SideBySideSyncScrollToggled);
+        let close_button = Button::new(fonts::primary_text(tr("Close Side by Side"), Some(-1.0)))
+            .padding([4, 10])
+            .on_press(Message::SideBySideClose)
+            .style(move |_, status| palette.subtle_button_style(4.0, status));
+        let toolbar = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(sync_checkbox)
+            .push(Space::new().width(Length::Fill))
+            .push(close_button);
+
+        let grid = pane_grid::PaneGrid::new(&state.panes, |_, tab_id, _| {
+            let content = self
+                .tabs
+                .iter()
+                .find(|tab| tab.id == *tab_id)
+                .map(|tab| tab.view())
+                .unwrap_or_else(|| Space::new().into());
+            pane_grid::Content::new(content)
+        })
+        .on_resize(8, Message::SideBySideResized)
+        .spacing(12)
+        .height(Length::Fill);
+
+        Column::new().spacing(8).push(toolbar).push(grid).height(Length::Fill).into()
+    }
+
     fn collection_names_for_db(&self, client_id: ClientId, db_name: &str) -> Vec<String> {
         self.clients
             .iter()
@@ -5393,44 +10449,127 @@ impl App {
         Some((client_id, db_name, collection, id_value))
     }
 
-    fn open_related_document_tab(
+    fn open_related_document_tab(
+        &mut self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        id_value: Bson,
+    ) -> Task<Message> {
+        let tab_id = self.open_collection_tab(client_id, db_name, collection.clone());
+        if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            let escaped_collection = Self::escape_collection_name_for_shell(&collection);
+            let id_literal = shell::format_bson_shell(&id_value);
+            let query = format!(
+                "db.getCollection('{collection_name}').find({{ _id: {id_literal} }})",
+                collection_name = escaped_collection,
+                id_literal = id_literal
+            );
+            tab.collection.editor = TextEditorContent::with_text(&query);
+        }
+
+        let query_task = self.collection_query_task(tab_id);
+        let focus_task = self.schedule_collection_editor_focus(tab_id);
+        Task::batch([query_task, focus_task])
+    }
+
+    fn open_collection_tab(
+        &mut self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+    ) -> TabId {
+        log::debug!("Open tab client_id={} db={} collection={}", client_id, db_name, collection);
+        let id = self.next_tab_id;
+        self.next_tab_id += 1;
+        let tab = self.build_collection_tab(id, client_id, db_name, collection);
+        self.tabs.push(tab);
+        self.active_tab = Some(id);
+        id
+    }
+
+    fn replace_collection_tab(
+        &mut self,
+        tab_id: TabId,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+    ) {
+        log::debug!("Reuse tab id={} db={} collection={}", tab_id, db_name, collection);
+        let mut replacement = self.build_collection_tab(tab_id, client_id, db_name, collection);
+        if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            replacement.color = tab.color;
+            *tab = replacement;
+        }
+        self.active_tab = Some(tab_id);
+    }
+
+    /// Saves the presets to disk and hands the collection's list to every tab showing it.
+    fn store_projection_presets(&mut self, key: &str) {
+        let presets = self.projection_presets.get(key).cloned().unwrap_or_default();
+        for tab in &mut self.tabs {
+            let collection = &mut tab.collection;
+            if collection.paging_key() != key {
+                continue;
+            }
+            if collection
+                .active_projection
+                .as_ref()
+                .is_some_and(|name| !presets.iter().any(|preset| &preset.name == name))
+            {
+                collection.active_projection = None;
+            }
+            collection.projection_presets = presets.clone();
+            collection.refresh_query_preview();
+        }
+        if let Err(error) = settings::save_projection_presets(&self.projection_presets) {
+            log::warn!("Failed to save projection presets: {error}");
+        }
+    }
+
+    fn remember_collection_paging(
         &mut self,
         client_id: ClientId,
-        db_name: String,
-        collection: String,
-        id_value: Bson,
-    ) -> Task<Message> {
-        let tab_id = self.open_collection_tab(client_id, db_name, collection.clone());
-        if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
-            let escaped_collection = Self::escape_collection_name_for_shell(&collection);
-            let id_literal = shell::format_bson_shell(&id_value);
-            let query = format!(
-                "db.getCollection('{collection_name}').find({{ _id: {id_literal} }})",
-                collection_name = escaped_collection,
-                id_literal = id_literal
-            );
-            tab.collection.editor = TextEditorContent::with_text(&query);
+        db_name: &str,
+        collection: &str,
+        skip: u64,
+        limit: u64,
+    ) {
+        let Some(client) = self.clients.iter().find(|client| client.id == client_id) else {
+            return;
+        };
+
+        let key = collection_paging_key(&client.name, db_name, collection);
+        let paging = CollectionPaging { skip, limit };
+        if self.collection_paging.get(&key) == Some(&paging) {
+            return;
         }
 
-        let query_task = self.collection_query_task(tab_id);
-        let focus_task = self.schedule_collection_editor_focus(tab_id);
-        Task::batch([query_task, focus_task])
+        self.collection_paging.insert(key, paging);
+        if let Err(error) = settings::save_collection_paging(&self.collection_paging) {
+            log::warn!("Failed to save collection paging: {error}");
+        }
     }
 
-    fn open_collection_tab(
-        &mut self,
+    fn build_collection_tab(
+        &self,
+        id: TabId,
         client_id: ClientId,
         db_name: String,
         collection: String,
-    ) -> TabId {
+    ) -> TabData {
         let mut client_name = String::from(tr("Unknown client"));
         let known_collections = self.collection_names_for_db(client_id, &db_name);
         let mut values = vec![Bson::String(String::from(tr(
             "Query not yet executed. Compose a query and press Send.",
         )))];
 
+        let mut read_only = false;
+        let mut accent_color = None;
         if let Some(client) = self.clients.iter().find(|c| c.id == client_id) {
             client_name = client.name.clone();
+            read_only = client.entry.read_only;
+            accent_color = client.entry.color.map(RgbaColor::to_color);
 
             if client.handle.is_none() {
                 values = vec![Bson::String(String::from(tr(
@@ -5439,10 +10578,12 @@ impl App {
             }
         }
 
-        log::debug!("Open tab client_id={} db={} collection={}", client_id, db_name, collection);
-        let id = self.next_tab_id;
-        self.next_tab_id += 1;
-        self.tabs.push(TabData::new_collection(
+        let paging_key = collection_paging_key(&client_name, &db_name, &collection);
+        let paging = self.collection_paging.get(&paging_key).copied();
+        let projection_presets =
+            self.projection_presets.get(&paging_key).cloned().unwrap_or_default();
+
+        let mut tab = TabData::new_collection(
             id,
             client_id,
             client_name,
@@ -5451,9 +10592,14 @@ impl App {
             known_collections,
             values,
             &self.settings,
-        ));
-        self.active_tab = Some(id);
-        id
+        );
+        if let Some(paging) = paging {
+            tab.collection.apply_paging(paging);
+        }
+        tab.collection.read_only = read_only;
+        tab.collection.accent_color = accent_color;
+        tab.collection.projection_presets = projection_presets;
+        tab
     }
 
     fn focus_collection_editor(&self, tab_id: TabId) -> Task<Message> {
@@ -5475,14 +10621,16 @@ impl App {
                 tab.collection.db_name.clone(),
                 tab.collection.collection.clone(),
                 tab.collection.editor.text().to_string(),
+                tab.collection.kind,
                 tab.color,
             )
         });
 
-        if let Some((client_id, db_name, collection, query_text, color)) = snapshot {
+        if let Some((client_id, db_name, collection, query_text, kind, color)) = snapshot {
             let new_tab_id = self.open_collection_tab(client_id, db_name, collection);
             if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == new_tab_id) {
                 tab.collection.editor = TextEditorContent::with_text(&query_text);
+                tab.collection.kind = kind;
                 tab.color = color;
             }
             log::debug!("Tab duplicated from={} to={}", tab_id, new_tab_id);
@@ -5495,6 +10643,7 @@ impl App {
 
         if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
             tab.collection.editor = TextEditorContent::with_text(tr("db.stats()"));
+            tab.collection.kind = TabKind::DatabaseStats;
             tab.title = String::from(tr("stats"));
         }
 
@@ -5528,6 +10677,7 @@ impl App {
                 collection = collection
             );
             tab.collection.editor = TextEditorContent::with_text(&command);
+            tab.collection.kind = TabKind::CollectionStats;
             tab.title = String::from(tr("collStats"));
         }
 
@@ -5548,6 +10698,7 @@ impl App {
                 collection_name = collection
             );
             tab.collection.editor = TextEditorContent::with_text(&command);
+            tab.collection.kind = TabKind::Indexes;
             tab.title = String::from(tr("indexes"));
         }
 
@@ -5605,6 +10756,7 @@ impl App {
         tab.title = String::from(tr("serverStatus"));
         tab.collection.editor =
             TextEditorContent::with_text(tr("db.runCommand({ serverStatus: 1 })"));
+        tab.collection.kind = TabKind::ServerStatus;
 
         self.tabs.push(tab);
         self.active_tab = Some(id);
@@ -5631,6 +10783,41 @@ impl App {
         )
     }
 
+    /// Stores the outcome of a connection attempt on the client and reports whether it succeeded.
+    fn apply_connection_result(
+        &mut self,
+        client_id: ClientId,
+        result: Result<ConnectionBootstrap, String>,
+    ) -> bool {
+        let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) else {
+            return false;
+        };
+        match result {
+            Ok(ConnectionBootstrap { handle, mut databases, ssh_tunnel }) => {
+                databases.sort_unstable();
+                log::debug!(
+                    "Connection established client_id={} databases={}",
+                    client_id,
+                    databases.len()
+                );
+                client.status = ConnectionStatus::Ready;
+                client.handle = Some(handle);
+                client.databases = databases.into_iter().map(DatabaseNode::new).collect();
+                client.expanded = true;
+                client.ssh_tunnel = ssh_tunnel;
+                true
+            }
+            Err(error) => {
+                log::error!("{error}");
+                client.status = ConnectionStatus::Failed(error);
+                client.databases.clear();
+                client.handle = None;
+                client.ssh_tunnel = None;
+                false
+            }
+        }
+    }
+
     fn refresh_databases(&mut self, client_id: ClientId) -> Task<Message> {
         let Some(client) = self.clients.iter_mut().find(|client| client.id == client_id) else {
             return Task::none();
@@ -5676,19 +10863,146 @@ impl App {
         )
     }
 
+    fn refresh_all_connections(&mut self) -> Task<Message> {
+        let client_ids: Vec<ClientId> = self
+            .clients
+            .iter()
+            .filter(|client| matches!(client.status, ConnectionStatus::Ready))
+            .map(|client| client.id)
+            .collect();
+        log::debug!("Refresh all connections count={}", client_ids.len());
+        Task::batch(client_ids.into_iter().map(|client_id| self.refresh_databases(client_id)))
+    }
+
+    fn database_sizes_task(&self, client_id: ClientId) -> Task<Message> {
+        if self.settings.tree_sort_mode != TreeSortMode::Size {
+            return Task::none();
+        }
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            return Task::none();
+        };
+
+        Task::perform(async move { fetch_database_sizes(handle) }, move |result| {
+            Message::DatabaseSizesLoaded { client_id, result }
+        })
+    }
+
+    fn collection_sizes_task(&self, client_id: ClientId, db_name: String) -> Task<Message> {
+        if self.settings.tree_sort_mode != TreeSortMode::Size {
+            return Task::none();
+        }
+        let Some(client) = self.clients.iter().find(|client| client.id == client_id) else {
+            return Task::none();
+        };
+        let (Some(handle), Some(database)) =
+            (client.handle.clone(), client.databases.iter().find(|d| d.name == db_name))
+        else {
+            return Task::none();
+        };
+        if database.collections.is_empty() {
+            return Task::none();
+        }
+        let names: Vec<String> =
+            database.collections.iter().map(|node| node.name.clone()).collect();
+        let db_for_task = db_name.clone();
+
+        Task::perform(
+            async move { fetch_collection_sizes(handle, db_for_task, names) },
+            move |sizes| Message::CollectionSizesLoaded {
+                client_id,
+                db_name: db_name.clone(),
+                sizes,
+            },
+        )
+    }
+
+    fn collection_counts_task(&self, client_id: ClientId, db_name: String) -> Task<Message> {
+        let Some(client) = self.clients.iter().find(|client| client.id == client_id) else {
+            return Task::none();
+        };
+        let (Some(handle), Some(database)) =
+            (client.handle.clone(), client.databases.iter().find(|d| d.name == db_name))
+        else {
+            return Task::none();
+        };
+        if database.collections.is_empty() {
+            return Task::none();
+        }
+        let names: Vec<String> =
+            database.collections.iter().map(|node| node.name.clone()).collect();
+        let db_for_task = db_name.clone();
+
+        Task::perform(
+            async move { fetch_collection_counts(handle, db_for_task, names) },
+            move |counts| Message::CollectionCountsLoaded {
+                client_id,
+                db_name: db_name.clone(),
+                counts,
+            },
+        )
+    }
+
+    /// Re-applies the sidebar sort mode and fetches the sizes it needs for loaded nodes.
+    fn resort_tree(&mut self) -> Task<Message> {
+        let mode = self.settings.tree_sort_mode;
+        let mut tasks = Vec::new();
+        for client in &mut self.clients {
+            sort_database_nodes(&mut client.databases, mode);
+            for database in &mut client.databases {
+                sort_collection_nodes(&mut database.collections, mode);
+            }
+        }
+        for client in &self.clients {
+            tasks.push(self.database_sizes_task(client.id));
+            for database in &client.databases {
+                if matches!(database.state, DatabaseState::Loaded) {
+                    tasks.push(self.collection_sizes_task(client.id, database.name.clone()));
+                }
+            }
+        }
+        Task::batch(tasks)
+    }
+
     fn add_collection_to_tree(&mut self, client_id: ClientId, db_name: &str, collection: &str) {
+        let mode = self.settings.tree_sort_mode;
         if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
             if let Some(database) = client.databases.iter_mut().find(|d| d.name == db_name) {
                 if database.collections.iter().any(|node| node.name == collection) {
                     return;
                 }
                 database.collections.push(CollectionNode::new(collection.to_string()));
-                database.collections.sort_by(|a, b| a.name.cmp(&b.name));
+                sort_collection_nodes(&mut database.collections, mode);
             }
         }
         self.sync_known_collections_for_tabs(client_id, db_name);
     }
 
+    /// Shows the namespace written by `$out`/`$merge` in the sidebar. An unknown target
+    /// database means it was just created, so the database list is reloaded.
+    fn reveal_aggregate_output(
+        &mut self,
+        client_id: ClientId,
+        target: &AggregateWriteTarget,
+    ) -> Task<Message> {
+        let known_database =
+            self.clients.iter().find(|client| client.id == client_id).map(|client| {
+                client.databases.iter().any(|database| database.name == target.db_name)
+            });
+        match known_database {
+            Some(true) => {
+                self.add_collection_to_tree(client_id, &target.db_name, &target.collection);
+                Task::none()
+            }
+            Some(false) => self.refresh_databases(client_id),
+            None => Task::none(),
+        }
+    }
+
     fn remove_collection_from_tree(
         &mut self,
         client_id: ClientId,
@@ -5797,6 +11111,8 @@ impl App {
         old_collection: &str,
         new_name: &str,
     ) {
+        let mode = self.settings.tree_sort_mode;
+        let mut moved_pins = None;
         if let Some(client) = self.clients.iter_mut().find(|c| c.id == client_id) {
             if let Some(database) = client.databases.iter_mut().find(|d| d.name == db_name) {
                 if let Some(node) =
@@ -5804,9 +11120,16 @@ impl App {
                 {
                     node.name = new_name.to_string();
                 }
-                database.collections.sort_by(|a, b| a.name.cmp(&b.name));
+                sort_collection_nodes(&mut database.collections, mode);
+            }
+            if client.entry.rename_collection_pin(db_name, old_collection, new_name) {
+                moved_pins =
+                    Some((client.entry.name.clone(), client.entry.pinned_collections.clone()));
             }
         }
+        if let Some((name, pins)) = moved_pins {
+            self.store_collection_pins(&name, pins);
+        }
 
         for tab in &mut self.tabs {
             let collection = &mut tab.collection;
@@ -5830,6 +11153,17 @@ impl App {
         }
     }
 
+    /// Copies the pins of an open connection to its saved entry and writes the connections file.
+    fn store_collection_pins(&mut self, connection_name: &str, pins: Vec<String>) {
+        for entry in self.connections.iter_mut().filter(|entry| entry.name == connection_name) {
+            entry.pinned_collections = pins.clone();
+        }
+        if let Err(error) = save_connections_to_disk(&self.connections) {
+            log::error!("{error}");
+            self.status_notice = Some(format!("{}{}", tr("Save error: "), error));
+        }
+    }
+
     fn query_operation_label(operation: &QueryOperation) -> &'static str {
         match operation {
             QueryOperation::Find { .. } => "find",
@@ -5855,6 +11189,7 @@ impl App {
             QueryOperation::FindOneAndReplace { .. } => "findOneAndReplace",
             QueryOperation::FindOneAndDelete { .. } => "findOneAndDelete",
             QueryOperation::ListIndexes => "getIndexes",
+            QueryOperation::MapReduce { .. } => "mapReduce",
             QueryOperation::ReplicaSetCommand { command } => match command {
                 ReplicaSetCommand::Status => "rs.status",
                 ReplicaSetCommand::Config => "rs.conf",
@@ -5889,37 +11224,99 @@ impl App {
     }
 
     fn collection_query_task(&mut self, tab_id: TabId) -> Task<Message> {
+        self.start_collection_query(tab_id, false)
+    }
+
+    /// Pipelines ending with `$out`/`$merge` wait for a confirmation of the target namespace
+    /// unless `write_confirmed` is set.
+    fn start_collection_query(&mut self, tab_id: TabId, write_confirmed: bool) -> Task<Message> {
         let mut request: Option<(ClientId, String, String, QueryOperation, u64, u64)> = None;
+        let mut write_confirmation = None;
+        let mut keyset_page = false;
 
         if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
             let collection = &mut tab.collection;
             let query_text = collection.editor.text().to_string();
             match collection.parse_query(&query_text) {
+                Ok((_, operation)) if collection.read_only && operation.is_write() => {
+                    collection.set_tree_error(read_only_connection_error(&collection.client_name));
+                }
                 Ok((effective_collection, operation)) => {
-                    let skip = collection.skip_value();
-                    let limit = collection.limit_value();
-                    let op_label = Self::query_operation_label(&operation);
-                    log::debug!(
-                        "Query start tab_id={} client_id={} db={} collection={} op={} skip={} limit={}",
-                        tab_id,
-                        collection.client_id,
-                        collection.db_name,
-                        effective_collection,
-                        op_label,
-                        skip,
-                        limit
-                    );
-                    collection.query_in_progress = true;
-                    collection.last_query_duration = None;
-                    collection.pending_collection = Some(effective_collection.clone());
-                    request = Some((
-                        collection.client_id,
-                        collection.db_name.clone(),
-                        effective_collection,
-                        operation,
-                        skip,
-                        limit,
-                    ));
+                    let stage_count = operation.aggregate_stage_count().unwrap_or(0);
+                    // A stage limit chosen for another pipeline must not cut the new one.
+                    if stage_count != collection.pipeline_stage_count
+                        || collection.last_executed_text.as_deref() != Some(query_text.as_str())
+                    {
+                        collection.pipeline_stage_limit = None;
+                    }
+                    collection.pipeline_stage_count = stage_count;
+                    if collection.pipeline_stage_limit.is_some_and(|stages| stages >= stage_count) {
+                        collection.pipeline_stage_limit = None;
+                    }
+                    let operation = collection.apply_pipeline_stage_limit(operation);
+                    let previous_cursor = collection.cursor_state.take();
+                    let write_target = operation.aggregate_write_target(&collection.db_name);
+                    if let Some(target) = write_target.clone()
+                        && !write_confirmed
+                    {
+                        write_confirmation = Some(AggregateWriteModalState {
+                            tab_id,
+                            source_db: collection.db_name.clone(),
+                            source_collection: effective_collection,
+                            target,
+                        });
+                    } else {
+                        let skip = collection.skip_value();
+                        let limit = collection.limit_value();
+                        let op_label = Self::query_operation_label(&operation);
+                        log::debug!(
+                            "Query start tab_id={} client_id={} db={} collection={} op={} skip={} limit={}",
+                            tab_id,
+                            collection.client_id,
+                            collection.db_name,
+                            effective_collection,
+                            op_label,
+                            skip,
+                            limit
+                        );
+                        collection.query_in_progress = true;
+                        collection.last_query_duration = None;
+                        collection.pending_collection = Some(effective_collection.clone());
+                        collection.running_query = Some(query_text.trim().to_string());
+                        collection.last_executed_text = Some(query_text.clone());
+                        collection.pending_write_target = write_target;
+                        collection.total_count = None;
+                        collection.total_count_filter = match &operation {
+                            QueryOperation::Find { filter, .. } if skip > 0 => Some(filter.clone()),
+                            _ => None,
+                        };
+                        let mut operation = operation;
+                        if matches!(operation, QueryOperation::Find { .. }) && limit > 0 {
+                            let keyset = previous_cursor
+                                .filter(|cursor| {
+                                    cursor.query == query_text && cursor.next_skip == skip
+                                })
+                                .and_then(|cursor| cursor.last_id)
+                                .and_then(|last_id| operation.keyset_after(&last_id));
+                            if let Some(keyset) = keyset {
+                                operation = keyset;
+                                keyset_page = true;
+                            }
+                            collection.cursor_state = Some(CursorState {
+                                query: query_text.clone(),
+                                next_skip: skip.saturating_add(limit),
+                                last_id: None,
+                            });
+                        }
+                        request = Some((
+                            collection.client_id,
+                            collection.db_name.clone(),
+                            effective_collection,
+                            operation,
+                            skip,
+                            limit,
+                        ));
+                    }
                 }
                 Err(error) => {
                     collection.set_tree_error(error);
@@ -5927,6 +11324,12 @@ impl App {
             }
         }
 
+        if let Some(state) = write_confirmation {
+            self.aggregate_write_modal = Some(state);
+            self.mode = AppMode::AggregateWriteModal;
+            return Task::none();
+        }
+
         let Some((client_id, db_name, collection_name, operation, skip, limit)) = request else {
             return Task::none();
         };
@@ -5937,6 +11340,24 @@ impl App {
             .find(|client| client.id == client_id)
             .and_then(|client| client.handle.clone())
         else {
+            if self.settings.reconnect_on_query
+                && let Some(client) = self.clients.iter_mut().find(|client| client.id == client_id)
+                && !matches!(client.status, ConnectionStatus::Connecting)
+            {
+                log::debug!("Reconnecting before query tab_id={} client_id={}", tab_id, client_id);
+                client.status = ConnectionStatus::Connecting;
+                client.ssh_tunnel = None;
+                let connection = OMDBConnection::from_entry(client.entry.clone());
+                return Task::perform(
+                    async move { connect_and_discover(connection) },
+                    move |result| Message::QueryReconnectCompleted {
+                        tab_id,
+                        client_id,
+                        write_confirmed,
+                        result,
+                    },
+                );
+            }
             if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
                 tab.collection.set_tree_error(String::from(tr("No active connection")));
                 tab.collection.query_in_progress = false;
@@ -5944,11 +11365,22 @@ impl App {
             return Task::none();
         };
 
-        let timeout_secs = self.settings.query_timeout_secs;
-        let timeout =
-            if timeout_secs == 0 { None } else { Some(Duration::from_secs(timeout_secs)) };
+        if matches!(operation, QueryOperation::Find { .. }) {
+            self.remember_collection_paging(client_id, &db_name, &collection_name, skip, limit);
+        }
+
+        let timeout = self.settings.query_timeout_duration();
+        // A keyset page already starts after the previous one, so skipping again would lose rows.
+        let skip = if keyset_page { 0 } else { skip };
 
-        match operation {
+        let cancel = Arc::new(AtomicBool::new(false));
+        if let Some(tab) = self.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            tab.collection.query_cancel = Some(cancel.clone());
+            tab.collection.query_started = Some(Instant::now());
+        }
+        let progress = self.query_progress_tick_task();
+
+        let query = match operation {
             QueryOperation::Watch { pipeline, target, options } => {
                 let target_label = match target {
                     WatchTarget::Collection => "collection",
@@ -5989,15 +11421,92 @@ impl App {
                         limit,
                         timeout,
                     );
-                    (result, started.elapsed())
+                    (result, started.elapsed(), cancel.load(Ordering::Relaxed))
                 },
-                move |(result, duration)| Message::CollectionQueryCompleted {
-                    tab_id,
-                    result,
-                    duration,
+                move |(result, duration, cancelled)| {
+                    if cancelled {
+                        Message::CollectionQueryDiscarded(tab_id)
+                    } else {
+                        Message::CollectionQueryCompleted { tab_id, result, duration }
+                    }
                 },
             ),
+        };
+        Task::batch([query, progress])
+    }
+
+    /// Keeps redrawing the running time of in-flight queries until the last one finishes.
+    fn query_progress_tick_task(&mut self) -> Task<Message> {
+        if self.query_progress_ticking
+            || !self.tabs.iter().any(|tab| tab.collection.query_in_progress)
+        {
+            return Task::none();
         }
+        self.query_progress_ticking = true;
+        Task::perform(async { std::thread::sleep(Duration::from_millis(200)) }, |_| {
+            Message::QueryProgressTick
+        })
+    }
+
+    fn collection_count_task(
+        &mut self,
+        client_id: ClientId,
+        db_name: String,
+        collection: String,
+        operation: QueryOperation,
+    ) -> Task<Message> {
+        let namespace = format!("{db_name}.{collection}");
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            self.status_notice = Some(String::from(tr("No active connection")));
+            return Task::none();
+        };
+
+        let estimated = matches!(operation, QueryOperation::EstimatedDocumentCount { .. });
+        self.status_notice = Some(tr_format("Counting documents in {}...", &[&namespace]));
+        let timeout = self.settings.query_timeout_duration();
+        Task::perform(
+            async move { run_collection_query(handle, db_name, collection, operation, 0, 0, timeout) },
+            move |result| Message::CollectionCountCompleted {
+                namespace: namespace.clone(),
+                estimated,
+                result,
+            },
+        )
+    }
+
+    fn collection_total_count_task(
+        &self,
+        tab_id: TabId,
+        client_id: ClientId,
+        db_name: String,
+        collection_name: String,
+        filter: Document,
+    ) -> Task<Message> {
+        let Some(handle) = self
+            .clients
+            .iter()
+            .find(|client| client.id == client_id)
+            .and_then(|client| client.handle.clone())
+        else {
+            return Task::none();
+        };
+
+        Task::perform(
+            async move {
+                handle
+                    .database(&db_name)
+                    .collection::<Document>(&collection_name)
+                    .count_documents(filter)
+                    .run()
+                    .map_err(|error| error.to_string())
+            },
+            move |result| Message::CollectionTotalCountCompleted { tab_id, result },
+        )
     }
 
     fn collection_watch_task(
@@ -6012,6 +11521,12 @@ impl App {
         limit: u64,
     ) -> Task<Message> {
         let started = Instant::now();
+        let cancel = self
+            .tabs
+            .iter()
+            .find(|tab| tab.id == tab_id)
+            .and_then(|tab| tab.collection.query_cancel.clone())
+            .unwrap_or_default();
         let capped_limit = if limit > usize::MAX as u64 { usize::MAX } else { limit as usize };
 
         let state = match open_change_stream(
@@ -6029,6 +11544,7 @@ impl App {
                 started,
                 finished: false,
                 error: None,
+                cancel: cancel.clone(),
             },
             Err(error) => WatchStreamState {
                 change_stream: None,
@@ -6037,11 +11553,12 @@ impl App {
                 started,
                 finished: false,
                 error: Some(error),
+                cancel,
             },
         };
 
         let stream = stream::unfold(state, |mut state| async move {
-            if state.finished {
+            if state.finished || state.cancel.load(Ordering::Relaxed) {
                 return None;
             }
 
@@ -6071,6 +11588,9 @@ impl App {
 
             let next_event = change_stream.next();
             state.change_stream = Some(change_stream);
+            if state.cancel.load(Ordering::Relaxed) {
+                return None;
+            }
 
             match next_event {
                 Some(Ok(event)) => {
@@ -6129,8 +11649,10 @@ impl App {
 
     fn open_connections_window(&mut self) {
         log::debug!("Open connections window");
-        let mut state =
-            self.connections_window.take().unwrap_or_else(|| ConnectionsWindowState::new(None));
+        let mut state = self
+            .connections_window
+            .take()
+            .unwrap_or_else(|| ConnectionsWindowState::new(self.last_connection_selection));
 
         if let Some(selected) = state.selected {
             if self.connections.is_empty() {
@@ -6148,10 +11670,23 @@ impl App {
         self.mode = AppMode::Connections;
     }
 
+    fn scroll_to_selected_connection(&self) -> Task<Message> {
+        let Some(y) = self
+            .connections_window
+            .as_ref()
+            .and_then(|window| window.selected_scroll_offset(&self.connections))
+        else {
+            return Task::none();
+        };
+        snap_to(CONNECTIONS_LIST_SCROLL_ID, scrollable::RelativeOffset { x: 0.0, y })
+    }
+
     fn close_connections_window(&mut self) {
         log::debug!("Close connections window");
         self.mode = AppMode::Main;
-        self.connections_window = None;
+        if let Some(window) = self.connections_window.take() {
+            self.last_connection_selection = window.selected;
+        }
         self.connection_form = None;
     }
 
@@ -6159,6 +11694,7 @@ impl App {
         log::debug!("Open connection form mode={:?}", mode);
         if let Some(window) = self.connections_window.as_mut() {
             window.confirm_delete = false;
+            window.last_click = None;
         }
         let entry = match mode {
             ConnectionFormMode::Create => None,
@@ -6272,10 +11808,9 @@ impl App {
         entry.uri()?;
         entry.ssh_tunnel.validate()?;
         log::debug!(
-            "Connect requested name={} host={} port={} ssh_tunnel={}",
+            "Connect requested name={} uri={} ssh_tunnel={}",
             entry.name,
-            entry.host,
-            entry.port,
+            entry.display_uri(),
             entry.ssh_tunnel.enabled
         );
         let connection = OMDBConnection::from_entry(entry.clone());
@@ -6316,7 +11851,42 @@ impl TabData {
                 settings,
             ),
             color: None,
+            pinned: false,
+            custom_title: None,
+        }
+    }
+
+    fn is_reusable(&self) -> bool {
+        !self.pinned && !self.collection.has_unrun_changes() && !self.collection.query_in_progress
+    }
+
+    fn formatted_title(&self, format: TabTitleFormat) -> String {
+        if let Some(custom_title) = &self.custom_title {
+            return custom_title.clone();
         }
+        let collection = &self.collection;
+        let mut parts = Vec::new();
+        if format.includes_connection() {
+            parts.push(collection.client_name.as_str());
+        }
+        if format.includes_database() && collection.db_name != self.title {
+            parts.push(collection.db_name.as_str());
+        }
+        parts.push(self.title.as_str());
+        parts.join(" / ")
+    }
+
+    fn display_title(&self, mut title: String) -> String {
+        if self.pinned {
+            title = format!("📌 {title}");
+        }
+        if self.collection.read_only {
+            title = format!("🔒 {title}");
+        }
+        if self.collection.has_unrun_changes() {
+            title.push_str(" ●");
+        }
+        title
     }
 
     fn view(&self) -> Element<'_, Message> {
@@ -6341,16 +11911,46 @@ impl OMDBClient {
 
 impl DatabaseNode {
     fn new(name: String) -> Self {
-        Self { name, expanded: false, state: DatabaseState::Idle, collections: Vec::new() }
+        Self {
+            name,
+            expanded: false,
+            state: DatabaseState::Idle,
+            collections: Vec::new(),
+            size: None,
+        }
     }
 }
 
 impl CollectionNode {
     fn new(name: String) -> Self {
-        Self { name }
+        Self { name, size: None, count: None }
+    }
+}
+
+/// Orders sidebar nodes by name, or by size with the largest first and unknown sizes last.
+fn sort_tree_nodes<T>(
+    nodes: &mut [T],
+    mode: TreeSortMode,
+    key: impl Fn(&T) -> (&str, Option<u64>),
+) {
+    match mode {
+        TreeSortMode::Name => nodes.sort_by(|a, b| key(a).0.cmp(key(b).0)),
+        TreeSortMode::Size => nodes.sort_by(|a, b| {
+            let (a_name, a_size) = key(a);
+            let (b_name, b_size) = key(b);
+            b_size.cmp(&a_size).then_with(|| a_name.cmp(b_name))
+        }),
     }
 }
 
+fn sort_database_nodes(databases: &mut [DatabaseNode], mode: TreeSortMode) {
+    sort_tree_nodes(databases, mode, |node| (node.name.as_str(), node.size));
+}
+
+fn sort_collection_nodes(collections: &mut [CollectionNode], mode: TreeSortMode) {
+    sort_tree_nodes(collections, mode, |node| (node.name.as_str(), node.size));
+}
+
 pub(crate) fn shared_icon_handle(lock: &OnceLock<Handle>, bytes: &'static [u8]) -> Handle {
     lock.get_or_init(|| Handle::from_bytes(bytes.to_vec())).clone()
 }
@@ -0,0 +1,173 @@
+use mongodb::bson::{Bson, Document};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffEntry {
+    pub path: String,
+    pub kind: DiffKind,
+    pub left: Option<Bson>,
+    pub right: Option<Bson>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+}
+
+impl DiffSummary {
+    pub fn from_entries(entries: &[DiffEntry]) -> Self {
+        let mut summary = Self::default();
+        for entry in entries {
+            match entry.kind {
+                DiffKind::Added => summary.added += 1,
+                DiffKind::Removed => summary.removed += 1,
+                DiffKind::Changed => summary.changed += 1,
+                DiffKind::Unchanged => summary.unchanged += 1,
+            }
+        }
+        summary
+    }
+
+    pub fn has_differences(&self) -> bool {
+        self.added + self.removed + self.changed > 0
+    }
+}
+
+/// Compares two documents field by field. Nested documents present on both sides are
+/// compared recursively, arrays and scalar values are compared as a whole.
+pub fn diff_documents(left: &Document, right: &Document) -> Vec<DiffEntry> {
+    let mut entries = Vec::new();
+    collect_document_diff(None, left, right, &mut entries);
+    entries
+}
+
+fn collect_document_diff(
+    prefix: Option<&str>,
+    left: &Document,
+    right: &Document,
+    entries: &mut Vec<DiffEntry>,
+) {
+    for (key, left_value) in left {
+        let path = join_path(prefix, key);
+        match right.get(key) {
+            Some(right_value) => collect_value_diff(path, left_value, right_value, entries),
+            None => entries.push(DiffEntry {
+                path,
+                kind: DiffKind::Removed,
+                left: Some(left_value.clone()),
+                right: None,
+            }),
+        }
+    }
+
+    for (key, right_value) in right {
+        if left.contains_key(key) {
+            continue;
+        }
+        entries.push(DiffEntry {
+            path: join_path(prefix, key),
+            kind: DiffKind::Added,
+            left: None,
+            right: Some(right_value.clone()),
+        });
+    }
+}
+
+fn collect_value_diff(path: String, left: &Bson, right: &Bson, entries: &mut Vec<DiffEntry>) {
+    if let (Bson::Document(left_doc), Bson::Document(right_doc)) = (left, right)
+        && (!left_doc.is_empty() || !right_doc.is_empty())
+    {
+        collect_document_diff(Some(&path), left_doc, right_doc, entries);
+        return;
+    }
+
+    let kind = if left == right { DiffKind::Unchanged } else { DiffKind::Changed };
+    entries.push(DiffEntry { path, kind, left: Some(left.clone()), right: Some(right.clone()) });
+}
+
+fn join_path(prefix: Option<&str>, key: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}.{key}"),
+        None => key.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    fn kinds(entries: &[DiffEntry]) -> Vec<(&str, DiffKind)> {
+        entries.iter().map(|entry| (entry.path.as_str(), entry.kind)).collect()
+    }
+
+    #[test]
+    fn diff_detects_added_removed_and_changed_fields() {
+        let left = doc! { "_id": 1, "name": "alpha", "legacy": true };
+        let right = doc! { "_id": 1, "name": "beta", "extra": 5 };
+
+        let entries = diff_documents(&left, &right);
+
+        assert_eq!(
+            kinds(&entries),
+            vec![
+                ("_id", DiffKind::Unchanged),
+                ("name", DiffKind::Changed),
+                ("legacy", DiffKind::Removed),
+                ("extra", DiffKind::Added),
+            ]
+        );
+        assert_eq!(entries[1].left, Some(Bson::String("alpha".into())));
+        assert_eq!(entries[1].right, Some(Bson::String("beta".into())));
+        assert_eq!(entries[3].left, None);
+    }
+
+    #[test]
+    fn diff_recurses_into_nested_documents() {
+        let left = doc! { "profile": { "age": 30, "city": "Riga" } };
+        let right = doc! { "profile": { "age": 31, "city": "Riga", "zip": "LV-1010" } };
+
+        let entries = diff_documents(&left, &right);
+
+        assert_eq!(
+            kinds(&entries),
+            vec![
+                ("profile.age", DiffKind::Changed),
+                ("profile.city", DiffKind::Unchanged),
+                ("profile.zip", DiffKind::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_compares_arrays_and_type_changes_as_whole_values() {
+        let left = doc! { "tags": ["a", "b"], "meta": { "x": 1 } };
+        let right = doc! { "tags": ["a", "c"], "meta": "plain" };
+
+        let entries = diff_documents(&left, &right);
+        let summary = DiffSummary::from_entries(&entries);
+
+        assert_eq!(kinds(&entries), vec![("tags", DiffKind::Changed), ("meta", DiffKind::Changed)]);
+        assert_eq!(summary.changed, 2);
+        assert!(summary.has_differences());
+    }
+
+    #[test]
+    fn diff_of_identical_documents_has_no_differences() {
+        let document = doc! { "a": 1, "b": { "c": [1, 2] } };
+        let summary = DiffSummary::from_entries(&diff_documents(&document, &document));
+
+        assert!(!summary.has_differences());
+        assert_eq!(summary.unchanged, 2);
+    }
+}
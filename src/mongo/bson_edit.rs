@@ -1,8 +1,125 @@
+use std::fmt;
 use std::str::FromStr;
 
-use mongodb::bson::{Bson, DateTime, Decimal128, oid::ObjectId};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use mongodb::bson::{Binary, Bson, DateTime, Decimal128, oid::ObjectId, spec::BinarySubtype};
 
-use crate::{i18n::tr, mongo::shell};
+use crate::i18n::{tr, tr_format};
+use crate::mongo::shell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryInputFormat {
+    Base64,
+    Hex,
+}
+
+pub const BINARY_INPUT_FORMATS: &[BinaryInputFormat] =
+    &[BinaryInputFormat::Base64, BinaryInputFormat::Hex];
+
+impl BinaryInputFormat {
+    pub fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base64 => BASE64_STANDARD.encode(bytes),
+            Self::Hex => bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+        }
+    }
+
+    pub fn decode(self, input: &str) -> Result<Vec<u8>, String> {
+        let cleaned: String = input.chars().filter(|ch| !ch.is_whitespace()).collect();
+        match self {
+            Self::Base64 => BASE64_STANDARD
+                .decode(cleaned)
+                .map_err(|_| String::from(tr("Value must be a valid base64 string."))),
+            Self::Hex => shell::decode_hex(&cleaned),
+        }
+    }
+}
+
+impl fmt::Display for BinaryInputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64 => f.write_str("Base64"),
+            Self::Hex => f.write_str("Hex"),
+        }
+    }
+}
+
+/// Binary subtype shown in the value editor picker, stored as the raw subtype byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinarySubtypeChoice(pub u8);
+
+pub const BINARY_SUBTYPE_CHOICES: &[BinarySubtypeChoice] = &[
+    BinarySubtypeChoice(0x00),
+    BinarySubtypeChoice(0x01),
+    BinarySubtypeChoice(0x02),
+    BinarySubtypeChoice(0x03),
+    BinarySubtypeChoice(0x04),
+    BinarySubtypeChoice(0x05),
+    BinarySubtypeChoice(0x06),
+    BinarySubtypeChoice(0x07),
+    BinarySubtypeChoice(0x08),
+    BinarySubtypeChoice(0x80),
+];
+
+impl BinarySubtypeChoice {
+    pub fn name(self) -> &'static str {
+        match self.0 {
+            0x00 => tr("Generic"),
+            0x01 => tr("Function"),
+            0x02 => tr("Binary (old)"),
+            0x03 => tr("UUID (old)"),
+            0x04 => tr("UUID"),
+            0x05 => tr("MD5"),
+            0x06 => tr("Encrypted"),
+            0x07 => tr("Column"),
+            0x08 => tr("Sensitive"),
+            0x80..=0xff => tr("User defined"),
+            _ => tr("Reserved"),
+        }
+    }
+}
+
+impl fmt::Display for BinarySubtypeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.0, self.name())
+    }
+}
+
+impl From<BinarySubtype> for BinarySubtypeChoice {
+    fn from(subtype: BinarySubtype) -> Self {
+        Self(subtype.into())
+    }
+}
+
+/// Target type picked in the value editor; `None` keeps inferring it from the input text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueTypeChoice(pub Option<ValueEditKind>);
+
+pub const VALUE_TYPE_CHOICES: &[ValueTypeChoice] = &[
+    ValueTypeChoice(None),
+    ValueTypeChoice(Some(ValueEditKind::String)),
+    ValueTypeChoice(Some(ValueEditKind::Boolean)),
+    ValueTypeChoice(Some(ValueEditKind::Int32)),
+    ValueTypeChoice(Some(ValueEditKind::Int64)),
+    ValueTypeChoice(Some(ValueEditKind::Double)),
+    ValueTypeChoice(Some(ValueEditKind::Decimal128)),
+    ValueTypeChoice(Some(ValueEditKind::DateTime)),
+    ValueTypeChoice(Some(ValueEditKind::ObjectId)),
+    ValueTypeChoice(Some(ValueEditKind::Null)),
+    ValueTypeChoice(Some(ValueEditKind::Document)),
+    ValueTypeChoice(Some(ValueEditKind::Array)),
+    ValueTypeChoice(Some(ValueEditKind::MinKey)),
+    ValueTypeChoice(Some(ValueEditKind::MaxKey)),
+];
+
+impl fmt::Display for ValueTypeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(kind) => f.write_str(kind.label()),
+            None => f.write_str(tr("Auto")),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValueEditKind {
@@ -153,7 +270,9 @@ impl ValueEditKind {
             Self::Null => Ok(Bson::Null),
             Self::Document => shell::parse_shell_document(input),
             Self::Array => shell::parse_shell_array(input),
-            Self::Binary => Err(String::from(tr("Binary parsing not implemented."))),
+            Self::Binary => {
+                Self::parse_binary(input, BinarySubtypeChoice(0), BinaryInputFormat::Base64)
+            }
             Self::Regex => Err(String::from(tr("Regex parsing not implemented."))),
             Self::Code => Err(String::from(tr("Code parsing not implemented."))),
             Self::CodeWithScope => Err(String::from(tr("CodeWithScope parsing not implemented."))),
@@ -166,6 +285,49 @@ impl ValueEditKind {
         }
     }
 
+    /// Parses `input` strictly as this kind. Unlike `parse`, text the shell would read as another
+    /// type is converted (`123` as String stays text) or rejected instead of changing the type.
+    pub fn parse_as(self, input: &str) -> Result<Bson, String> {
+        let parsed = match self {
+            Self::String => Ok(Bson::String(Self::parse_string_literal(input))),
+            Self::Boolean => Self::parse_boolean_literal(input)
+                .map(Bson::Boolean)
+                .ok_or_else(|| String::from(tr("Value must be true or false."))),
+            Self::Int32 => Self::parse_int32_value(input),
+            Self::Int64 => Self::parse_int64_value(input),
+            Self::Double => Self::parse_double_literal(input).map(Bson::Double),
+            Self::Decimal128 => Self::parse_decimal128(input),
+            Self::DateTime => Self::parse_datetime_literal(input).map(Bson::DateTime),
+            Self::ObjectId => Self::parse_object_id_literal(input).map(Bson::ObjectId),
+            Self::Null => match input.trim() {
+                "" | "null" => Ok(Bson::Null),
+                _ => Err(String::from(tr("Clear the text or enter null."))),
+            },
+            _ => self.parse(input),
+        };
+        let parsed = parsed.map_err(|error| {
+            tr_format("Cannot convert the value to {}: {}", &[self.label(), &error])
+        })?;
+        if Self::from_bson(&parsed) != self {
+            return Err(tr_format(
+                "Cannot convert the value to {}: it reads as {}.",
+                &[self.label(), shell::bson_type_name(&parsed)],
+            ));
+        }
+        Ok(parsed)
+    }
+
+    /// Decodes a raw binary payload typed without the `BinData(...)` wrapper.
+    pub fn parse_binary(
+        input: &str,
+        subtype: BinarySubtypeChoice,
+        format: BinaryInputFormat,
+    ) -> Result<Bson, String> {
+        let payload = Self::trim_quotes(input).unwrap_or(input.trim());
+        let bytes = format.decode(payload)?;
+        Ok(Bson::Binary(Binary { subtype: BinarySubtype::from(subtype.0), bytes }))
+    }
+
     fn parse_string_literal(input: &str) -> String {
         Self::trim_quotes(input).unwrap_or(input.trim()).to_string()
     }
@@ -208,9 +370,16 @@ impl ValueEditKind {
         literal.parse::<f64>().map_err(|_| String::from(tr("Value must be a Double.")))
     }
 
+    /// Parses plain or `NumberDecimal(...)` input straight into Decimal128 so that the digits
+    /// and scale are kept exactly, without passing through a double.
+    pub fn parse_decimal128(input: &str) -> Result<Bson, String> {
+        Self::parse_decimal_literal(input).map(Bson::Decimal128)
+    }
+
     fn parse_decimal_literal(input: &str) -> Result<Decimal128, String> {
         let literal = Self::extract_numeric_literal(input, &["NumberDecimal", "numberDecimal"])
             .unwrap_or_else(|| input.trim().to_string());
+        let literal = Self::trim_quotes(&literal).unwrap_or(literal.trim());
 
         Decimal128::from_str(literal.trim())
             .map_err(|_| String::from(tr("Value must be a Decimal128.")))
@@ -226,6 +395,9 @@ impl ValueEditKind {
 
     fn parse_object_id_literal(input: &str) -> Result<ObjectId, String> {
         let literal = if let Some(argument) = Self::strip_call(input, &["ObjectId"]) {
+            if argument.trim().is_empty() {
+                return Ok(ObjectId::new());
+            }
             Self::trim_quotes(argument).unwrap_or(argument.trim()).to_string()
         } else {
             input.trim().to_string()
@@ -297,6 +469,20 @@ mod tests {
         assert_eq!(ValueEditKind::infer(input), Some(expected));
     }
 
+    #[test]
+    fn parse_as_keeps_the_chosen_type() {
+        assert_eq!(ValueEditKind::String.parse_as("123").unwrap(), Bson::String("123".into()));
+        assert_eq!(ValueEditKind::Int64.parse_as("123").unwrap(), Bson::Int64(123));
+        assert_eq!(ValueEditKind::Double.parse_as("2").unwrap(), Bson::Double(2.0));
+        assert_eq!(ValueEditKind::Null.parse_as("").unwrap(), Bson::Null);
+
+        let error = ValueEditKind::Int32.parse_as("abc").unwrap_err();
+        assert!(error.contains("Int32"), "{error}");
+        assert!(ValueEditKind::Int32.parse_as("3000000000").is_err());
+        assert!(ValueEditKind::Boolean.parse_as("yes").is_err());
+        assert!(ValueEditKind::Document.parse_as("[1, 2]").is_err());
+    }
+
     #[test]
     fn infer_handles_basic_literals() {
         assert_infer("42", ValueEditKind::Int32);
@@ -355,6 +541,68 @@ mod tests {
         assert_eq!(array_value, shell::parse_shell_array("[1, 2]").unwrap());
     }
 
+    #[test]
+    fn parse_binary_respects_subtype_and_format() {
+        let from_hex = ValueEditKind::parse_binary(
+            "00 01 ff",
+            BinarySubtypeChoice(0x05),
+            BinaryInputFormat::Hex,
+        )
+        .unwrap();
+        assert_eq!(
+            from_hex,
+            Bson::Binary(Binary { subtype: BinarySubtype::Md5, bytes: vec![0x00, 0x01, 0xff] })
+        );
+
+        let from_base64 = ValueEditKind::parse_binary(
+            "\"AAH/\"",
+            BinarySubtypeChoice(0x80),
+            BinaryInputFormat::Base64,
+        )
+        .unwrap();
+        assert_eq!(
+            from_base64,
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::UserDefined(0x80),
+                bytes: vec![0x00, 0x01, 0xff],
+            })
+        );
+
+        assert_eq!(BinaryInputFormat::Hex.encode(&[0x00, 0x01, 0xff]), "0001ff");
+        assert_eq!(BinaryInputFormat::Base64.encode(&[0x00, 0x01, 0xff]), "AAH/");
+        assert!(
+            ValueEditKind::parse_binary("0g", BinarySubtypeChoice(0), BinaryInputFormat::Hex)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_decimal128_preserves_exact_representation() {
+        for input in ["0.10", "1234567890.123456789012345678", "-1E+3"] {
+            let Bson::Decimal128(value) = ValueEditKind::parse_decimal128(input).unwrap() else {
+                panic!("expected Decimal128 for {input}");
+            };
+            assert_eq!(value.to_string(), input);
+        }
+
+        assert_eq!(
+            ValueEditKind::parse_decimal128("NumberDecimal(\"19.99\")").unwrap(),
+            ValueEditKind::parse_decimal128("19.99").unwrap()
+        );
+        assert!(ValueEditKind::parse_decimal128("12,5").is_err());
+    }
+
+    #[test]
+    fn parse_generates_object_id_for_empty_constructor() {
+        assert_infer("ObjectId()", ValueEditKind::ObjectId);
+
+        let first = ValueEditKind::ObjectId.parse("ObjectId()").unwrap();
+        let second = ValueEditKind::ObjectId.parse("ObjectId( )").unwrap();
+        assert!(matches!(first, Bson::ObjectId(_)));
+        assert!(matches!(second, Bson::ObjectId(_)));
+        assert_ne!(first, second);
+    }
+
     #[test]
     fn parse_reports_errors_for_invalid_input() {
         let err = ValueEditKind::Int32.parse("abc").unwrap_err();
@@ -366,8 +614,8 @@ mod tests {
         let err = ValueEditKind::Decimal128.parse("NumberDecimal(\"not-a-number\")").unwrap_err();
         assert!(err.contains("Decimal128"));
 
-        let err = ValueEditKind::Binary.parse("abc").unwrap_err();
-        assert!(err.contains("not implemented"));
+        let err = ValueEditKind::Binary.parse("a").unwrap_err();
+        assert!(err.contains("base64"));
 
         let err = ValueEditKind::Int64.parse("NumberLong(text)").unwrap_err();
         assert!(err.contains("64-bit"));
@@ -1,14 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use iced::alignment::{Horizontal, Vertical};
+use iced::font::Weight;
 use iced::widget::text::Wrapping;
 use iced::widget::{self, Button, Column, Container, Row, Scrollable, Space};
-use iced::{Color, Element, Length, Shadow, Vector, border};
+use iced::{Color, Element, Font, Length, Shadow, Vector, border};
 use iced_aw::ContextMenu;
-use mongodb::bson::{Bson, Document};
+use mongodb::bson::{self, Bson, DateTime, Document};
 
 use crate::fonts;
-use crate::i18n::tr;
+use crate::i18n::{tr, tr_format};
 use crate::mongo::shell;
 use crate::settings::{
     AppSettings, ButtonColors, MenuColors, RgbaColor, TableColors, ThemePalette,
@@ -26,8 +27,68 @@ pub struct BsonTree {
     menu_colors: MenuColors,
     text_color: RgbaColor,
     button_colors: ButtonColors,
+    compare_node: Option<usize>,
+    /// Rows picked with Ctrl+click for "Copy Selected Rows".
+    selected_rows: HashSet<usize>,
+    deferred: HashMap<usize, String>,
+    relative_dates: bool,
+    filter: Option<TreeFilter>,
 }
 
+/// Result of `BsonTree::apply_filter`: rows kept on screen and the expansion state to restore
+/// once the filter is cleared.
+#[derive(Debug)]
+struct TreeFilter {
+    matches: HashSet<usize>,
+    visible: HashSet<usize>,
+    saved_expanded: HashSet<usize>,
+    /// Nodes whose children were loaded for the filter; they are unloaded again on clear.
+    loaded: HashSet<usize>,
+}
+
+struct TreeFilterScan {
+    needle: String,
+    case_sensitive: bool,
+    matches: HashSet<usize>,
+    visible: HashSet<usize>,
+    expanded: HashSet<usize>,
+}
+
+impl TreeFilterScan {
+    fn contains(&self, text: &str) -> bool {
+        if self.case_sensitive {
+            text.contains(&self.needle)
+        } else {
+            text.to_lowercase().contains(&self.needle)
+        }
+    }
+
+    /// Returns whether `node` or one of its descendants matches. Descendants of a match stay
+    /// visible so a matched document or array can still be browsed.
+    fn visit(&mut self, node: &BsonNode, ancestor_matched: bool) -> bool {
+        let is_match = node.display_key.as_deref().is_some_and(|key| self.contains(key))
+            || matches!(&node.kind, BsonKind::Value { display, .. } if self.contains(display));
+        let mut child_matched = false;
+        for child in node.children().unwrap_or_default() {
+            child_matched |= self.visit(child, ancestor_matched || is_match);
+        }
+
+        if is_match {
+            self.matches.insert(node.id);
+        }
+        if child_matched {
+            self.expanded.insert(node.id);
+        }
+        if is_match || child_matched || ancestor_matched {
+            self.visible.insert(node.id);
+        }
+        is_match || child_matched
+    }
+}
+
+pub const DEFAULT_MAX_DOCUMENT_BYTES: usize = 4 * 1024 * 1024;
+pub const DEFAULT_MAX_DOCUMENT_DEPTH: usize = 32;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BsonTreeStats {
     pub root_count: usize,
@@ -55,6 +116,9 @@ pub struct BsonTreeOptions {
     pub menu_colors: MenuColors,
     pub text_color: RgbaColor,
     pub button_colors: ButtonColors,
+    pub max_document_bytes: usize,
+    pub max_document_depth: usize,
+    pub relative_dates: bool,
 }
 
 impl BsonTreeOptions {
@@ -73,8 +137,75 @@ impl BsonTreeOptions {
             menu_colors,
             text_color,
             button_colors,
+            max_document_bytes: DEFAULT_MAX_DOCUMENT_BYTES,
+            max_document_depth: DEFAULT_MAX_DOCUMENT_DEPTH,
+            relative_dates: false,
+        }
+    }
+
+    /// Describes why a result document should not be expanded automatically, if it
+    /// exceeds the configured byte size or nesting depth.
+    fn oversized_reason(&self, document: &Document) -> Option<String> {
+        let size = bson::to_vec(document).map(|bytes| bytes.len()).unwrap_or(0);
+        if size > self.max_document_bytes {
+            return Some(tr_format(
+                "Large document ({}), expand to load",
+                &[&format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))],
+            ));
+        }
+
+        let depth = document_depth(document);
+        if depth > self.max_document_depth {
+            return Some(tr_format(
+                "Deeply nested document ({} levels), expand to load",
+                &[&depth.to_string()],
+            ));
+        }
+
+        None
+    }
+}
+
+/// Formats the distance between `value` and `now` as a short "5 min ago" / "in 2 d" label.
+fn relative_time_label(value: DateTime, now: DateTime) -> String {
+    let delta_ms = now.timestamp_millis().saturating_sub(value.timestamp_millis());
+    let seconds = delta_ms.unsigned_abs() / 1000;
+    if seconds < 5 {
+        return String::from(tr("just now"));
+    }
+
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+
+    let amount = if seconds < MINUTE {
+        tr_format("{} s", &[&seconds.to_string()])
+    } else if seconds < HOUR {
+        tr_format("{} min", &[&(seconds / MINUTE).to_string()])
+    } else if seconds < DAY {
+        tr_format("{} h", &[&(seconds / HOUR).to_string()])
+    } else if seconds < MONTH {
+        tr_format("{} d", &[&(seconds / DAY).to_string()])
+    } else if seconds < YEAR {
+        tr_format("{} mo", &[&(seconds / MONTH).to_string()])
+    } else {
+        tr_format("{} y", &[&(seconds / YEAR).to_string()])
+    };
+
+    if delta_ms >= 0 { tr_format("{} ago", &[&amount]) } else { tr_format("in {}", &[&amount]) }
+}
+
+fn document_depth(document: &Document) -> usize {
+    fn value_depth(value: &Bson) -> usize {
+        match value {
+            Bson::Document(doc) => document_depth(doc),
+            Bson::Array(items) => 1 + items.iter().map(value_depth).max().unwrap_or(0),
+            _ => 0,
         }
     }
+    1 + document.values().map(value_depth).max().unwrap_or(0)
 }
 
 impl Default for BsonTreeOptions {
@@ -98,14 +229,16 @@ impl From<&AppSettings> for BsonTreeOptions {
         let menu_colors = palette.menu.clone();
         let text_color = palette.text_primary;
         let button_colors = palette.subtle_buttons.clone();
-        Self::new(
+        let mut options = Self::new(
             settings.sort_fields_alphabetically,
             settings.sort_index_names_alphabetically,
             table_colors,
             menu_colors,
             text_color,
             button_colors,
-        )
+        );
+        options.relative_dates = settings.show_relative_dates;
+        options
     }
 }
 
@@ -168,6 +301,7 @@ struct BsonRowEntry<'a> {
     expanded: bool,
     path_enabled: bool,
     value_edit_enabled: bool,
+    in_array: bool,
     is_root_document: bool,
     relation_hint: Option<&'a str>,
 }
@@ -265,6 +399,12 @@ impl BsonNode {
         }
     }
 
+    fn unload_children(&mut self) {
+        if let BsonKind::Document { children } | BsonKind::Array { children } = &mut self.kind {
+            *children = None;
+        }
+    }
+
     fn materialize_children(&mut self, next_node_id: &mut usize, sort_fields_alphabetically: bool) {
         let mut id_gen = IdGenerator { next_id: *next_node_id };
         match &mut self.kind {
@@ -518,10 +658,34 @@ pub(crate) fn is_supported_reference_id_type(value: &Bson) -> bool {
     !matches!(value, Bson::Array(_) | Bson::RegularExpression(_) | Bson::Undefined | Bson::Null)
 }
 
+fn is_quick_filter_id_type(value: &Bson) -> bool {
+    matches!(
+        value,
+        Bson::ObjectId(_) | Bson::Int32(_) | Bson::Int64(_) | Bson::Double(_) | Bson::Decimal128(_)
+    )
+}
+
+/// Returns the single result document when it looks like `$facet` output: no `_id` and only
+/// array fields.
+fn facet_result_document(values: &[Bson]) -> Option<&Document> {
+    let [Bson::Document(document)] = values else {
+        return None;
+    };
+    let is_facet = !document.is_empty()
+        && !document.contains_key("_id")
+        && document.values().all(|value| matches!(value, Bson::Array(_)));
+    is_facet.then_some(document)
+}
+
 impl BsonTree {
     pub fn from_values(values: &[Bson], options: BsonTreeOptions) -> Self {
+        if let Some(facets) = facet_result_document(values) {
+            return Self::from_facets(facets, options);
+        }
+
         let mut id_gen = IdGenerator::default();
         let mut roots = Vec::new();
+        let mut deferred = HashMap::new();
 
         if values.is_empty() {
             let info_value = Bson::String(String::from(tr("No documents found")));
@@ -543,7 +707,13 @@ impl BsonTree {
                         .unwrap_or_else(|| base_label.clone()),
                     _ => base_label.clone(),
                 };
-                roots.push(BsonNode::from_bson_lazy(Some(key), None, value, &mut id_gen));
+                let node = BsonNode::from_bson_lazy(Some(key), None, value, &mut id_gen);
+                if let Bson::Document(doc) = value
+                    && let Some(reason) = options.oversized_reason(doc)
+                {
+                    deferred.insert(node.id, reason);
+                }
+                roots.push(node);
             }
         }
 
@@ -559,6 +729,48 @@ impl BsonTree {
             menu_colors: options.menu_colors.clone(),
             text_color: options.text_color,
             button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred,
+            relative_dates: options.relative_dates,
+            filter: None,
+        }
+    }
+
+    fn from_facets(facets: &Document, options: BsonTreeOptions) -> Self {
+        let mut id_gen = IdGenerator::default();
+        let mut roots = Vec::with_capacity(facets.len());
+        let mut expanded = HashSet::new();
+
+        for (name, value) in facets {
+            let mut node = BsonNode::from_bson_lazy(
+                Some(name.clone()),
+                Some(name.clone()),
+                value,
+                &mut id_gen,
+            );
+            if node.has_children() {
+                node.materialize_children(&mut id_gen.next_id, options.sort_fields_alphabetically);
+                expanded.insert(node.id);
+            }
+            roots.push(node);
+        }
+
+        Self {
+            roots,
+            expanded,
+            context: BsonTreeContext::Default,
+            next_node_id: id_gen.next_id,
+            sort_fields_alphabetically: options.sort_fields_alphabetically,
+            table_colors: options.table_colors.clone(),
+            menu_colors: options.menu_colors.clone(),
+            text_color: options.text_color,
+            button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred: HashMap::new(),
+            relative_dates: options.relative_dates,
+            filter: None,
         }
     }
 
@@ -589,6 +801,11 @@ impl BsonTree {
             menu_colors: options.menu_colors.clone(),
             text_color: options.text_color,
             button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred: HashMap::new(),
+            relative_dates: options.relative_dates,
+            filter: None,
         }
     }
 
@@ -610,6 +827,11 @@ impl BsonTree {
             menu_colors: options.menu_colors.clone(),
             text_color: options.text_color,
             button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred: HashMap::new(),
+            relative_dates: options.relative_dates,
+            filter: None,
         }
     }
 
@@ -644,6 +866,11 @@ impl BsonTree {
             menu_colors: options.menu_colors.clone(),
             text_color: options.text_color,
             button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred: HashMap::new(),
+            relative_dates: options.relative_dates,
+            filter: None,
         }
     }
 
@@ -700,6 +927,11 @@ impl BsonTree {
             menu_colors: options.menu_colors.clone(),
             text_color: options.text_color,
             button_colors: options.button_colors.clone(),
+            compare_node: None,
+            selected_rows: HashSet::new(),
+            deferred: HashMap::new(),
+            relative_dates: options.relative_dates,
+            filter: None,
         }
     }
 
@@ -707,6 +939,16 @@ impl BsonTree {
         matches!(self.context, BsonTreeContext::Indexes)
     }
 
+    pub fn node_index_keys(&self, node_id: usize) -> Option<Document> {
+        if !self.is_indexes_view() || !self.is_root_node(node_id) {
+            return None;
+        }
+        match &Self::find_node(&self.roots, node_id)?.bson {
+            Bson::Document(doc) => doc.get_document("key").ok().cloned(),
+            _ => None,
+        }
+    }
+
     pub fn node_index_name(&self, node_id: usize) -> Option<String> {
         if !self.is_indexes_view() {
             return None;
@@ -748,6 +990,10 @@ impl BsonTree {
         let header_bg = self.table_colors.header_background.to_color();
         let separator_color = self.table_colors.separator.to_color();
         let text_color = self.text_color.to_color();
+        let selected_row_color = self.menu_colors.hover_background.to_color();
+        let match_row_color = self.table_colors.header_background.to_color();
+        let result_font = fonts::active_fonts().result_font;
+        let match_font = Font { weight: Weight::Bold, ..result_font };
 
         let header_row = Row::new()
             .spacing(0)
@@ -807,12 +1053,23 @@ impl BsonTree {
                 expanded,
                 path_enabled,
                 value_edit_enabled,
+                in_array,
                 is_root_document,
                 relation_hint,
             },
         ) in rows.into_iter().enumerate()
         {
-            let background = if index % 2 == 0 { row_color_a } else { row_color_b };
+            let filter_match =
+                self.filter.as_ref().is_some_and(|filter| filter.matches.contains(&node.id));
+            let background = if self.selected_rows.contains(&node.id) {
+                selected_row_color
+            } else if filter_match {
+                match_row_color
+            } else if index % 2 == 0 {
+                row_color_a
+            } else {
+                row_color_b
+            };
 
             let mut key_row = Row::new().spacing(6).align_y(Vertical::Center);
             key_row = key_row.push(Space::new().width(Length::Fixed((depth as f32) * 16.0)));
@@ -846,18 +1103,28 @@ impl BsonTree {
             let key_label = node.display_key();
             key_row = key_row.push(
                 fonts::result_text(key_label.clone(), None)
+                    .font(if filter_match { match_font } else { result_font })
                     .color(text_color)
                     .wrapping(Wrapping::Word)
                     .width(Length::Fill),
             );
 
-            let value_text = node.value_display().unwrap_or_default();
+            let value_text = match (self.deferred.get(&node.id), &node.bson) {
+                (Some(reason), _) => reason.clone(),
+                (None, Bson::DateTime(value)) if self.relative_dates => format!(
+                    "{} ({})",
+                    node.value_display().unwrap_or_default(),
+                    relative_time_label(*value, DateTime::now())
+                ),
+                (None, _) => node.value_display().unwrap_or_default(),
+            };
             let type_text = node.type_label();
 
             let key_cell = Container::new(key_row).width(Length::FillPortion(4)).padding([6, 8]);
 
             let value_cell = Container::new(
                 fonts::result_text(value_text.clone(), None)
+                    .font(if filter_match { match_font } else { result_font })
                     .color(text_color)
                     .wrapping(Wrapping::Word)
                     .width(Length::Fill),
@@ -896,6 +1163,8 @@ impl BsonTree {
 
             let menu_node_id = node.id;
             let menu_tab_id = tab_id;
+            let root_id_available =
+                matches!(&node.bson, Bson::Document(doc) if doc.contains_key("_id"));
             let index_context = if self.is_indexes_view() && is_root_document {
                 let (maybe_name, maybe_hidden, ttl_enabled) = match &node.bson {
                     Bson::Document(doc) => {
@@ -924,9 +1193,16 @@ impl BsonTree {
                     ..Default::default()
                 }
             });
+            let row_container = widget::mouse_area(row_container)
+                .on_press(Message::CollectionTreeRowClicked { tab_id, node_id: node.id });
 
             let menu_colors = self.menu_colors.clone();
             let menu_border = self.table_colors.separator.to_color();
+            let compare_node = self.compare_node;
+            let find_by_id_enabled = depth == 1
+                && node.path_key.as_deref() == Some("_id")
+                && is_quick_filter_id_type(&node.bson);
+            let has_selected_rows = !self.selected_rows.is_empty();
 
             let row_with_menu = TableContextMenu::new(row_container, move || {
                 let mut menu = Column::new().spacing(6).padding([4, 6]);
@@ -1020,6 +1296,45 @@ impl BsonTree {
                     menu_border,
                 );
 
+                let copy_markdown = style_menu_button(
+                    Button::new(fonts::primary_text(tr("Copy Results as Markdown Table"), None))
+                        .padding([4, 12])
+                        .width(Length::Shrink)
+                        .on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::CopyMarkdownTable,
+                        }),
+                    &menu_colors,
+                    menu_border,
+                );
+
+                let export_json = style_menu_button(
+                    Button::new(fonts::primary_text(tr("Export Results to JSON..."), None))
+                        .padding([4, 12])
+                        .width(Length::Shrink)
+                        .on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::ExportJson,
+                        }),
+                    &menu_colors,
+                    menu_border,
+                );
+
+                let export_csv = style_menu_button(
+                    Button::new(fonts::primary_text(tr("Export Results to CSV..."), None))
+                        .padding([4, 12])
+                        .width(Length::Shrink)
+                        .on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::ExportCsv,
+                        }),
+                    &menu_colors,
+                    menu_border,
+                );
+
                 let copy_key = style_menu_button(
                     Button::new(fonts::primary_text(tr("Copy Key"), None))
                         .padding([4, 12])
@@ -1060,9 +1375,64 @@ impl BsonTree {
                 let copy_path = style_menu_button(copy_path, &menu_colors, menu_border);
 
                 menu = menu.push(menu_item_container(copy_json.into(), &menu_colors, menu_border));
+                menu =
+                    menu.push(menu_item_container(copy_markdown.into(), &menu_colors, menu_border));
+                menu =
+                    menu.push(menu_item_container(export_json.into(), &menu_colors, menu_border));
+                menu = menu.push(menu_item_container(export_csv.into(), &menu_colors, menu_border));
+                if is_root_document {
+                    let mut copy_id = Button::new(fonts::primary_text(tr("Copy _id"), None))
+                        .padding([4, 12])
+                        .width(Length::Shrink);
+                    if root_id_available {
+                        copy_id = copy_id.on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::CopyId,
+                        });
+                    }
+                    let copy_id = style_menu_button(copy_id, &menu_colors, menu_border);
+                    menu =
+                        menu.push(menu_item_container(copy_id.into(), &menu_colors, menu_border));
+                }
                 menu = menu.push(menu_item_container(copy_key.into(), &menu_colors, menu_border));
                 menu = menu.push(menu_item_container(copy_value.into(), &menu_colors, menu_border));
                 menu = menu.push(menu_item_container(copy_path.into(), &menu_colors, menu_border));
+
+                if find_by_id_enabled {
+                    let find_by_id = style_menu_button(
+                        Button::new(fonts::primary_text(tr("Find This Document"), None))
+                            .padding([4, 12])
+                            .width(Length::Shrink)
+                            .on_press(Message::TableContextMenu {
+                                tab_id: menu_tab_id,
+                                node_id: menu_node_id,
+                                action: TableContextAction::FindById,
+                            }),
+                        &menu_colors,
+                        menu_border,
+                    );
+                    menu = menu.push(menu_item_container(
+                        find_by_id.into(),
+                        &menu_colors,
+                        menu_border,
+                    ));
+                }
+
+                let mut copy_selected =
+                    Button::new(fonts::primary_text(tr("Copy Selected Rows"), None))
+                        .padding([4, 12])
+                        .width(Length::Shrink);
+                if has_selected_rows {
+                    copy_selected = copy_selected.on_press(Message::TableContextMenu {
+                        tab_id: menu_tab_id,
+                        node_id: menu_node_id,
+                        action: TableContextAction::CopySelectedRows,
+                    });
+                }
+                let copy_selected = style_menu_button(copy_selected, &menu_colors, menu_border);
+                menu =
+                    menu.push(menu_item_container(copy_selected.into(), &menu_colors, menu_border));
                 if value_edit_enabled {
                     let edit_value = style_menu_button(
                         Button::new(fonts::primary_text(tr("Edit Value Only..."), None))
@@ -1082,6 +1452,44 @@ impl BsonTree {
                         menu_border,
                     ));
                 }
+                if value_edit_enabled && matches!(node.kind, BsonKind::Array { .. }) {
+                    let append_element = style_menu_button(
+                        Button::new(fonts::primary_text(tr("Append Array Element..."), None))
+                            .padding([4, 12])
+                            .width(Length::Shrink)
+                            .on_press(Message::TableContextMenu {
+                                tab_id: menu_tab_id,
+                                node_id: menu_node_id,
+                                action: TableContextAction::AppendArrayElement,
+                            }),
+                        &menu_colors,
+                        menu_border,
+                    );
+                    menu = menu.push(menu_item_container(
+                        append_element.into(),
+                        &menu_colors,
+                        menu_border,
+                    ));
+                }
+                if value_edit_enabled && in_array {
+                    let delete_element = style_menu_button(
+                        Button::new(fonts::primary_text(tr("Delete Array Element"), None))
+                            .padding([4, 12])
+                            .width(Length::Shrink)
+                            .on_press(Message::TableContextMenu {
+                                tab_id: menu_tab_id,
+                                node_id: menu_node_id,
+                                action: TableContextAction::DeleteArrayElement,
+                            }),
+                        &menu_colors,
+                        menu_border,
+                    );
+                    menu = menu.push(menu_item_container(
+                        delete_element.into(),
+                        &menu_colors,
+                        menu_border,
+                    ));
+                }
 
                 if can_open_related_document {
                     let goto_related = style_menu_button(
@@ -1201,6 +1609,43 @@ impl BsonTree {
                         &menu_colors,
                         menu_border,
                     ));
+
+                    let mut select_button =
+                        Button::new(fonts::primary_text(tr("Select for Compare"), None))
+                            .padding([4, 12])
+                            .width(Length::Shrink);
+                    if compare_node != Some(menu_node_id) {
+                        select_button = select_button.on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::SelectForCompare,
+                        });
+                    }
+                    let select_button = style_menu_button(select_button, &menu_colors, menu_border);
+                    menu = menu.push(menu_item_container(
+                        select_button.into(),
+                        &menu_colors,
+                        menu_border,
+                    ));
+
+                    let mut compare_button =
+                        Button::new(fonts::primary_text(tr("Compare with Selected"), None))
+                            .padding([4, 12])
+                            .width(Length::Shrink);
+                    if compare_node.is_some_and(|selected| selected != menu_node_id) {
+                        compare_button = compare_button.on_press(Message::TableContextMenu {
+                            tab_id: menu_tab_id,
+                            node_id: menu_node_id,
+                            action: TableContextAction::CompareWithSelected,
+                        });
+                    }
+                    let compare_button =
+                        style_menu_button(compare_button, &menu_colors, menu_border);
+                    menu = menu.push(menu_item_container(
+                        compare_button.into(),
+                        &menu_colors,
+                        menu_border,
+                    ));
                 }
 
                 menu.into()
@@ -1231,15 +1676,22 @@ impl BsonTree {
         if self.expanded.contains(&node_id) {
             self.expanded.remove(&node_id);
         } else if self.is_container(node_id) && self.node_has_children(node_id) {
+            self.deferred.remove(&node_id);
             self.ensure_children_loaded(node_id);
             self.expanded.insert(node_id);
         }
     }
 
     pub fn expand_recursive(&mut self, node_id: usize) {
-        if !self.is_container(node_id) || !self.node_has_children(node_id) {
+        self.expand_to_depth(node_id, None);
+    }
+
+    /// Expands `node_id` and its descendants, opening at most `levels` levels when set.
+    fn expand_to_depth(&mut self, node_id: usize, levels: Option<usize>) {
+        if levels == Some(0) || !self.is_container(node_id) || !self.node_has_children(node_id) {
             return;
         }
+        self.deferred.remove(&node_id);
         self.ensure_children_loaded(node_id);
         self.expanded.insert(node_id);
         if let Some(child_ids) = Self::find_node(&self.roots, node_id)
@@ -1247,7 +1699,7 @@ impl BsonTree {
             .map(|children| children.iter().map(|child| child.id).collect::<Vec<_>>())
         {
             for child_id in child_ids {
-                self.expand_recursive(child_id);
+                self.expand_to_depth(child_id, levels.map(|levels| levels - 1));
             }
         }
     }
@@ -1283,10 +1735,144 @@ impl BsonTree {
         self.expanded.remove(&node_id);
     }
 
-    pub fn expand_all(&mut self) {
-        let root_ids: Vec<usize> = self.roots.iter().map(|node| node.id).collect();
+    /// Expands every root except oversized documents, which stay collapsed until the user
+    /// expands them explicitly. `max_depth` caps the number of opened levels, roots included.
+    pub fn expand_all(&mut self, max_depth: Option<usize>) {
+        let root_ids: Vec<usize> = self
+            .roots
+            .iter()
+            .map(|node| node.id)
+            .filter(|id| !self.deferred.contains_key(id))
+            .collect();
         for root_id in root_ids {
-            self.expand_recursive(root_id);
+            self.expand_to_depth(root_id, max_depth);
+        }
+    }
+
+    /// Whether `expand_all(max_depth)` would leave the tree unchanged.
+    pub fn is_fully_expanded(&self, max_depth: Option<usize>) -> bool {
+        self.roots
+            .iter()
+            .filter(|node| !self.deferred.contains_key(&node.id))
+            .all(|node| self.subtree_expanded(node, max_depth))
+    }
+
+    fn subtree_expanded(&self, node: &BsonNode, levels: Option<usize>) -> bool {
+        if levels == Some(0) || !node.is_container() || !node.has_children() {
+            return true;
+        }
+        if !self.expanded.contains(&node.id) {
+            return false;
+        }
+        node.children()
+            .unwrap_or_default()
+            .iter()
+            .all(|child| self.subtree_expanded(child, levels.map(|levels| levels - 1)))
+    }
+
+    pub fn has_expanded_nodes(&self) -> bool {
+        !self.expanded.is_empty()
+    }
+
+    /// Shows only nodes whose key or scalar value contains `query`, their ancestors (expanded)
+    /// and their descendants. An empty query restores the previous expansion state.
+    ///
+    /// The first call loads every document below the size limit in full; later calls reuse the
+    /// loaded nodes until the filter is cleared.
+    pub fn apply_filter(&mut self, query: &str, case_sensitive: bool) {
+        if query.is_empty() {
+            self.clear_filter();
+            return;
+        }
+
+        let (saved_expanded, loaded) = match self.filter.take() {
+            Some(filter) => (filter.saved_expanded, filter.loaded),
+            None => {
+                let mut loaded = HashSet::new();
+                for root in &mut self.roots {
+                    Self::materialize_subtree(
+                        root,
+                        &mut self.next_node_id,
+                        self.sort_fields_alphabetically,
+                        &self.deferred,
+                        &mut loaded,
+                    );
+                }
+                (self.expanded.clone(), loaded)
+            }
+        };
+
+        let mut scan = TreeFilterScan {
+            needle: if case_sensitive { query.to_string() } else { query.to_lowercase() },
+            case_sensitive,
+            matches: HashSet::new(),
+            visible: HashSet::new(),
+            expanded: HashSet::new(),
+        };
+        for root in &self.roots {
+            scan.visit(root, false);
+        }
+
+        self.expanded = scan.expanded;
+        self.filter = Some(TreeFilter {
+            matches: scan.matches,
+            visible: scan.visible,
+            saved_expanded,
+            loaded,
+        });
+    }
+
+    pub fn clear_filter(&mut self) {
+        if let Some(filter) = self.filter.take() {
+            self.expanded = filter.saved_expanded;
+            for root in &mut self.roots {
+                Self::unload_subtree(root, &filter.loaded);
+            }
+        }
+    }
+
+    pub fn filter_match_count(&self) -> Option<usize> {
+        self.filter.as_ref().map(|filter| filter.matches.len())
+    }
+
+    /// Oversized documents are skipped; they are only matched by their own key. Nodes whose
+    /// children are loaded here are added to `loaded`.
+    fn materialize_subtree(
+        node: &mut BsonNode,
+        next_node_id: &mut usize,
+        sort_fields_alphabetically: bool,
+        deferred: &HashMap<usize, String>,
+        loaded: &mut HashSet<usize>,
+    ) {
+        if deferred.contains_key(&node.id) {
+            return;
+        }
+        if node.is_container() && node.children().is_none() {
+            loaded.insert(node.id);
+        }
+        node.materialize_children(next_node_id, sort_fields_alphabetically);
+        if let Some(children) = node.children_mut() {
+            for child in children {
+                Self::materialize_subtree(
+                    child,
+                    next_node_id,
+                    sort_fields_alphabetically,
+                    deferred,
+                    loaded,
+                );
+            }
+        }
+    }
+
+    fn unload_subtree(node: &mut BsonNode, loaded: &HashSet<usize>) {
+        if loaded.contains(&node.id) {
+            node.unload_children();
+            return;
+        }
+        if let Some(children) = node.children_mut() {
+            for child in children {
+                Self::unload_subtree(child, loaded);
+            }
         }
     }
 
@@ -1305,12 +1891,64 @@ impl BsonTree {
         self.roots.first().map(|node| node.id)
     }
 
+    pub fn select_for_compare(&mut self, node_id: usize) {
+        if self.is_root_node(node_id) && !self.is_indexes_view() {
+            self.compare_node = Some(node_id);
+        }
+    }
+
+    /// Ctrl+click adds or removes a row from the selection; a plain click clears it.
+    pub fn click_row(&mut self, node_id: usize, extend_selection: bool) {
+        if !extend_selection {
+            self.selected_rows.clear();
+        } else if !self.selected_rows.remove(&node_id) {
+            self.selected_rows.insert(node_id);
+        }
+    }
+
+    /// Joins the visible selected rows as `key: value` lines in display order. Documents and
+    /// arrays are written as compact JSON.
+    pub fn selected_rows_text(&self) -> Option<String> {
+        let mut rows = Vec::new();
+        self.collect_rows(&mut rows);
+        let lines: Vec<String> = rows
+            .into_iter()
+            .filter(|row| self.selected_rows.contains(&row.node.id))
+            .map(|row| {
+                let value = if row.node.is_container() {
+                    row.node.bson.clone().into_relaxed_extjson().to_string()
+                } else {
+                    row.node.value_display().unwrap_or_default()
+                };
+                format!("{}: {}", row.node.display_key(), value)
+            })
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Returns the documents of the previously selected node and `node_id`, in that order.
+    pub fn compare_documents(&self, node_id: usize) -> Option<(Document, Document)> {
+        let selected = self.compare_node.filter(|selected| *selected != node_id)?;
+        let left = match self.node_bson(selected)? {
+            Bson::Document(document) => document,
+            _ => return None,
+        };
+        let right = match self.node_bson(node_id)? {
+            Bson::Document(document) => document,
+            _ => return None,
+        };
+        Some((left, right))
+    }
+
     #[cfg(test)]
     pub(crate) fn root_id_at(&self, index: usize) -> Option<usize> {
         self.roots.get(index).map(|node| node.id)
     }
 
     pub fn expand_node(&mut self, node_id: usize) {
+        if self.deferred.contains_key(&node_id) {
+            return;
+        }
         if self.is_container(node_id) && self.node_has_children(node_id) {
             self.ensure_children_loaded(node_id);
             self.expanded.insert(node_id);
@@ -1329,6 +1967,14 @@ impl BsonTree {
         Self::find_node(&self.roots, node_id).map(|node| node.bson.clone())
     }
 
+    pub fn node_document_id(&self, node_id: usize) -> Option<String> {
+        let node = Self::find_node(&self.roots, node_id)?;
+        match &node.bson {
+            Bson::Document(doc) => doc.get("_id").map(shell::format_bson_shell),
+            _ => None,
+        }
+    }
+
     pub fn node_path(&self, node_id: usize) -> Option<String> {
         let nodes = Self::find_node_path(&self.roots, node_id, &mut Vec::new())?;
         let mut components = Vec::new();
@@ -1341,6 +1987,18 @@ impl BsonTree {
         if components.is_empty() { None } else { Some(components.join(".")) }
     }
 
+    /// Value of a top-level `_id` field holding an ObjectId or a number.
+    pub fn node_root_id_value(&self, node_id: usize) -> Option<Bson> {
+        let nodes = Self::find_node_path(&self.roots, node_id, &mut Vec::new())?;
+        let [root, node] = nodes.as_slice() else {
+            return None;
+        };
+        let is_root_id = matches!(root.kind, BsonKind::Document { .. })
+            && node.path_key.as_deref() == Some("_id")
+            && is_quick_filter_id_type(&node.bson);
+        is_root_id.then(|| node.bson.clone())
+    }
+
     pub fn node_relation_hint(&self, node_id: usize) -> Option<String> {
         let nodes = Self::find_node_path(&self.roots, node_id, &mut Vec::new())?;
         let mut hint = None;
@@ -1407,7 +2065,7 @@ impl BsonTree {
     }
 
     pub fn value_edit_context(&self, node_id: usize) -> Option<ValueEditContext> {
-        let (components, root_doc, value_node) = self.edit_requirements(node_id)?;
+        let (components, root_doc, value_node, in_array) = self.edit_requirements(node_id)?;
         let mut filter = Document::new();
         filter.insert("_id", root_doc.get("_id")?.clone());
 
@@ -1415,12 +2073,20 @@ impl BsonTree {
             path: components.join("."),
             filter,
             current_value: value_node.bson.clone(),
+            in_array,
         })
     }
 
-    fn edit_requirements(&self, node_id: usize) -> Option<(Vec<String>, &Document, &BsonNode)> {
+    /// Path components, root document and target node of an editable value, plus whether the
+    /// value is an element of an array.
+    fn edit_requirements(
+        &self,
+        node_id: usize,
+    ) -> Option<(Vec<String>, &Document, &BsonNode, bool)> {
         let nodes = Self::find_node_path(&self.roots, node_id, &mut Vec::new())?;
         let target = nodes.last()?;
+        let in_array =
+            nodes.len() >= 2 && matches!(nodes[nodes.len() - 2].kind, BsonKind::Array { .. });
 
         if !is_editable_value(&target.bson) {
             return None;
@@ -1447,7 +2113,7 @@ impl BsonTree {
             return None;
         }
 
-        Some((components, root_document, target))
+        Some((components, root_document, target, in_array))
     }
 
     fn collect_rows<'a>(&'a self, rows: &mut Vec<BsonRowEntry<'a>>) {
@@ -1469,6 +2135,9 @@ impl BsonTree {
         is_root: bool,
         relation_hint: Option<&'a str>,
     ) {
+        if self.filter.as_ref().is_some_and(|filter| !filter.visible.contains(&node.id)) {
+            return;
+        }
         let expanded = self.expanded.contains(&node.id);
         let value_edit_enabled =
             root_has_id && path_len_from_root > 0 && is_editable_value(&node.bson);
@@ -1485,13 +2154,16 @@ impl BsonTree {
             expanded,
             path_enabled: has_path,
             value_edit_enabled,
+            in_array: false,
             is_root_document,
             relation_hint: current_relation_hint,
         });
 
         if expanded {
+            let children_in_array = matches!(node.kind, BsonKind::Array { .. });
             if let Some(children) = node.children() {
                 for child in children {
+                    let child_row = rows.len();
                     let child_has_path = has_path || child.path_key.is_some();
                     let child_path_len_from_root =
                         path_len_from_root + usize::from(child.path_key.is_some());
@@ -1505,6 +2177,9 @@ impl BsonTree {
                         false,
                         current_relation_hint,
                     );
+                    if let Some(row) = rows.get_mut(child_row) {
+                        row.in_array = children_in_array;
+                    }
                 }
             }
         }
@@ -1735,6 +2410,46 @@ mod tests {
         assert!(!empty_arr.has_children());
     }
 
+    #[test]
+    fn facet_result_renders_each_facet_as_expanded_root() {
+        let facets = doc! {
+            "byStatus": [{ "_id": "A", "count": 3 }, { "_id": "B", "count": 1 }],
+            "total": [{ "count": 4 }],
+            "empty": [],
+        };
+        let mut tree = BsonTree::from_values(&[Bson::Document(facets)], default_options());
+
+        assert_eq!(tree.roots.len(), 3);
+        let by_status = tree.roots[0].id;
+        assert_eq!(tree.node_display_key(by_status).as_deref(), Some("byStatus"));
+        assert!(tree.expanded.contains(&by_status));
+        assert!(!tree.expanded.contains(&tree.roots[2].id));
+
+        let first_item = tree.roots[0].children().expect("facet items")[0].id;
+        tree.expand_node(first_item);
+        let count_node = find_child(&tree.roots[0].children().unwrap()[0], "count").id;
+        assert_eq!(tree.node_path(count_node).as_deref(), Some("byStatus.0.count"));
+        assert!(tree.value_edit_context(count_node).is_none());
+    }
+
+    #[test]
+    fn documents_with_id_or_scalars_are_not_treated_as_facets() {
+        let with_id = doc! { "_id": 1, "items": [1, 2] };
+        let tree = BsonTree::from_values(&[Bson::Document(with_id)], default_options());
+        assert_eq!(tree.roots.len(), 1);
+
+        let mixed = doc! { "items": [1, 2], "name": "x" };
+        let tree = BsonTree::from_values(&[Bson::Document(mixed)], default_options());
+        assert_eq!(tree.roots.len(), 1);
+
+        let facets = doc! { "items": [1, 2] };
+        let tree = BsonTree::from_values(
+            &[Bson::Document(facets.clone()), Bson::Document(facets)],
+            default_options(),
+        );
+        assert_eq!(tree.roots.len(), 2);
+    }
+
     #[test]
     fn distinct_tree_expands_root() {
         let tree = BsonTree::from_distinct(
@@ -1817,6 +2532,83 @@ mod tests {
         assert!(!tree.expanded.contains(&profile_id));
     }
 
+    #[test]
+    fn filter_keeps_matches_with_ancestors_and_restores_expansion() {
+        let tree_doc = doc! {
+            "_id": 1,
+            "profile": { "City": "Oslo", "age": 30 },
+            "tags": ["north", "south"],
+        };
+        let mut tree = single_document_tree(tree_doc);
+        let root_id = tree.roots[0].id;
+        tree.expand_node(root_id);
+
+        tree.apply_filter("oslo", false);
+        let root = &tree.roots[0];
+        let profile = find_child(root, "profile");
+        let city_id = find_child(profile, "City").id;
+        let age_id = find_child(profile, "age").id;
+        let tags_id = find_child(root, "tags").id;
+        assert_eq!(tree.filter_match_count(), Some(1));
+        assert!(tree.expanded.contains(&profile.id));
+
+        let mut rows = Vec::new();
+        tree.collect_rows(&mut rows);
+        let visible: Vec<usize> = rows.iter().map(|row| row.node.id).collect();
+        assert_eq!(visible, vec![root_id, profile.id, city_id]);
+        assert!(!visible.contains(&age_id) && !visible.contains(&tags_id));
+
+        tree.apply_filter("oslo", true);
+        assert_eq!(tree.filter_match_count(), Some(0));
+        tree.apply_filter("city", true);
+        assert_eq!(tree.filter_match_count(), Some(0));
+
+        tree.apply_filter("", false);
+        assert_eq!(tree.filter_match_count(), None);
+        assert_eq!(tree.expanded, HashSet::from([root_id]));
+    }
+
+    #[test]
+    fn filter_loads_children_once_and_unloads_them_on_clear() {
+        let mut tree = single_document_tree(doc! { "_id": 1, "profile": { "city": "Oslo" } });
+        let root_id = tree.roots[0].id;
+        tree.expand_node(root_id);
+        assert!(find_child(&tree.roots[0], "profile").children().is_none());
+
+        tree.apply_filter("o", false);
+        let next_node_id = tree.next_node_id;
+        assert!(find_child(&tree.roots[0], "profile").children().is_some());
+        tree.apply_filter("os", false);
+        assert_eq!(tree.next_node_id, next_node_id);
+        assert_eq!(tree.filter_match_count(), Some(1));
+
+        tree.clear_filter();
+        assert!(tree.roots[0].children().is_some());
+        assert!(find_child(&tree.roots[0], "profile").children().is_none());
+    }
+
+    #[test]
+    fn expand_all_respects_depth_cap() {
+        let tree_doc = doc! { "_id": 1, "profile": { "address": { "city": "Paris" } } };
+        let mut tree = single_document_tree(tree_doc);
+        let root_id = tree.roots[0].id;
+        assert!(!tree.has_expanded_nodes());
+
+        tree.expand_all(Some(2));
+        let profile_id = find_child(&tree.roots[0], "profile").id;
+        let address_id = find_child(find_child(&tree.roots[0], "profile"), "address").id;
+        assert_eq!(tree.expanded, HashSet::from([root_id, profile_id]));
+        assert!(tree.is_fully_expanded(Some(2)));
+        assert!(!tree.is_fully_expanded(None));
+
+        tree.expand_all(None);
+        assert!(tree.expanded.contains(&address_id));
+        assert!(tree.is_fully_expanded(None));
+
+        tree.collapse_all();
+        assert!(!tree.has_expanded_nodes());
+    }
+
     #[test]
     fn expand_node_does_not_expand_empty_container() {
         let id = ObjectId::new();
@@ -1946,9 +2738,37 @@ mod tests {
         assert_eq!(context.path, "profile.age");
         assert_eq!(context.filter, doc! { "_id": Bson::ObjectId(id) });
         assert_eq!(context.current_value, Bson::Int32(30));
+        assert!(!context.in_array);
         assert_eq!(tree.node_path(age_node.id).as_deref(), Some("profile.age"));
     }
 
+    #[test]
+    fn value_edit_context_marks_array_elements() {
+        let document = doc! { "_id": 1, "items": ["a", "b", "c"] };
+        let mut tree = BsonTree::from_values(&[Bson::Document(document)], default_options());
+        let root_id = tree.roots[0].id;
+        tree.expand_recursive(root_id);
+
+        let items_node = match &tree.roots[0].kind {
+            BsonKind::Document { children } => children
+                .as_ref()
+                .expect("materialized root children")
+                .iter()
+                .find(|node| node.display_key.as_deref() == Some("items"))
+                .expect("items field"),
+            _ => panic!("expected document root"),
+        };
+        let third = match &items_node.kind {
+            BsonKind::Array { children } => &children.as_ref().expect("array children")[2],
+            _ => panic!("expected items array"),
+        };
+
+        let context = tree.value_edit_context(third.id).expect("element context");
+        assert_eq!(context.path, "items.2");
+        assert!(context.in_array);
+        assert!(!tree.value_edit_context(items_node.id).expect("array context").in_array);
+    }
+
     #[test]
     fn related_collection_candidates_cover_common_reference_forms() {
         let user_id_candidates = related_collection_name_candidates("userId");
@@ -1999,4 +2819,141 @@ mod tests {
             options: String::new(),
         },)));
     }
+
+    #[test]
+    fn oversized_documents_are_not_expanded_automatically() {
+        let nested = doc! { "a": { "b": { "c": 1 } } };
+        let small = doc! { "_id": 1 };
+        let mut options = default_options();
+        options.max_document_depth = 2;
+        let mut tree =
+            BsonTree::from_values(&[Bson::Document(nested), Bson::Document(small)], options);
+        let deep_id = tree.root_id_at(0).unwrap();
+        let small_id = tree.root_id_at(1).unwrap();
+
+        tree.expand_node(deep_id);
+        tree.expand_all(None);
+        assert!(!tree.expanded.contains(&deep_id));
+        assert!(tree.expanded.contains(&small_id));
+
+        tree.toggle(deep_id);
+        assert!(tree.expanded.contains(&deep_id));
+        assert!(tree.deferred.is_empty());
+    }
+
+    #[test]
+    fn documents_above_byte_limit_are_deferred() {
+        let blob = doc! { "_id": 1, "payload": "x".repeat(256) };
+        let mut options = default_options();
+        options.max_document_bytes = 128;
+        let tree = BsonTree::from_values(&[Bson::Document(blob)], options);
+
+        assert_eq!(tree.deferred.len(), 1);
+    }
+
+    #[test]
+    fn compare_documents_returns_selected_and_target_roots() {
+        let first = doc! { "_id": 1, "name": "alpha" };
+        let second = doc! { "_id": 2, "name": "beta" };
+        let mut tree = BsonTree::from_values(
+            &[Bson::Document(first.clone()), Bson::Document(second.clone())],
+            default_options(),
+        );
+        let first_id = tree.root_id_at(0).expect("first root");
+        let second_id = tree.root_id_at(1).expect("second root");
+
+        assert!(tree.compare_documents(second_id).is_none());
+
+        tree.select_for_compare(first_id);
+        assert!(tree.compare_documents(first_id).is_none());
+        assert_eq!(tree.compare_documents(second_id), Some((first, second)));
+    }
+
+    #[test]
+    fn root_id_value_accepts_object_ids_and_numbers() {
+        let id = ObjectId::new();
+        let mut tree = BsonTree::from_values(
+            &[
+                Bson::Document(doc! { "_id": id, "ref": { "_id": 7 } }),
+                Bson::Document(doc! { "_id": 42_i64 }),
+                Bson::Document(doc! { "_id": "slug" }),
+            ],
+            default_options(),
+        );
+        let roots: Vec<usize> = tree.roots.iter().map(|root| root.id).collect();
+        for root_id in &roots {
+            tree.expand_node(*root_id);
+        }
+        let ref_id = find_child(&tree.roots[0], "ref").id;
+        tree.expand_node(ref_id);
+        let id_node = |root: usize| find_child(&tree.roots[root], "_id").id;
+        let nested_id = find_child(find_child(&tree.roots[0], "ref"), "_id").id;
+
+        assert_eq!(tree.node_root_id_value(id_node(0)), Some(Bson::ObjectId(id)));
+        assert_eq!(tree.node_root_id_value(id_node(1)), Some(Bson::Int64(42)));
+        assert_eq!(tree.node_root_id_value(id_node(2)), None);
+        assert_eq!(tree.node_root_id_value(nested_id), None);
+        assert_eq!(tree.node_root_id_value(roots[0]), None);
+    }
+
+    #[test]
+    fn selected_rows_text_follows_display_order() {
+        let mut tree = single_document_tree(doc! { "name": "Ann", "tags": ["a"], "age": 31 });
+        let root_id = tree.first_root_id().expect("root");
+        tree.expand_node(root_id);
+        let root = tree.roots[0].clone();
+        let name_id = find_child(&root, "name").id;
+        let tags_id = find_child(&root, "tags").id;
+        let age_id = find_child(&root, "age").id;
+
+        assert_eq!(tree.selected_rows_text(), None);
+        tree.click_row(age_id, true);
+        tree.click_row(name_id, true);
+        tree.click_row(tags_id, true);
+        tree.click_row(tags_id, true);
+        tree.click_row(tags_id, true);
+        assert_eq!(tree.selected_rows_text().as_deref(), Some("name: Ann\ntags: [\"a\"]\nage: 31"));
+
+        tree.collapse_recursive(root_id);
+        assert_eq!(tree.selected_rows_text(), None);
+
+        tree.click_row(root_id, false);
+        tree.expand_node(root_id);
+        assert_eq!(tree.selected_rows_text(), None);
+    }
+
+    #[test]
+    fn relative_time_label_describes_past_and_future() {
+        let now = DateTime::from_millis(1_700_000_000_000);
+        let at = |offset_ms: i64| DateTime::from_millis(now.timestamp_millis() + offset_ms);
+
+        assert_eq!(relative_time_label(at(-2_000), now), tr("just now"));
+        let ago = |amount: String| tr_format("{} ago", &[&amount]);
+        assert_eq!(relative_time_label(at(-45_000), now), ago(tr_format("{} s", &["45"])));
+        assert_eq!(relative_time_label(at(-3 * 86_400_000), now), ago(tr_format("{} d", &["3"])));
+        assert_eq!(
+            relative_time_label(at(2 * 3_600_000), now),
+            tr_format("in {}", &[&tr_format("{} h", &["2"])])
+        );
+        assert_eq!(relative_time_label(at(-400 * 86_400_000), now), ago(tr_format("{} y", &["1"])));
+    }
+
+    #[test]
+    fn node_document_id_formats_root_id() {
+        let id = ObjectId::parse_str("65f1a2b3c4d5e6f708192a3b").unwrap();
+        let tree = BsonTree::from_values(
+            &[
+                Bson::Document(doc! { "_id": id, "name": "a" }),
+                Bson::Document(doc! { "name": "b" }),
+            ],
+            default_options(),
+        );
+        let roots: Vec<usize> = tree.roots.iter().map(|root| root.id).collect();
+
+        assert_eq!(
+            tree.node_document_id(roots[0]).as_deref(),
+            Some("ObjectId(\"65f1a2b3c4d5e6f708192a3b\")")
+        );
+        assert_eq!(tree.node_document_id(roots[1]), None);
+    }
 }
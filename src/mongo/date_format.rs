@@ -0,0 +1,214 @@
+//! Client-side approximation of `$dateToString` used by the date format preview.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+use crate::i18n::{tr, tr_format};
+
+pub const DEFAULT_DATE_TO_STRING_FORMAT: &str = "%Y-%m-%dT%H:%M:%S.%LZ";
+
+const NAIVE_DATE_TIME_FORMATS: &[&str] =
+    &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"];
+
+/// Parses a sample date typed by the user: ISO 8601 text (optionally wrapped in `ISODate(...)`
+/// or `new Date(...)`), a plain `YYYY-MM-DD` date or milliseconds since the epoch.
+pub fn parse_sample_date(input: &str) -> Result<DateTime<Utc>, String> {
+    let mut value = input.trim();
+    for prefix in ["ISODate(", "new Date(", "Date("] {
+        if let Some(inner) = value.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(')')) {
+            value = inner.trim();
+            break;
+        }
+    }
+    let value = value.trim_matches(|ch| ch == '"' || ch == '\'').trim();
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    for format in NAIVE_DATE_TIME_FORMATS {
+        if let Ok(date) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(date.and_utc());
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        && let Some(date) = date.and_hms_opt(0, 0, 0)
+    {
+        return Ok(date.and_utc());
+    }
+    if let Ok(millis) = value.parse::<i64>()
+        && let Some(date) = DateTime::from_timestamp_millis(millis)
+    {
+        return Ok(date);
+    }
+
+    Err(String::from(tr("Enter the sample date as ISO 8601, for example 2024-03-15T13:45:30Z.")))
+}
+
+/// Parses the `timezone` argument. Only UTC and fixed offsets (`+03`, `+0300`, `+03:00`) are
+/// supported; Olson names need a timezone database that the preview does not ship.
+pub fn parse_timezone_offset(input: &str) -> Result<FixedOffset, String> {
+    let value = input.trim();
+    let unsupported =
+        || tr_format("Unsupported timezone '{}'. Use UTC or an offset such as +03:00.", &[value]);
+
+    if value.is_empty() || matches!(value, "UTC" | "GMT" | "Z") {
+        return Ok(FixedOffset::east_opt(0).expect("zero offset is valid"));
+    }
+
+    let (sign, digits) = match value.as_bytes().first() {
+        Some(b'+') => (1, &value[1..]),
+        Some(b'-') => (-1, &value[1..]),
+        _ => return Err(unsupported()),
+    };
+    let digits = digits.replace(':', "");
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(unsupported());
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (&digits[..2], "0"),
+        4 => (&digits[..2], &digits[2..]),
+        _ => return Err(unsupported()),
+    };
+    let hours: i32 = hours.parse().map_err(|_| unsupported())?;
+    let minutes: i32 = minutes.parse().map_err(|_| unsupported())?;
+    if minutes >= 60 {
+        return Err(unsupported());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(unsupported)
+}
+
+/// Formats `date` the way `$dateToString` does for the given format specifiers.
+pub fn format_date_to_string(
+    date: DateTime<Utc>,
+    format: &str,
+    offset: FixedOffset,
+) -> Result<String, String> {
+    let local = date.with_timezone(&offset);
+    let offset_minutes = offset.local_minus_utc() / 60;
+    let mut output = String::new();
+    let mut chars = format.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            output.push(ch);
+            continue;
+        }
+
+        let Some(specifier) = chars.next() else {
+            return Err(String::from(tr("Unmatched '%' at the end of the format string.")));
+        };
+        match specifier {
+            'd' => output.push_str(&format!("{:02}", local.day())),
+            'G' => output.push_str(&format!("{:04}", local.iso_week().year())),
+            'H' => output.push_str(&format!("{:02}", local.hour())),
+            'j' => output.push_str(&format!("{:03}", local.ordinal())),
+            'L' => output.push_str(&format!("{:03}", local.timestamp_subsec_millis() % 1000)),
+            'm' => output.push_str(&format!("{:02}", local.month())),
+            'M' => output.push_str(&format!("{:02}", local.minute())),
+            'S' => output.push_str(&format!("{:02}", local.second())),
+            'w' => output.push_str(&local.weekday().number_from_sunday().to_string()),
+            'u' => output.push_str(&local.weekday().number_from_monday().to_string()),
+            'U' => output.push_str(&local.format("%U").to_string()),
+            'V' => output.push_str(&format!("{:02}", local.iso_week().week())),
+            'Y' => output.push_str(&format!("{:04}", local.year())),
+            'b' => output.push_str(&local.format("%b").to_string()),
+            'B' => output.push_str(&local.format("%B").to_string()),
+            'z' => {
+                let sign = if offset_minutes < 0 { '-' } else { '+' };
+                let minutes = offset_minutes.abs();
+                output.push_str(&format!("{sign}{:02}{:02}", minutes / 60, minutes % 60));
+            }
+            'Z' => output.push_str(&format!("{offset_minutes:+}")),
+            '%' => output.push('%'),
+            other => {
+                return Err(tr_format(
+                    "Invalid format character '%{}' in the format string.",
+                    &[&other.to_string()],
+                ));
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+pub fn preview_date_to_string(
+    sample: &str,
+    format: &str,
+    timezone: &str,
+) -> Result<String, String> {
+    let date = parse_sample_date(sample)?;
+    let offset = parse_timezone_offset(timezone)?;
+    format_date_to_string(date, format, offset)
+}
+
+/// Builds the `$dateToString` expression for `field`, omitting the timezone when it is UTC.
+pub fn date_to_string_expression(field: &str, format: &str, timezone: &str) -> String {
+    let field = field.trim();
+    let field = if field.starts_with('$') { field.to_string() } else { format!("${field}") };
+    let quote = |value: &str| serde_json::Value::String(value.to_string()).to_string();
+
+    let mut expression =
+        format!("{{ $dateToString: {{ format: {}, date: {}", quote(format), quote(&field));
+    let timezone = timezone.trim();
+    if !timezone.is_empty() && timezone != "UTC" {
+        expression.push_str(&format!(", timezone: {}", quote(timezone)));
+    }
+    expression.push_str(" } }");
+    expression
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_common_specifiers() {
+        let date = parse_sample_date("ISODate(\"2024-03-15T13:45:30.123Z\")").expect("date");
+        let utc = parse_timezone_offset("UTC").expect("utc");
+
+        assert_eq!(
+            format_date_to_string(date, DEFAULT_DATE_TO_STRING_FORMAT, utc).unwrap(),
+            "2024-03-15T13:45:30.123Z"
+        );
+        assert_eq!(
+            format_date_to_string(date, "%j %w %u %V %G %%", utc).unwrap(),
+            "075 6 5 11 2024 %"
+        );
+        assert!(format_date_to_string(date, "%Q", utc).unwrap_err().contains("%Q"));
+        assert!(format_date_to_string(date, "%Y%", utc).is_err());
+    }
+
+    #[test]
+    fn applies_fixed_offsets() {
+        let date = parse_sample_date("2024-03-15T22:30:00Z").expect("date");
+        let offset = parse_timezone_offset("+04:45").expect("offset");
+
+        assert_eq!(
+            format_date_to_string(date, "%Y-%m-%d %H:%M %z %Z", offset).unwrap(),
+            "2024-03-16 03:15 +0445 +285"
+        );
+        assert_eq!(parse_timezone_offset("-05").unwrap().local_minus_utc(), -5 * 3600);
+        assert!(parse_timezone_offset("Europe/Paris").unwrap_err().contains("Europe/Paris"));
+    }
+
+    #[test]
+    fn parses_sample_date_variants() {
+        let expected = parse_sample_date("2024-03-15T00:00:00Z").unwrap();
+        assert_eq!(parse_sample_date("2024-03-15").unwrap(), expected);
+        assert_eq!(parse_sample_date("1710460800000").unwrap(), expected);
+        assert!(parse_sample_date("yesterday").is_err());
+    }
+
+    #[test]
+    fn builds_expression_with_optional_timezone() {
+        assert_eq!(
+            date_to_string_expression("createdAt", "%Y-%m", "UTC"),
+            "{ $dateToString: { format: \"%Y-%m\", date: \"$createdAt\" } }"
+        );
+        assert_eq!(
+            date_to_string_expression("$createdAt", "%Y", "+03:00"),
+            "{ $dateToString: { format: \"%Y\", date: \"$createdAt\", timezone: \"+03:00\" } }"
+        );
+    }
+}
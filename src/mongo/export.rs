@@ -0,0 +1,203 @@
+//! Conversions of query results into text formats for sharing outside the application.
+
+use mongodb::bson::{Bson, Document};
+
+use crate::i18n::tr;
+use crate::mongo::query::QueryResult;
+use crate::mongo::shell;
+
+/// Renders documents as a GitHub-flavored markdown table. Columns are the union of top-level
+/// keys in first-appearance order; results that are not all documents produce a note instead.
+pub fn markdown_table(results: &[Bson]) -> String {
+    let Some(documents) = results
+        .iter()
+        .map(Bson::as_document)
+        .collect::<Option<Vec<_>>>()
+        .filter(|docs| !docs.is_empty())
+    else {
+        return format!(
+            "> {}",
+            tr("The results are not a list of documents and cannot be shown as a table.")
+        );
+    };
+
+    let mut columns: Vec<&str> = Vec::new();
+    for document in &documents {
+        for key in document.keys() {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let mut lines = Vec::with_capacity(documents.len() + 2);
+    lines.push(row(columns.iter().map(|column| markdown_cell(column)).collect()));
+    lines.push(row(columns.iter().map(|_| String::from("---")).collect()));
+    for document in &documents {
+        lines.push(row(columns
+            .iter()
+            .map(|column| document.get(*column).map(markdown_value).unwrap_or_default())
+            .collect()));
+    }
+    lines.join("\n")
+}
+
+/// Serializes a result as pretty relaxed extended JSON: lists become an array, a single document
+/// or count is written as is.
+pub fn json_file(result: &QueryResult) -> String {
+    let value = match result {
+        QueryResult::Documents(values) | QueryResult::Indexes(values) => {
+            Bson::Array(values.clone())
+        }
+        QueryResult::Distinct { values, .. } => Bson::Array(values.clone()),
+        QueryResult::SingleDocument { document } => Bson::Document(document.clone()),
+        QueryResult::Count { value } => value.clone(),
+    };
+    serde_json::to_string_pretty(&value.into_relaxed_extjson()).unwrap_or_default()
+}
+
+/// Writes a result as CSV. Nested documents are flattened into dotted column names, arrays are
+/// kept as JSON and fields missing from a document leave the cell empty.
+pub fn csv_file(result: &QueryResult) -> String {
+    let rows: Vec<Document> = match result {
+        QueryResult::Documents(values) | QueryResult::Indexes(values) => values
+            .iter()
+            .map(|value| match value {
+                Bson::Document(document) => document.clone(),
+                other => Document::from_iter([(String::from("value"), other.clone())]),
+            })
+            .collect(),
+        QueryResult::Distinct { field, values } => values
+            .iter()
+            .map(|value| Document::from_iter([(field.clone(), value.clone())]))
+            .collect(),
+        QueryResult::SingleDocument { document } => vec![document.clone()],
+        QueryResult::Count { value } => return format!("{}\n", csv_cell(&csv_value(value))),
+    };
+
+    let mut columns: Vec<String> = Vec::new();
+    let mut flattened = Vec::with_capacity(rows.len());
+    for row in &rows {
+        let mut cells = Vec::new();
+        flatten_document(row, "", &mut cells);
+        for (column, _) in &cells {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            }
+        }
+        flattened.push(cells);
+    }
+
+    let mut output = columns.iter().map(|column| csv_cell(column)).collect::<Vec<_>>().join(",");
+    output.push('\n');
+    for cells in &flattened {
+        let line = columns
+            .iter()
+            .map(|column| {
+                cells
+                    .iter()
+                    .find(|(key, _)| key == column)
+                    .map(|(_, value)| csv_cell(value))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        output.push_str(&line);
+        output.push('\n');
+    }
+    output
+}
+
+fn flatten_document(document: &Document, prefix: &str, cells: &mut Vec<(String, String)>) {
+    for (key, value) in document {
+        let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+        match value {
+            Bson::Document(nested) if !nested.is_empty() => flatten_document(nested, &path, cells),
+            _ => cells.push((path, csv_value(value))),
+        }
+    }
+}
+
+fn csv_value(value: &Bson) -> String {
+    match value {
+        Bson::String(text) => text.clone(),
+        Bson::ObjectId(id) => id.to_hex(),
+        Bson::Null => String::new(),
+        Bson::Document(_) | Bson::Array(_) => value.clone().into_relaxed_extjson().to_string(),
+        _ => shell::format_bson_scalar(value).0,
+    }
+}
+
+fn csv_cell(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn markdown_value(value: &Bson) -> String {
+    let text = match value {
+        Bson::Document(_) | Bson::Array(_) => value.clone().into_relaxed_extjson().to_string(),
+        _ => shell::format_bson_scalar(value).0,
+    };
+    markdown_cell(&text)
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::doc;
+
+    #[test]
+    fn renders_union_of_keys_as_columns() {
+        let results = vec![
+            Bson::Document(doc! { "name": "Ann", "age": 31 }),
+            Bson::Document(doc! { "name": "Bob | Jr", "tags": ["a", "b"] }),
+        ];
+
+        assert_eq!(
+            markdown_table(&results),
+            "| name | age | tags |\n| --- | --- | --- |\n| Ann | 31 |  |\n| Bob \\| Jr |  | [\"a\",\"b\"] |"
+        );
+    }
+
+    #[test]
+    fn csv_flattens_nested_fields_and_leaves_missing_cells_empty() {
+        let result = QueryResult::Documents(vec![
+            Bson::Document(doc! { "name": "Ann", "address": { "city": "Oslo", "zip": 150 } }),
+            Bson::Document(doc! { "name": "Bob, Jr", "tags": ["a"] }),
+        ]);
+
+        assert_eq!(
+            csv_file(&result),
+            "name,address.city,address.zip,tags\nAnn,Oslo,150,\n\"Bob, Jr\",,,\"[\"\"a\"\"]\"\n"
+        );
+        assert_eq!(csv_file(&QueryResult::Count { value: Bson::Int64(7) }), "7\n");
+        assert_eq!(
+            csv_file(&QueryResult::Distinct {
+                field: String::from("city"),
+                values: vec![Bson::String(String::from("Oslo")), Bson::Int32(3)],
+            }),
+            "city\nOslo\n3\n"
+        );
+    }
+
+    #[test]
+    fn json_writes_lists_as_arrays_and_single_values_as_is() {
+        let documents = QueryResult::Documents(vec![Bson::Document(doc! { "a": 1 })]);
+        assert_eq!(json_file(&documents), "[\n  {\n    \"a\": 1\n  }\n]");
+        assert_eq!(json_file(&QueryResult::Count { value: Bson::Int32(5) }), "5");
+    }
+
+    #[test]
+    fn non_document_results_produce_a_note() {
+        assert!(markdown_table(&[Bson::Int32(1)]).starts_with("> "));
+        assert!(markdown_table(&[]).starts_with("> "));
+    }
+}
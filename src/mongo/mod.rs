@@ -1,6 +1,9 @@
+pub mod bson_diff;
 pub mod bson_edit;
 pub mod bson_tree;
 pub mod connection;
+pub mod date_format;
+pub mod export;
 pub mod query;
 pub mod shell;
 pub mod shell_preprocessor;
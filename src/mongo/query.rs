@@ -7,14 +7,15 @@ use mongodb::bson::{self, Array, Bson, DateTime, Document, Timestamp as BsonTime
 use mongodb::change_stream::event::ChangeStreamEvent;
 use mongodb::options::{
     Acknowledgment, BulkWriteOptions, Collation, DeleteManyModel, DeleteOneModel, Hint,
-    InsertOneModel, ReplaceOneModel, ReturnDocument, UpdateManyModel, UpdateModifications,
-    UpdateOneModel, WriteConcern, WriteModel,
+    InsertOneModel, ReadConcern, ReplaceOneModel, ReturnDocument, UpdateManyModel,
+    UpdateModifications, UpdateOneModel, WriteConcern, WriteModel,
 };
 use mongodb::options::{FullDocumentBeforeChangeType, FullDocumentType};
 use mongodb::sync::Client;
 use serde_json::{Map, Number, Value};
 
 use crate::i18n::{tr, tr_format};
+use crate::mongo::shell;
 use crate::mongo::shell_preprocessor::quote_unquoted_keys;
 
 #[derive(Debug, Clone, Default)]
@@ -67,6 +68,7 @@ pub struct AggregateParsedOptions {
     hint: Option<Hint>,
     max_time: Option<Duration>,
     let_vars: Option<Document>,
+    explain: bool,
 }
 
 impl AggregateParsedOptions {
@@ -79,6 +81,7 @@ impl AggregateParsedOptions {
             || self.hint.is_some()
             || self.max_time.is_some()
             || self.let_vars.is_some()
+            || self.explain
     }
 }
 
@@ -299,7 +302,9 @@ pub struct FindCursorChain {
     skip: Option<u64>,
     limit: Option<u64>,
     max_time: Option<Duration>,
+    read_concern: Option<ReadConcern>,
     comment: Option<Bson>,
+    let_vars: Option<Document>,
 }
 
 impl FindCursorChain {
@@ -311,7 +316,9 @@ impl FindCursorChain {
             || self.skip.is_some()
             || self.limit.is_some()
             || self.max_time.is_some()
+            || self.read_concern.is_some()
             || self.comment.is_some()
+            || self.let_vars.is_some()
     }
 }
 
@@ -444,6 +451,12 @@ pub enum QueryOperation {
         options: Option<FindOneAndDeleteParsedOptions>,
     },
     ListIndexes,
+    /// `options` always carries `out`; it defaults to `{ inline: 1 }`.
+    MapReduce {
+        map: String,
+        reduce: String,
+        options: Document,
+    },
     ReplicaSetCommand {
         command: ReplicaSetCommand,
     },
@@ -453,6 +466,398 @@ pub enum QueryOperation {
     },
 }
 
+const EXPLAIN_VERBOSITIES: [&str; 3] = ["queryPlanner", "executionStats", "allPlansExecution"];
+const DEFAULT_EXPLAIN_VERBOSITY: &str = "queryPlanner";
+
+/// Database commands that only read data; any other command counts as a write.
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "aggregate",
+    "buildinfo",
+    "collstats",
+    "connectionstatus",
+    "count",
+    "currentop",
+    "datasize",
+    "dbstats",
+    "distinct",
+    "explain",
+    "find",
+    "getcmdlineopts",
+    "getlog",
+    "getmore",
+    "getparameter",
+    "hello",
+    "hostinfo",
+    "ismaster",
+    "listcollections",
+    "listcommands",
+    "listdatabases",
+    "listindexes",
+    "ping",
+    "replsetgetconfig",
+    "replsetgetstatus",
+    "rolesinfo",
+    "serverstatus",
+    "top",
+    "usersinfo",
+];
+
+impl QueryOperation {
+    /// Applies `projection` to a `find` that does not project fields itself.
+    pub fn with_default_projection(self, projection: Document) -> Self {
+        match self {
+            QueryOperation::Find { filter, options } => {
+                let mut chain = options.unwrap_or_default();
+                if chain.projection.is_none() {
+                    chain.projection = Some(projection);
+                }
+                QueryOperation::Find { filter, options: Some(chain) }
+            }
+            other => other,
+        }
+    }
+
+    pub fn aggregate_stage_count(&self) -> Option<usize> {
+        match self {
+            QueryOperation::Aggregate { pipeline, .. } => Some(pipeline.len()),
+            _ => None,
+        }
+    }
+
+    /// Keeps only the first `stages` stages of an aggregation pipeline.
+    pub fn with_stage_limit(self, stages: usize) -> Self {
+        match self {
+            QueryOperation::Aggregate { mut pipeline, options } => {
+                pipeline.truncate(stages);
+                QueryOperation::Aggregate { pipeline, options }
+            }
+            other => other,
+        }
+    }
+
+    /// Rewrites a `find` sorted only by `_id` to continue after `last_id` instead of skipping the
+    /// previous pages. Other sort orders cannot be resumed from an `_id` and return `None`.
+    pub fn keyset_after(&self, last_id: &Bson) -> Option<Self> {
+        let QueryOperation::Find { filter, options: Some(chain) } = self else {
+            return None;
+        };
+        let sort = chain.sort.as_ref()?;
+        if chain.skip.is_some() || sort.len() != 1 {
+            return None;
+        }
+        let operator = match sort.get("_id")? {
+            Bson::Int32(1) | Bson::Int64(1) => "$gt",
+            Bson::Int32(-1) | Bson::Int64(-1) => "$lt",
+            Bson::Double(value) if *value == 1.0 => "$gt",
+            Bson::Double(value) if *value == -1.0 => "$lt",
+            _ => return None,
+        };
+
+        let anchor = doc! { "_id": { operator: last_id.clone() } };
+        let filter = if filter.is_empty() {
+            anchor
+        } else {
+            doc! { "$and": [filter.clone(), anchor] }
+        };
+        Some(QueryOperation::Find { filter, options: Some(chain.clone()) })
+    }
+
+    /// Namespace written by a trailing `$out`/`$merge` stage of an aggregation, including one
+    /// sent as a raw `aggregate` command.
+    pub fn aggregate_write_target(&self, db_name: &str) -> Option<AggregateWriteTarget> {
+        match self {
+            QueryOperation::Aggregate { pipeline, .. } => aggregate_write_target(pipeline, db_name),
+            QueryOperation::DatabaseCommand { db, command }
+                if command
+                    .keys()
+                    .next()
+                    .is_some_and(|name| name.eq_ignore_ascii_case("aggregate")) =>
+            {
+                let pipeline = command
+                    .get_array("pipeline")
+                    .ok()?
+                    .iter()
+                    .filter_map(Bson::as_document)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                aggregate_write_target(&pipeline, db)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the operation can modify data, indexes or server state.
+    pub fn is_write(&self) -> bool {
+        match self {
+            QueryOperation::Find { .. }
+            | QueryOperation::FindOne { .. }
+            | QueryOperation::Count { .. }
+            | QueryOperation::CountDocuments { .. }
+            | QueryOperation::EstimatedDocumentCount { .. }
+            | QueryOperation::Distinct { .. }
+            | QueryOperation::Watch { .. }
+            | QueryOperation::ListIndexes => false,
+            QueryOperation::MapReduce { options, .. } => !matches!(
+                options.get("out"),
+                Some(Bson::Document(out)) if out.contains_key("inline")
+            ),
+            QueryOperation::Aggregate { pipeline, .. } => pipeline
+                .iter()
+                .any(|stage| stage.contains_key("$out") || stage.contains_key("$merge")),
+            QueryOperation::ReplicaSetCommand { command } => !matches!(
+                command,
+                ReplicaSetCommand::Status
+                    | ReplicaSetCommand::Config
+                    | ReplicaSetCommand::IsMaster
+                    | ReplicaSetCommand::Hello
+                    | ReplicaSetCommand::PrintReplicationInfo
+                    | ReplicaSetCommand::PrintSecondaryReplicationInfo
+                    | ReplicaSetCommand::SlaveOk
+            ),
+            QueryOperation::DatabaseCommand { command, .. } => {
+                let Some(name) = command.keys().next() else {
+                    return false;
+                };
+                let name = name.to_ascii_lowercase();
+                if name == "aggregate" {
+                    return command.get_array("pipeline").is_ok_and(|pipeline| {
+                        pipeline
+                            .iter()
+                            .filter_map(Bson::as_document)
+                            .any(|stage| stage.contains_key("$out") || stage.contains_key("$merge"))
+                    });
+                }
+                !READ_ONLY_COMMANDS.contains(&name.as_str())
+            }
+            QueryOperation::InsertOne { .. }
+            | QueryOperation::InsertMany { .. }
+            | QueryOperation::BulkWrite { .. }
+            | QueryOperation::DeleteOne { .. }
+            | QueryOperation::DeleteMany { .. }
+            | QueryOperation::UpdateOne { .. }
+            | QueryOperation::UpdateMany { .. }
+            | QueryOperation::ReplaceOne { .. }
+            | QueryOperation::FindOneAndUpdate { .. }
+            | QueryOperation::FindOneAndReplace { .. }
+            | QueryOperation::FindOneAndDelete { .. } => true,
+        }
+    }
+
+    /// Options of the operation keyed the way the Node.js driver names them (`maxTimeMS`,
+    /// `arrayFilters`, ...). Cursor modifiers of `find` are included as well.
+    pub fn driver_options(&self) -> Document {
+        let mut options = DriverOptions::default();
+        match self {
+            QueryOperation::Find { options: Some(chain), .. } => {
+                options
+                    .document("projection", &chain.projection)
+                    .document("sort", &chain.sort)
+                    .serialized("collation", &chain.collation)
+                    .serialized("hint", &chain.hint)
+                    .count("skip", chain.skip)
+                    .count("limit", chain.limit)
+                    .millis("maxTimeMS", chain.max_time)
+                    .serialized("readConcern", &chain.read_concern)
+                    .put("comment", chain.comment.clone())
+                    .document("let", &chain.let_vars);
+            }
+            QueryOperation::FindOne { projection, options: parsed, .. } => {
+                options.document("projection", projection);
+                if let Some(parsed) = parsed {
+                    options
+                        .document("sort", &parsed.sort)
+                        .serialized("collation", &parsed.collation)
+                        .serialized("hint", &parsed.hint)
+                        .millis("maxTimeMS", parsed.max_time)
+                        .put("comment", parsed.comment.clone());
+                }
+            }
+            QueryOperation::Count { options: Some(parsed), .. }
+            | QueryOperation::CountDocuments { options: Some(parsed), .. } => {
+                options
+                    .count("limit", parsed.limit)
+                    .count("skip", parsed.skip)
+                    .serialized("hint", &parsed.hint)
+                    .millis("maxTimeMS", parsed.max_time);
+            }
+            QueryOperation::EstimatedDocumentCount { options: Some(parsed) } => {
+                options.millis("maxTimeMS", parsed.max_time);
+            }
+            QueryOperation::Distinct { options: Some(parsed), .. } => {
+                options
+                    .millis("maxTimeMS", parsed.max_time)
+                    .serialized("collation", &parsed.collation);
+            }
+            QueryOperation::Aggregate { options: Some(parsed), .. } => {
+                options
+                    .flag("allowDiskUse", parsed.allow_disk_use)
+                    .count("batchSize", parsed.batch_size.map(u64::from))
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .serialized("collation", &parsed.collation)
+                    .put("comment", parsed.comment.clone())
+                    .serialized("hint", &parsed.hint)
+                    .millis("maxTimeMS", parsed.max_time)
+                    .document("let", &parsed.let_vars)
+                    .flag("explain", parsed.explain.then_some(true));
+            }
+            QueryOperation::Watch { options: Some(parsed), .. } => {
+                options
+                    .serialized("fullDocument", &parsed.full_document)
+                    .serialized("fullDocumentBeforeChange", &parsed.full_document_before_change)
+                    .millis("maxAwaitTimeMS", parsed.max_await_time)
+                    .count("batchSize", parsed.batch_size.map(u64::from))
+                    .serialized("collation", &parsed.collation)
+                    .flag("showExpandedEvents", parsed.show_expanded_events)
+                    .put("comment", parsed.comment.clone())
+                    .put(
+                        "startAtOperationTime",
+                        parsed.start_at_operation_time.map(Bson::Timestamp),
+                    );
+            }
+            QueryOperation::InsertOne { options: Some(parsed), .. } => {
+                options.serialized("writeConcern", &parsed.write_concern);
+            }
+            QueryOperation::InsertMany { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .flag("ordered", parsed.ordered);
+            }
+            QueryOperation::BulkWrite { options: Some(parsed), .. } => {
+                options
+                    .flag("ordered", parsed.ordered)
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .put("comment", parsed.comment.clone())
+                    .document("let", &parsed.let_vars)
+                    .serialized("writeConcern", &parsed.write_concern);
+            }
+            QueryOperation::DeleteOne { options: Some(parsed), .. }
+            | QueryOperation::DeleteMany { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint);
+            }
+            QueryOperation::UpdateOne { options: Some(parsed), .. }
+            | QueryOperation::UpdateMany { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .flag("upsert", parsed.upsert)
+                    .put("arrayFilters", parsed.array_filters.clone().map(documents_to_bson))
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint)
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .document("let", &parsed.let_vars)
+                    .put("comment", parsed.comment.clone())
+                    .document("sort", &parsed.sort);
+            }
+            QueryOperation::ReplaceOne { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .flag("upsert", parsed.upsert)
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint)
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .document("let", &parsed.let_vars)
+                    .put("comment", parsed.comment.clone())
+                    .document("sort", &parsed.sort);
+            }
+            QueryOperation::FindOneAndUpdate { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .flag("upsert", parsed.upsert)
+                    .put("arrayFilters", parsed.array_filters.clone().map(documents_to_bson))
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .millis("maxTimeMS", parsed.max_time)
+                    .document("projection", &parsed.projection)
+                    .put(
+                        "returnDocument",
+                        parsed.return_document.as_ref().map(return_document_bson),
+                    )
+                    .document("sort", &parsed.sort)
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint)
+                    .document("let", &parsed.let_vars)
+                    .put("comment", parsed.comment.clone());
+            }
+            QueryOperation::FindOneAndReplace { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .flag("upsert", parsed.upsert)
+                    .flag("bypassDocumentValidation", parsed.bypass_document_validation)
+                    .millis("maxTimeMS", parsed.max_time)
+                    .document("projection", &parsed.projection)
+                    .put(
+                        "returnDocument",
+                        parsed.return_document.as_ref().map(return_document_bson),
+                    )
+                    .document("sort", &parsed.sort)
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint)
+                    .document("let", &parsed.let_vars)
+                    .put("comment", parsed.comment.clone());
+            }
+            QueryOperation::FindOneAndDelete { options: Some(parsed), .. } => {
+                options
+                    .serialized("writeConcern", &parsed.write_concern)
+                    .millis("maxTimeMS", parsed.max_time)
+                    .document("projection", &parsed.projection)
+                    .document("sort", &parsed.sort)
+                    .serialized("collation", &parsed.collation)
+                    .serialized("hint", &parsed.hint)
+                    .document("let", &parsed.let_vars)
+                    .put("comment", parsed.comment.clone());
+            }
+            _ => {}
+        }
+        options.0
+    }
+}
+
+#[derive(Default)]
+struct DriverOptions(Document);
+
+impl DriverOptions {
+    fn put(&mut self, key: &str, value: Option<Bson>) -> &mut Self {
+        if let Some(value) = value {
+            self.0.insert(key, value);
+        }
+        self
+    }
+
+    fn document(&mut self, key: &str, value: &Option<Document>) -> &mut Self {
+        self.put(key, value.clone().map(Bson::Document))
+    }
+
+    fn flag(&mut self, key: &str, value: Option<bool>) -> &mut Self {
+        self.put(key, value.map(Bson::Boolean))
+    }
+
+    fn count(&mut self, key: &str, value: Option<u64>) -> &mut Self {
+        self.put(
+            key,
+            value
+                .map(|value| i32::try_from(value).map_or_else(|_| u64_to_bson(value), Bson::Int32)),
+        )
+    }
+
+    fn millis(&mut self, key: &str, value: Option<Duration>) -> &mut Self {
+        self.count(key, value.map(|duration| duration.as_millis() as u64))
+    }
+
+    fn serialized<T: serde::Serialize>(&mut self, key: &str, value: &Option<T>) -> &mut Self {
+        self.put(key, value.as_ref().and_then(|value| bson::to_bson(value).ok()))
+    }
+}
+
+fn documents_to_bson(documents: Vec<Document>) -> Bson {
+    Bson::Array(documents.into_iter().map(Bson::Document).collect())
+}
+
+fn return_document_bson(value: &ReturnDocument) -> Bson {
+    let name = if matches!(value, ReturnDocument::After) { "after" } else { "before" };
+    Bson::String(String::from(name))
+}
+
 #[derive(Debug, Clone)]
 pub enum QueryResult {
     Documents(Vec<Bson>),
@@ -462,6 +867,25 @@ pub enum QueryResult {
     Count { value: Bson },
 }
 
+impl QueryResult {
+    /// Keeps at most `max` documents or distinct values and returns how many there were when
+    /// anything was dropped. Zero disables the cap.
+    pub fn truncate_to(&mut self, max: usize) -> Option<usize> {
+        let values = match self {
+            QueryResult::Documents(values)
+            | QueryResult::Indexes(values)
+            | QueryResult::Distinct { values, .. } => values,
+            QueryResult::SingleDocument { .. } | QueryResult::Count { .. } => return None,
+        };
+        if max == 0 || values.len() <= max {
+            return None;
+        }
+        let returned = values.len();
+        values.truncate(max);
+        Some(returned)
+    }
+}
+
 struct QueryParser<'a> {
     db_name: &'a str,
     collection: &'a str,
@@ -499,18 +923,48 @@ impl<'a> QueryParser<'a> {
         let (method_name, args, remainder) = Self::extract_primary_method(after_collection)?;
         if method_name == "find" {
             let (filter, projection, options) = Self::parse_find_arguments(args.trim())?;
-            return self.parse_find_chain(filter, projection, options, remainder, false);
+            return self.parse_find_chain(filter, projection, options, remainder, None);
         }
         if method_name == "explain" {
-            if !args.trim().is_empty() {
-                return Err(String::from(tr("explain does not take any arguments.")));
-            }
+            let verbosity = Self::parse_explain_verbosity(args.trim())?;
             let (next_method, next_args, next_remainder) = Self::extract_primary_method(remainder)?;
+            if next_method == "aggregate" {
+                if !next_remainder.trim().is_empty() {
+                    return Err(String::from(tr(
+                        "No methods are supported after explain().aggregate(...).",
+                    )));
+                }
+                let (pipeline, options) = Self::parse_aggregate_arguments(next_args.trim())?;
+                return self.build_aggregate_explain_command(pipeline, options, verbosity);
+            }
             if next_method != "find" {
-                return Err(String::from(tr("explain must be followed by find(...).")));
+                return Err(String::from(tr(
+                    "explain must be followed by find(...) or aggregate(...).",
+                )));
             }
             let (filter, projection, options) = Self::parse_find_arguments(next_args.trim())?;
-            return self.parse_find_chain(filter, projection, options, next_remainder, true);
+            return self.parse_find_chain(
+                filter,
+                projection,
+                options,
+                next_remainder,
+                Some(verbosity),
+            );
+        }
+        if method_name == "aggregate" && !remainder.trim().is_empty() {
+            let (next_method, next_args, next_remainder) = Self::extract_primary_method(remainder)?;
+            if next_method != "explain" {
+                return Err(tr_format(
+                    "Method '{}' is not supported after aggregate(...).",
+                    &[&next_method],
+                ));
+            }
+            let verbosity = Self::parse_explain_verbosity(next_args.trim())?;
+            if !next_remainder.trim().is_empty() {
+                return Err(String::from(tr("No methods are supported after explain().")));
+            }
+            let (pipeline, options) = Self::parse_aggregate_arguments(args.trim())?;
+            return self.build_aggregate_explain_command(pipeline, options, verbosity);
         }
         if method_name == "watch" {
             if !remainder.trim().is_empty() {
@@ -922,37 +1376,14 @@ impl<'a> QueryParser<'a> {
                 Ok(QueryOperation::Distinct { field, filter, options })
             }
             "aggregate" => {
-                if args_trimmed.is_empty() {
-                    return Err(String::from(tr(
-                        "aggregate requires an array of stages as its argument.",
-                    )));
-                }
-
-                let parts = Self::split_arguments(args_trimmed);
-                if parts.len() > 2 {
-                    return Err(String::from(tr(
-                        "aggregate supports at most two arguments: pipeline and options.",
-                    )));
-                }
-
-                let value: Value = Self::parse_shell_json_value(&parts[0])?;
-                let array = value
-                    .as_array()
-                    .ok_or_else(|| String::from(tr("The aggregate argument must be an array.")))?;
-                let mut pipeline = Vec::new();
-                for item in array {
-                    let doc = item
-                        .as_object()
-                        .ok_or_else(|| String::from(tr("Pipeline elements must be objects.")))?;
-                    pipeline.push(Self::json_object_to_document(doc)?);
+                let (pipeline, options) = Self::parse_aggregate_arguments(args_trimmed)?;
+                if options.as_ref().is_some_and(|options| options.explain) {
+                    return self.build_aggregate_explain_command(
+                        pipeline,
+                        options,
+                        DEFAULT_EXPLAIN_VERBOSITY,
+                    );
                 }
-
-                let options = match parts.get(1) {
-                    Some(second) if second.trim().is_empty() => None,
-                    Some(second) => Self::parse_aggregate_options(second.trim())?,
-                    None => None,
-                };
-
                 Ok(QueryOperation::Aggregate { pipeline, options })
             }
             "insertOne" => {
@@ -1068,6 +1499,10 @@ impl<'a> QueryParser<'a> {
                 } else {
                     None
                 };
+                Self::validate_array_filters(
+                    &update,
+                    options.as_ref().and_then(|options| options.array_filters.as_deref()),
+                )?;
 
                 Ok(QueryOperation::UpdateOne { filter, update, options })
             }
@@ -1090,6 +1525,10 @@ impl<'a> QueryParser<'a> {
                 } else {
                     None
                 };
+                Self::validate_array_filters(
+                    &update,
+                    options.as_ref().and_then(|options| options.array_filters.as_deref()),
+                )?;
 
                 Ok(QueryOperation::UpdateMany { filter, update, options })
             }
@@ -1134,6 +1573,10 @@ impl<'a> QueryParser<'a> {
                 } else {
                     None
                 };
+                Self::validate_array_filters(
+                    &update,
+                    options.as_ref().and_then(|options| options.array_filters.as_deref()),
+                )?;
 
                 Ok(QueryOperation::FindOneAndUpdate { filter, update, options })
             }
@@ -1227,8 +1670,21 @@ impl<'a> QueryParser<'a> {
 
                 Ok(QueryOperation::DeleteMany { filter, options })
             }
+            "mapReduce" => {
+                let parts = Self::split_arguments(args_trimmed);
+                if parts.len() < 2 || parts.len() > 3 {
+                    return Err(String::from(tr(
+                        "mapReduce expects map and reduce functions and an optional options object.",
+                    )));
+                }
+
+                let map = Self::parse_javascript_function(&parts[0], "map")?;
+                let reduce = Self::parse_javascript_function(&parts[1], "reduce")?;
+                let options = Self::parse_map_reduce_options(parts.get(2).map(String::as_str))?;
+                Ok(QueryOperation::MapReduce { map, reduce, options })
+            }
             other => Err(tr_format(
-                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
+                "Method {} is not supported. Available methods: find, watch, findOne, count, countDocuments, estimatedDocumentCount, distinct, aggregate, insertOne, insertMany, bulkWrite, updateOne, updateMany, replaceOne, findOneAndUpdate, findOneAndReplace, findOneAndDelete, deleteOne, deleteMany, mapReduce, createIndex, createIndexes, dropIndex, dropIndexes, getIndexes, hideIndex, unhideIndex.",
                 &[other],
             )),
         }
@@ -1380,6 +1836,66 @@ impl<'a> QueryParser<'a> {
         Err(String::from(tr("Expected '(' after the method name.")))
     }
 
+    /// Accepts a `function` literal or a string with the function source.
+    fn parse_javascript_function(source: &str, field: &str) -> Result<String, String> {
+        match Self::parse_shell_bson_value(source) {
+            Ok(Bson::JavaScriptCode(code) | Bson::String(code)) => Ok(code),
+            _ => Err(tr_format("mapReduce '{}' must be a function.", &[field])),
+        }
+    }
+
+    fn parse_map_reduce_options(source: Option<&str>) -> Result<Document, String> {
+        let mut options = match source {
+            Some(source) => match Self::parse_shell_bson_value(source)? {
+                Bson::Document(document) => document,
+                _ => return Err(String::from(tr("mapReduce options must be a JSON object."))),
+            },
+            None => Document::new(),
+        };
+
+        for (key, value) in options.iter_mut() {
+            match key.as_str() {
+                "out" => {
+                    if !matches!(value, Bson::String(_) | Bson::Document(_)) {
+                        return Err(String::from(tr(
+                            "mapReduce 'out' must be a collection name or a document.",
+                        )));
+                    }
+                }
+                "query" | "sort" => {
+                    if !matches!(value, Bson::Document(_)) {
+                        return Err(tr_format("mapReduce '{}' must be a document.", &[key]));
+                    }
+                }
+                "limit" => {
+                    if bson_to_i64(value).is_none_or(|limit| limit < 0) {
+                        return Err(String::from(tr(
+                            "mapReduce 'limit' must be a non-negative integer.",
+                        )));
+                    }
+                }
+                "finalize" => {
+                    if let Bson::String(code) = value {
+                        *value = Bson::JavaScriptCode(std::mem::take(code));
+                    } else if !matches!(value, Bson::JavaScriptCode(_)) {
+                        return Err(tr_format("mapReduce '{}' must be a function.", &[key]));
+                    }
+                }
+                other => {
+                    return Err(tr_format(
+                        "Parameter '{}' is not supported in mapReduce options. Allowed: out, query, sort, limit, finalize.",
+                        &[other],
+                    ));
+                }
+            }
+        }
+
+        if !options.contains_key("out") {
+            options.insert("out", doc! { "inline": 1 });
+        }
+        Ok(options)
+    }
+
     fn parse_count_documents_options(
         source: &str,
     ) -> Result<Option<CountDocumentsParsedOptions>, String> {
@@ -1521,6 +2037,121 @@ impl<'a> QueryParser<'a> {
         if options.has_values() { Ok(Some(options)) } else { Ok(None) }
     }
 
+    fn parse_aggregate_arguments(
+        args: &str,
+    ) -> Result<(Vec<Document>, Option<AggregateParsedOptions>), String> {
+        if args.is_empty() {
+            return Err(String::from(tr("aggregate requires an array of stages as its argument.")));
+        }
+
+        let parts = Self::split_arguments(args);
+        if parts.len() > 2 {
+            return Err(String::from(tr(
+                "aggregate supports at most two arguments: pipeline and options.",
+            )));
+        }
+
+        let value: Value = Self::parse_shell_json_value(&parts[0])?;
+        let array = value
+            .as_array()
+            .ok_or_else(|| String::from(tr("The aggregate argument must be an array.")))?;
+        let mut pipeline = Vec::new();
+        for item in array {
+            let doc = item
+                .as_object()
+                .ok_or_else(|| String::from(tr("Pipeline elements must be objects.")))?;
+            pipeline.push(Self::json_object_to_document(doc)?);
+        }
+        validate_pipeline_stages(&pipeline)?;
+
+        let options = match parts.get(1) {
+            Some(second) if second.trim().is_empty() => None,
+            Some(second) => Self::parse_aggregate_options(second.trim())?,
+            None => None,
+        };
+
+        Ok((pipeline, options))
+    }
+
+    /// Reads the argument of `explain(...)`: one of the standard verbosities, or a boolean the
+    /// way mongosh treats it. No argument means `queryPlanner`.
+    fn parse_explain_verbosity(source: &str) -> Result<&'static str, String> {
+        if source.is_empty() {
+            return Ok(DEFAULT_EXPLAIN_VERBOSITY);
+        }
+        match Self::parse_shell_json_value(source)? {
+            Value::String(name) => {
+                EXPLAIN_VERBOSITIES.iter().copied().find(|verbosity| *verbosity == name).ok_or_else(
+                    || {
+                        tr_format(
+                            "Unknown explain verbosity '{}'. Allowed: queryPlanner, executionStats, allPlansExecution.",
+                            &[&name],
+                        )
+                    },
+                )
+            }
+            Value::Bool(true) => Ok("allPlansExecution"),
+            Value::Bool(false) => Ok(DEFAULT_EXPLAIN_VERBOSITY),
+            _ => Err(String::from(tr(
+                "explain expects a verbosity string: queryPlanner, executionStats or allPlansExecution.",
+            ))),
+        }
+    }
+
+    fn build_aggregate_explain_command(
+        &self,
+        pipeline: Vec<Document>,
+        options: Option<AggregateParsedOptions>,
+        verbosity: &str,
+    ) -> Result<QueryOperation, String> {
+        let mut aggregate_doc = Document::new();
+        aggregate_doc.insert("aggregate", Bson::String(self.collection.to_string()));
+        aggregate_doc
+            .insert("pipeline", Bson::Array(pipeline.into_iter().map(Bson::Document).collect()));
+        aggregate_doc.insert("cursor", Bson::Document(Document::new()));
+
+        if let Some(options) = options {
+            if let Some(allow_disk_use) = options.allow_disk_use {
+                aggregate_doc.insert("allowDiskUse", Bson::Boolean(allow_disk_use));
+            }
+            if let Some(bypass) = options.bypass_document_validation {
+                aggregate_doc.insert("bypassDocumentValidation", Bson::Boolean(bypass));
+            }
+            if let Some(collation) = options.collation {
+                let collation_doc = bson::to_document(&collation)
+                    .map_err(|error| format!("BSON conversion error: {error}"))?;
+                aggregate_doc.insert("collation", Bson::Document(collation_doc));
+            }
+            if let Some(hint) = options.hint {
+                let hint_bson = match hint {
+                    Hint::Name(name) => Bson::String(name),
+                    Hint::Keys(doc) => Bson::Document(doc),
+                    other => {
+                        return Err(tr_format(
+                            "Unsupported hint value in explain: {:?}",
+                            &[&format!("{other:?}")],
+                        ));
+                    }
+                };
+                aggregate_doc.insert("hint", hint_bson);
+            }
+            if let Some(max_time) = options.max_time {
+                aggregate_doc.insert("maxTimeMS", Bson::Int64(max_time.as_millis() as i64));
+            }
+            if let Some(let_vars) = options.let_vars {
+                aggregate_doc.insert("let", Bson::Document(let_vars));
+            }
+            if let Some(comment) = options.comment {
+                aggregate_doc.insert("comment", comment);
+            }
+        }
+
+        let mut command = Document::new();
+        command.insert("explain", Bson::Document(aggregate_doc));
+        command.insert("verbosity", verbosity);
+        Ok(QueryOperation::DatabaseCommand { db: self.db_name.to_string(), command })
+    }
+
     fn parse_aggregate_options(source: &str) -> Result<Option<AggregateParsedOptions>, String> {
         let value: Value = Self::parse_shell_json_value(source)?;
         let object = value
@@ -1565,6 +2196,9 @@ impl<'a> QueryParser<'a> {
                 "let" => {
                     options.let_vars = Some(Self::parse_document_field(value, "let")?);
                 }
+                "explain" => {
+                    options.explain = Self::parse_bool_field(value, "explain")?;
+                }
                 "cursor" => {
                     let cursor = value.as_object().ok_or_else(|| {
                         String::from(tr("aggregate cursor options must be a JSON object."))
@@ -1590,7 +2224,7 @@ impl<'a> QueryParser<'a> {
                 }
                 other => {
                     return Err(tr_format(
-                        "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, hint, let, maxTimeMS, cursor.",
+                        "Parameter '{}' is not supported in aggregate options. Allowed: allowDiskUse, batchSize, bypassDocumentValidation, collation, comment, explain, hint, let, maxTimeMS, cursor.",
                         &[other],
                     ));
                 }
@@ -2245,9 +2879,12 @@ impl<'a> QueryParser<'a> {
                 "collation" => {
                     options.collation = Some(Self::parse_collation_value(value)?);
                 }
+                "let" => {
+                    options.let_vars = Some(Self::parse_document_field(value, "let")?);
+                }
                 other => {
                     return Err(tr_format(
-                        "Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation.",
+                        "Parameter '{}' is not supported in find options. Allowed: sort, hint, skip, limit, maxTimeMS, comment, collation, let.",
                         &[other],
                     ));
                 }
@@ -2354,7 +2991,7 @@ impl<'a> QueryParser<'a> {
         projection: Option<Document>,
         base_options: Option<FindCursorChain>,
         remainder: &str,
-        explain_mode: bool,
+        explain_verbosity: Option<&str>,
     ) -> Result<QueryOperation, String> {
         let mut modifiers = base_options.unwrap_or_default();
         modifiers.projection = projection;
@@ -2371,6 +3008,14 @@ impl<'a> QueryParser<'a> {
                     let sort_doc = Self::parse_json_object(args_trimmed)?;
                     modifiers.sort = Some(sort_doc);
                 }
+                "projection" => {
+                    if args_trimmed.is_empty() {
+                        return Err(String::from(tr(
+                            "projection requires a document with the fields to return.",
+                        )));
+                    }
+                    modifiers.projection = Some(Self::parse_json_object(args_trimmed)?);
+                }
                 "hint" => {
                     if args_trimmed.is_empty() {
                         return Err(String::from(tr("hint requires an index specification.")));
@@ -2390,6 +3035,15 @@ impl<'a> QueryParser<'a> {
                     let duration = Self::parse_find_max_time_argument(args_trimmed)?;
                     modifiers.max_time = Some(duration);
                 }
+                "readConcern" => {
+                    if args_trimmed.is_empty() {
+                        return Err(String::from(tr(
+                            "readConcern requires a document with a level.",
+                        )));
+                    }
+                    let value = Self::parse_shell_json_value(args_trimmed)?;
+                    modifiers.read_concern = Some(Self::parse_read_concern_value(&value)?);
+                }
                 "comment" => {
                     if args_trimmed.is_empty() {
                         return Err(String::from(tr("comment expects a value.")));
@@ -2398,29 +3052,28 @@ impl<'a> QueryParser<'a> {
                     modifiers.comment = Some(Self::json_value_to_bson(&value)?);
                 }
                 "explain" => {
-                    if !args_trimmed.is_empty() {
-                        return Err(String::from(tr("explain does not take any arguments.")));
-                    }
+                    let verbosity = Self::parse_explain_verbosity(args_trimmed)?;
                     if !rest.trim().is_empty() {
                         return Err(String::from(tr("No methods are supported after explain().")));
                     }
-                    return self.build_explain_command(filter, modifiers);
+                    return self.build_explain_command(filter, modifiers, verbosity);
                 }
-                "finish" if explain_mode => {
+                "finish" if explain_verbosity.is_some() => {
+                    let verbosity = explain_verbosity.expect("checked by the match guard");
                     if !args_trimmed.is_empty() {
                         return Err(String::from(tr("finish does not take any arguments.")));
                     }
                     if !rest.trim().is_empty() {
                         return Err(String::from(tr("No methods are supported after finish().")));
                     }
-                    return self.build_explain_command(filter, modifiers);
+                    return self.build_explain_command(filter, modifiers, verbosity);
                 }
                 "count" | "countDocuments" => {
                     return self.finish_find_with_count(filter, modifiers, args_trimmed, rest);
                 }
                 other => {
                     return Err(tr_format(
-                        "Method '{}' is not supported after find(...).",
+                        "Method '{}' is not supported after find(...). Supported: sort, projection, hint, limit, skip, maxTimeMS, readConcern, comment, explain, count, countDocuments.",
                         &[other],
                     ));
                 }
@@ -2428,8 +3081,8 @@ impl<'a> QueryParser<'a> {
             tail = rest.trim_start();
         }
 
-        if explain_mode {
-            return self.build_explain_command(filter, modifiers);
+        if let Some(verbosity) = explain_verbosity {
+            return self.build_explain_command(filter, modifiers, verbosity);
         }
 
         let options = if modifiers.has_effect() { Some(modifiers) } else { None };
@@ -2440,6 +3093,7 @@ impl<'a> QueryParser<'a> {
         &self,
         filter: Document,
         modifiers: FindCursorChain,
+        verbosity: &str,
     ) -> Result<QueryOperation, String> {
         let mut find_doc = Document::new();
         find_doc.insert("find", Bson::String(self.collection.to_string()));
@@ -2478,12 +3132,21 @@ impl<'a> QueryParser<'a> {
         if let Some(max_time) = modifiers.max_time {
             find_doc.insert("maxTimeMS", Bson::Int64(max_time.as_millis() as i64));
         }
+        if let Some(read_concern) = modifiers.read_concern {
+            let read_concern_doc = bson::to_document(&read_concern)
+                .map_err(|error| format!("BSON conversion error: {error}"))?;
+            find_doc.insert("readConcern", Bson::Document(read_concern_doc));
+        }
         if let Some(comment) = modifiers.comment {
             find_doc.insert("comment", comment);
         }
+        if let Some(let_vars) = modifiers.let_vars {
+            find_doc.insert("let", Bson::Document(let_vars));
+        }
 
         let mut command = Document::new();
         command.insert("explain", Bson::Document(find_doc));
+        command.insert("verbosity", verbosity);
         Ok(QueryOperation::DatabaseCommand { db: self.db_name.to_string(), command })
     }
 
@@ -2712,6 +3375,81 @@ impl<'a> QueryParser<'a> {
         if options.has_values() { Ok(Some(options)) } else { Ok(None) }
     }
 
+    /// Cross-checks filtered positional operators (`$[identifier]`) in an update document
+    /// against the identifiers declared in `arrayFilters`.
+    /// Collects the identifiers an arrayFilters entry declares, looking inside `$or`, `$and`
+    /// and `$nor` branches as well.
+    fn collect_array_filter_identifiers<'f>(filter: &'f Document, declared: &mut Vec<&'f str>) {
+        for (key, value) in filter {
+            if matches!(key.as_str(), "$or" | "$and" | "$nor") {
+                if let Bson::Array(branches) = value {
+                    for branch in branches.iter().filter_map(Bson::as_document) {
+                        Self::collect_array_filter_identifiers(branch, declared);
+                    }
+                }
+                continue;
+            }
+            if key.starts_with('$') {
+                continue;
+            }
+            let identifier = key.split('.').next().unwrap_or(key);
+            if !declared.contains(&identifier) {
+                declared.push(identifier);
+            }
+        }
+    }
+
+    fn validate_array_filters(
+        update: &UpdateModificationsSpec,
+        array_filters: Option<&[Document]>,
+    ) -> Result<(), String> {
+        let UpdateModificationsSpec::Document(document) = update else {
+            return Ok(());
+        };
+
+        let mut used = Vec::new();
+        for (operator, fields) in document {
+            let Bson::Document(fields) = fields else {
+                continue;
+            };
+            if !operator.starts_with('$') {
+                continue;
+            }
+            for path in fields.keys() {
+                for segment in path.split('.') {
+                    let Some(identifier) =
+                        segment.strip_prefix("$[").and_then(|rest| rest.strip_suffix(']'))
+                    else {
+                        continue;
+                    };
+                    if !identifier.is_empty() && !used.contains(&identifier) {
+                        used.push(identifier);
+                    }
+                }
+            }
+        }
+
+        let mut declared = Vec::new();
+        for filter in array_filters.unwrap_or_default() {
+            Self::collect_array_filter_identifiers(filter, &mut declared);
+        }
+
+        if let Some(missing) = used.iter().find(|identifier| !declared.contains(identifier)) {
+            return Err(tr_format(
+                "Positional operator '$[{}]' has no matching entry in arrayFilters.",
+                &[missing],
+            ));
+        }
+        if let Some(unused) = declared.iter().find(|identifier| !used.contains(identifier)) {
+            return Err(tr_format(
+                "arrayFilters entry for identifier '{}' is not used in the update.",
+                &[unused],
+            ));
+        }
+
+        Ok(())
+    }
+
     fn parse_update_spec(source: &str) -> Result<UpdateModificationsSpec, String> {
         let value: Value = Self::parse_shell_json_value(source)?;
 
@@ -3003,7 +3741,10 @@ impl<'a> QueryParser<'a> {
 
                 Ok(WriteModel::DeleteMany(model))
             }
-            _ => Err(tr_format("bulkWrite operation '{}' is not supported.", &[&operation])),
+            _ => Err(tr_format(
+                "bulkWrite operation '{}' at index {} is not supported. Allowed: insertOne, updateOne, updateMany, replaceOne, deleteOne, deleteMany.",
+                &[&operation, &index.to_string()],
+            )),
         }
     }
 
@@ -3643,6 +4384,7 @@ impl<'a> QueryParser<'a> {
         let update_spec = update_spec.ok_or_else(|| {
             String::from(tr("findAndModify requires an 'update' parameter when remove=false."))
         })?;
+        Self::validate_array_filters(&update_spec, array_filters.as_deref())?;
 
         let mut options = FindOneAndUpdateParsedOptions::default();
         options.write_concern = write_concern;
@@ -3764,6 +4506,37 @@ impl<'a> QueryParser<'a> {
         }
     }
 
+    fn parse_read_concern_value(value: &Value) -> Result<ReadConcern, String> {
+        let level = match value {
+            Value::String(level) => level.as_str(),
+            Value::Object(map) => match map.get("level") {
+                Some(Value::String(level)) if map.len() == 1 => level.as_str(),
+                _ => {
+                    return Err(String::from(tr(
+                        "readConcern must be a document with a single string field 'level'.",
+                    )));
+                }
+            },
+            _ => {
+                return Err(String::from(tr(
+                    "readConcern must be a document with a single string field 'level'.",
+                )));
+            }
+        };
+
+        match level {
+            "local" => Ok(ReadConcern::local()),
+            "majority" => Ok(ReadConcern::majority()),
+            "linearizable" => Ok(ReadConcern::linearizable()),
+            "available" => Ok(ReadConcern::available()),
+            "snapshot" => Ok(ReadConcern::snapshot()),
+            other => Err(tr_format(
+                "Unsupported readConcern level '{}'. Allowed: local, majority, linearizable, available, snapshot.",
+                &[other],
+            )),
+        }
+    }
+
     fn parse_non_negative_u64(value: &Value, field: &str) -> Result<u64, String> {
         match value {
             Value::Number(number) => number.as_u64().ok_or_else(|| {
@@ -3836,11 +4609,11 @@ impl<'a> QueryParser<'a> {
                     in_string = true;
                     current.push(ch);
                 }
-                '{' | '[' => {
+                '{' | '[' | '(' => {
                     depth += 1;
                     current.push(ch);
                 }
-                '}' | ']' => {
+                '}' | ']' | ')' => {
                     depth -= 1;
                     current.push(ch);
                 }
@@ -3988,18 +4761,436 @@ impl<'a> QueryParser<'a> {
     }
 }
 
-pub fn parse_collection_query_with_collection(
-    db_name: &str,
-    collection: &str,
-    text: &str,
-) -> Result<(String, QueryOperation), String> {
-    QueryParser { db_name, collection }.parse_query_with_collection(text)
-}
+const DATABASE_NAME_FORBIDDEN_CHARS: &[char] = &['/', '\\', '.', ' ', '"', '$'];
+const MAX_DATABASE_NAME_BYTES: usize = 63;
+const MAX_NAMESPACE_BYTES: usize = 255;
 
-fn resolve_effective_limit(ui_limit: u64, chain_limit: Option<u64>) -> u64 {
-    match chain_limit {
-        Some(chain) => {
-            if ui_limit == 0 {
+/// Checks a database name against the MongoDB naming restrictions before it reaches the server.
+pub fn validate_database_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(String::from(tr("Database name cannot be empty.")));
+    }
+    if name.contains('\0') {
+        return Err(String::from(tr("Database name cannot contain null characters.")));
+    }
+    if let Some(ch) = name.chars().find(|ch| DATABASE_NAME_FORBIDDEN_CHARS.contains(ch)) {
+        return Err(tr_format(
+            "Database name cannot contain the character '{}'.",
+            &[&ch.to_string()],
+        ));
+    }
+    if name.len() > MAX_DATABASE_NAME_BYTES {
+        return Err(tr_format(
+            "Database name must be at most {} bytes long.",
+            &[&MAX_DATABASE_NAME_BYTES.to_string()],
+        ));
+    }
+    Ok(())
+}
+
+/// Light client-side check that every stage is a single `$operator` document, so common
+/// mistakes are reported with the stage index instead of an opaque server error.
+pub fn validate_pipeline_stages(pipeline: &[Document]) -> Result<(), String> {
+    for (index, stage) in pipeline.iter().enumerate() {
+        let index_text = index.to_string();
+        let keys: Vec<&str> = stage.keys().map(String::as_str).collect();
+        match keys.as_slice() {
+            [] => {
+                return Err(tr_format("Pipeline stage at index {} is empty.", &[&index_text]));
+            }
+            [key] if key.starts_with('$') => {}
+            [key] => {
+                return Err(tr_format(
+                    "Pipeline stage at index {} must start with '$', found '{}'. Wrap the expression in a stage such as $match.",
+                    &[&index_text, key],
+                ));
+            }
+            _ => {
+                return Err(tr_format(
+                    "Pipeline stage at index {} must contain exactly one operator, found: {}.",
+                    &[&index_text, &keys.join(", ")],
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks a collection name against the MongoDB naming restrictions, including the length of
+/// the full `db.collection` namespace.
+pub fn validate_collection_name(db_name: &str, name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(String::from(tr("Collection name cannot be empty.")));
+    }
+    if name.contains('\0') {
+        return Err(String::from(tr("Collection name cannot contain null characters.")));
+    }
+    if name.contains('$') {
+        return Err(tr_format("Collection name cannot contain the character '{}'.", &["$"]));
+    }
+    if name.starts_with("system.") {
+        return Err(String::from(tr("Collection name cannot start with 'system.'.")));
+    }
+    let namespace = format!("{db_name}.{name}");
+    if namespace.len() > MAX_NAMESPACE_BYTES {
+        return Err(tr_format(
+            "Namespace '{}' exceeds {} bytes.",
+            &[&namespace, &MAX_NAMESPACE_BYTES.to_string()],
+        ));
+    }
+    Ok(())
+}
+
+/// Matches a drop confirmation against an index, accepting either its name or its key
+/// specification, and returns the value to send as `dropIndexes.index`.
+pub fn resolve_drop_index_target(
+    input: &str,
+    index_name: &str,
+    index_keys: Option<&Document>,
+) -> Option<Bson> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    if input == index_name {
+        return Some(Bson::String(index_name.to_string()));
+    }
+
+    match QueryParser::parse_index_argument(input).ok()? {
+        Bson::String(name) if name == index_name => Some(Bson::String(name)),
+        Bson::Document(keys) => index_keys
+            .filter(|index_keys| index_keys_match(index_keys, &keys))
+            .map(|index_keys| Bson::Document(index_keys.clone())),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexKeyKind {
+    Ascending,
+    Descending,
+    Text,
+    Geo2dSphere,
+}
+
+pub const INDEX_KEY_KINDS: [IndexKeyKind; 4] = [
+    IndexKeyKind::Ascending,
+    IndexKeyKind::Descending,
+    IndexKeyKind::Text,
+    IndexKeyKind::Geo2dSphere,
+];
+
+impl IndexKeyKind {
+    fn value(self) -> Bson {
+        match self {
+            IndexKeyKind::Ascending => Bson::Int32(1),
+            IndexKeyKind::Descending => Bson::Int32(-1),
+            IndexKeyKind::Text => Bson::String("text".to_string()),
+            IndexKeyKind::Geo2dSphere => Bson::String("2dsphere".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for IndexKeyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            IndexKeyKind::Ascending => "1",
+            IndexKeyKind::Descending => "-1",
+            IndexKeyKind::Text => "text",
+            IndexKeyKind::Geo2dSphere => "2dsphere",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Form input of the index builder, turned into a `createIndex(...)` call.
+#[derive(Debug, Clone)]
+pub struct IndexBuilderSpec {
+    pub keys: Vec<(String, IndexKeyKind)>,
+    pub name: String,
+    pub unique: bool,
+    pub sparse: bool,
+    pub ttl_seconds: String,
+    pub partial_filter: String,
+}
+
+impl Default for IndexBuilderSpec {
+    fn default() -> Self {
+        Self {
+            keys: vec![(String::new(), IndexKeyKind::Ascending)],
+            name: String::new(),
+            unique: false,
+            sparse: false,
+            ttl_seconds: String::new(),
+            partial_filter: String::new(),
+        }
+    }
+}
+
+impl IndexBuilderSpec {
+    pub fn key_document(&self) -> Result<Document, String> {
+        let mut keys = Document::new();
+        for (field, kind) in &self.keys {
+            let field = field.trim();
+            if field.is_empty() {
+                continue;
+            }
+            if keys.contains_key(field) {
+                return Err(tr_format("Field '{}' is used more than once.", &[field]));
+            }
+            keys.insert(field, kind.value());
+        }
+        if keys.is_empty() {
+            return Err(String::from(tr("Choose at least one field for the index.")));
+        }
+        Ok(keys)
+    }
+
+    /// Returns the name of an existing index (`getIndexes` output) with the same key pattern.
+    pub fn find_matching_index<'a>(&self, existing: &'a [Document]) -> Option<&'a str> {
+        let keys = self.key_document().ok()?;
+        existing.iter().find_map(|index| {
+            let index_keys = index.get_document("key").ok()?;
+            if index_keys_match(index_keys, &keys) { index.get_str("name").ok() } else { None }
+        })
+    }
+
+    pub fn to_shell(&self, collection: &str) -> Result<String, String> {
+        let keys = self.key_document()?;
+
+        let mut options = Document::new();
+        let name = self.name.trim();
+        let name =
+            if name.is_empty() { QueryParser::default_index_name(&keys) } else { name.to_string() };
+        options.insert("name", name);
+        if self.unique {
+            options.insert("unique", true);
+        }
+        if self.sparse {
+            options.insert("sparse", true);
+        }
+
+        let ttl = self.ttl_seconds.trim();
+        if !ttl.is_empty() {
+            let seconds: i32 =
+                ttl.parse().ok().filter(|value| *value >= 0).ok_or_else(|| {
+                    String::from(tr("TTL must be a non-negative number of seconds."))
+                })?;
+            if keys.len() != 1 {
+                return Err(String::from(tr("TTL indexes must have exactly one field.")));
+            }
+            options.insert("expireAfterSeconds", seconds);
+        }
+
+        let partial_filter = self.partial_filter.trim();
+        if !partial_filter.is_empty() {
+            let filter = QueryParser::parse_json_object(partial_filter)?;
+            options.insert("partialFilterExpression", filter);
+        }
+
+        Ok(format!(
+            "db.getCollection('{collection}').createIndex(\n    {},\n    {}\n)",
+            compact_shell_value(&Bson::Document(keys)),
+            compact_shell_value(&Bson::Document(options)),
+        ))
+    }
+}
+
+/// Compares key specifications field by field in order, treating numeric directions of
+/// different BSON types (1, NumberLong(1), 1.0) as equal.
+fn index_keys_match(left: &Document, right: &Document) -> bool {
+    fn numeric(value: &Bson) -> Option<f64> {
+        match value {
+            Bson::Int32(value) => Some(f64::from(*value)),
+            Bson::Int64(value) => Some(*value as f64),
+            Bson::Double(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    left.len() == right.len()
+        && left.iter().zip(right.iter()).all(
+            |((left_key, left_value), (right_key, right_value))| {
+                left_key == right_key
+                    && match (numeric(left_value), numeric(right_value)) {
+                        (Some(left_number), Some(right_number)) => left_number == right_number,
+                        _ => left_value == right_value,
+                    }
+            },
+        )
+}
+
+/// Returns the name of the collection's text index. `listIndexes` reports text indexes with a
+/// `"text"` key direction (`{ _fts: "text", _ftsx: 1 }`).
+pub fn find_text_index(indexes: &[Document]) -> Option<String> {
+    indexes.iter().find_map(|index| {
+        let keys = index.get_document("key").ok()?;
+        keys.values()
+            .any(|direction| direction.as_str() == Some("text"))
+            .then(|| index.get_str("name").unwrap_or_default().to_string())
+    })
+}
+
+/// Builds a `$text` search sorted by relevance, exposing the score as a `score` field.
+pub fn text_search_query(collection: &str, terms: &str) -> String {
+    let search = serde_json::Value::String(terms.trim().to_string()).to_string();
+    format!(
+        "db.getCollection('{collection}').find(\n    {{ $text: {{ $search: {search} }} }},\n    {{ score: {{ $meta: \"textScore\" }} }}\n).sort({{ score: {{ $meta: \"textScore\" }} }})"
+    )
+}
+
+const VALIDATION_RULE_KEYS: [&str; 3] = ["validator", "validationLevel", "validationAction"];
+
+/// Extracts the editable validation settings from the `options` reported by `listCollections`,
+/// falling back to an empty `$jsonSchema` template when the collection has no validator.
+pub fn validation_rules_document(options: &Document) -> Document {
+    let mut rules = Document::new();
+    rules.insert(
+        "validator",
+        options.get("validator").cloned().unwrap_or_else(|| {
+            Bson::Document(doc! {
+                "$jsonSchema": { "bsonType": "object", "required": [], "properties": {} }
+            })
+        }),
+    );
+    rules.insert("validationLevel", options.get_str("validationLevel").unwrap_or("strict"));
+    rules.insert("validationAction", options.get_str("validationAction").unwrap_or("error"));
+    rules
+}
+
+/// Builds the `collMod` command applying edited validation settings. An empty validator
+/// removes validation from the collection.
+pub fn validation_coll_mod_command(collection: &str, rules: &Document) -> Result<Document, String> {
+    if let Some(key) = rules.keys().find(|key| !VALIDATION_RULE_KEYS.contains(&key.as_str())) {
+        return Err(tr_format(
+            "Unsupported field \"{}\". Only validator, validationLevel and validationAction can be changed.",
+            &[key],
+        ));
+    }
+    if rules.get("validator").is_some_and(|validator| validator.as_document().is_none()) {
+        return Err(String::from(tr("The validator must be a document.")));
+    }
+
+    let mut command = doc! { "collMod": collection };
+    for key in VALIDATION_RULE_KEYS {
+        if let Some(value) = rules.get(key) {
+            command.insert(key, value.clone());
+        }
+    }
+    Ok(command)
+}
+
+/// Builds a skeleton document from a `$jsonSchema` validator: every required field gets a
+/// placeholder matching its declared type. Without a schema the skeleton is an empty object.
+pub fn schema_insert_template(validator: Option<&Document>) -> Document {
+    validator
+        .and_then(|validator| validator.get_document("$jsonSchema").ok())
+        .map(schema_object_template)
+        .unwrap_or_default()
+}
+
+fn schema_object_template(schema: &Document) -> Document {
+    let properties = schema.get_document("properties").ok();
+    let mut template = Document::new();
+    for field in schema.get_array("required").into_iter().flatten().filter_map(Bson::as_str) {
+        let property = properties.and_then(|properties| properties.get_document(field).ok());
+        template.insert(field, property.map_or(Bson::Null, schema_placeholder));
+    }
+    template
+}
+
+fn schema_placeholder(property: &Document) -> Bson {
+    if let Some(first) = property.get_array("enum").ok().and_then(|values| values.first()) {
+        return first.clone();
+    }
+
+    let declared = property.get("bsonType").or_else(|| property.get("type"));
+    let type_name = match declared {
+        Some(Bson::String(name)) => Some(name.as_str()),
+        Some(Bson::Array(names)) => {
+            names.iter().filter_map(Bson::as_str).find(|name| *name != "null")
+        }
+        _ => None,
+    };
+
+    match type_name {
+        Some("string") => Bson::String(String::new()),
+        Some("int") => Bson::Int32(0),
+        Some("long") => Bson::Int64(0),
+        Some("double" | "number") => Bson::Double(0.0),
+        Some("decimal") => Bson::Decimal128(bson::Decimal128::from_bytes([0; 16])),
+        Some("bool" | "boolean") => Bson::Boolean(false),
+        Some("date") => Bson::DateTime(DateTime::now()),
+        Some("objectId") => Bson::ObjectId(bson::oid::ObjectId::new()),
+        Some("array") => Bson::Array(Vec::new()),
+        Some("object") => Bson::Document(schema_object_template(property)),
+        _ => Bson::Null,
+    }
+}
+
+pub fn insert_template_query(collection: &str, template: &Document) -> String {
+    format!(
+        "db.getCollection('{collection}').insertOne({});",
+        shell::format_bson_shell(&Bson::Document(template.clone()))
+    )
+}
+
+/// Splits a comma or whitespace separated list of field paths, dropping duplicates.
+pub fn parse_projection_fields(text: &str) -> Result<Vec<String>, String> {
+    let mut fields: Vec<String> = Vec::new();
+    for field in text.split(|ch: char| ch == ',' || ch.is_whitespace()) {
+        if field.is_empty() || fields.iter().any(|known| known == field) {
+            continue;
+        }
+        if field.starts_with('$') || field.starts_with('.') || field.ends_with('.') {
+            return Err(tr_format("Invalid field path \"{}\".", &[field]));
+        }
+        fields.push(field.to_string());
+    }
+    if fields.is_empty() {
+        return Err(String::from(tr("Enter at least one field to project.")));
+    }
+    Ok(fields)
+}
+
+/// Inclusion projection for `fields`; `_id` is excluded unless it is listed explicitly.
+pub fn projection_from_fields(fields: &[String]) -> Document {
+    let mut projection = Document::new();
+    if !fields.iter().any(|field| field == "_id") {
+        projection.insert("_id", 0);
+    }
+    for field in fields {
+        projection.insert(field.clone(), 1);
+    }
+    projection
+}
+
+/// Two updates that remove the array element at `path` (for example `items.2`). The element is
+/// first replaced by a unique marker that is then pulled, so equal elements elsewhere stay.
+pub fn array_element_removal_updates(path: &str) -> Option<(Document, Document)> {
+    let (array_path, index) = path.rsplit_once('.')?;
+    if array_path.is_empty() || index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let marker = doc! { "__oxide_mongo_removed": bson::oid::ObjectId::new() };
+    let mark = doc! { "$set": { path: marker.clone() } };
+    let pull = doc! { "$pull": { array_path: marker } };
+    Some((mark, pull))
+}
+
+pub fn parse_collection_query_with_collection(
+    db_name: &str,
+    collection: &str,
+    text: &str,
+) -> Result<(String, QueryOperation), String> {
+    QueryParser { db_name, collection }.parse_query_with_collection(text)
+}
+
+fn resolve_effective_limit(ui_limit: u64, chain_limit: Option<u64>) -> u64 {
+    match chain_limit {
+        Some(chain) => {
+            if ui_limit == 0 {
                 chain
             } else {
                 ui_limit.min(chain)
@@ -4493,6 +5684,139 @@ fn build_secondary_replication_info(client: &Client) -> Result<Vec<Bson>, String
     Ok(output)
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateWriteTarget {
+    pub stage: &'static str,
+    pub db_name: String,
+    pub collection: String,
+}
+
+/// Resolves the namespace written by a trailing `$out` or `$merge` stage. Targets given
+/// without a database are resolved against `db_name`.
+pub fn aggregate_write_target(
+    pipeline: &[Document],
+    db_name: &str,
+) -> Option<AggregateWriteTarget> {
+    let stage = pipeline.last()?;
+    let (stage_name, spec) = if let Some(spec) = stage.get("$out") {
+        ("$out", spec)
+    } else {
+        ("$merge", stage.get("$merge")?)
+    };
+
+    let target = match (stage_name, spec) {
+        ("$merge", Bson::Document(merge)) => merge.get("into")?,
+        _ => spec,
+    };
+
+    let (db, collection) = match target {
+        Bson::String(collection) => (db_name.to_string(), collection.clone()),
+        Bson::Document(namespace) => (
+            namespace.get_str("db").unwrap_or(db_name).to_string(),
+            namespace.get_str("coll").ok()?.to_string(),
+        ),
+        _ => return None,
+    };
+
+    Some(AggregateWriteTarget { stage: stage_name, db_name: db, collection })
+}
+
+/// Appends `$skip`/`$limit` stages for the tab pagination controls. Pipelines that end with
+/// `$out` or `$merge` are left untouched because those stages must remain the last ones.
+pub(crate) fn append_pagination_stages(pipeline: &mut Vec<Document>, skip: u64, limit: u64) {
+    let ends_with_write_stage = pipeline
+        .last()
+        .is_some_and(|stage| stage.contains_key("$out") || stage.contains_key("$merge"));
+    if ends_with_write_stage {
+        return;
+    }
+
+    if skip > 0 {
+        let skip_i64 = i64::try_from(skip).unwrap_or(i64::MAX);
+        pipeline.push(doc! { "$skip": skip_i64 });
+    }
+
+    if limit > 0 {
+        let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
+        pipeline.push(doc! { "$limit": limit_i64 });
+    }
+}
+
+/// Renders the operation the way `run_collection_query` will execute it, with the tab
+/// skip/limit controls merged into the parsed query. Only find and aggregate are affected
+/// by those controls, other operations return `None`.
+pub fn describe_effective_query(
+    operation: &QueryOperation,
+    skip: u64,
+    limit: u64,
+) -> Option<String> {
+    match operation {
+        QueryOperation::Find { filter, options } => {
+            let mut rendered =
+                format!("find({})", compact_shell_value(&Bson::Document(filter.clone())));
+            let mut effective_skip = skip;
+            let mut chain_limit = None;
+            if let Some(opts) = options {
+                if let Some(projection) = &opts.projection {
+                    rendered.push_str(&format!(
+                        ".projection({})",
+                        compact_shell_value(&Bson::Document(projection.clone()))
+                    ));
+                }
+                if let Some(sort) = &opts.sort {
+                    rendered.push_str(&format!(
+                        ".sort({})",
+                        compact_shell_value(&Bson::Document(sort.clone()))
+                    ));
+                }
+                match &opts.hint {
+                    Some(Hint::Name(name)) => rendered.push_str(&format!(".hint(\"{name}\")")),
+                    Some(Hint::Keys(keys)) => rendered.push_str(&format!(
+                        ".hint({})",
+                        compact_shell_value(&Bson::Document(keys.clone()))
+                    )),
+                    _ => {}
+                }
+                effective_skip = effective_skip.saturating_add(opts.skip.unwrap_or(0));
+                chain_limit = opts.limit;
+                if let Some(duration) = opts.max_time {
+                    rendered.push_str(&format!(".maxTimeMS({})", duration.as_millis()));
+                }
+                if let Some(read_concern) =
+                    opts.read_concern.as_ref().and_then(|value| bson::to_document(value).ok())
+                {
+                    rendered.push_str(&format!(
+                        ".readConcern({})",
+                        compact_shell_value(&Bson::Document(read_concern))
+                    ));
+                }
+            }
+            if effective_skip > 0 {
+                rendered.push_str(&format!(".skip({effective_skip})"));
+            }
+            let effective_limit = resolve_effective_limit(limit, chain_limit);
+            if effective_limit > 0 {
+                rendered.push_str(&format!(".limit({effective_limit})"));
+            }
+            Some(rendered)
+        }
+        QueryOperation::Aggregate { pipeline, .. } => {
+            let mut pipeline = pipeline.clone();
+            append_pagination_stages(&mut pipeline, skip, limit);
+            let stages: Vec<String> = pipeline
+                .into_iter()
+                .map(|stage| compact_shell_value(&Bson::Document(stage)))
+                .collect();
+            Some(format!("aggregate([{}])", stages.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+fn compact_shell_value(value: &Bson) -> String {
+    shell::format_bson_shell(value).lines().map(str::trim).collect::<Vec<_>>().join(" ")
+}
+
 pub fn run_collection_query(
     client: Arc<Client>,
     db_name: String,
@@ -4525,6 +5849,9 @@ pub fn run_collection_query(
                 if let Some(hint) = opts.hint {
                     builder = builder.hint(hint);
                 }
+                if let Some(read_concern) = opts.read_concern {
+                    builder = builder.read_concern(read_concern);
+                }
                 if let Some(chain_skip) = opts.skip {
                     effective_skip = effective_skip.saturating_add(chain_skip);
                 }
@@ -4535,6 +5862,9 @@ pub fn run_collection_query(
                 if let Some(comment) = opts.comment {
                     builder = builder.comment(comment);
                 }
+                if let Some(let_vars) = opts.let_vars {
+                    builder = builder.let_vars(let_vars);
+                }
             }
 
             if effective_skip > 0 {
@@ -4730,15 +6060,7 @@ pub fn run_collection_query(
             Ok(QueryResult::Distinct { field, values })
         }
         QueryOperation::Aggregate { mut pipeline, options } => {
-            if skip > 0 {
-                let skip_i64 = i64::try_from(skip).unwrap_or(i64::MAX);
-                pipeline.push(doc! { "$skip": skip_i64 });
-            }
-
-            if limit > 0 {
-                let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
-                pipeline.push(doc! { "$limit": limit_i64 });
-            }
+            append_pagination_stages(&mut pipeline, skip, limit);
 
             let mut action = collection.aggregate(pipeline);
             if let Some(opts) = options {
@@ -5224,6 +6546,16 @@ pub fn run_collection_query(
             }
             Ok(QueryResult::Indexes(documents))
         }
+        QueryOperation::MapReduce { map, reduce, options } => {
+            let mut command = doc! {
+                "mapReduce": collection_name,
+                "map": Bson::JavaScriptCode(map),
+                "reduce": Bson::JavaScriptCode(reduce),
+            };
+            command.extend(options);
+            let document = database.run_command(command).run().map_err(|err| err.to_string())?;
+            Ok(QueryResult::SingleDocument { document })
+        }
         QueryOperation::ReplicaSetCommand { command } => run_replica_set_command(client, command),
         QueryOperation::DatabaseCommand { db, command } => {
             let database = client.database(&db);
@@ -5275,6 +6607,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_map_reduce_with_function_bodies_intact() {
+        let operation = parse(
+            "db.users.mapReduce(function() { emit(this.city, {count: 1}); }, function(key, values) { return Array.sum(values); }, { query: { active: true }, limit: 100, finalize: function(key, value) { return {key: key, value: value}; } })",
+        );
+        match &operation {
+            QueryOperation::MapReduce { map, reduce, options } => {
+                assert_eq!(map, "function() { emit(this.city, {count: 1}); }");
+                assert_eq!(reduce, "function(key, values) { return Array.sum(values); }");
+                assert_eq!(options.get_document("query").unwrap(), &doc! { "active": true });
+                assert_eq!(options.get_document("out").unwrap(), &doc! { "inline": 1 });
+                assert_eq!(
+                    options.get("finalize"),
+                    Some(&Bson::JavaScriptCode(String::from(
+                        "function(key, value) { return {key: key, value: value}; }"
+                    )))
+                );
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+        assert!(!operation.is_write());
+
+        let operation = parse(
+            "db.users.mapReduce('function() { emit(1, 1); }', 'function(k, v) { return 1; }', { out: 'totals' })",
+        );
+        assert!(operation.is_write());
+
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.users.mapReduce(function() {}, function() {}, { verbose: true })",
+        )
+        .unwrap_err();
+        assert!(error.contains("verbose"));
+    }
+
     #[test]
     fn parses_db_admin_command() {
         let operation = parse("db.adminCommand({ serverStatus: 1 })");
@@ -5287,6 +6655,202 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_find_and_aggregate_let_variables() {
+        let operation = parse(
+            "db.users.find({$expr: {$gte: [\"$age\", \"$$minAge\"]}}, {}, {let: {minAge: 18}})",
+        );
+        match operation {
+            QueryOperation::Find { options, .. } => {
+                let options = options.expect("options expected");
+                assert_eq!(options.let_vars, Some(doc! { "minAge": 18i32 }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+
+        let operation = parse(
+            "db.users.aggregate([{$match: {$expr: {$eq: [\"$role\", \"$$role\"]}}}], {let: {role: \"admin\"}})",
+        );
+        match operation {
+            QueryOperation::Aggregate { options, .. } => {
+                let options = options.expect("options expected");
+                assert_eq!(options.let_vars, Some(doc! { "role": "admin" }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_non_document_let_variables() {
+        for query in
+            ["db.users.find({}, {}, {let: 5})", "db.users.aggregate([], {let: [\"role\"]})"]
+        {
+            assert!(
+                parse_collection_query_with_collection("testdb", "users", query).is_err(),
+                "{query}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_sample_aggregation() {
+        let operation = parse("db.getCollection('users').aggregate([{ $sample: { size: 50 } }])");
+        match operation {
+            QueryOperation::Aggregate { pipeline, options } => {
+                assert_eq!(pipeline, vec![doc! { "$sample": { "size": 50i32 } }]);
+                assert!(options.is_none());
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    fn expect_aggregate_explain(query: &str) -> Document {
+        let (_, operation) =
+            parse_collection_query_with_collection("db", "users", query).expect("parse explain");
+        match operation {
+            QueryOperation::DatabaseCommand { db, command } => {
+                assert_eq!(db, "db");
+                command.get_document("explain").expect("explain document").clone()
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn aggregate_explain_forms_build_explain_command() {
+        let expected = doc! {
+            "aggregate": "users",
+            "pipeline": [{ "$match": { "active": true } }],
+            "cursor": {},
+        };
+
+        for query in [
+            "db.users.aggregate([{ $match: { active: true } }], { explain: true })",
+            "db.users.aggregate([{ $match: { active: true } }]).explain()",
+            "db.users.explain().aggregate([{ $match: { active: true } }])",
+        ] {
+            assert_eq!(expect_aggregate_explain(query), expected, "query: {query}");
+        }
+
+        let with_options = expect_aggregate_explain(
+            "db.users.aggregate([], { explain: true, allowDiskUse: true, maxTimeMS: 500 })",
+        );
+        assert_eq!(with_options.get_bool("allowDiskUse"), Ok(true));
+        assert_eq!(with_options.get_i64("maxTimeMS"), Ok(500));
+    }
+
+    #[test]
+    fn explain_accepts_standard_verbosities() {
+        let verbosity =
+            |query: &str| match parse_collection_query_with_collection("db", "users", query) {
+                Ok((_, QueryOperation::DatabaseCommand { command, .. })) => {
+                    command.get_str("verbosity").expect("verbosity").to_string()
+                }
+                other => panic!("unexpected result: {:?}", other),
+            };
+
+        assert_eq!(verbosity("db.users.find({}).explain()"), "queryPlanner");
+        assert_eq!(verbosity("db.users.find({}).explain('executionStats')"), "executionStats");
+        assert_eq!(
+            verbosity("db.users.aggregate([]).explain(\"allPlansExecution\")"),
+            "allPlansExecution"
+        );
+        assert_eq!(
+            verbosity("db.users.explain('executionStats').find({ a: 1 }).finish()"),
+            "executionStats"
+        );
+        assert_eq!(verbosity("db.users.aggregate([], { explain: true })"), "queryPlanner");
+        assert_eq!(verbosity("db.users.find({}).explain(true)"), "allPlansExecution");
+
+        let error = parse_collection_query_with_collection(
+            "db",
+            "users",
+            "db.users.find({}).explain('fast')",
+        )
+        .expect_err("unknown verbosity");
+        assert!(error.contains("fast"));
+    }
+
+    #[test]
+    fn aggregate_explain_false_runs_pipeline() {
+        let (_, operation) = parse_collection_query_with_collection(
+            "db",
+            "users",
+            "db.users.aggregate([], { explain: false })",
+        )
+        .expect("parse aggregate");
+        assert!(matches!(operation, QueryOperation::Aggregate { .. }));
+
+        let error = parse_collection_query_with_collection(
+            "db",
+            "users",
+            "db.users.aggregate([]).limit(5)",
+        )
+        .expect_err("unsupported chain");
+        assert!(error.contains("limit"));
+    }
+
+    #[test]
+    fn effective_find_query_merges_tab_skip_and_limit() {
+        let (_, operation) = parse_collection_query_with_collection(
+            "db",
+            "users",
+            "db.users.find({ active: true }).sort({ name: 1 }).skip(5).limit(10)",
+        )
+        .expect("parse find");
+
+        assert_eq!(
+            describe_effective_query(&operation, 20, 50).as_deref(),
+            Some("find({ \"active\": true }).sort({ \"name\": 1 }).skip(25).limit(10)")
+        );
+    }
+
+    #[test]
+    fn effective_aggregate_query_shows_pagination_stages() {
+        let (_, operation) = parse_collection_query_with_collection(
+            "db",
+            "users",
+            "db.users.aggregate([{ $match: { active: true } }])",
+        )
+        .expect("parse aggregate");
+
+        let rendered = describe_effective_query(&operation, 0, 50).expect("aggregate preview");
+        assert!(rendered.starts_with("aggregate([{ \"$match\": { \"active\": true } }, "));
+        assert!(rendered.ends_with("{ \"$limit\": 50 }])"));
+
+        let (_, operation) =
+            parse_collection_query_with_collection("db", "users", "db.users.countDocuments({})")
+                .expect("parse count");
+        assert!(describe_effective_query(&operation, 0, 50).is_none());
+    }
+
+    #[test]
+    fn pagination_stages_are_appended_to_aggregate_pipeline() {
+        let mut pipeline = vec![doc! { "$match": { "active": true } }];
+        append_pagination_stages(&mut pipeline, 20, 10);
+        assert_eq!(
+            pipeline,
+            vec![
+                doc! { "$match": { "active": true } },
+                doc! { "$skip": 20i64 },
+                doc! { "$limit": 10i64 },
+            ]
+        );
+
+        let mut pipeline = vec![doc! { "$match": {} }];
+        append_pagination_stages(&mut pipeline, 0, 0);
+        assert_eq!(pipeline, vec![doc! { "$match": {} }]);
+    }
+
+    #[test]
+    fn pagination_stages_are_not_appended_after_write_stages() {
+        for last_stage in [doc! { "$out": "archive" }, doc! { "$merge": { "into": "archive" } }] {
+            let mut pipeline = vec![doc! { "$match": {} }, last_stage.clone()];
+            append_pagination_stages(&mut pipeline, 5, 50);
+            assert_eq!(pipeline, vec![doc! { "$match": {} }, last_stage]);
+        }
+    }
+
     #[test]
     fn parses_simple_find_query() {
         let operation = parse("db.users.find({ \"name\": \"Alice\" })");
@@ -5332,6 +6896,362 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_find_chain_with_read_concern() {
+        let operation =
+            parse("db.users.find({}).readConcern({ level: \"majority\" }).maxTimeMS(1000)");
+        match operation {
+            QueryOperation::Find { options, .. } => {
+                let parsed = options.expect("modifiers expected");
+                assert_eq!(parsed.read_concern, Some(ReadConcern::majority()));
+                assert_eq!(parsed.max_time, Some(Duration::from_millis(1000)));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.users.find({}).readConcern({ level: \"strong\" })",
+        )
+        .expect_err("unknown level should fail");
+        assert!(error.contains("strong"));
+
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.users.find({}).batchSize(10)",
+        )
+        .expect_err("unknown method should fail");
+        assert!(error.contains("readConcern"));
+    }
+
+    #[test]
+    fn rejects_malformed_aggregate_stages() {
+        let parse_error = |query: &str| {
+            parse_collection_query_with_collection("testdb", "users", query)
+                .expect_err("pipeline should be rejected")
+        };
+
+        let error = parse_error("db.users.aggregate([{ $match: {} }, { status: \"A\" }])");
+        assert!(error.contains(" 1 "));
+        assert!(error.contains("'status'"));
+
+        let error = parse_error("db.users.aggregate([{ $match: {}, $limit: 5 }])");
+        assert!(error.contains(" 0 "));
+        assert!(error.contains("$match, $limit"));
+
+        parse_error("db.users.aggregate([{}])");
+
+        assert!(validate_pipeline_stages(&[doc! { "$match": {} }, doc! { "$limit": 5 }]).is_ok());
+    }
+
+    #[test]
+    fn classifies_write_operations() {
+        assert!(!parse("db.users.find({})").is_write());
+        assert!(!parse("db.users.aggregate([{ $match: {} }])").is_write());
+        assert!(parse("db.users.aggregate([{ $match: {} }, { $out: \"copy\" }])").is_write());
+        assert!(parse("db.users.insertOne({ a: 1 })").is_write());
+        assert!(parse("db.users.deleteMany({})").is_write());
+        assert!(parse("db.users.createIndex({ a: 1 })").is_write());
+        assert!(!parse("db.runCommand({ dbStats: 1 })").is_write());
+        assert!(parse("db.runCommand({ drop: \"users\" })").is_write());
+    }
+
+    #[test]
+    fn builds_text_search_query() {
+        let indexes = vec![
+            doc! { "v": 2, "key": { "_id": 1 }, "name": "_id_" },
+            doc! { "v": 2, "key": { "_fts": "text", "_ftsx": 1 }, "name": "body_text" },
+        ];
+        assert_eq!(find_text_index(&indexes).as_deref(), Some("body_text"));
+        assert_eq!(find_text_index(&indexes[..1]), None);
+
+        let query = text_search_query("users", " coffee \"dark roast\" ");
+        match parse(&query) {
+            QueryOperation::Find { filter, options } => {
+                assert_eq!(filter, doc! { "$text": { "$search": "coffee \"dark roast\"" } });
+                let options = options.expect("projection and sort expected");
+                let score = doc! { "score": { "$meta": "textScore" } };
+                assert_eq!(options.projection, Some(score.clone()));
+                assert_eq!(options.sort, Some(score));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn builds_insert_template_from_schema() {
+        let validator = doc! {
+            "$jsonSchema": {
+                "bsonType": "object",
+                "required": ["name", "age", "status", "address"],
+                "properties": {
+                    "name": { "bsonType": "string" },
+                    "age": { "bsonType": ["int", "null"] },
+                    "status": { "enum": ["active", "archived"] },
+                    "address": {
+                        "bsonType": "object",
+                        "required": ["city"],
+                        "properties": { "city": { "bsonType": "string" } },
+                    },
+                    "nickname": { "bsonType": "string" },
+                },
+            }
+        };
+        assert_eq!(
+            schema_insert_template(Some(&validator)),
+            doc! { "name": "", "age": 0, "status": "active", "address": { "city": "" } }
+        );
+        assert_eq!(schema_insert_template(Some(&doc! { "age": { "$gte": 0 } })), doc! {});
+        assert_eq!(schema_insert_template(None), doc! {});
+
+        match parse(&insert_template_query("users", &doc! { "name": "" })) {
+            QueryOperation::InsertOne { document, .. } => assert_eq!(document, doc! { "name": "" }),
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn limits_aggregate_pipeline_stages() {
+        let (_, operation) = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.aggregate([{ $match: { a: 1 } }, { $group: { _id: '$b' } }, { $out: 'x' }])",
+        )
+        .unwrap();
+        assert_eq!(operation.aggregate_stage_count(), Some(3));
+
+        let stepped = operation.with_stage_limit(2);
+        assert!(!stepped.is_write());
+        match stepped {
+            QueryOperation::Aggregate { pipeline, .. } => {
+                assert_eq!(
+                    pipeline,
+                    vec![doc! { "$match": { "a": 1 } }, doc! { "$group": { "_id": "$b" } }]
+                );
+            }
+            other => panic!("unexpected operation: {other:?}"),
+        }
+
+        let (_, find) =
+            parse_collection_query_with_collection("db", "orders", "db.orders.find({})").unwrap();
+        assert_eq!(find.aggregate_stage_count(), None);
+    }
+
+    #[test]
+    fn parses_bulk_write_models_in_order() {
+        let (_, operation) = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.bulkWrite([{ insertOne: { document: { a: 1 } } }, { updateOne: { filter: { a: 1 }, update: { $set: { b: 2 } } } }, { deleteMany: { filter: { c: 3 } } }], { ordered: false })",
+        )
+        .unwrap();
+        match operation {
+            QueryOperation::BulkWrite { models, options } => {
+                assert!(matches!(
+                    models.as_slice(),
+                    [WriteModel::InsertOne(_), WriteModel::UpdateOne(_), WriteModel::DeleteMany(_)]
+                ));
+                assert_eq!(options.and_then(|options| options.ordered), Some(false));
+            }
+            other => panic!("unexpected operation: {other:?}"),
+        }
+
+        let unknown = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.bulkWrite([{ insertOne: { document: {} } }, { upsertOne: { filter: {} } }])",
+        )
+        .unwrap_err();
+        assert!(unknown.contains("upsertOne") && unknown.contains("deleteMany"), "{unknown}");
+
+        let mixed = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.bulkWrite([{ insertOne: { document: {} }, deleteOne: { filter: {} } }])",
+        )
+        .unwrap_err();
+        assert_eq!(
+            mixed,
+            tr_format("bulkWrite element at index {} must contain a single operation.", &["0"])
+        );
+    }
+
+    #[test]
+    fn keyset_paging_follows_id_sort_only() {
+        let last_id = Bson::Int32(42);
+        let (_, ascending) = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.find({ status: 'new' }).sort({ _id: 1 })",
+        )
+        .unwrap();
+        match ascending.keyset_after(&last_id) {
+            Some(QueryOperation::Find { filter, .. }) => {
+                assert_eq!(filter, doc! { "$and": [{ "status": "new" }, { "_id": { "$gt": 42 } }] })
+            }
+            other => panic!("unexpected operation: {other:?}"),
+        }
+
+        let (_, descending) = parse_collection_query_with_collection(
+            "db",
+            "orders",
+            "db.orders.find({}).sort({ _id: -1 })",
+        )
+        .unwrap();
+        match descending.keyset_after(&last_id) {
+            Some(QueryOperation::Find { filter, .. }) => {
+                assert_eq!(filter, doc! { "_id": { "$lt": 42 } })
+            }
+            other => panic!("unexpected operation: {other:?}"),
+        }
+
+        for text in [
+            "db.orders.find({})",
+            "db.orders.find({}).sort({ _id: 1, name: 1 })",
+            "db.orders.find({}).sort({ name: 1 })",
+            "db.orders.find({}).sort({ _id: 1 }).skip(5)",
+            "db.orders.aggregate([])",
+        ] {
+            let (_, operation) =
+                parse_collection_query_with_collection("db", "orders", text).unwrap();
+            assert!(operation.keyset_after(&last_id).is_none(), "{text}");
+        }
+    }
+
+    #[test]
+    fn truncates_results_to_render_cap() {
+        let mut result = QueryResult::Documents((0..5).map(Bson::Int32).collect());
+        assert_eq!(result.truncate_to(0), None);
+        assert_eq!(result.truncate_to(5), None);
+        assert_eq!(result.truncate_to(2), Some(5));
+        assert!(matches!(&result, QueryResult::Documents(values) if values.len() == 2));
+
+        let mut count = QueryResult::Count { value: Bson::Int64(10) };
+        assert_eq!(count.truncate_to(1), None);
+    }
+
+    #[test]
+    fn builds_array_element_removal_updates() {
+        let (mark, pull) = array_element_removal_updates("order.items.2").expect("updates");
+        let marker = mark.get_document("$set").unwrap().get_document("order.items.2").unwrap();
+        assert!(marker.get_object_id("__oxide_mongo_removed").is_ok());
+        assert_eq!(pull, doc! { "$pull": { "order.items": marker.clone() } });
+
+        assert!(array_element_removal_updates("items").is_none());
+        assert!(array_element_removal_updates("items.name").is_none());
+    }
+
+    #[test]
+    fn applies_projection_presets_to_find() {
+        let fields = parse_projection_fields("name, status  address.city,name").unwrap();
+        assert_eq!(fields, vec!["name", "status", "address.city"]);
+        assert!(parse_projection_fields(" , ").is_err());
+        assert!(parse_projection_fields("$name").is_err());
+
+        let projection = projection_from_fields(&fields);
+        assert_eq!(projection, doc! { "_id": 0, "name": 1, "status": 1, "address.city": 1 });
+        let with_id = projection_from_fields(&[String::from("_id"), String::from("name")]);
+        assert_eq!(with_id, doc! { "_id": 1, "name": 1 });
+
+        match parse("db.users.find({})").with_default_projection(projection.clone()) {
+            QueryOperation::Find { options, .. } => {
+                assert_eq!(options.unwrap().projection, Some(projection.clone()));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+        match parse("db.users.find({}, { age: 1 })").with_default_projection(projection) {
+            QueryOperation::Find { options, .. } => {
+                assert_eq!(options.unwrap().projection, Some(doc! { "age": 1 }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn find_and_find_one_accept_projection_argument() {
+        match parse("db.users.find({ active: true }, { name: 1, _id: 0 })") {
+            QueryOperation::Find { filter, options } => {
+                assert_eq!(filter, doc! { "active": true });
+                assert_eq!(options.unwrap().projection, Some(doc! { "name": 1, "_id": 0 }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+        match parse("db.users.findOne({}, { name: 1 })") {
+            QueryOperation::FindOne { projection, .. } => {
+                assert_eq!(projection, Some(doc! { "name": 1 }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+
+        for query in ["db.users.find({}, 5)", "db.users.findOne({}, 'name')"] {
+            assert!(parse_collection_query_with_collection("testdb", "users", query).is_err());
+        }
+        assert!(
+            parse_collection_query_with_collection(
+                "testdb",
+                "users",
+                "db.users.find({}, {}, {}, {})"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn find_cursor_chain_accumulates_sort_limit_skip_and_projection() {
+        match parse("db.users.find({}).sort({ age: -1 }).limit(10).skip(5).projection({ name: 1 })")
+        {
+            QueryOperation::Find { options, .. } => {
+                let options = options.expect("chain options");
+                assert_eq!(options.sort, Some(doc! { "age": -1 }));
+                assert_eq!(options.limit, Some(10));
+                assert_eq!(options.skip, Some(5));
+                assert_eq!(options.projection, Some(doc! { "name": 1 }));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+
+        assert_eq!(resolve_effective_limit(50, Some(10)), 10);
+        assert_eq!(resolve_effective_limit(0, Some(10)), 10);
+        assert_eq!(resolve_effective_limit(50, None), 50);
+
+        for query in [
+            "db.users.find({}).projection()",
+            "db.users.find({}).projection(5)",
+            "db.users.find({}).toArray()",
+            "db.users.countDocuments({}).limit(5)",
+        ] {
+            assert!(parse_collection_query_with_collection("testdb", "users", query).is_err());
+        }
+    }
+
+    #[test]
+    fn builds_validation_coll_mod_command() {
+        let template = validation_rules_document(&Document::new());
+        assert_eq!(template.get_str("validationLevel"), Ok("strict"));
+        assert!(template.get_document("validator").unwrap().contains_key("$jsonSchema"));
+
+        let options = doc! {
+            "validator": { "age": { "$gte": 0 } },
+            "validationLevel": "moderate",
+            "validationAction": "warn",
+        };
+        let rules = validation_rules_document(&options);
+        assert_eq!(
+            validation_coll_mod_command("users", &rules).unwrap(),
+            doc! {
+                "collMod": "users",
+                "validator": { "age": { "$gte": 0 } },
+                "validationLevel": "moderate",
+                "validationAction": "warn",
+            }
+        );
+
+        assert!(validation_coll_mod_command("users", &doc! { "capped": true }).is_err());
+        assert!(validation_coll_mod_command("users", &doc! { "validator": 1 }).is_err());
+    }
+
     #[test]
     fn parses_find_chain_count_with_options() {
         let operation = parse(
@@ -5438,6 +7358,138 @@ mod tests {
         }
     }
 
+    fn parse_update_document(source: &str) -> Document {
+        match parse(source) {
+            QueryOperation::UpdateOne { update, .. }
+            | QueryOperation::UpdateMany { update, .. } => match update {
+                UpdateModificationsSpec::Document(document) => document,
+                other => panic!("expected update document, got {:?}", other),
+            },
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_update_with_current_date_operator() {
+        let document = parse_update_document(
+            "db.a.updateOne({_id: 1}, {$currentDate: {updatedAt: true, stamp: {$type: \"timestamp\"}}})",
+        );
+
+        assert_eq!(
+            document,
+            doc! { "$currentDate": { "updatedAt": true, "stamp": { "$type": "timestamp" } } }
+        );
+    }
+
+    #[test]
+    fn parses_update_with_inc_and_set_operators() {
+        let document = parse_update_document(
+            "db.a.updateMany({}, {$inc: {views: 1, score: -2.5}, $set: {state: \"seen\"}})",
+        );
+
+        assert_eq!(
+            document,
+            doc! { "$inc": { "views": 1i32, "score": -2.5 }, "$set": { "state": "seen" } }
+        );
+    }
+
+    #[test]
+    fn parses_update_with_push_modifiers() {
+        let document = parse_update_document(
+            "db.a.updateOne({_id: 1}, {$push: {scores: {$each: [90, 85], $sort: -1, $slice: 3}}})",
+        );
+
+        assert_eq!(
+            document,
+            doc! { "$push": { "scores": { "$each": [90i32, 85i32], "$sort": -1i32, "$slice": 3i32 } } }
+        );
+    }
+
+    #[test]
+    fn parses_update_with_pull_condition() {
+        let document = parse_update_document(
+            "db.a.updateMany({}, {$pull: {results: {score: {$lt: 5}}, tags: \"old\"}})",
+        );
+
+        assert_eq!(
+            document,
+            doc! { "$pull": { "results": { "score": { "$lt": 5i32 } }, "tags": "old" } }
+        );
+    }
+
+    #[test]
+    fn parses_update_with_add_to_set_each() {
+        let document = parse_update_document(
+            "db.a.updateOne({_id: 1}, {$addToSet: {tags: {$each: [\"a\", \"b\"]}}})",
+        );
+
+        assert_eq!(document, doc! { "$addToSet": { "tags": { "$each": ["a", "b"] } } });
+    }
+
+    #[test]
+    fn parses_update_with_matching_array_filters() {
+        let operation = parse(
+            "db.a.updateMany({}, {$set: {\"grades.$[elem].passed\": true, \"tags.$[]\": \"x\"}}, {arrayFilters: [{\"elem.score\": {$gte: 60}}]})",
+        );
+
+        match operation {
+            QueryOperation::UpdateMany { options, .. } => {
+                let options = options.expect("options expected");
+                assert_eq!(
+                    options.array_filters,
+                    Some(vec![doc! { "elem.score": { "$gte": 60i32 } }])
+                );
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_positional_identifier_without_array_filter() {
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.a.updateOne({}, {$inc: {\"items.$[item].qty\": 1}})",
+        )
+        .expect_err("missing arrayFilters must fail");
+        assert!(error.contains("$[item]"), "{error}");
+
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.a.updateOne({}, {$inc: {\"items.$[item].qty\": 1}}, {arrayFilters: [{\"other.qty\": 0}]})",
+        )
+        .expect_err("mismatched arrayFilters must fail");
+        assert!(error.contains("$[item]"), "{error}");
+    }
+
+    #[test]
+    fn accepts_array_filter_identifiers_inside_logical_operators() {
+        let operation = parse(
+            "db.a.updateMany({}, {$set: {\"items.$[elem].flag\": true}}, {arrayFilters: [{$or: [{\"elem.a\": 1}, {\"elem.b\": 2}]}]})",
+        );
+        assert!(matches!(operation, QueryOperation::UpdateMany { .. }));
+
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.a.updateMany({}, {$set: {\"items.$[elem].flag\": true}}, {arrayFilters: [{$and: [{\"other.a\": 1}]}]})",
+        )
+        .expect_err("identifier inside $and must still match");
+        assert!(error.contains("$[elem]"), "{error}");
+    }
+
+    #[test]
+    fn rejects_unused_array_filter_identifier() {
+        let error = parse_collection_query_with_collection(
+            "testdb",
+            "users",
+            "db.a.findOneAndUpdate({}, {$set: {\"items.$[]\": 0}}, {arrayFilters: [{\"item.qty\": 0}]})",
+        )
+        .expect_err("unused arrayFilters must fail");
+        assert!(error.contains("'item'"), "{error}");
+    }
+
     #[test]
     fn parses_update_one_with_number_long_preserves_int64() {
         let operation = parse(
@@ -5625,4 +7677,197 @@ mod tests {
             other => panic!("unexpected operation: {:?}", other),
         }
     }
+
+    #[test]
+    fn drop_index_target_accepts_name_or_key_spec() {
+        let keys = doc! { "status": 1i32, "createdAt": -1i32 };
+
+        assert_eq!(
+            resolve_drop_index_target(
+                " status_1_createdAt_-1 ",
+                "status_1_createdAt_-1",
+                Some(&keys)
+            ),
+            Some(Bson::String("status_1_createdAt_-1".to_string()))
+        );
+        assert_eq!(
+            resolve_drop_index_target(
+                "{status: 1, createdAt: -1}",
+                "status_1_createdAt_-1",
+                Some(&keys)
+            ),
+            Some(Bson::Document(keys.clone()))
+        );
+        assert_eq!(
+            resolve_drop_index_target(
+                "{createdAt: -1, status: 1}",
+                "status_1_createdAt_-1",
+                Some(&keys)
+            ),
+            None
+        );
+        assert_eq!(
+            resolve_drop_index_target("status_1", "status_1_createdAt_-1", Some(&keys)),
+            None
+        );
+        assert_eq!(resolve_drop_index_target("", "status_1_createdAt_-1", Some(&keys)), None);
+    }
+
+    #[test]
+    fn index_builder_generates_parseable_create_index() {
+        let spec = IndexBuilderSpec {
+            keys: vec![
+                ("status".to_string(), IndexKeyKind::Ascending),
+                (" ".to_string(), IndexKeyKind::Descending),
+                ("createdAt".to_string(), IndexKeyKind::Descending),
+            ],
+            unique: true,
+            partial_filter: "{archived: false}".to_string(),
+            ..IndexBuilderSpec::default()
+        };
+
+        let query = spec.to_shell("users").expect("spec should be valid");
+        let (_, operation) = parse_collection_query_with_collection("testdb", "users", &query)
+            .expect("generated query should parse");
+        match operation {
+            QueryOperation::DatabaseCommand { command, .. } => {
+                let indexes = command.get_array("indexes").expect("indexes");
+                let Some(Bson::Document(index)) = indexes.first() else {
+                    panic!("index spec expected");
+                };
+                assert!(index_keys_match(
+                    index.get_document("key").unwrap(),
+                    &doc! { "status": 1i32, "createdAt": -1i32 }
+                ));
+                assert_eq!(index.get_str("name").unwrap(), "status_1_createdAt_-1");
+                assert!(index.get_bool("unique").unwrap());
+                assert_eq!(
+                    index.get_document("partialFilterExpression").unwrap(),
+                    &doc! { "archived": false }
+                );
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn index_builder_validates_keys_and_ttl() {
+        let empty = IndexBuilderSpec::default();
+        assert!(empty.to_shell("users").is_err());
+
+        let compound_ttl = IndexBuilderSpec {
+            keys: vec![
+                ("a".to_string(), IndexKeyKind::Ascending),
+                ("b".to_string(), IndexKeyKind::Ascending),
+            ],
+            ttl_seconds: "60".to_string(),
+            ..IndexBuilderSpec::default()
+        };
+        assert!(compound_ttl.to_shell("users").is_err());
+
+        let ttl = IndexBuilderSpec {
+            keys: vec![("expiresAt".to_string(), IndexKeyKind::Ascending)],
+            ttl_seconds: "3600".to_string(),
+            name: "expiry".to_string(),
+            ..IndexBuilderSpec::default()
+        };
+        let query = ttl.to_shell("sessions").expect("ttl spec should be valid");
+        assert!(query.contains("\"expireAfterSeconds\": 3600"), "{query}");
+        assert!(query.contains("\"expiry\""), "{query}");
+    }
+
+    #[test]
+    fn index_builder_detects_existing_key_pattern() {
+        let existing = vec![
+            doc! { "v": 2i32, "key": { "_id": 1i32 }, "name": "_id_" },
+            doc! { "v": 2i32, "key": { "status": 1i32, "createdAt": -1.0 }, "name": "by_status" },
+        ];
+        let mut spec = IndexBuilderSpec {
+            keys: vec![
+                ("status".to_string(), IndexKeyKind::Ascending),
+                ("createdAt".to_string(), IndexKeyKind::Descending),
+            ],
+            ..IndexBuilderSpec::default()
+        };
+
+        assert_eq!(spec.find_matching_index(&existing), Some("by_status"));
+
+        spec.keys[1].1 = IndexKeyKind::Ascending;
+        assert_eq!(spec.find_matching_index(&existing), None);
+    }
+
+    #[test]
+    fn aggregate_write_target_resolves_out_and_merge_namespaces() {
+        let target =
+            |stage: Document| aggregate_write_target(&[doc! { "$match": {} }, stage], "app");
+
+        assert_eq!(
+            target(doc! { "$out": "archive" }),
+            Some(AggregateWriteTarget {
+                stage: "$out",
+                db_name: "app".into(),
+                collection: "archive".into()
+            })
+        );
+        assert_eq!(
+            target(doc! { "$out": { "db": "reports", "coll": "daily" } }),
+            Some(AggregateWriteTarget {
+                stage: "$out",
+                db_name: "reports".into(),
+                collection: "daily".into()
+            })
+        );
+        assert_eq!(
+            target(doc! { "$merge": "totals" }),
+            Some(AggregateWriteTarget {
+                stage: "$merge",
+                db_name: "app".into(),
+                collection: "totals".into()
+            })
+        );
+        assert_eq!(
+            target(
+                doc! { "$merge": { "into": { "db": "reports", "coll": "totals" }, "on": "_id" } }
+            ),
+            Some(AggregateWriteTarget {
+                stage: "$merge",
+                db_name: "reports".into(),
+                collection: "totals".into()
+            })
+        );
+        assert_eq!(target(doc! { "$limit": 5 }), None);
+
+        let command = QueryOperation::DatabaseCommand {
+            db: String::from("app"),
+            command: doc! { "aggregate": "orders", "pipeline": [{ "$out": "archive" }], "cursor": {} },
+        };
+        assert_eq!(
+            command.aggregate_write_target("app").map(|target| target.collection),
+            Some(String::from("archive"))
+        );
+        let find = QueryOperation::Find { filter: doc! {}, options: None };
+        assert_eq!(find.aggregate_write_target("app"), None);
+    }
+
+    #[test]
+    fn database_names_follow_mongodb_rules() {
+        assert!(validate_database_name("analytics_2024").is_ok());
+        assert!(validate_database_name("").is_err());
+        for invalid in ["my db", "a.b", "a/b", "a\\b", "a$b", "a\"b", "a\0b"] {
+            assert!(validate_database_name(invalid).is_err(), "{invalid:?} should be rejected");
+        }
+        assert!(validate_database_name(&"d".repeat(63)).is_ok());
+        assert!(validate_database_name(&"d".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn collection_names_follow_mongodb_rules() {
+        assert!(validate_collection_name("app", "orders.archive").is_ok());
+        assert!(validate_collection_name("app", "").is_err());
+        assert!(validate_collection_name("app", "or$ders").is_err());
+        assert!(validate_collection_name("app", "a\0b").is_err());
+        assert!(validate_collection_name("app", "system.profile").is_err());
+        assert!(validate_collection_name("app", &"c".repeat(251)).is_ok());
+        assert!(validate_collection_name("app", &"c".repeat(252)).is_err());
+    }
 }
@@ -8,10 +8,12 @@ use mongodb::bson::{
     Binary, Bson, DateTime, Decimal128, Document, JavaScriptCodeWithScope, Regex,
     Timestamp as BsonTimestamp, oid::ObjectId,
 };
+use mongodb::options::{UpdateModifications, WriteModel};
 use serde_json::Value;
 use uuid::Uuid;
 
 use crate::i18n::{tr, tr_format};
+use crate::mongo::query::{QueryOperation, QueryResult, UpdateModificationsSpec, WatchTarget};
 
 pub fn format_bson_scalar(value: &Bson) -> (String, String) {
     match value {
@@ -1253,7 +1255,7 @@ fn parse_u32_argument(value: &str, context: &str, field: &str) -> Result<u32, St
     }
 }
 
-fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+pub(crate) fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
     let cleaned: String = value.chars().filter(|ch| !ch.is_whitespace()).collect();
     if cleaned.len() % 2 != 0 {
         return Err(String::from(tr("Hex string must contain an even number of characters.")));
@@ -1303,6 +1305,276 @@ pub fn format_shell_value(value: &Bson) -> String {
     }
 }
 
+/// Renders a result as canonical extended JSON (`$oid`, `$date`, `$numberLong`, ...) indented
+/// by two spaces. Document lists become a single array.
+pub fn format_extended_json(result: &QueryResult) -> String {
+    let value = match result {
+        QueryResult::Documents(values) | QueryResult::Indexes(values) => {
+            Bson::Array(values.clone())
+        }
+        QueryResult::Distinct { values, .. } => Bson::Array(values.clone()),
+        QueryResult::SingleDocument { document } => Bson::Document(document.clone()),
+        QueryResult::Count { value } => value.clone(),
+    };
+    serde_json::to_string_pretty(&value.into_canonical_extjson()).unwrap_or_default()
+}
+
+/// Renders an operation as an async call of the Node.js driver, e.g.
+/// `const result = await db.collection('users').find({...}).toArray();`. Arguments holding
+/// types plain JSON cannot express go through `EJSON.deserialize` of canonical extended JSON.
+pub fn format_node_driver_call(
+    db_name: &str,
+    collection: &str,
+    operation: &QueryOperation,
+) -> Result<String, String> {
+    let mut call = NodeCall::default();
+    let target = format!("db.collection({})", js_string(collection));
+    let update_bson = |update: &UpdateModificationsSpec| match update {
+        UpdateModificationsSpec::Document(document) => Bson::Document(document.clone()),
+        UpdateModificationsSpec::Pipeline(stages) => documents_bson(stages),
+    };
+
+    let (receiver, method, suffix) = match operation {
+        QueryOperation::Find { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "find", ".toArray()")
+        }
+        QueryOperation::FindOne { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "findOne", "")
+        }
+        QueryOperation::Count { filter, .. } | QueryOperation::CountDocuments { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "countDocuments", "")
+        }
+        QueryOperation::EstimatedDocumentCount { .. } => (target, "estimatedDocumentCount", ""),
+        QueryOperation::Distinct { field, filter, .. } => {
+            call.push(Bson::String(field.clone()));
+            call.push(Bson::Document(filter.clone()));
+            (target, "distinct", "")
+        }
+        QueryOperation::Aggregate { pipeline, .. } => {
+            call.push(documents_bson(pipeline));
+            (target, "aggregate", ".toArray()")
+        }
+        QueryOperation::Watch { pipeline, target: watch_target, .. } => {
+            call.push(documents_bson(pipeline));
+            call.push_options(operation.driver_options());
+            let receiver = match watch_target {
+                WatchTarget::Collection => target,
+                WatchTarget::Database => String::from("db"),
+            };
+            let code = format!("const changeStream = {receiver}.watch({});", call.arguments());
+            return Ok(call.finish(code));
+        }
+        QueryOperation::InsertOne { document, .. } => {
+            call.push(Bson::Document(document.clone()));
+            (target, "insertOne", "")
+        }
+        QueryOperation::InsertMany { documents, .. } => {
+            call.push(documents_bson(documents));
+            (target, "insertMany", "")
+        }
+        QueryOperation::BulkWrite { models, .. } => {
+            let operations = models.iter().map(node_write_model).collect::<Result<Vec<_>, _>>()?;
+            call.push(documents_bson(&operations));
+            (target, "bulkWrite", "")
+        }
+        QueryOperation::DeleteOne { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "deleteOne", "")
+        }
+        QueryOperation::DeleteMany { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "deleteMany", "")
+        }
+        QueryOperation::UpdateOne { filter, update, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            call.push(update_bson(update));
+            (target, "updateOne", "")
+        }
+        QueryOperation::UpdateMany { filter, update, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            call.push(update_bson(update));
+            (target, "updateMany", "")
+        }
+        QueryOperation::ReplaceOne { filter, replacement, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            call.push(Bson::Document(replacement.clone()));
+            (target, "replaceOne", "")
+        }
+        QueryOperation::FindOneAndUpdate { filter, update, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            call.push(update_bson(update));
+            (target, "findOneAndUpdate", "")
+        }
+        QueryOperation::FindOneAndReplace { filter, replacement, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            call.push(Bson::Document(replacement.clone()));
+            (target, "findOneAndReplace", "")
+        }
+        QueryOperation::FindOneAndDelete { filter, .. } => {
+            call.push(Bson::Document(filter.clone()));
+            (target, "findOneAndDelete", "")
+        }
+        QueryOperation::ListIndexes => (target, "listIndexes", ".toArray()"),
+        QueryOperation::MapReduce { map, reduce, options } => {
+            // The driver dropped its mapReduce helper, so the command is sent as is.
+            let mut command = Document::new();
+            command.insert("mapReduce", collection);
+            command.insert("map", map.as_str());
+            command.insert("reduce", reduce.as_str());
+            command.extend(options.clone());
+            call.push(Bson::Document(command));
+            (String::from("db"), "command", "")
+        }
+        QueryOperation::DatabaseCommand { db, command } => {
+            call.push(Bson::Document(command.clone()));
+            let receiver = if db == db_name {
+                String::from("db")
+            } else {
+                format!("client.db({})", js_string(db))
+            };
+            (receiver, "command", "")
+        }
+        QueryOperation::ReplicaSetCommand { .. } => {
+            return Err(String::from(tr("Replica set helpers have no Node.js driver equivalent.")));
+        }
+    };
+
+    call.push_options(operation.driver_options());
+    let code = format!("const result = await {receiver}.{method}({}){suffix};", call.arguments());
+    Ok(call.finish(code))
+}
+
+#[derive(Default)]
+struct NodeCall {
+    arguments: Vec<String>,
+    uses_ejson: bool,
+}
+
+impl NodeCall {
+    fn push(&mut self, value: Bson) {
+        let rendered = if is_plain_json(&value) {
+            value.into_relaxed_extjson().to_string()
+        } else {
+            self.uses_ejson = true;
+            format!("EJSON.deserialize({})", value.into_canonical_extjson())
+        };
+        self.arguments.push(rendered);
+    }
+
+    fn push_options(&mut self, options: Document) {
+        if !options.is_empty() {
+            self.push(Bson::Document(options));
+        }
+    }
+
+    fn arguments(&self) -> String {
+        self.arguments.join(", ")
+    }
+
+    fn finish(self, code: String) -> String {
+        if self.uses_ejson { format!("const {{ EJSON }} = require('bson');\n{code}") } else { code }
+    }
+}
+
+fn is_plain_json(value: &Bson) -> bool {
+    match value {
+        Bson::Document(document) => document.values().all(is_plain_json),
+        Bson::Array(items) => items.iter().all(is_plain_json),
+        Bson::Double(number) => number.is_finite(),
+        Bson::String(_) | Bson::Boolean(_) | Bson::Int32(_) | Bson::Null => true,
+        _ => false,
+    }
+}
+
+fn documents_bson(documents: &[Document]) -> Bson {
+    Bson::Array(documents.iter().cloned().map(Bson::Document).collect())
+}
+
+fn js_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// One entry of the driver's `bulkWrite` array, e.g. `{ updateOne: { filter, update } }`.
+fn node_write_model(model: &WriteModel) -> Result<Document, String> {
+    let update_bson = |update: &UpdateModifications| match update {
+        UpdateModifications::Document(document) => Ok(Bson::Document(document.clone())),
+        UpdateModifications::Pipeline(stages) => Ok(documents_bson(stages)),
+        _ => Err(String::from(tr("Unsupported update in bulkWrite."))),
+    };
+    let mut body = Document::new();
+    let name = match model {
+        WriteModel::InsertOne(model) => {
+            body.insert("document", model.document.clone());
+            "insertOne"
+        }
+        WriteModel::UpdateOne(model) => {
+            body.insert("filter", model.filter.clone());
+            body.insert("update", update_bson(&model.update)?);
+            insert_write_model_options(
+                &mut body,
+                &model.array_filters,
+                &model.collation,
+                &model.hint,
+            );
+            insert_some(&mut body, "upsert", model.upsert.map(Bson::Boolean));
+            insert_some(&mut body, "sort", model.sort.clone().map(Bson::Document));
+            "updateOne"
+        }
+        WriteModel::UpdateMany(model) => {
+            body.insert("filter", model.filter.clone());
+            body.insert("update", update_bson(&model.update)?);
+            insert_write_model_options(
+                &mut body,
+                &model.array_filters,
+                &model.collation,
+                &model.hint,
+            );
+            insert_some(&mut body, "upsert", model.upsert.map(Bson::Boolean));
+            "updateMany"
+        }
+        WriteModel::ReplaceOne(model) => {
+            body.insert("filter", model.filter.clone());
+            body.insert("replacement", model.replacement.clone());
+            insert_write_model_options(&mut body, &None, &model.collation, &model.hint);
+            insert_some(&mut body, "upsert", model.upsert.map(Bson::Boolean));
+            insert_some(&mut body, "sort", model.sort.clone().map(Bson::Document));
+            "replaceOne"
+        }
+        WriteModel::DeleteOne(model) => {
+            body.insert("filter", model.filter.clone());
+            insert_write_model_options(&mut body, &None, &model.collation, &model.hint);
+            "deleteOne"
+        }
+        WriteModel::DeleteMany(model) => {
+            body.insert("filter", model.filter.clone());
+            insert_write_model_options(&mut body, &None, &model.collation, &model.hint);
+            "deleteMany"
+        }
+        _ => return Err(String::from(tr("Unsupported operation in bulkWrite."))),
+    };
+    Ok(Document::from_iter([(String::from(name), Bson::Document(body))]))
+}
+
+fn insert_write_model_options(
+    body: &mut Document,
+    array_filters: &Option<Vec<Bson>>,
+    collation: &Option<Document>,
+    hint: &Option<Bson>,
+) {
+    insert_some(body, "arrayFilters", array_filters.clone().map(Bson::Array));
+    insert_some(body, "collation", collation.clone().map(Bson::Document));
+    insert_some(body, "hint", hint.clone());
+}
+
+fn insert_some(document: &mut Document, key: &str, value: Option<Bson>) {
+    if let Some(value) = value {
+        document.insert(key, value);
+    }
+}
+
 pub fn bson_type_name(bson: &Bson) -> &'static str {
     match bson {
         Bson::Document(_) => "Document",
@@ -1541,6 +1813,13 @@ mod tests {
             Bson::ObjectId(oid)
         );
 
+        for source in ["ObjectId()", "new ObjectId()", "{ \"_id\": ObjectId() }"] {
+            let first = parse_shell_bson_value(source).unwrap();
+            let second = parse_shell_bson_value(source).unwrap();
+            assert_ne!(first, second, "{source} should generate a fresh id on every parse");
+        }
+        assert!(matches!(parse_shell_bson_value("ObjectId()").unwrap(), Bson::ObjectId(_)));
+
         // Checking ISODate in full ISO 8601 format and date-only format
         let dt_str = "2021-03-25T11:26:20Z";
         let dt = DateTime::from_millis(1616671580000);
@@ -1768,4 +2047,51 @@ mod tests {
             ("Binary(len=3, subtype=Generic)".to_string(), "Binary".to_string())
         );
     }
+
+    #[test]
+    fn extended_json_is_canonical_and_indented_by_two_spaces() {
+        let oid = ObjectId::from_str("605c7d5c5b5d7b5d7b5d7b5d").unwrap();
+        let result = QueryResult::Documents(vec![Bson::Document(doc! {
+            "_id": oid,
+            "at": DateTime::from_millis(0),
+            "n": 5_i64,
+        })]);
+
+        assert_eq!(
+            format_extended_json(&result),
+            "[\n  {\n    \"_id\": {\n      \"$oid\": \"605c7d5c5b5d7b5d7b5d7b5d\"\n    },\n    \"at\": {\n      \"$date\": {\n        \"$numberLong\": \"0\"\n      }\n    },\n    \"n\": {\n      \"$numberLong\": \"5\"\n    }\n  }\n]"
+        );
+        assert_eq!(
+            format_extended_json(&QueryResult::Count { value: Bson::Int32(3) }),
+            "{\n  \"$numberInt\": \"3\"\n}"
+        );
+    }
+
+    #[test]
+    fn node_driver_call_carries_arguments_and_options() {
+        use crate::mongo::query::parse_collection_query_with_collection;
+
+        let node = |query: &str| {
+            let (collection, operation) =
+                parse_collection_query_with_collection("app", "users", query).unwrap();
+            format_node_driver_call("app", &collection, &operation)
+        };
+
+        assert_eq!(
+            node("db.users.find({ age: { $gt: 30 } }).sort({ name: 1 }).limit(10)").unwrap(),
+            "const result = await db.collection('users').find({\"age\":{\"$gt\":30}}, {\"sort\":{\"name\":1},\"limit\":10}).toArray();"
+        );
+        assert_eq!(
+            node(
+                "db.users.updateOne({ name: 'Ann' }, { $set: { active: true } }, { upsert: true })"
+            )
+            .unwrap(),
+            "const result = await db.collection('users').updateOne({\"name\":\"Ann\"}, {\"$set\":{\"active\":true}}, {\"upsert\":true});"
+        );
+        assert_eq!(
+            node("db.users.deleteOne({ _id: ObjectId('605c7d5c5b5d7b5d7b5d7b5d') })").unwrap(),
+            "const { EJSON } = require('bson');\nconst result = await db.collection('users').deleteOne(EJSON.deserialize({\"_id\":{\"$oid\":\"605c7d5c5b5d7b5d7b5d7b5d\"}}));"
+        );
+        assert!(node("rs.status()").is_err());
+    }
 }
@@ -16,16 +16,21 @@ struct PendingKey {
 }
 
 /// Wrap unquoted object keys (e.g. `_id`, `$or`) with double quotes so the input becomes
-/// valid JSON while leaving strings, regex literals and already quoted keys untouched.
+/// valid JSON while leaving strings, regex literals, `function` literals and already quoted keys
+/// untouched.
 pub fn quote_unquoted_keys(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
     let mut output = String::with_capacity(input.len());
     let mut state = ScanState::Normal;
     let mut string_escape = false;
     let mut regex_escape = false;
     let mut pending: Option<PendingKey> = None;
     let mut prev_non_ws: Option<char> = None;
+    let mut index = 0usize;
 
-    for ch in input.chars() {
+    while index < chars.len() {
+        let ch = chars[index];
+        index += 1;
         match state {
             ScanState::SingleString => {
                 output.push(ch);
@@ -88,6 +93,15 @@ pub fn quote_unquoted_keys(input: &str) -> String {
                     }
                 }
 
+                if ch == 'f'
+                    && let Some(end) = function_literal_end(&chars, index - 1)
+                {
+                    output.extend(&chars[index - 1..end]);
+                    prev_non_ws = Some('}');
+                    index = end;
+                    continue;
+                }
+
                 match ch {
                     c if c.is_whitespace() => {
                         output.push(c);
@@ -156,6 +170,74 @@ fn flush_key(output: &mut String, candidate: &PendingKey, allow_quoting: bool) -
     }
 }
 
+/// Returns the index just past the closing brace when a `function` literal starts at `start`.
+/// Strings and comments inside the body are skipped so their braces do not count.
+fn function_literal_end(chars: &[char], start: usize) -> Option<usize> {
+    const KEYWORD: &str = "function";
+    let keyword_end = start + KEYWORD.len();
+    if !chars.get(start..keyword_end)?.iter().copied().eq(KEYWORD.chars()) {
+        return None;
+    }
+    if start > 0 && is_key_char(chars[start - 1]) {
+        return None;
+    }
+    if chars.get(keyword_end).is_some_and(|ch| is_key_char(*ch)) {
+        return None;
+    }
+    // Only `function (` or `function name(` starts a literal; `{ function: 1 }` is a key.
+    let mut index = keyword_end;
+    while chars.get(index).is_some_and(|ch| ch.is_whitespace() || is_key_char(*ch)) {
+        index += 1;
+    }
+    if chars.get(index) != Some(&'(') {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut string_delim: Option<char> = None;
+    let mut escape = false;
+    while index < chars.len() {
+        let ch = chars[index];
+        index += 1;
+        if let Some(delim) = string_delim {
+            if escape {
+                escape = false;
+            } else if ch == '\\' {
+                escape = true;
+            } else if ch == delim {
+                string_delim = None;
+            }
+            continue;
+        }
+        match ch {
+            '\'' | '"' | '`' => string_delim = Some(ch),
+            '/' if chars.get(index) == Some(&'/') => {
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+            }
+            '/' if chars.get(index) == Some(&'*') => {
+                index += 1;
+                while index < chars.len()
+                    && !(chars[index] == '*' && chars.get(index + 1) == Some(&'/'))
+                {
+                    index += 1;
+                }
+                index = (index + 2).min(chars.len());
+            }
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 fn can_start_regex(prev: Option<char>) -> bool {
     match prev {
         None => true,
@@ -428,6 +510,18 @@ mod tests {
         assert_eq!(quote_unquoted_keys(input), expected);
     }
 
+    #[test]
+    fn leaves_function_literals_untouched() {
+        let input = "{ finalize: function(key, value) { var out = {total: value}; return out; }, limit: 5 }";
+        let expected = "{ \"finalize\": function(key, value) { var out = {total: value}; return out; }, \"limit\": 5 }";
+        assert_eq!(quote_unquoted_keys(input), expected);
+
+        let input = "function() { emit(this.a, {n: '}'}); }";
+        assert_eq!(quote_unquoted_keys(input), input);
+        assert_eq!(quote_unquoted_keys("{ functions: 1 }"), "{ \"functions\": 1 }");
+        assert_eq!(quote_unquoted_keys("{ function: {a: 1} }"), "{ \"function\": {\"a\": 1} }");
+    }
+
     #[test]
     fn test_complex_mongo_query() {
         let input = r#"{
@@ -197,8 +197,7 @@ impl App {
     }
 
     pub(crate) fn test_query_timeout(&self) -> Option<Duration> {
-        let secs = self.settings.query_timeout_secs;
-        if secs == 0 { None } else { Some(Duration::from_secs(secs)) }
+        self.settings.query_timeout_duration()
     }
 
     #[allow(dead_code)]
@@ -1,17 +1,24 @@
 use crate::fonts;
-use crate::i18n::Language;
+use crate::i18n::{Language, tr, tr_format};
+use iced::keyboard;
+use iced::keyboard::key::Named;
 use iced::widget::button;
 use iced::{Color, Shadow, border};
 use log::LevelFilter;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 use std::sync::{OnceLock, RwLock, RwLockWriteGuard};
+use std::time::Duration;
 
 pub const SETTINGS_FILE_NAME: &str = "settings.toml";
 pub const DEFAULT_LOG_FILE_NAME: &str = "oxide_mongo.log";
+pub const COLLECTION_PAGING_FILE_NAME: &str = "collection_paging.toml";
+pub const PROJECTION_PRESETS_FILE_NAME: &str = "projection_presets.toml";
+pub const SESSION_FILE_NAME: &str = "session.json";
 
 static GLOBAL_SETTINGS: OnceLock<RwLock<AppSettings>> = OnceLock::new();
 
@@ -104,16 +111,327 @@ impl Default for LogLevel {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TabTitleFormat {
+    #[default]
+    Collection,
+    DatabaseCollection,
+    ConnectionCollection,
+    ConnectionDatabaseCollection,
+}
+
+impl TabTitleFormat {
+    pub const fn label(self) -> &'static str {
+        match self {
+            TabTitleFormat::Collection => "Collection",
+            TabTitleFormat::DatabaseCollection => "Database / Collection",
+            TabTitleFormat::ConnectionCollection => "Connection / Collection",
+            TabTitleFormat::ConnectionDatabaseCollection => "Connection / Database / Collection",
+        }
+    }
+
+    pub const fn includes_connection(self) -> bool {
+        matches!(
+            self,
+            TabTitleFormat::ConnectionCollection | TabTitleFormat::ConnectionDatabaseCollection
+        )
+    }
+
+    pub const fn includes_database(self) -> bool {
+        matches!(
+            self,
+            TabTitleFormat::DatabaseCollection | TabTitleFormat::ConnectionDatabaseCollection
+        )
+    }
+}
+
+impl fmt::Display for TabTitleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(tr(self.label()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeSortMode {
+    #[default]
+    Name,
+    Size,
+}
+
+impl TreeSortMode {
+    pub const fn label(self) -> &'static str {
+        match self {
+            TreeSortMode::Name => "By name",
+            TreeSortMode::Size => "By size (largest first)",
+        }
+    }
+}
+
+impl fmt::Display for TreeSortMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(tr(self.label()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeoutUnit {
+    Milliseconds,
+    #[default]
+    Seconds,
+}
+
+impl TimeoutUnit {
+    pub const fn label(self) -> &'static str {
+        match self {
+            TimeoutUnit::Milliseconds => "ms",
+            TimeoutUnit::Seconds => "s",
+        }
+    }
+
+    pub const fn to_duration(self, value: u64) -> Duration {
+        match self {
+            TimeoutUnit::Milliseconds => Duration::from_millis(value),
+            TimeoutUnit::Seconds => Duration::from_secs(value),
+        }
+    }
+}
+
+impl fmt::Display for TimeoutUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    SendQuery,
+    RerunQuery,
+    CloseTab,
+    NextTab,
+    PreviousTab,
+    ToggleSidebar,
+    TableView,
+    TextView,
+}
+
+pub const ALL_SHORTCUT_ACTIONS: [ShortcutAction; 8] = [
+    ShortcutAction::SendQuery,
+    ShortcutAction::RerunQuery,
+    ShortcutAction::CloseTab,
+    ShortcutAction::NextTab,
+    ShortcutAction::PreviousTab,
+    ShortcutAction::ToggleSidebar,
+    ShortcutAction::TableView,
+    ShortcutAction::TextView,
+];
+
+impl ShortcutAction {
+    pub const fn label(self) -> &'static str {
+        match self {
+            ShortcutAction::SendQuery => "Send query",
+            ShortcutAction::RerunQuery => "Rerun last query",
+            ShortcutAction::CloseTab => "Close tab",
+            ShortcutAction::NextTab => "Next tab",
+            ShortcutAction::PreviousTab => "Previous tab",
+            ShortcutAction::ToggleSidebar => "Toggle sidebar",
+            ShortcutAction::TableView => "Table view",
+            ShortcutAction::TextView => "Text view",
+        }
+    }
+
+    const fn default_shortcut(self) -> &'static str {
+        match self {
+            ShortcutAction::SendQuery => "Ctrl+Enter",
+            ShortcutAction::RerunQuery => "F5",
+            ShortcutAction::CloseTab => "Ctrl+W",
+            ShortcutAction::NextTab => "Ctrl+Tab",
+            ShortcutAction::PreviousTab => "Ctrl+Shift+Tab",
+            ShortcutAction::ToggleSidebar => "Ctrl+B",
+            ShortcutAction::TableView => "F2",
+            ShortcutAction::TextView => "F4",
+        }
+    }
+
+    pub fn default_binding(self) -> KeyCombo {
+        KeyCombo::parse(self.default_shortcut()).expect("default shortcuts are valid")
+    }
+}
+
+const NAMED_KEYS: &[(Named, &str)] = &[
+    (Named::Enter, "Enter"),
+    (Named::Tab, "Tab"),
+    (Named::Space, "Space"),
+    (Named::Backspace, "Backspace"),
+    (Named::Delete, "Delete"),
+    (Named::Insert, "Insert"),
+    (Named::Home, "Home"),
+    (Named::End, "End"),
+    (Named::PageUp, "PageUp"),
+    (Named::PageDown, "PageDown"),
+    (Named::ArrowUp, "ArrowUp"),
+    (Named::ArrowDown, "ArrowDown"),
+    (Named::ArrowLeft, "ArrowLeft"),
+    (Named::ArrowRight, "ArrowRight"),
+    (Named::F1, "F1"),
+    (Named::F2, "F2"),
+    (Named::F3, "F3"),
+    (Named::F4, "F4"),
+    (Named::F5, "F5"),
+    (Named::F6, "F6"),
+    (Named::F7, "F7"),
+    (Named::F8, "F8"),
+    (Named::F9, "F9"),
+    (Named::F10, "F10"),
+    (Named::F11, "F11"),
+    (Named::F12, "F12"),
+];
+
+/// Key combination written as `Ctrl+Shift+Tab`. `Ctrl` matches Cmd on macOS.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyCombo {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub key: String,
+}
+
+impl KeyCombo {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut combo = KeyCombo { ctrl: false, shift: false, alt: false, key: String::new() };
+        let parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let Some((key, modifiers)) = parts.split_last().filter(|(key, _)| !key.is_empty()) else {
+            return Err(String::from(tr("Shortcut must end with a key.")));
+        };
+
+        for modifier in modifiers {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "command" => combo.ctrl = true,
+                "shift" => combo.shift = true,
+                "alt" | "option" => combo.alt = true,
+                _ => return Err(tr_format("Unknown modifier \"{}\".", &[modifier])),
+            }
+        }
+
+        combo.key = if key.chars().count() == 1 {
+            key.to_uppercase()
+        } else if let Some((_, name)) =
+            NAMED_KEYS.iter().find(|(_, name)| name.eq_ignore_ascii_case(key))
+        {
+            name.to_string()
+        } else {
+            return Err(tr_format("Unknown key \"{}\".", &[key]));
+        };
+        Ok(combo)
+    }
+
+    pub fn from_key_press(key: &keyboard::Key, modifiers: keyboard::Modifiers) -> Option<Self> {
+        let key = match key.as_ref() {
+            keyboard::Key::Character(text) => text.to_uppercase(),
+            keyboard::Key::Named(named) => {
+                let (_, name) = NAMED_KEYS.iter().find(|(key, _)| *key == named)?;
+                name.to_string()
+            }
+            keyboard::Key::Unidentified => return None,
+        };
+        Some(KeyCombo {
+            ctrl: modifiers.command(),
+            shift: modifiers.shift(),
+            alt: modifiers.alt(),
+            key,
+        })
+    }
+}
+
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        f.write_str(&self.key)
+    }
+}
+
+impl TryFrom<String> for KeyCombo {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        KeyCombo::parse(&value)
+    }
+}
+
+impl From<KeyCombo> for String {
+    fn from(value: KeyCombo) -> Self {
+        value.to_string()
+    }
+}
+
+pub fn default_key_bindings() -> BTreeMap<ShortcutAction, KeyCombo> {
+    ALL_SHORTCUT_ACTIONS.iter().map(|action| (*action, action.default_binding())).collect()
+}
+
+/// Shortcuts that always work next to the configurable ones, with the action they perform.
+const FIXED_SHORTCUTS: [(&str, ShortcutAction); 2] =
+    [("Ctrl+PageDown", ShortcutAction::NextTab), ("Ctrl+PageUp", ShortcutAction::PreviousTab)];
+
+/// Returns the pairs of actions that share a shortcut. A binding that takes over one of the fixed
+/// shortcuts conflicts with the action of that shortcut.
+pub fn conflicting_shortcuts(
+    bindings: &BTreeMap<ShortcutAction, KeyCombo>,
+) -> Vec<(ShortcutAction, ShortcutAction)> {
+    let mut conflicts = Vec::new();
+    for (index, (action, combo)) in bindings.iter().enumerate() {
+        for (other, other_combo) in bindings.iter().skip(index + 1) {
+            if combo == other_combo {
+                conflicts.push((*action, *other));
+            }
+        }
+        for (shortcut, fixed_action) in FIXED_SHORTCUTS {
+            let fixed = KeyCombo::parse(shortcut).expect("fixed shortcuts are valid");
+            if *action != fixed_action && *combo == fixed {
+                conflicts.push((*action, fixed_action));
+            }
+        }
+    }
+    conflicts
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppSettings {
     pub language: Language,
     pub expand_first_result: bool,
-    pub query_timeout_secs: u64,
+    pub prefill_find_template: bool,
+    pub show_relative_dates: bool,
+    #[serde(alias = "query_timeout_secs")]
+    pub query_timeout: u64,
+    pub query_timeout_unit: TimeoutUnit,
+    pub max_render_documents: u64,
+    /// Levels opened by "Expand All"; 0 opens the whole tree.
+    pub expand_all_depth: u64,
     pub sort_fields_alphabetically: bool,
     pub sort_index_names_alphabetically: bool,
     pub close_tabs_on_database_close: bool,
+    pub reuse_unpinned_tab: bool,
+    pub group_connections_by_host: bool,
+    pub compact_sidebar: bool,
+    pub highlight_queries: bool,
+    pub reconnect_on_query: bool,
+    pub tree_sort_mode: TreeSortMode,
+    pub tab_title_format: TabTitleFormat,
+    pub confirm_close_edited_tabs: bool,
     pub strict_delete_confirmation: bool,
+    pub key_bindings: BTreeMap<ShortcutAction, KeyCombo>,
     pub logging_enabled: bool,
     pub logging_level: LogLevel,
     pub logging_path: String,
@@ -132,11 +450,25 @@ impl Default for AppSettings {
         Self {
             language: Language::English,
             expand_first_result: true,
-            query_timeout_secs: 600,
+            prefill_find_template: true,
+            show_relative_dates: false,
+            query_timeout: 600,
+            query_timeout_unit: TimeoutUnit::Seconds,
+            max_render_documents: 0,
+            expand_all_depth: 0,
             sort_fields_alphabetically: false,
             sort_index_names_alphabetically: false,
             close_tabs_on_database_close: true,
+            reuse_unpinned_tab: false,
+            group_connections_by_host: false,
+            compact_sidebar: false,
+            highlight_queries: true,
+            reconnect_on_query: false,
+            tree_sort_mode: TreeSortMode::Name,
+            tab_title_format: TabTitleFormat::Collection,
+            confirm_close_edited_tabs: false,
             strict_delete_confirmation: true,
+            key_bindings: default_key_bindings(),
             logging_enabled: false,
             logging_level: LogLevel::Info,
             logging_path: DEFAULT_LOG_FILE_NAME.to_string(),
@@ -824,6 +1156,112 @@ pub fn save_to_disk(settings: &AppSettings) -> Result<(), SettingsSaveError> {
     fs::write(settings_path(), rendered).map_err(SettingsSaveError::Io)
 }
 
+/// Skip and limit last used for a collection, restored when the collection is opened again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionPaging {
+    pub skip: u64,
+    pub limit: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CollectionPagingStore {
+    #[serde(default)]
+    collections: BTreeMap<String, CollectionPaging>,
+}
+
+pub fn collection_paging_key(connection: &str, db_name: &str, collection: &str) -> String {
+    format!("{connection}/{db_name}/{collection}")
+}
+
+pub fn load_collection_paging() -> Result<BTreeMap<String, CollectionPaging>, String> {
+    match fs::read_to_string(COLLECTION_PAGING_FILE_NAME) {
+        Ok(contents) => toml::from_str::<CollectionPagingStore>(&contents)
+            .map(|store| store.collections)
+            .map_err(|error| error.to_string()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+pub fn save_collection_paging(
+    collections: &BTreeMap<String, CollectionPaging>,
+) -> Result<(), String> {
+    let store = CollectionPagingStore { collections: collections.clone() };
+    let rendered = toml::to_string_pretty(&store).map_err(|error| error.to_string())?;
+    fs::write(COLLECTION_PAGING_FILE_NAME, rendered).map_err(|error| error.to_string())
+}
+
+/// Named list of fields projected by `find` queries of a collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectionPreset {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProjectionPresetStore {
+    #[serde(default)]
+    collections: BTreeMap<String, Vec<ProjectionPreset>>,
+}
+
+/// Presets keyed like collection paging, see [`collection_paging_key`].
+pub fn load_projection_presets() -> Result<BTreeMap<String, Vec<ProjectionPreset>>, String> {
+    match fs::read_to_string(PROJECTION_PRESETS_FILE_NAME) {
+        Ok(contents) => toml::from_str::<ProjectionPresetStore>(&contents)
+            .map(|store| store.collections)
+            .map_err(|error| error.to_string()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+pub fn save_projection_presets(
+    collections: &BTreeMap<String, Vec<ProjectionPreset>>,
+) -> Result<(), String> {
+    let store = ProjectionPresetStore { collections: collections.clone() };
+    let rendered = toml::to_string_pretty(&store).map_err(|error| error.to_string())?;
+    fs::write(PROJECTION_PRESETS_FILE_NAME, rendered).map_err(|error| error.to_string())
+}
+
+/// Collection tab reopened on the next start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTab {
+    pub id: u32,
+    pub client_name: String,
+    pub db_name: String,
+    pub collection: String,
+    #[serde(default)]
+    pub editor_text: String,
+    pub skip: u64,
+    pub limit: u64,
+    /// Database-level tab; `collection` is ignored on restore.
+    #[serde(default)]
+    pub database: bool,
+    #[serde(default)]
+    pub custom_title: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionState {
+    #[serde(default)]
+    pub tabs: Vec<SessionTab>,
+    #[serde(default)]
+    pub active_tab: Option<u32>,
+}
+
+pub fn load_session() -> Result<SessionState, String> {
+    match fs::read_to_string(SESSION_FILE_NAME) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(|error| error.to_string()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(SessionState::default()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+pub fn save_session(session: &SessionState) -> Result<(), String> {
+    let rendered = serde_json::to_string_pretty(session).map_err(|error| error.to_string())?;
+    fs::write(SESSION_FILE_NAME, rendered).map_err(|error| error.to_string())
+}
+
 pub fn initialize(settings: AppSettings) {
     if GLOBAL_SETTINGS.set(RwLock::new(settings.clone())).is_err() {
         replace(settings);
@@ -857,7 +1295,36 @@ pub const ALL_THEMES: &[ThemeChoice] = &[
 pub const ALL_LOG_LEVELS: &[LogLevel] =
     &[LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug, LogLevel::Trace];
 
+pub const ALL_TIMEOUT_UNITS: &[TimeoutUnit] = &[TimeoutUnit::Milliseconds, TimeoutUnit::Seconds];
+
+pub const ALL_TREE_SORT_MODES: &[TreeSortMode] = &[TreeSortMode::Name, TreeSortMode::Size];
+
+pub const ALL_TAB_TITLE_FORMATS: &[TabTitleFormat] = &[
+    TabTitleFormat::Collection,
+    TabTitleFormat::DatabaseCollection,
+    TabTitleFormat::ConnectionCollection,
+    TabTitleFormat::ConnectionDatabaseCollection,
+];
+
 impl AppSettings {
+    /// Query timeout as a duration, `None` when the timeout is disabled with zero.
+    pub fn query_timeout_duration(&self) -> Option<Duration> {
+        (self.query_timeout > 0).then(|| self.query_timeout_unit.to_duration(self.query_timeout))
+    }
+
+    pub fn key_binding(&self, action: ShortcutAction) -> KeyCombo {
+        self.key_bindings.get(&action).cloned().unwrap_or_else(|| action.default_binding())
+    }
+
+    pub fn shortcut_for(
+        &self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<ShortcutAction> {
+        let combo = KeyCombo::from_key_press(key, modifiers)?;
+        ALL_SHORTCUT_ACTIONS.into_iter().find(|action| self.key_binding(*action) == combo)
+    }
+
     pub fn normalize_fonts(&mut self) {
         self.primary_font = normalize_font_id(&self.primary_font);
         self.result_font = normalize_font_id(&self.result_font);
@@ -926,3 +1393,89 @@ fn normalize_query_editor_font_id(value: &str) -> String {
 
     fonts::default_query_editor_font_id().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combo(text: &str) -> KeyCombo {
+        KeyCombo::parse(text).expect("valid shortcut")
+    }
+
+    #[test]
+    fn parse_normalizes_modifiers_and_keys() {
+        let parsed = combo(" cmd + Shift + pageup ");
+        assert_eq!(
+            parsed,
+            KeyCombo { ctrl: true, shift: true, alt: false, key: String::from("PageUp") }
+        );
+        assert_eq!(parsed.to_string(), "Ctrl+Shift+PageUp");
+        assert_eq!(combo("option+w").to_string(), "Alt+W");
+        assert_eq!(combo("f5").to_string(), "F5");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_shortcuts() {
+        assert!(KeyCombo::parse("Ctrl+").is_err());
+        assert!(KeyCombo::parse("Hyper+W").is_err());
+        assert!(KeyCombo::parse("Ctrl+Escape").is_err());
+    }
+
+    #[test]
+    fn from_key_press_maps_characters_and_named_keys() {
+        let pressed = KeyCombo::from_key_press(
+            &keyboard::Key::Character("w".into()),
+            keyboard::Modifiers::COMMAND,
+        );
+        assert_eq!(pressed, Some(combo("Ctrl+W")));
+
+        let pressed = KeyCombo::from_key_press(
+            &keyboard::Key::Named(Named::ArrowLeft),
+            keyboard::Modifiers::SHIFT | keyboard::Modifiers::ALT,
+        );
+        assert_eq!(pressed, Some(combo("Shift+Alt+ArrowLeft")));
+
+        for (named, name) in NAMED_KEYS {
+            let pressed = KeyCombo::from_key_press(
+                &keyboard::Key::Named(*named),
+                keyboard::Modifiers::empty(),
+            );
+            assert_eq!(pressed, Some(combo(name)));
+        }
+    }
+
+    #[test]
+    fn from_key_press_ignores_unsupported_keys() {
+        let modifiers = keyboard::Modifiers::empty();
+        assert_eq!(KeyCombo::from_key_press(&keyboard::Key::Named(Named::Escape), modifiers), None);
+        assert_eq!(KeyCombo::from_key_press(&keyboard::Key::Unidentified, modifiers), None);
+    }
+
+    #[test]
+    fn default_bindings_do_not_conflict() {
+        assert!(conflicting_shortcuts(&default_key_bindings()).is_empty());
+    }
+
+    #[test]
+    fn conflicting_shortcuts_reports_shared_bindings() {
+        let mut bindings = default_key_bindings();
+        bindings.insert(ShortcutAction::ToggleSidebar, combo("Ctrl+W"));
+
+        assert_eq!(
+            conflicting_shortcuts(&bindings),
+            vec![(ShortcutAction::CloseTab, ShortcutAction::ToggleSidebar)]
+        );
+    }
+
+    #[test]
+    fn conflicting_shortcuts_reports_fixed_tab_bindings() {
+        let mut bindings = default_key_bindings();
+        bindings.insert(ShortcutAction::ToggleSidebar, combo("Ctrl+PageDown"));
+        bindings.insert(ShortcutAction::PreviousTab, combo("Ctrl+PageUp"));
+
+        assert_eq!(
+            conflicting_shortcuts(&bindings),
+            vec![(ShortcutAction::ToggleSidebar, ShortcutAction::NextTab)]
+        );
+    }
+}
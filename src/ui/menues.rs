@@ -54,14 +54,23 @@ pub(crate) enum ConnectionContextAction {
 pub(crate) enum CollectionContextAction {
     OpenEmptyTab,
     ViewDocuments,
+    SampleDocuments,
     ChangeStream,
+    TogglePin,
+    InsertDocument,
+    InsertTemplate,
     DeleteTemplate,
     DeleteAllDocuments,
     DeleteCollection,
     RenameCollection,
+    CountDocuments,
+    EstimatedCount,
     Stats,
     Indexes,
     CreateIndex,
+    IndexBuilder,
+    TextSearch,
+    ValidationRules,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -95,6 +104,12 @@ pub(crate) fn build_menu_bar<'a>(palette: ThemePalette) -> MenuBar<'a, Message,
         &[
             MenuEntry::ViewMode(ResponseViewMode::Table),
             MenuEntry::ViewMode(ResponseViewMode::Text),
+            MenuEntry::ViewMode(ResponseViewMode::ExtendedJson),
+            MenuEntry::Action("Saved Queries"),
+            MenuEntry::Action("Query Log"),
+            MenuEntry::Action("Sidebar"),
+            MenuEntry::Action("Date Format Preview"),
+            MenuEntry::Action("Refresh All Connections"),
         ],
     ));
     roots.push(menu_root(
@@ -103,6 +118,7 @@ pub(crate) fn build_menu_bar<'a>(palette: ThemePalette) -> MenuBar<'a, Message,
         &[
             MenuEntry::Action("Documentation"),
             MenuEntry::Action("Licenses"),
+            MenuEntry::Action("Export Diagnostic Report"),
             MenuEntry::Action("About"),
         ],
     ));
@@ -226,6 +242,7 @@ pub(crate) fn collection_context_menu<'a>(
     client_id: ClientId,
     db_name: String,
     collection_name: String,
+    pinned: bool,
 ) -> Element<'a, Message> {
     ContextMenu::new(base_button, move || {
         let mut menu = Column::new().spacing(4).padding([4, 6]);
@@ -245,8 +262,19 @@ pub(crate) fn collection_context_menu<'a>(
         };
 
         menu = menu.push(make_button(tr("Open Empty Tab"), CollectionContextAction::OpenEmptyTab));
+        menu = menu.push(make_button(
+            if pinned { tr("Unpin Collection") } else { tr("Pin to Top") },
+            CollectionContextAction::TogglePin,
+        ));
         menu = menu.push(make_button(tr("View Documents"), CollectionContextAction::ViewDocuments));
+        menu = menu
+            .push(make_button(tr("Sample Documents"), CollectionContextAction::SampleDocuments));
         menu = menu.push(make_button(tr("Change Stream"), CollectionContextAction::ChangeStream));
+        menu = menu.push(make_button(tr("Text Search..."), CollectionContextAction::TextSearch));
+        menu = menu
+            .push(make_button(tr("Insert Document..."), CollectionContextAction::InsertDocument));
+        menu = menu
+            .push(make_button(tr("Insert Template..."), CollectionContextAction::InsertTemplate));
         menu = menu
             .push(make_button(tr("Delete Documents..."), CollectionContextAction::DeleteTemplate));
         menu = menu.push(make_button(
@@ -259,9 +287,17 @@ pub(crate) fn collection_context_menu<'a>(
         ));
         menu = menu
             .push(make_button(tr("Drop Collection..."), CollectionContextAction::DeleteCollection));
+        menu =
+            menu.push(make_button(tr("Count Documents"), CollectionContextAction::CountDocuments));
+        menu =
+            menu.push(make_button(tr("Estimated Count"), CollectionContextAction::EstimatedCount));
         menu = menu.push(make_button(tr("Statistics"), CollectionContextAction::Stats));
         menu = menu.push(make_button(tr("Create Index"), CollectionContextAction::CreateIndex));
+        menu =
+            menu.push(make_button(tr("Index Builder..."), CollectionContextAction::IndexBuilder));
         menu = menu.push(make_button(tr("Indexes"), CollectionContextAction::Indexes));
+        menu = menu
+            .push(make_button(tr("Validation Rules..."), CollectionContextAction::ValidationRules));
 
         menu.into()
     })
@@ -4,4 +4,6 @@ pub mod fonts_dropdown;
 pub mod help_docs;
 pub mod menues;
 pub mod modal;
+pub mod query_highlighter;
+pub mod saved_queries;
 pub mod settings;
@@ -39,6 +39,28 @@ pub fn success_accent_color(palette: &ThemePalette) -> Color {
     palette.primary_buttons.active.to_color()
 }
 
+pub fn warning_accent_color(palette: &ThemePalette) -> Color {
+    let base = palette.widget_background_color();
+    if color_luminance(base) > 0.5 {
+        Color::from_rgba(0.78, 0.52, 0.05, 1.0)
+    } else {
+        Color::from_rgba(0.98, 0.78, 0.36, 1.0)
+    }
+}
+
+/// Small rounded swatch used to show a connection accent next to its name.
+pub fn accent_swatch<'a>(color: Color, width: f32, height: f32) -> Element<'a, Message> {
+    Container::new(
+        iced::widget::Space::new().width(Length::Fixed(width)).height(Length::Fixed(height)),
+    )
+    .style(move |_| container::Style {
+        background: Some(color.into()),
+        border: border::rounded(3),
+        ..Default::default()
+    })
+    .into()
+}
+
 pub fn modal_layout<'a>(
     palette: ThemePalette,
     content: Element<'a, Message>,
@@ -0,0 +1,197 @@
+use std::ops::Range;
+
+use iced::Color;
+use iced::Font;
+use iced_renderer::core::text::highlighter::{Format, Highlighter};
+
+use crate::settings::ThemePalette;
+use crate::ui::modal::{color_luminance, warning_accent_color};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryHighlightColors {
+    pub key: Color,
+    pub string: Color,
+    pub number: Color,
+    pub method: Color,
+    pub punctuation: Color,
+    pub comment: Color,
+    pub helper: Color,
+    /// When off the highlighter emits no spans and the editor renders plain text.
+    pub enabled: bool,
+}
+
+/// Constructors of BSON values in the shell syntax, colored apart from collection methods.
+const BSON_HELPERS: &[&str] = &[
+    "ObjectId",
+    "ISODate",
+    "Date",
+    "NumberInt",
+    "NumberLong",
+    "NumberDecimal",
+    "Timestamp",
+    "BinData",
+    "UUID",
+    "RegExp",
+    "MinKey",
+    "MaxKey",
+];
+
+impl QueryHighlightColors {
+    pub fn from_palette(palette: &ThemePalette, enabled: bool) -> Self {
+        let light = color_luminance(palette.widget_background_color()) > 0.5;
+        let (string, method, helper) = if light {
+            (
+                Color::from_rgb(0.16, 0.52, 0.24),
+                Color::from_rgb(0.55, 0.27, 0.70),
+                Color::from_rgb(0.00, 0.50, 0.56),
+            )
+        } else {
+            (
+                Color::from_rgb(0.56, 0.84, 0.56),
+                Color::from_rgb(0.80, 0.62, 0.96),
+                Color::from_rgb(0.45, 0.82, 0.88),
+            )
+        };
+
+        Self {
+            key: palette.primary_buttons.active.to_color(),
+            string,
+            number: warning_accent_color(palette),
+            method,
+            punctuation: palette.text_muted.to_color(),
+            comment: palette.text_muted.to_color(),
+            helper,
+            enabled,
+        }
+    }
+}
+
+pub fn to_format(color: &Color, _theme: &iced::Theme) -> Format<Font> {
+    Format { color: Some(*color), font: None }
+}
+
+/// Line based highlighter for shell queries: keys, strings, numbers, brackets, method calls,
+/// BSON helpers and `//` comments. Every line is tokenized on its own, so no state is carried between lines.
+pub struct QueryHighlighter {
+    colors: QueryHighlightColors,
+    current_line: usize,
+}
+
+impl Highlighter for QueryHighlighter {
+    type Settings = QueryHighlightColors;
+    type Highlight = Color;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Color)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        Self { colors: *settings, current_line: 0 }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.colors = *new_settings;
+        self.current_line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.current_line += 1;
+        if !self.colors.enabled {
+            return Vec::new().into_iter();
+        }
+        highlight_query_line(line, &self.colors).into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+fn highlight_query_line(line: &str, colors: &QueryHighlightColors) -> Vec<(Range<usize>, Color)> {
+    let bytes = line.as_bytes();
+    let mut spans = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match byte {
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                spans.push((index..bytes.len(), colors.comment));
+                break;
+            }
+            b'"' | b'\'' => {
+                let end = string_end(bytes, index);
+                let color = if followed_by_colon(bytes, end) { colors.key } else { colors.string };
+                spans.push((index..end, color));
+                index = end;
+            }
+            b'{' | b'}' | b'[' | b']' | b'(' | b')' => {
+                spans.push((index..index + 1, colors.punctuation));
+                index += 1;
+            }
+            b'0'..=b'9' => {
+                let end = number_end(bytes, index);
+                spans.push((index..end, colors.number));
+                index = end;
+            }
+            b'-' if bytes.get(index + 1).is_some_and(u8::is_ascii_digit) => {
+                let end = number_end(bytes, index + 1);
+                spans.push((index..end, colors.number));
+                index = end;
+            }
+            _ if is_identifier_byte(byte) => {
+                let end = bytes[index..]
+                    .iter()
+                    .position(|byte| !is_identifier_byte(*byte))
+                    .map_or(bytes.len(), |offset| index + offset);
+                if followed_by_colon(bytes, end) {
+                    spans.push((index..end, colors.key));
+                } else if next_non_space(bytes, end) == Some(b'(') {
+                    let color = if BSON_HELPERS.contains(&&line[index..end]) {
+                        colors.helper
+                    } else {
+                        colors.method
+                    };
+                    spans.push((index..end, color));
+                }
+                index = end;
+            }
+            _ => index += 1,
+        }
+    }
+
+    spans
+}
+
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|byte| !(byte.is_ascii_alphanumeric() || *byte == b'.'))
+        .map_or(bytes.len(), |offset| start + offset)
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+fn next_non_space(bytes: &[u8], from: usize) -> Option<u8> {
+    bytes.get(from..)?.iter().copied().find(|byte| !byte.is_ascii_whitespace())
+}
+
+fn followed_by_colon(bytes: &[u8], from: usize) -> bool {
+    next_non_space(bytes, from) == Some(b':')
+}
@@ -0,0 +1,468 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use iced::alignment::Vertical;
+use iced::widget::text_editor::{self, Content as TextEditorContent};
+use iced::widget::{self, Button, Column, Container, Row, Scrollable, Space, button, text_input};
+use iced::{Color, Element, Length, Theme, border};
+use serde::{Deserialize, Serialize};
+
+use crate::fonts;
+use crate::i18n::tr;
+use crate::settings::ThemePalette;
+use crate::ui::connections::ListClick;
+use crate::ui::modal::modal_layout;
+use crate::{Message, TabId};
+
+const SAVED_QUERIES_FILE: &str = "saved_queries.toml";
+const COLLECTION_PLACEHOLDER: &str = "{{collection}}";
+const DATABASE_PLACEHOLDER: &str = "{{db}}";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub db_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection: Option<String>,
+}
+
+impl SavedQuery {
+    /// `db.collection`, `db` or `collection`, depending on which parts of the target are set.
+    pub fn target_label(&self) -> Option<String> {
+        match (&self.db_name, &self.collection) {
+            (Some(db_name), Some(collection)) => Some(format!("{db_name}.{collection}")),
+            (Some(name), None) | (None, Some(name)) => Some(name.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Whether the query was saved for another database or collection than the given one.
+    pub fn targets_other_collection(&self, db_name: &str, collection: &str) -> bool {
+        self.db_name.as_deref().is_some_and(|target| target != db_name)
+            || self.collection.as_deref().is_some_and(|target| target != collection)
+    }
+
+    /// Query text with `{{collection}}` and `{{db}}` replaced by the names of the target tab.
+    pub fn render(&self, db_name: &str, collection: &str) -> String {
+        self.query
+            .replace(COLLECTION_PLACEHOLDER, collection)
+            .replace(DATABASE_PLACEHOLDER, db_name)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SavedQueryStore {
+    #[serde(default)]
+    queries: Vec<SavedQuery>,
+}
+
+pub fn load_saved_queries_from_disk() -> Result<Vec<SavedQuery>, String> {
+    let data = match fs::read_to_string(saved_queries_file_path()) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let store: SavedQueryStore = toml::from_str(&data).map_err(|err| err.to_string())?;
+    Ok(store.queries)
+}
+
+pub fn save_saved_queries_to_disk(queries: &[SavedQuery]) -> Result<(), String> {
+    let store = SavedQueryStore { queries: queries.to_vec() };
+    let data = toml::to_string_pretty(&store).map_err(|err| err.to_string())?;
+    let mut file = fs::File::create(saved_queries_file_path()).map_err(|err| err.to_string())?;
+    file.write_all(data.as_bytes()).map_err(|err| err.to_string())
+}
+
+fn saved_queries_file_path() -> PathBuf {
+    PathBuf::from(SAVED_QUERIES_FILE)
+}
+
+#[derive(Debug)]
+pub struct SavedQueriesWindowState {
+    pub(crate) selected: Option<usize>,
+    pub(crate) confirm_delete: bool,
+    pub(crate) feedback: Option<String>,
+    pub(crate) last_click: Option<ListClick>,
+    /// Tab that "Load" writes into; none when the library was opened without a collection tab.
+    pub(crate) target_tab: Option<TabId>,
+}
+
+impl SavedQueriesWindowState {
+    pub fn new(target_tab: Option<TabId>) -> Self {
+        Self { selected: None, confirm_delete: false, feedback: None, last_click: None, target_tab }
+    }
+}
+
+#[derive(Debug)]
+pub struct SavedQueryFormState {
+    pub(crate) name: String,
+    pub(crate) db_name: String,
+    pub(crate) collection: String,
+    pub(crate) editor: TextEditorContent,
+    /// Name of the edited query; `None` when saving a new one.
+    pub(crate) original_name: Option<String>,
+    /// Whether closing the form returns to the library instead of the main window.
+    pub(crate) from_library: bool,
+    pub(crate) error: Option<String>,
+}
+
+impl SavedQueryFormState {
+    pub fn new(query: &str, db_name: &str, collection: &str) -> Self {
+        Self {
+            name: String::new(),
+            db_name: db_name.to_string(),
+            collection: collection.to_string(),
+            editor: TextEditorContent::with_text(query),
+            original_name: None,
+            from_library: false,
+            error: None,
+        }
+    }
+
+    pub fn edit(query: &SavedQuery) -> Self {
+        Self {
+            name: query.name.clone(),
+            db_name: query.db_name.clone().unwrap_or_default(),
+            collection: query.collection.clone().unwrap_or_default(),
+            editor: TextEditorContent::with_text(&query.query),
+            original_name: Some(query.name.clone()),
+            from_library: true,
+            error: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<SavedQuery, String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(String::from(tr("Query name cannot be empty.")));
+        }
+        let query = self.editor.text().trim_end().to_string();
+        if query.trim().is_empty() {
+            return Err(String::from(tr("Query text cannot be empty.")));
+        }
+        let optional = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+
+        Ok(SavedQuery {
+            name: name.to_string(),
+            query,
+            db_name: optional(&self.db_name),
+            collection: optional(&self.collection),
+        })
+    }
+}
+
+pub fn saved_queries_view<'a>(
+    state: &'a SavedQueriesWindowState,
+    queries: &'a [SavedQuery],
+    palette: &ThemePalette,
+) -> Element<'a, Message> {
+    let palette = palette.clone();
+    let border_color = palette.widget_border_color();
+    let normal_bg = palette.widget_background_color();
+    let selected_bg = palette.subtle_buttons.hover.to_color();
+    let primary_text = palette.text_primary.to_color();
+    let muted_text = palette.text_muted.to_color();
+    let accent_text = palette.primary_buttons.active.to_color();
+
+    let mut entries = Column::new().spacing(4).width(Length::Fill);
+    if queries.is_empty() {
+        entries = entries.push(
+            Container::new(
+                fonts::primary_text(
+                    tr("No saved queries. Use \"Save Query\" in a collection tab to add one."),
+                    Some(0.0),
+                )
+                .color(muted_text),
+            )
+            .width(Length::Fill)
+            .padding([12, 8]),
+        );
+    }
+
+    for (index, query) in queries.iter().enumerate() {
+        let is_selected = state.selected == Some(index);
+        let mut header = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(fonts::primary_text(query.name.clone(), Some(2.0)).color(primary_text))
+            .push(Space::new().width(Length::Fill));
+        if let Some(target) = query.target_label() {
+            header = header.push(fonts::primary_text(target, Some(-1.0)).color(accent_text));
+        }
+        let first_line = query.query.lines().next().unwrap_or_default().to_string();
+        let labels = Column::new()
+            .spacing(4)
+            .push(header)
+            .push(fonts::result_text(first_line, Some(-1.0)).color(muted_text));
+
+        let container =
+            Container::new(labels).padding([8, 12]).width(Length::Fill).style(move |_| {
+                widget::container::Style {
+                    background: Some(if is_selected { selected_bg } else { normal_bg }.into()),
+                    border: border::rounded(8).width(1).color(border_color),
+                    ..Default::default()
+                }
+            });
+        entries = entries.push(
+            Button::new(container)
+                .width(Length::Fill)
+                .padding(0)
+                .style(entry_button_style(palette.clone(), 6.0))
+                .on_press(Message::SavedQueriesSelect(index)),
+        );
+    }
+
+    let list = Scrollable::new(entries).width(Length::Fill).height(Length::Fixed(280.0));
+
+    let action_button = |label: &'static str, message: Option<Message>| {
+        Button::new(fonts::primary_text(tr(label), None))
+            .padding([6, 16])
+            .style(primary_button_style(palette.clone(), 6.0))
+            .on_press_maybe(message)
+    };
+    let has_selection = state.selected.is_some();
+    let left_controls = Row::new()
+        .spacing(8)
+        .push(action_button("Edit", has_selection.then_some(Message::SavedQueriesEdit)))
+        .push(action_button("Delete", has_selection.then_some(Message::SavedQueriesDelete)));
+    let right_controls = Row::new()
+        .spacing(8)
+        .push(action_button("Cancel", Some(Message::SavedQueriesClose)))
+        .push(action_button(
+            "Load",
+            (has_selection && state.target_tab.is_some()).then_some(Message::SavedQueriesLoad),
+        ));
+
+    let mut content = Column::new()
+        .spacing(16)
+        .push(fonts::primary_text(tr("Saved Queries"), Some(10.0)).color(primary_text))
+        .push(
+            fonts::primary_text(
+                tr("{{collection}} and {{db}} are replaced with the names of the tab the query is loaded into."),
+                Some(-1.0),
+            )
+            .color(muted_text),
+        )
+        .push(list);
+
+    if let Some(feedback) = &state.feedback {
+        content = content.push(
+            fonts::primary_text(feedback.clone(), None).color(Color::from_rgb8(0xd9, 0x53, 0x4f)),
+        );
+    }
+
+    if state.confirm_delete {
+        let name = state
+            .selected
+            .and_then(|index| queries.get(index))
+            .map(|query| query.name.clone())
+            .unwrap_or_default();
+        let confirm_row = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(
+                fonts::primary_text(format!("{} \"{}\"?", tr("Delete"), name), None)
+                    .color(primary_text),
+            )
+            .push(action_button("Yes", Some(Message::SavedQueriesDeleteConfirmed)))
+            .push(action_button("No", Some(Message::SavedQueriesDeleteCancelled)));
+        content = content.push(confirm_row);
+    }
+
+    content = content.push(
+        Row::new()
+            .spacing(16)
+            .align_y(Vertical::Center)
+            .push(left_controls)
+            .push(Space::new().width(Length::Fill))
+            .push(right_controls),
+    );
+
+    let card_element: Element<Message> = content.into();
+    modal_layout(palette, card_element, Length::Fixed(700.0), 20, 6.0)
+}
+
+pub fn saved_query_form_view<'a>(
+    state: &'a SavedQueryFormState,
+    palette: &ThemePalette,
+) -> Element<'a, Message> {
+    let palette = palette.clone();
+    let primary_text = palette.text_primary.to_color();
+    let muted_text = palette.text_muted.to_color();
+    let editor_fonts = fonts::active_fonts();
+
+    let title =
+        if state.original_name.is_some() { tr("Edit Saved Query") } else { tr("Save Query") };
+    let target_row = Row::new()
+        .spacing(12)
+        .push(
+            text_input(tr("Database (optional)"), &state.db_name)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::SavedQueryFormDbChanged),
+        )
+        .push(
+            text_input(tr("Collection (optional)"), &state.collection)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::SavedQueryFormCollectionChanged),
+        );
+
+    let mut column = Column::new()
+        .spacing(12)
+        .push(fonts::primary_text(title, Some(6.0)).color(primary_text))
+        .push(fonts::primary_text(tr("Name"), None).color(primary_text))
+        .push(
+            text_input(tr("Slow operations"), &state.name)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .on_input(Message::SavedQueryFormNameChanged)
+                .on_submit(Message::SavedQueryFormSave),
+        )
+        .push(fonts::primary_text(tr("Target"), None).color(primary_text))
+        .push(target_row)
+        .push(fonts::primary_text(tr("Query"), None).color(primary_text))
+        .push(
+            text_editor::TextEditor::new(&state.editor)
+                .font(editor_fonts.editor_font)
+                .size(editor_fonts.editor_size)
+                .height(Length::Fixed(180.0))
+                .on_action(Message::SavedQueryFormEditorAction),
+        )
+        .push(
+            fonts::primary_text(
+                tr("Use {{collection}} and {{db}} for the names of the tab the query is loaded into."),
+                Some(-1.0),
+            )
+            .color(muted_text),
+        );
+
+    if let Some(error) = &state.error {
+        column = column.push(
+            fonts::primary_text(error.clone(), Some(-1.0))
+                .color(Color::from_rgb8(0xd9, 0x53, 0x4f)),
+        );
+    }
+
+    let cancel_button = Button::new(fonts::primary_text(tr("Cancel"), None))
+        .padding([6, 16])
+        .on_press(Message::SavedQueryFormCancel)
+        .style(subtle_button_style(palette.clone(), 6.0));
+    let save_button = Button::new(fonts::primary_text(tr("Save"), None))
+        .padding([6, 16])
+        .on_press(Message::SavedQueryFormSave)
+        .style(primary_button_style(palette.clone(), 6.0));
+    column = column.push(Row::new().spacing(12).push(cancel_button).push(save_button));
+
+    let content: Element<Message> = column.into();
+    modal_layout(palette, content, Length::Fixed(600.0), 24, 12.0)
+}
+
+fn subtle_button_style(
+    palette: ThemePalette,
+    radius: f32,
+) -> impl Fn(&Theme, button::Status) -> button::Style {
+    move |_, status| palette.subtle_button_style(radius, status)
+}
+
+fn primary_button_style(
+    palette: ThemePalette,
+    radius: f32,
+) -> impl Fn(&Theme, button::Status) -> button::Style {
+    move |_, status| palette.primary_button_style(radius, status)
+}
+
+fn entry_button_style(
+    palette: ThemePalette,
+    radius: f32,
+) -> impl Fn(&Theme, button::Status) -> button::Style {
+    move |_, status| {
+        let mut style = palette.subtle_button_style(radius, status);
+        style.border = border::rounded(0).width(0).color(Color::TRANSPARENT);
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved(query: &str, db_name: Option<&str>, collection: Option<&str>) -> SavedQuery {
+        SavedQuery {
+            name: String::from("sample"),
+            query: query.to_string(),
+            db_name: db_name.map(str::to_string),
+            collection: collection.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn render_replaces_collection_and_db_placeholders() {
+        let query = saved(
+            "db.getSiblingDB(\"{{db}}\").getCollection(\"{{collection}}\").find({}); // {{collection}}",
+            None,
+            None,
+        );
+
+        assert_eq!(
+            query.render("shop", "orders"),
+            "db.getSiblingDB(\"shop\").getCollection(\"orders\").find({}); // orders"
+        );
+    }
+
+    #[test]
+    fn render_keeps_text_without_placeholders() {
+        let query = saved("db.users.find({ \"active\": true })", Some("app"), Some("users"));
+
+        assert_eq!(query.render("other", "accounts"), "db.users.find({ \"active\": true })");
+    }
+
+    #[test]
+    fn targets_other_collection_compares_only_set_parts() {
+        assert!(!saved("{}", None, None).targets_other_collection("app", "users"));
+        assert!(!saved("{}", Some("app"), None).targets_other_collection("app", "orders"));
+        assert!(!saved("{}", None, Some("users")).targets_other_collection("shop", "users"));
+        assert!(!saved("{}", Some("app"), Some("users")).targets_other_collection("app", "users"));
+        assert!(saved("{}", Some("app"), None).targets_other_collection("shop", "users"));
+        assert!(saved("{}", None, Some("users")).targets_other_collection("app", "orders"));
+        assert!(saved("{}", Some("app"), Some("users")).targets_other_collection("app", "orders"));
+    }
+
+    #[test]
+    fn validate_trims_fields_and_drops_empty_target() {
+        let mut form = SavedQueryFormState::new("db.users.find({})\n\n", "  ", "");
+        form.name = String::from("  active users  ");
+
+        let query = form.validate().expect("valid form");
+        assert_eq!(query.name, "active users");
+        assert_eq!(query.query, "db.users.find({})");
+        assert_eq!(query.db_name, None);
+        assert_eq!(query.collection, None);
+    }
+
+    #[test]
+    fn validate_keeps_target_of_edited_query() {
+        let original = saved("db.{{collection}}.find({})", Some("app"), Some("users"));
+        let form = SavedQueryFormState::edit(&original);
+
+        assert_eq!(form.validate(), Ok(original));
+    }
+
+    #[test]
+    fn validate_rejects_empty_name_and_query() {
+        let mut form = SavedQueryFormState::new("db.users.find({})", "app", "users");
+        form.name = String::from("   ");
+        assert_eq!(form.validate(), Err(String::from(tr("Query name cannot be empty."))));
+
+        let mut form = SavedQueryFormState::new(" \n ", "app", "users");
+        form.name = String::from("blank");
+        assert_eq!(form.validate(), Err(String::from(tr("Query text cannot be empty."))));
+    }
+}
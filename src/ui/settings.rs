@@ -11,18 +11,22 @@ use crate::Message;
 use crate::fonts;
 use crate::i18n::{ALL_LANGUAGES, Language, tr, tr_format};
 use crate::settings::{
-    ALL_LOG_LEVELS, ALL_THEMES, AppSettings, DEFAULT_LOG_FILE_NAME, LogLevel, RgbaColor,
-    ThemeChoice, ThemeColors, ThemePalette,
+    ALL_LOG_LEVELS, ALL_SHORTCUT_ACTIONS, ALL_TAB_TITLE_FORMATS, ALL_THEMES, ALL_TIMEOUT_UNITS,
+    ALL_TREE_SORT_MODES, AppSettings, DEFAULT_LOG_FILE_NAME, KeyCombo, LogLevel, RgbaColor,
+    ShortcutAction, TabTitleFormat, ThemeChoice, ThemeColors, ThemePalette, TimeoutUnit,
+    TreeSortMode, conflicting_shortcuts,
 };
 use crate::ui::fonts_dropdown::{self, FontDropdown};
 use crate::ui::modal::modal_layout;
 use iced_aw::ColorPicker;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SettingsTab {
     Behavior,
     Appearance,
     ColorTheme,
+    Shortcuts,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -88,6 +92,7 @@ impl SettingsTab {
             SettingsTab::Behavior => "Behavior",
             SettingsTab::Appearance => "Appearance",
             SettingsTab::ColorTheme => "Color Theme",
+            SettingsTab::Shortcuts => "Shortcuts",
         }
     }
 }
@@ -96,11 +101,25 @@ impl SettingsTab {
 pub struct SettingsWindowState {
     pub active_tab: SettingsTab,
     pub expand_first_result: bool,
-    pub query_timeout_secs: String,
+    pub prefill_find_template: bool,
+    pub show_relative_dates: bool,
+    pub query_timeout: String,
+    pub query_timeout_unit: TimeoutUnit,
+    pub max_render_documents: String,
+    pub expand_all_depth: String,
     pub sort_fields_alphabetically: bool,
     pub sort_index_names_alphabetically: bool,
     pub close_tabs_on_database_close: bool,
+    pub reuse_unpinned_tab: bool,
+    pub group_connections_by_host: bool,
+    pub compact_sidebar: bool,
+    pub highlight_queries: bool,
+    pub reconnect_on_query: bool,
+    pub tree_sort_mode: TreeSortMode,
+    pub tab_title_format: TabTitleFormat,
+    pub confirm_close_edited_tabs: bool,
     pub strict_delete_confirmation: bool,
+    pub key_bindings: BTreeMap<ShortcutAction, String>,
     pub logging_enabled: bool,
     pub logging_level: LogLevel,
     pub logging_path: String,
@@ -158,11 +177,28 @@ impl SettingsWindowState {
         Self {
             active_tab: SettingsTab::Behavior,
             expand_first_result: settings.expand_first_result,
-            query_timeout_secs: settings.query_timeout_secs.to_string(),
+            prefill_find_template: settings.prefill_find_template,
+            show_relative_dates: settings.show_relative_dates,
+            query_timeout: settings.query_timeout.to_string(),
+            query_timeout_unit: settings.query_timeout_unit,
+            max_render_documents: settings.max_render_documents.to_string(),
+            expand_all_depth: settings.expand_all_depth.to_string(),
             sort_fields_alphabetically: settings.sort_fields_alphabetically,
             sort_index_names_alphabetically: settings.sort_index_names_alphabetically,
             close_tabs_on_database_close: settings.close_tabs_on_database_close,
+            reuse_unpinned_tab: settings.reuse_unpinned_tab,
+            group_connections_by_host: settings.group_connections_by_host,
+            compact_sidebar: settings.compact_sidebar,
+            highlight_queries: settings.highlight_queries,
+            reconnect_on_query: settings.reconnect_on_query,
+            tree_sort_mode: settings.tree_sort_mode,
+            tab_title_format: settings.tab_title_format,
+            confirm_close_edited_tabs: settings.confirm_close_edited_tabs,
             strict_delete_confirmation: settings.strict_delete_confirmation,
+            key_bindings: ALL_SHORTCUT_ACTIONS
+                .iter()
+                .map(|action| (*action, settings.key_binding(*action).to_string()))
+                .collect(),
             logging_enabled: settings.logging_enabled,
             logging_level: settings.logging_level,
             logging_path: settings.logging_path.clone(),
@@ -195,8 +231,11 @@ impl SettingsWindowState {
     }
 
     pub fn to_app_settings(&self) -> Result<AppSettings, String> {
-        let timeout =
-            parse_integer::<u64>(&self.query_timeout_secs, tr("Query timeout (seconds)"))?;
+        let timeout = parse_integer::<u64>(&self.query_timeout, tr("Query timeout"))?;
+        let max_render_documents =
+            parse_integer::<u64>(&self.max_render_documents, tr("Render at most"))?;
+        let expand_all_depth =
+            parse_integer::<u64>(&self.expand_all_depth, tr("Expand All opens at most"))?;
         let primary_size = parse_integer::<u16>(&self.primary_font_size, tr("Primary Font"))?;
         let result_size = parse_integer::<u16>(&self.result_font_size, tr("Query Result Font"))?;
         let query_editor_size =
@@ -211,13 +250,40 @@ impl SettingsWindowState {
             return Err(tr("Font size must be greater than zero").to_owned());
         }
 
+        let mut key_bindings = BTreeMap::new();
+        for (action, text) in &self.key_bindings {
+            let combo = KeyCombo::parse(text)
+                .map_err(|error| format!("{}: {error}", tr(action.label())))?;
+            key_bindings.insert(*action, combo);
+        }
+        if let Some((action, other)) = conflicting_shortcuts(&key_bindings).first() {
+            return Err(tr_format(
+                "\"{}\" and \"{}\" use the same shortcut.",
+                &[tr(action.label()), tr(other.label())],
+            ));
+        }
+
         Ok(AppSettings {
             expand_first_result: self.expand_first_result,
-            query_timeout_secs: timeout,
+            prefill_find_template: self.prefill_find_template,
+            show_relative_dates: self.show_relative_dates,
+            query_timeout: timeout,
+            query_timeout_unit: self.query_timeout_unit,
+            max_render_documents,
+            expand_all_depth,
             sort_fields_alphabetically: self.sort_fields_alphabetically,
             sort_index_names_alphabetically: self.sort_index_names_alphabetically,
             close_tabs_on_database_close: self.close_tabs_on_database_close,
+            reuse_unpinned_tab: self.reuse_unpinned_tab,
+            group_connections_by_host: self.group_connections_by_host,
+            compact_sidebar: self.compact_sidebar,
+            highlight_queries: self.highlight_queries,
+            reconnect_on_query: self.reconnect_on_query,
+            tree_sort_mode: self.tree_sort_mode,
+            tab_title_format: self.tab_title_format,
+            confirm_close_edited_tabs: self.confirm_close_edited_tabs,
             strict_delete_confirmation: self.strict_delete_confirmation,
+            key_bindings,
             logging_enabled: self.logging_enabled,
             logging_level: self.logging_level,
             logging_path: log_path,
@@ -394,6 +460,7 @@ pub fn settings_view(state: &SettingsWindowState) -> Element<'_, Message> {
         SettingsTab::Behavior => behavior_tab(state, text_color),
         SettingsTab::Appearance => appearance_tab(state, text_color),
         SettingsTab::ColorTheme => color_theme_tab(state, palette.clone(), text_color, muted_color),
+        SettingsTab::Shortcuts => shortcuts_tab(state, &palette, text_color, muted_color),
     };
 
     let mut scroll_content = Column::new().spacing(20).push(tab_content);
@@ -430,17 +497,57 @@ fn behavior_tab(state: &SettingsWindowState, text_color: Color) -> Element<'_, M
     let expand_checkbox = checkbox(tr("Expand first result item"), state.expand_first_result)
         .on_toggle(Message::SettingsToggleExpandFirstResult);
 
+    let prefill_find_template =
+        checkbox(tr("Prefill new tabs with a find query"), state.prefill_find_template)
+            .on_toggle(Message::SettingsTogglePrefillFindTemplate);
+
     let timeout_row = Row::new()
         .spacing(12)
         .align_y(Vertical::Center)
-        .push(fonts::primary_text(tr("Query timeout (seconds)"), None).color(text_color))
+        .push(fonts::primary_text(tr("Query timeout"), None).color(text_color))
         .push(
-            text_input(tr("Seconds"), &state.query_timeout_secs)
+            text_input(tr("0 = no timeout"), &state.query_timeout)
                 .on_input(Message::SettingsQueryTimeoutChanged)
                 .padding([6, 10])
                 .width(Length::Fixed(120.0)),
+        )
+        .push(
+            PickList::new(
+                ALL_TIMEOUT_UNITS,
+                Some(state.query_timeout_unit),
+                Message::SettingsQueryTimeoutUnitChanged,
+            )
+            .width(Length::Fixed(80.0)),
         );
 
+    let max_render_row = Row::new()
+        .spacing(12)
+        .align_y(Vertical::Center)
+        .push(fonts::primary_text(tr("Render at most"), None).color(text_color))
+        .push(
+            text_input(tr("0 = no cap"), &state.max_render_documents)
+                .on_input(Message::SettingsMaxRenderDocumentsChanged)
+                .padding([6, 10])
+                .width(Length::Fixed(120.0)),
+        )
+        .push(fonts::primary_text(tr("documents per result"), None).color(text_color));
+
+    let expand_depth_row = Row::new()
+        .spacing(12)
+        .align_y(Vertical::Center)
+        .push(fonts::primary_text(tr("Expand All opens at most"), None).color(text_color))
+        .push(
+            text_input(tr("0 = all levels"), &state.expand_all_depth)
+                .on_input(Message::SettingsExpandAllDepthChanged)
+                .padding([6, 10])
+                .width(Length::Fixed(120.0)),
+        )
+        .push(fonts::primary_text(tr("levels"), None).color(text_color));
+
+    let relative_dates =
+        checkbox(tr("Show relative time next to dates"), state.show_relative_dates)
+            .on_toggle(Message::SettingsToggleRelativeDates);
+
     let sort_fields = checkbox(tr("Sort fields alphabetically"), state.sort_fields_alphabetically)
         .on_toggle(Message::SettingsToggleSortFields);
 
@@ -454,6 +561,57 @@ fn behavior_tab(state: &SettingsWindowState, text_color: Color) -> Element<'_, M
     )
     .on_toggle(Message::SettingsToggleCloseTabsOnDbClose);
 
+    let reuse_unpinned_tab = checkbox(
+        tr("Reuse the current unpinned tab when opening a collection"),
+        state.reuse_unpinned_tab,
+    )
+    .on_toggle(Message::SettingsToggleReuseUnpinnedTab);
+
+    let group_connections_by_host =
+        checkbox(tr("Group connections by host in the sidebar"), state.group_connections_by_host)
+            .on_toggle(Message::SettingsToggleGroupConnectionsByHost);
+
+    let compact_sidebar = checkbox(tr("Compact sidebar density"), state.compact_sidebar)
+        .on_toggle(Message::SettingsToggleCompactSidebar);
+
+    let tree_sort_row = Row::new()
+        .spacing(12)
+        .align_y(Vertical::Center)
+        .push(fonts::primary_text(tr("Sort databases and collections"), None).color(text_color))
+        .push(
+            PickList::new(
+                ALL_TREE_SORT_MODES,
+                Some(state.tree_sort_mode),
+                Message::SettingsTreeSortModeChanged,
+            )
+            .width(Length::Fixed(240.0)),
+        );
+
+    let reconnect_on_query = checkbox(
+        tr("Reconnect automatically when running a query on a closed connection"),
+        state.reconnect_on_query,
+    )
+    .on_toggle(Message::SettingsToggleReconnectOnQuery);
+
+    let confirm_close_edited_tabs = checkbox(
+        tr("Ask before closing tabs with edited queries that were not run"),
+        state.confirm_close_edited_tabs,
+    )
+    .on_toggle(Message::SettingsToggleConfirmCloseEditedTabs);
+
+    let tab_title_row = Row::new()
+        .spacing(12)
+        .align_y(Vertical::Center)
+        .push(fonts::primary_text(tr("Tab title"), None).color(text_color))
+        .push(
+            PickList::new(
+                ALL_TAB_TITLE_FORMATS,
+                Some(state.tab_title_format),
+                Message::SettingsTabTitleFormatChanged,
+            )
+            .width(Length::Fixed(300.0)),
+        );
+
     let strict_delete_confirmation = checkbox(
         tr("Require typing names to confirm database/collection/index deletion"),
         state.strict_delete_confirmation,
@@ -490,10 +648,21 @@ fn behavior_tab(state: &SettingsWindowState, text_color: Color) -> Element<'_, M
     Column::new()
         .spacing(16)
         .push(expand_checkbox)
+        .push(prefill_find_template)
         .push(timeout_row)
+        .push(max_render_row)
+        .push(expand_depth_row)
+        .push(relative_dates)
         .push(sort_fields)
         .push(sort_indexes)
         .push(close_tabs_on_db_close)
+        .push(reuse_unpinned_tab)
+        .push(group_connections_by_host)
+        .push(compact_sidebar)
+        .push(tree_sort_row)
+        .push(reconnect_on_query)
+        .push(confirm_close_edited_tabs)
+        .push(tab_title_row)
         .push(strict_delete_confirmation)
         .push(logging_enabled)
         .push(log_level_row)
@@ -585,12 +754,20 @@ fn appearance_tab(state: &SettingsWindowState, text_color: Color) -> Element<'_,
         text_color,
     );
 
+    let fonts_state = fonts::active_fonts();
+    let highlight_queries = Checkbox::new(state.highlight_queries)
+        .label(tr("Highlight query syntax in the editor"))
+        .font(fonts_state.primary_font)
+        .text_size(fonts_state.primary_size)
+        .on_toggle(Message::SettingsToggleHighlightQueries);
+
     Column::new()
         .spacing(16)
         .push(language_row)
         .push(primary_row)
         .push(result_row)
         .push(query_editor_row)
+        .push(highlight_queries)
         .into()
 }
 
@@ -709,6 +886,81 @@ fn color_picker_row<'a>(
         .into()
 }
 
+fn shortcuts_tab<'a>(
+    state: &'a SettingsWindowState,
+    palette: &ThemePalette,
+    text_color: Color,
+    muted_color: Color,
+) -> Element<'a, Message> {
+    let parsed: BTreeMap<ShortcutAction, KeyCombo> = state
+        .key_bindings
+        .iter()
+        .filter_map(|(action, text)| KeyCombo::parse(text).ok().map(|combo| (*action, combo)))
+        .collect();
+    let conflicts = conflicting_shortcuts(&parsed);
+    let warning_color = Color::from_rgb8(0xd9, 0x53, 0x4f);
+
+    let mut column = Column::new().spacing(12).push(
+        fonts::primary_text(
+            tr("Combine Ctrl, Shift or Alt with a key, for example Ctrl+Shift+Tab. Ctrl stands for Cmd on macOS."),
+            Some(-1.0),
+        )
+        .color(muted_color),
+    );
+
+    for (action, text) in &state.key_bindings {
+        let action = *action;
+        let note = match KeyCombo::parse(text) {
+            Err(error) => error,
+            Ok(_) => conflicts
+                .iter()
+                .find_map(|(left, right)| {
+                    if *left == action {
+                        Some(*right)
+                    } else if *right == action {
+                        Some(*left)
+                    } else {
+                        None
+                    }
+                })
+                .map(|other| tr_format("Conflicts with \"{}\".", &[tr(other.label())]))
+                .unwrap_or_default(),
+        };
+
+        let row = Row::new()
+            .spacing(12)
+            .align_y(Vertical::Center)
+            .push(
+                fonts::primary_text(tr(action.label()), None)
+                    .color(text_color)
+                    .width(Length::FillPortion(3)),
+            )
+            .push(
+                text_input("", text)
+                    .on_input(move |value| Message::SettingsKeyBindingChanged(action, value))
+                    .padding([6, 10])
+                    .width(Length::FillPortion(3)),
+            )
+            .push(
+                fonts::primary_text(note, Some(-1.0))
+                    .color(warning_color)
+                    .width(Length::FillPortion(3)),
+            );
+        column = column.push(row);
+    }
+
+    let reset_palette = palette.clone();
+    let reset_button =
+        Button::new(fonts::primary_text(tr("Default Shortcuts"), None).color(text_color))
+            .padding([6, 16])
+            .on_press(Message::SettingsKeyBindingsReset)
+            .style(move |_, status| reset_palette.subtle_button_style(6.0, status));
+
+    column
+        .push(Row::new().spacing(12).push(Space::new().width(Length::Fill)).push(reset_button))
+        .into()
+}
+
 fn bottom_actions(palette: &ThemePalette) -> Element<'static, Message> {
     let apply_palette = palette.clone();
     let cancel_palette = palette.clone();
@@ -763,7 +1015,14 @@ fn tab_buttons(active: SettingsTab) -> Row<'static, Message> {
         color_theme = color_theme.on_press(Message::SettingsTabChanged(SettingsTab::ColorTheme));
     }
 
-    Row::new().spacing(8).push(behavior).push(appearance).push(color_theme)
+    let mut shortcuts = Button::new(fonts::primary_text(tr(SettingsTab::Shortcuts.label()), None))
+        .padding([6, 16])
+        .style(move |_, _| tab_button_style(active == SettingsTab::Shortcuts));
+    if active != SettingsTab::Shortcuts {
+        shortcuts = shortcuts.on_press(Message::SettingsTabChanged(SettingsTab::Shortcuts));
+    }
+
+    Row::new().spacing(8).push(behavior).push(appearance).push(color_theme).push(shortcuts)
 }
 
 fn tab_button_style(active: bool) -> button::Style {